@@ -0,0 +1,38 @@
+//! Configuration of session and stream
+
+/// Max size of a message defined by spec
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 1024 * 1024;
+/// Default max stream count
+pub const DEFAULT_MAX_STREAM_COUNT: usize = 65535;
+/// Default upper bound of unread data of a stream
+pub const DEFAULT_MAX_STREAM_BUFFER_SIZE: usize = 4 * 1024 * 1024;
+/// Default upper bound of unread data of all streams of a session
+pub const DEFAULT_MAX_SESSION_BUFFER_SIZE: usize = 16 * 1024 * 1024;
+
+/// Configuration of session and stream
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// Messages larger than it are split on sending and rejected on receiving
+    pub max_message_size: usize,
+
+    /// Inbound streams beyond it are reset
+    pub max_stream_count: usize,
+
+    /// Mplex has no flow control, a stream whose unread data exceeds it is reset
+    pub max_stream_buffer_size: usize,
+
+    /// A stream whose message takes the unread data of all streams beyond it is reset,
+    /// so memory of a session is bounded no matter how many streams remote opens
+    pub max_session_buffer_size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            max_stream_count: DEFAULT_MAX_STREAM_COUNT,
+            max_stream_buffer_size: DEFAULT_MAX_STREAM_BUFFER_SIZE,
+            max_session_buffer_size: DEFAULT_MAX_SESSION_BUFFER_SIZE,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! The error types
+
+use std::{error, fmt};
+
+/// The error types
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// SessionShutdown is used if there is a shutdown during
+    /// an operation
+    SessionShutdown,
+
+    /// StreamsExhausted is returned if we have no more
+    /// stream ids to issue or the stream count reaches the limit
+    StreamsExhausted,
+
+    /// Sub stream send event channel full, block to complete
+    WouldBlock,
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            Error::SessionShutdown => "Session shutdown",
+            Error::StreamsExhausted => "No more stream ids to issue",
+            Error::WouldBlock => "Sub stream send channel full",
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::SessionShutdown => write!(f, "Session shutdown"),
+            Error::StreamsExhausted => write!(f, "No more stream ids to issue"),
+            Error::WouldBlock => write!(f, "Sub stream send channel full"),
+        }
+    }
+}
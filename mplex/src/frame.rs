@@ -0,0 +1,327 @@
+//! Process the frame
+
+use std::io;
+
+use bytes::{BufMut, Bytes, BytesMut};
+use log::trace;
+use tokio::codec::{Decoder, Encoder};
+
+use crate::{config::DEFAULT_MAX_MESSAGE_SIZE, StreamId};
+
+// An unsigned varint of u64 takes 10 bytes at most
+const MAX_VARINT_SIZE: usize = 10;
+
+/// The base message type is frame
+#[derive(Debug)]
+pub struct Frame {
+    stream_id: StreamId,
+    ty: Type,
+    // Whether the sender of this frame is the one opened the stream
+    from_initiator: bool,
+    body: Bytes,
+}
+
+impl Frame {
+    /// Create a new stream frame, the body is the optional stream name
+    pub fn new_stream(stream_id: StreamId, name: Bytes) -> Frame {
+        Frame {
+            stream_id,
+            ty: Type::NewStream,
+            from_initiator: true,
+            body: name,
+        }
+    }
+
+    /// Create a message frame
+    pub fn new_message(stream_id: StreamId, from_initiator: bool, body: Bytes) -> Frame {
+        Frame {
+            stream_id,
+            ty: Type::Message,
+            from_initiator,
+            body,
+        }
+    }
+
+    /// Create a close frame, half-close the stream on sender side
+    pub fn new_close(stream_id: StreamId, from_initiator: bool) -> Frame {
+        Frame {
+            stream_id,
+            ty: Type::Close,
+            from_initiator,
+            body: Bytes::new(),
+        }
+    }
+
+    /// Create a reset frame
+    pub fn new_reset(stream_id: StreamId, from_initiator: bool) -> Frame {
+        Frame {
+            stream_id,
+            ty: Type::Reset,
+            from_initiator,
+            body: Bytes::new(),
+        }
+    }
+
+    /// The type of current frame
+    pub fn ty(&self) -> Type {
+        self.ty
+    }
+
+    /// The stream id of current frame
+    pub fn stream_id(&self) -> StreamId {
+        self.stream_id
+    }
+
+    /// Whether the frame is sent by the side that opened the stream
+    pub fn from_initiator(&self) -> bool {
+        self.from_initiator
+    }
+
+    /// The length of current body
+    pub fn length(&self) -> usize {
+        self.body.len()
+    }
+
+    /// Consume current frame, return the body
+    pub fn into_body(self) -> Bytes {
+        self.body
+    }
+
+    fn flag(&self) -> u64 {
+        match (self.ty, self.from_initiator) {
+            (Type::NewStream, _) => 0,
+            (Type::Message, false) => 1,
+            (Type::Message, true) => 2,
+            (Type::Close, false) => 3,
+            (Type::Close, true) => 4,
+            (Type::Reset, false) => 5,
+            (Type::Reset, true) => 6,
+        }
+    }
+}
+
+/// The message type, the flag on the wire also tells who sends it
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Type {
+    /// Open a stream, sent by the initiator
+    NewStream,
+
+    /// Used to transmit data
+    Message,
+
+    /// Half-close the stream, the sender will send no more data
+    Close,
+
+    /// Close the stream immediately on both sides
+    Reset,
+}
+
+impl Type {
+    // Map a flag to type and whether it is sent by initiator
+    fn try_from(flag: u64) -> Option<(Type, bool)> {
+        match flag {
+            0 => Some((Type::NewStream, true)),
+            1 => Some((Type::Message, false)),
+            2 => Some((Type::Message, true)),
+            3 => Some((Type::Close, false)),
+            4 => Some((Type::Close, true)),
+            5 => Some((Type::Reset, false)),
+            6 => Some((Type::Reset, true)),
+            _ => None,
+        }
+    }
+}
+
+// Decode an unsigned varint, return the value and the bytes it takes,
+// `None` means the data is not enough
+fn decode_varint(src: &[u8]) -> Result<Option<(u64, usize)>, io::Error> {
+    let mut value: u64 = 0;
+    for (i, byte) in src.iter().enumerate().take(MAX_VARINT_SIZE) {
+        let bits = u64::from(byte & 0x7f);
+        if i == MAX_VARINT_SIZE - 1 && bits > 1 {
+            break;
+        }
+        value |= bits << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok(Some((value, i + 1)));
+        }
+    }
+    if src.len() >= MAX_VARINT_SIZE {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "mplex varint overflow",
+        ))
+    } else {
+        Ok(None)
+    }
+}
+
+fn encode_varint(mut value: u64, dst: &mut BytesMut) {
+    while value >= 0x80 {
+        dst.put((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    dst.put(value as u8);
+}
+
+/// The frame decoder/encoder
+pub(crate) struct FrameCodec {
+    max_message_size: usize,
+}
+
+impl FrameCodec {
+    pub fn max_message_size(mut self, size: usize) -> Self {
+        self.max_message_size = size;
+        self
+    }
+}
+
+impl Default for FrameCodec {
+    fn default() -> Self {
+        Self {
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+}
+
+impl Decoder for FrameCodec {
+    type Item = Frame;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let (header, header_size) = match decode_varint(&src[..])? {
+            Some(header) => header,
+            None => {
+                trace!("not enough data for decode header");
+                return Ok(None);
+            }
+        };
+        let (length, length_size) = match decode_varint(&src[header_size..])? {
+            Some(length) => length,
+            None => {
+                trace!("not enough data for decode length");
+                return Ok(None);
+            }
+        };
+        if length > self.max_message_size as u64 {
+            let err = io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("mplex.length={}", length),
+            );
+            return Err(err);
+        }
+        let (ty, from_initiator) = match Type::try_from(header & 0x07) {
+            Some(ty) => ty,
+            None => {
+                let err = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("mplex.flag={}", header & 0x07),
+                );
+                return Err(err);
+            }
+        };
+
+        let length = length as usize;
+        if src.len() < header_size + length_size + length {
+            trace!("not enough data for decode body");
+            src.reserve(header_size + length_size + length - src.len());
+            return Ok(None);
+        }
+        let _ = src.split_to(header_size + length_size);
+        let body = src.split_to(length).freeze();
+
+        Ok(Some(Frame {
+            stream_id: header >> 3,
+            ty,
+            from_initiator,
+            body,
+        }))
+    }
+}
+
+impl Encoder for FrameCodec {
+    type Item = Frame;
+    type Error = io::Error;
+    fn encode(&mut self, item: Self::Item, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        // Must ensure that there is enough space in the buf
+        dst.reserve(MAX_VARINT_SIZE * 2 + item.length());
+        encode_varint((item.stream_id << 3) | item.flag(), dst);
+        encode_varint(item.length() as u64, dst);
+        dst.put(item.body);
+        trace!("encode item: length={}", dst.len());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode_varint, encode_varint, Frame, FrameCodec, Type};
+    use bytes::{Bytes, BytesMut};
+    use tokio::codec::{Decoder, Encoder};
+
+    #[test]
+    fn test_varint() {
+        for value in &[
+            0,
+            1,
+            127,
+            128,
+            300,
+            u64::from(u32::max_value()),
+            u64::max_value(),
+        ] {
+            let mut data = BytesMut::default();
+            encode_varint(*value, &mut data);
+            assert_eq!(decode_varint(&data).unwrap(), Some((*value, data.len())));
+            assert_eq!(decode_varint(&data[..data.len() - 1]).unwrap(), None);
+        }
+
+        let mut data = BytesMut::default();
+        encode_varint(300, &mut data);
+        assert_eq!(&data[..], &[0xac, 0x02]);
+    }
+
+    #[test]
+    fn test_decode_encode() {
+        let body = Bytes::from(vec![7; 512]);
+        let mut data = BytesMut::default();
+        let mut codec = FrameCodec::default();
+
+        codec
+            .encode(Frame::new_message(300, false, body.clone()), &mut data)
+            .unwrap();
+        codec
+            .encode(Frame::new_close(300, true), &mut data)
+            .unwrap();
+
+        // Incomplete body
+        let mut partial = BytesMut::from(&data[..100]);
+        assert!(codec.decode(&mut partial).unwrap().is_none());
+
+        let frame = codec.decode(&mut data).unwrap().unwrap();
+        assert_eq!(frame.stream_id(), 300);
+        assert_eq!(frame.ty(), Type::Message);
+        assert!(!frame.from_initiator());
+        assert_eq!(frame.into_body(), body);
+
+        let frame = codec.decode(&mut data).unwrap().unwrap();
+        assert_eq!(frame.ty(), Type::Close);
+        assert!(frame.from_initiator());
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn test_reject_large_message() {
+        let mut data = BytesMut::default();
+        FrameCodec::default()
+            .encode(
+                Frame::new_message(1, true, Bytes::from(vec![0; 128])),
+                &mut data,
+            )
+            .unwrap();
+        assert!(FrameCodec::default()
+            .max_message_size(64)
+            .decode(&mut data)
+            .is_err());
+    }
+}
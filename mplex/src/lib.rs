@@ -0,0 +1,24 @@
+//! A Rust implementation of mplex
+//!
+//! Spec: https://github.com/libp2p/specs/tree/master/mplex
+
+#![deny(missing_docs)]
+
+// Config module
+pub mod config;
+// Error module
+pub mod error;
+// Frame module
+pub mod frame;
+// Session module
+pub mod session;
+// Stream module
+pub mod stream;
+
+// Stream ID type
+pub(crate) type StreamId = u64;
+// Stream id plus whether the stream is opened by local,
+// both sides allocate ids independently, so the id alone is ambiguous
+pub(crate) type StreamKey = (StreamId, bool);
+
+pub use crate::{config::Config, error::Error, session::Session, stream::StreamHandle};
@@ -0,0 +1,604 @@
+//! The session, can open and manage substreams
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use futures::{
+    future::Future,
+    sync::mpsc::{channel, Receiver, Sender},
+    Async, AsyncSink, Poll, Sink, Stream,
+};
+use log::debug;
+use tokio::codec::Framed;
+use tokio::prelude::{AsyncRead, AsyncWrite};
+use tokio::timer::Delay;
+
+use crate::{
+    config::Config,
+    error::Error,
+    frame::{Frame, FrameCodec, Type},
+    stream::{SessionBuffer, StreamEvent, StreamHandle},
+    StreamId, StreamKey,
+};
+
+const BUF_SHRINK_THRESHOLD: usize = u8::max_value() as usize;
+const DELAY_TIME: Duration = Duration::from_millis(300);
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The session
+pub struct Session<T> {
+    // Framed low level raw stream
+    framed_stream: Framed<T, FrameCodec>,
+
+    // Got EOF from low level raw stream
+    eof: bool,
+
+    // Mplex has no session level close, local shutdown
+    // resets all streams and closes the low level stream
+    local_shutdown: bool,
+
+    // Both sides allocate ids from 0 for the streams they open
+    next_stream_id: StreamId,
+    ty: SessionType,
+
+    // config holds our configuration
+    config: Config,
+
+    // streams maps a stream key to a sender of stream,
+    streams: HashMap<StreamKey, Sender<Frame>>,
+    // The StreamHandle not yet been polled
+    pending_streams: VecDeque<StreamHandle>,
+    // Unread data of all streams, bounded by `max_session_buffer_size`
+    session_buffer: SessionBuffer,
+    // Count of streams opened by remote but reset by local
+    rejected_streams: u64,
+    // The buffer which will send to underlying network
+    write_pending_frames: VecDeque<Frame>,
+    // The buffer which will distribute to sub streams
+    read_pending_frames: VecDeque<Frame>,
+
+    // For receive events from sub streams (for clone to new stream)
+    event_sender: Sender<StreamEvent>,
+    // For receive events from sub streams
+    event_receiver: Receiver<StreamEvent>,
+
+    /// Delay notify with abnormally poor network status
+    delay: Arc<AtomicBool>,
+    /// Last successful send time
+    last_send_success: Instant,
+}
+
+/// Session type, client or server
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum SessionType {
+    /// The session is a client
+    Client,
+    /// The session is a server (typical low level stream is an accepted TcpStream)
+    Server,
+}
+
+impl SessionType {
+    /// If this is a client type (inbound connection)
+    pub fn is_client(self) -> bool {
+        self == SessionType::Client
+    }
+
+    /// If this is a server type (outbound connection)
+    pub fn is_server(self) -> bool {
+        self == SessionType::Server
+    }
+}
+
+impl<T> Session<T>
+where
+    T: AsyncRead + AsyncWrite,
+{
+    /// Create a new session from a low level stream
+    pub fn new(raw_stream: T, mut config: Config, ty: SessionType) -> Session<T> {
+        config.max_message_size = ::std::cmp::max(config.max_message_size, 1);
+        let (event_sender, event_receiver) = channel(32);
+        let framed_stream = Framed::new(
+            raw_stream,
+            FrameCodec::default().max_message_size(config.max_message_size),
+        );
+
+        Session {
+            framed_stream,
+            eof: false,
+            local_shutdown: false,
+            next_stream_id: 0,
+            ty,
+            config,
+            streams: HashMap::default(),
+            pending_streams: VecDeque::default(),
+            session_buffer: SessionBuffer::new(config.max_session_buffer_size),
+            rejected_streams: 0,
+            write_pending_frames: VecDeque::default(),
+            read_pending_frames: VecDeque::default(),
+            event_sender,
+            event_receiver,
+            delay: Arc::new(AtomicBool::new(false)),
+            last_send_success: Instant::now(),
+        }
+    }
+
+    /// Create a server session (typical raw_stream is an accepted TcpStream)
+    pub fn new_server(raw_stream: T, config: Config) -> Session<T> {
+        Self::new(raw_stream, config, SessionType::Server)
+    }
+
+    /// Create a client session
+    pub fn new_client(raw_stream: T, config: Config) -> Session<T> {
+        Self::new(raw_stream, config, SessionType::Client)
+    }
+
+    /// shutdown is used to close the session and all streams.
+    /// Attempts to reset all streams before closing the connection.
+    pub fn shutdown(&mut self) -> Poll<(), io::Error> {
+        if self.is_dead() {
+            return Ok(Async::Ready(()));
+        }
+
+        // Ignore frames remaining in pending queue
+        self.write_pending_frames.clear();
+        for (id, initiator) in self.streams.keys() {
+            self.write_pending_frames
+                .push_back(Frame::new_reset(*id, *initiator));
+        }
+        // Dropping senders tells local streams the session is gone
+        self.streams.clear();
+        self.pending_streams.clear();
+        self.send_all()?;
+        self.local_shutdown = true;
+        self.framed_stream.get_mut().shutdown()
+    }
+
+    // Send all pending frames to remote streams
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.recv_events()?;
+        self.send_all()?;
+        self.distribute_to_substream()?;
+        Ok(())
+    }
+
+    fn is_dead(&self) -> bool {
+        self.local_shutdown || self.eof
+    }
+
+    /// Count of streams opened by remote that were reset because of duplicate id
+    /// or `max_stream_count`
+    pub fn rejected_streams(&self) -> u64 {
+        self.rejected_streams
+    }
+
+    /// Bytes received but not read yet by all streams
+    pub fn buffered_size(&self) -> usize {
+        self.session_buffer.used()
+    }
+
+    /// Open a new stream to remote session
+    pub fn open_stream(&mut self) -> Result<StreamHandle, Error> {
+        if self.is_dead() {
+            return Err(Error::SessionShutdown);
+        }
+        if self.streams.len() >= self.config.max_stream_count {
+            return Err(Error::StreamsExhausted);
+        }
+        let stream_id = self.next_stream_id;
+        self.next_stream_id = self
+            .next_stream_id
+            .checked_add(1)
+            .ok_or(Error::StreamsExhausted)?;
+        let stream = self.create_stream(stream_id, true);
+        // Queued before any frame of the stream, which goes through the event channel
+        self.write_pending_frames.push_back(Frame::new_stream(
+            stream_id,
+            Bytes::from(stream_id.to_string()),
+        ));
+        Ok(stream)
+    }
+
+    fn create_stream(&mut self, stream_id: StreamId, initiator: bool) -> StreamHandle {
+        let (frame_sender, frame_receiver) = channel(8);
+        self.streams
+            .entry((stream_id, initiator))
+            .or_insert(frame_sender);
+        StreamHandle::new(
+            stream_id,
+            initiator,
+            self.event_sender.clone(),
+            frame_receiver,
+            self.config.max_message_size,
+            self.config.max_stream_buffer_size,
+            self.session_buffer.clone(),
+        )
+    }
+
+    /// Sink `start_send` Ready -> data in buffer or send
+    /// Sink `start_send` NotReady -> buffer full need poll complete
+    #[inline]
+    fn send_all(&mut self) -> Poll<(), io::Error> {
+        while let Some(frame) = self.write_pending_frames.pop_front() {
+            if self.is_dead() {
+                break;
+            }
+
+            match self.framed_stream.start_send(frame) {
+                Ok(AsyncSink::NotReady(frame)) => {
+                    debug!("[{:?}] framed_stream NotReady, frame: {:?}", self.ty, frame);
+                    self.write_pending_frames.push_front(frame);
+                    // No message has been sent for 30 seconds,
+                    // we believe the connection is no longer valid
+                    if self.last_send_success.elapsed() > TIMEOUT {
+                        return Err(io::ErrorKind::TimedOut.into());
+                    }
+
+                    if !self.poll_complete()? {
+                        return Ok(Async::NotReady);
+                    }
+                }
+                Ok(AsyncSink::Ready) => {
+                    self.last_send_success = Instant::now();
+                }
+                Err(err) => {
+                    debug!("[{:?}] framed_stream error: {:?}", self.ty, err);
+                    return Err(err);
+                }
+            }
+        }
+        if self.poll_complete()? {
+            Ok(Async::Ready(()))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+
+    /// https://docs.rs/tokio/0.1.19/tokio/prelude/trait.Sink.html
+    /// Must use poll complete to ensure data send to lower-level
+    ///
+    /// Sink `poll_complete` Ready -> no buffer remain, flush all
+    /// Sink `poll_complete` NotReady -> there is more work left to do, may wake up next poll
+    fn poll_complete(&mut self) -> Result<bool, io::Error> {
+        if self.framed_stream.poll_complete()?.is_not_ready() {
+            self.set_delay();
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    fn send_frame(&mut self, frame: Frame) -> Poll<(), io::Error> {
+        debug!("[{:?}] Session::send_frame()", self.ty);
+        self.write_pending_frames.push_back(frame);
+        if let Async::NotReady = self.send_all()? {
+            return Ok(Async::NotReady);
+        }
+        debug!("[{:?}] Session::send_frame() finished", self.ty);
+        Ok(Async::Ready(()))
+    }
+
+    fn handle_frame(&mut self, frame: Frame) -> Result<(), io::Error> {
+        debug!("[{:?}] Session::handle_frame({:?})", self.ty, frame.ty());
+        match frame.ty() {
+            Type::NewStream => {
+                self.handle_new_stream(&frame)?;
+            }
+            Type::Message | Type::Close | Type::Reset => {
+                self.read_pending_frames.push_back(frame);
+                self.distribute_to_substream()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_new_stream(&mut self, frame: &Frame) -> Result<(), io::Error> {
+        let stream_id = frame.stream_id();
+        if self.streams.contains_key(&(stream_id, false))
+            || self.streams.len() >= self.config.max_stream_count
+        {
+            debug!(
+                "[{:?}] reject a stream id={}, streams: {}",
+                self.ty,
+                stream_id,
+                self.streams.len()
+            );
+            self.rejected_streams += 1;
+            self.send_frame(Frame::new_reset(stream_id, false))?;
+            return Ok(());
+        }
+        debug!("[{:?}] Accept a stream id={}", self.ty, stream_id);
+        let stream = self.create_stream(stream_id, false);
+        self.pending_streams.push_back(stream);
+        Ok(())
+    }
+
+    /// Try send buffer to all sub streams
+    fn distribute_to_substream(&mut self) -> Result<(), io::Error> {
+        let mut block_substream = HashSet::new();
+
+        for frame in self.read_pending_frames.split_off(0) {
+            // The stream is opened by local if the frame comes from receiver
+            let key = (frame.stream_id(), !frame.from_initiator());
+            // Guarantee the order in which messages are sent
+            if block_substream.contains(&key) {
+                self.read_pending_frames.push_back(frame);
+                continue;
+            }
+            let disconnected = {
+                if let Some(frame_sender) = self.streams.get_mut(&key) {
+                    debug!("@> sending frame to stream: {:?}", key);
+                    match frame_sender.try_send(frame) {
+                        Ok(_) => false,
+                        Err(err) => {
+                            if err.is_full() {
+                                self.read_pending_frames.push_back(err.into_inner());
+                                self.set_delay();
+                                block_substream.insert(key);
+                                false
+                            } else {
+                                debug!("send to stream error: {:?}", err);
+                                true
+                            }
+                        }
+                    }
+                } else {
+                    // Stream already closed
+                    false
+                }
+            };
+            if disconnected {
+                debug!("[{:?}] remove a stream {:?}", self.ty, key);
+                self.streams.remove(&key);
+            }
+        }
+
+        if self.read_pending_frames.capacity() > BUF_SHRINK_THRESHOLD {
+            self.read_pending_frames.shrink_to_fit();
+        }
+
+        Ok(())
+    }
+
+    // Receive frames from low level stream
+    fn recv_frames(&mut self) -> Poll<(), io::Error> {
+        for _ in 0..64 {
+            if self.is_dead() {
+                return Ok(Async::Ready(()));
+            }
+
+            debug!("[{:?}] poll from framed_stream", self.ty);
+            match self.framed_stream.poll() {
+                Ok(Async::Ready(Some(frame))) => {
+                    self.handle_frame(frame)?;
+                }
+                Ok(Async::Ready(None)) => {
+                    self.eof = true;
+                }
+                Ok(Async::NotReady) => {
+                    debug!("[{:?}] poll framed_stream NotReady", self.ty);
+                    return Ok(Async::NotReady);
+                }
+                Err(err) => {
+                    debug!("[{:?}] Session recv_frames error: {:?}", self.ty, err);
+                    return Err(err);
+                }
+            }
+        }
+        self.set_delay();
+        Ok(Async::NotReady)
+    }
+
+    fn handle_event(&mut self, event: StreamEvent) -> Result<(), io::Error> {
+        debug!("[{:?}] Session::handle_event()", self.ty);
+        match event {
+            StreamEvent::Frame(frame) => {
+                self.send_frame(frame)?;
+            }
+            StreamEvent::Closed(key) => {
+                self.streams.remove(&key);
+            }
+            StreamEvent::Flush(key) => {
+                debug!("[{:?}] session flushing.....", key);
+                self.flush()?;
+                debug!("[{:?}] session flushed", key);
+            }
+        }
+        Ok(())
+    }
+
+    // Receive events from sub streams
+    fn recv_events(&mut self) -> Poll<(), io::Error> {
+        for _ in 0..64 {
+            if self.is_dead() {
+                return Ok(Async::Ready(()));
+            }
+
+            match self.event_receiver.poll() {
+                Ok(Async::Ready(Some(event))) => self.handle_event(event)?,
+                Ok(Async::Ready(None)) => {
+                    // Since session hold one event sender,
+                    // the channel can not be disconnected.
+                    unreachable!()
+                }
+                Ok(Async::NotReady) => {
+                    return Ok(Async::NotReady);
+                }
+                Err(()) => {
+                    // TODO: When would happend?
+                }
+            }
+        }
+        self.set_delay();
+        Ok(Async::NotReady)
+    }
+
+    #[inline]
+    fn set_delay(&mut self) {
+        // Why use `delay` instead of `notify`?
+        //
+        // In fact, on machines that can use multi-core normally, there is almost no problem with the `notify` behavior,
+        // and even the efficiency will be higher.
+        //
+        // However, if you are on a single-core bully machine, `notify` may have a very amazing starvation behavior.
+        //
+        // Under a single-core machine, `notify` may fall into the loop of infinitely preemptive CPU, causing starvation.
+        if !self.delay.load(Ordering::Acquire) {
+            self.delay.store(true, Ordering::Release);
+            let notify = futures::task::current();
+            let delay = self.delay.clone();
+            let delay_task = Delay::new(Instant::now() + DELAY_TIME).then(move |_| {
+                notify.notify();
+                delay.store(false, Ordering::Release);
+                Ok(())
+            });
+            tokio::spawn(delay_task);
+        }
+    }
+}
+
+impl<T> Stream for Session<T>
+where
+    T: AsyncRead + AsyncWrite,
+{
+    type Item = StreamHandle;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if self.is_dead() {
+            debug!("mplex::Session finished because is_dead");
+            return Ok(Async::Ready(None));
+        }
+
+        if !self.read_pending_frames.is_empty() || !self.write_pending_frames.is_empty() {
+            self.flush()?;
+        }
+
+        self.poll_complete()?;
+
+        debug!(
+            "send buf: {}, read buf: {}",
+            self.write_pending_frames.len(),
+            self.read_pending_frames.len()
+        );
+
+        self.recv_frames()?;
+        self.recv_events()?;
+
+        if self.is_dead() {
+            debug!("mplex::Session finished because is_dead, end");
+            return Ok(Async::Ready(None));
+        } else if let Some(stream) = self.pending_streams.pop_front() {
+            debug!("[{:?}] A stream is ready", self.ty);
+            return Ok(Async::Ready(Some(stream)));
+        }
+
+        Ok(Async::NotReady)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Session;
+    use crate::config::Config;
+    use futures::{future, Future, Stream};
+    use std::io;
+    use tokio::{
+        net::{TcpListener, TcpStream},
+        prelude::AsyncRead,
+    };
+
+    #[test]
+    fn test_open_stream_and_echo() {
+        let listener = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = listener
+            .incoming()
+            .into_future()
+            .map_err(|(err, _)| err)
+            .and_then(|(socket, _)| {
+                Session::new_server(socket.unwrap(), Config::default())
+                    .for_each(|stream| {
+                        let (reader, writer) = stream.split();
+                        tokio::spawn(tokio::io::copy(reader, writer).map(|_| ()).map_err(|_| ()));
+                        Ok(())
+                    })
+                    .or_else(|_| Ok(()))
+            });
+
+        let client = TcpStream::connect(&addr).and_then(|socket| {
+            let mut session = Session::new_client(socket, Config::default());
+            let stream = session.open_stream().unwrap();
+            tokio::spawn(session.for_each(|_| Ok(())).map_err(|_| ()));
+            tokio::io::write_all(stream, b"hello mplex")
+                .and_then(|(stream, _)| tokio::io::read_exact(stream, [0; 11]))
+                .and_then(|(_, buf)| {
+                    assert_eq!(&buf, b"hello mplex");
+                    Ok(())
+                })
+        });
+
+        let mut rt = tokio::runtime::Runtime::new().unwrap();
+        rt.spawn(server.map_err(|_: io::Error| ()));
+        rt.block_on(client).unwrap();
+        rt.shutdown_now().wait().unwrap();
+    }
+
+    #[test]
+    fn test_session_buffer_limit() {
+        let listener = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let config = Config {
+            max_session_buffer_size: 1024,
+            ..Config::default()
+        };
+        let server = listener
+            .incoming()
+            .into_future()
+            .map_err(|(err, _)| err)
+            .and_then(move |(socket, _)| {
+                Session::new_server(socket.unwrap(), config)
+                    .for_each(|stream| {
+                        // Read a little and keep the rest buffered
+                        let task = tokio::io::read_exact(stream, [0; 1])
+                            .and_then(|(stream, _)| future::empty().map(move |()| drop(stream)))
+                            .map_err(|_| ());
+                        tokio::spawn(task);
+                        Ok(())
+                    })
+                    .or_else(|_| Ok(()))
+            });
+
+        let client = TcpStream::connect(&addr).and_then(|socket| {
+            let mut session = Session::new_client(socket, Config::default());
+            let first = session.open_stream().unwrap();
+            let second = session.open_stream().unwrap();
+            tokio::spawn(session.for_each(|_| Ok(())).map_err(|_| ()));
+            // Each fits in a stream, both don't fit in the session
+            let first = tokio::io::write_all(first, vec![1; 800])
+                .and_then(|(stream, _)| tokio::io::read_exact(stream, [0; 1]));
+            let second = tokio::io::write_all(second, vec![1; 800])
+                .and_then(|(stream, _)| tokio::io::read_exact(stream, [0; 1]));
+            first.select2(second).then(|result| match result {
+                Err(future::Either::A((err, _))) | Err(future::Either::B((err, _))) => {
+                    assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
+                    Ok(())
+                }
+                Ok(_) => panic!("server never writes"),
+            })
+        });
+
+        let mut rt = tokio::runtime::Runtime::new().unwrap();
+        rt.spawn(server.map_err(|_: io::Error| ()));
+        rt.block_on(client.map_err(|_: io::Error| ())).unwrap();
+        rt.shutdown_now().wait().unwrap();
+    }
+}
@@ -0,0 +1,415 @@
+//! The substream, the main interface is AsyncRead/AsyncWrite
+
+use std::io;
+
+use bytes::{Bytes, BytesMut};
+use futures::{
+    sync::mpsc::{Receiver, Sender},
+    Async, Future, Poll, Stream,
+};
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::debug;
+use tokio::prelude::{AsyncRead, AsyncWrite};
+use tokio::timer::Delay;
+
+use crate::{
+    error::Error,
+    frame::{Frame, Type},
+    StreamId, StreamKey,
+};
+
+/// The substream
+#[derive(Debug)]
+pub struct StreamHandle {
+    id: StreamId,
+    // Opened by local
+    initiator: bool,
+    state: StreamState,
+
+    max_message_size: usize,
+    max_buffer_size: usize,
+    read_buf: BytesMut,
+    // Unread data of all streams of the session
+    session_buffer: SessionBuffer,
+
+    // Send stream event to parent session
+    event_sender: Sender<StreamEvent>,
+
+    // Receive frame of current stream from parent session
+    // (if the sender closed means session closed the stream should close too)
+    frame_receiver: Receiver<Frame>,
+
+    delay: Arc<AtomicBool>,
+
+    // Close local side once remote closed and all data is read
+    auto_close: bool,
+}
+
+impl StreamHandle {
+    // Create a StreamHandle from session
+    pub(crate) fn new(
+        id: StreamId,
+        initiator: bool,
+        event_sender: Sender<StreamEvent>,
+        frame_receiver: Receiver<Frame>,
+        max_message_size: usize,
+        max_buffer_size: usize,
+        session_buffer: SessionBuffer,
+    ) -> StreamHandle {
+        StreamHandle {
+            id,
+            initiator,
+            state: StreamState::Open,
+            max_message_size,
+            max_buffer_size,
+            read_buf: BytesMut::default(),
+            session_buffer,
+            event_sender,
+            frame_receiver,
+            delay: Arc::new(AtomicBool::new(false)),
+            auto_close: true,
+        }
+    }
+
+    /// Get the stream id, ids of streams opened by local and remote may be the same
+    pub fn id(&self) -> StreamId {
+        self.id
+    }
+    /// Whether the stream is opened by local
+    pub fn is_initiator(&self) -> bool {
+        self.initiator
+    }
+    /// Get the stream state
+    pub fn state(&self) -> StreamState {
+        self.state
+    }
+
+    /// Whether to close local side once remote closed and all data is read, default is true
+    ///
+    /// Disable it to keep writing after remote finished sending (half-close),
+    /// local side is closed by `shutdown`.
+    pub fn set_auto_close(&mut self, enable: bool) {
+        self.auto_close = enable;
+    }
+
+    fn key(&self) -> StreamKey {
+        (self.id, self.initiator)
+    }
+
+    fn close(&mut self) -> Result<(), Error> {
+        match self.state {
+            StreamState::Open => {
+                self.send_frame(Frame::new_close(self.id, self.initiator))?;
+                self.state = StreamState::LocalClosed;
+            }
+            StreamState::RemoteClosed => {
+                self.send_frame(Frame::new_close(self.id, self.initiator))?;
+                self.state = StreamState::Closed;
+                self.send_event(StreamEvent::Closed(self.key()))?;
+            }
+            StreamState::Reset | StreamState::Closed => {
+                self.state = StreamState::Closed;
+                self.send_event(StreamEvent::Closed(self.key()))?;
+            }
+            StreamState::LocalClosed => {}
+        }
+        Ok(())
+    }
+
+    /// Reset the stream, remote gets a reset and no more data is read or written on both sides.
+    ///
+    /// Best effort, the reset is lost if the session is busy.
+    pub fn reset(&mut self) {
+        // May be called on drop, out of any task
+        debug!("[{}] StreamHandle reset", self.id);
+        self.clear_read_buf();
+        self.state = StreamState::Reset;
+        let frame = Frame::new_reset(self.id, self.initiator);
+        let _ = self.event_sender.try_send(StreamEvent::Frame(frame));
+        let _ = self.event_sender.try_send(StreamEvent::Closed(self.key()));
+    }
+
+    fn clear_read_buf(&mut self) {
+        self.session_buffer.release(self.read_buf.len());
+        self.read_buf.clear();
+    }
+
+    #[inline]
+    fn send_event(&mut self, event: StreamEvent) -> Result<(), Error> {
+        debug!("[{}] StreamHandle.send_event()", self.id);
+        if let Err(e) = self.event_sender.try_send(event) {
+            if e.is_full() {
+                self.set_delay();
+                return Err(Error::WouldBlock);
+            } else {
+                return Err(Error::SessionShutdown);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn send_frame(&mut self, frame: Frame) -> Result<(), Error> {
+        let event = StreamEvent::Frame(frame);
+        self.send_event(event)
+    }
+
+    fn handle_frame(&mut self, frame: Frame) {
+        debug!("[{}] StreamHandle.handle_frame({:?})", self.id, frame.ty());
+        match frame.ty() {
+            Type::Message => match self.state {
+                StreamState::Open | StreamState::LocalClosed => {
+                    if self.read_buf.len() + frame.length() > self.max_buffer_size
+                        || !self.session_buffer.try_acquire(frame.length())
+                    {
+                        // No flow control in mplex, the only way to stop remote is reset
+                        self.reset();
+                    } else {
+                        self.read_buf.extend_from_slice(&frame.into_body());
+                    }
+                }
+                // Remote broke the protocol, just ignore it
+                _ => (),
+            },
+            Type::Close => match self.state {
+                StreamState::Open => self.state = StreamState::RemoteClosed,
+                StreamState::LocalClosed => {
+                    self.state = StreamState::Closed;
+                    let _ = self.send_event(StreamEvent::Closed(self.key()));
+                }
+                _ => (),
+            },
+            Type::Reset => {
+                self.clear_read_buf();
+                self.state = StreamState::Reset;
+                let _ = self.send_event(StreamEvent::Closed(self.key()));
+            }
+            Type::NewStream => (),
+        }
+    }
+
+    fn recv_frames(&mut self) {
+        for _ in 0..64 {
+            match self.state {
+                StreamState::RemoteClosed | StreamState::Reset | StreamState::Closed => return,
+                _ => {}
+            }
+
+            match self.frame_receiver.poll() {
+                Ok(Async::Ready(Some(frame))) => self.handle_frame(frame),
+                Ok(Async::Ready(None)) | Err(()) => {
+                    // Session is gone
+                    self.state = StreamState::Reset;
+                    return;
+                }
+                Ok(Async::NotReady) => return,
+            }
+        }
+        self.set_delay();
+    }
+
+    fn set_delay(&mut self) {
+        // Why use `delay` instead of `notify`?
+        //
+        // In fact, on machines that can use multi-core normally, there is almost no problem with the `notify` behavior,
+        // and even the efficiency will be higher.
+        //
+        // However, if you are on a single-core bully machine, `notify` may have a very amazing starvation behavior.
+        //
+        // Under a single-core machine, `notify` may fall into the loop of infinitely preemptive CPU, causing starvation.
+        if !self.delay.load(Ordering::Acquire) {
+            self.delay.store(true, Ordering::Release);
+            let notify = futures::task::current();
+            let delay = self.delay.clone();
+            let delay_task =
+                Delay::new(Instant::now() + Duration::from_millis(200)).then(move |_| {
+                    notify.notify();
+                    delay.store(false, Ordering::Release);
+                    Ok(())
+                });
+            tokio::spawn(delay_task);
+        }
+    }
+}
+
+impl io::Read for StreamHandle {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.recv_frames();
+
+        if self.read_buf.is_empty() {
+            return match self.state {
+                StreamState::RemoteClosed if self.auto_close => {
+                    debug!("closed(EOF)");
+                    match self.close() {
+                        Err(Error::WouldBlock) => Err(io::ErrorKind::WouldBlock.into()),
+                        _ => Err(io::ErrorKind::UnexpectedEof.into()),
+                    }
+                }
+                StreamState::RemoteClosed => {
+                    debug!("remote closed(EOF)");
+                    Err(io::ErrorKind::UnexpectedEof.into())
+                }
+                StreamState::Closed => {
+                    debug!("closed(EOF)");
+                    Err(io::ErrorKind::UnexpectedEof.into())
+                }
+                StreamState::Reset => {
+                    debug!("connection reset");
+                    Err(io::ErrorKind::ConnectionReset.into())
+                }
+                _ => Err(io::ErrorKind::WouldBlock.into()),
+            };
+        }
+
+        let n = ::std::cmp::min(buf.len(), self.read_buf.len());
+        let b = self.read_buf.split_to(n);
+        self.session_buffer.release(n);
+        debug!(
+            "[{}] StreamHandle.read({}), buf.len()={}, read_buf.len()={}",
+            self.id,
+            n,
+            buf.len(),
+            self.read_buf.len()
+        );
+        buf[..n].copy_from_slice(&b);
+
+        Ok(n)
+    }
+}
+
+impl io::Write for StreamHandle {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        debug!("[{}] StreamHandle.write({:?})", self.id, buf.len());
+        self.recv_frames();
+        match self.state {
+            StreamState::LocalClosed | StreamState::Closed => {
+                return Err(io::Error::new(
+                    io::ErrorKind::BrokenPipe,
+                    "The local is closed and data cannot be written.",
+                ));
+            }
+            StreamState::Reset => return Err(io::ErrorKind::ConnectionReset.into()),
+            _ => (),
+        }
+
+        let mut written = 0;
+        for chunk in buf.chunks(self.max_message_size) {
+            let frame = Frame::new_message(self.id, self.initiator, Bytes::from(chunk));
+            match self.send_frame(frame) {
+                Ok(_) => written += chunk.len(),
+                // Part of the data has been sent
+                Err(Error::WouldBlock) if written > 0 => break,
+                Err(Error::WouldBlock) => return Err(io::ErrorKind::WouldBlock.into()),
+                Err(_) => return Err(io::ErrorKind::BrokenPipe.into()),
+            }
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        debug!("[{}] StreamHandle.flush()", self.id);
+        let event = StreamEvent::Flush(self.key());
+        match self.send_event(event) {
+            Err(Error::WouldBlock) => Err(io::ErrorKind::WouldBlock.into()),
+            Err(_) => Err(io::ErrorKind::BrokenPipe.into()),
+            Ok(()) => Ok(()),
+        }
+    }
+}
+
+impl AsyncRead for StreamHandle {}
+
+impl AsyncWrite for StreamHandle {
+    fn shutdown(&mut self) -> Poll<(), io::Error> {
+        debug!("[{}] StreamHandle.shutdown()", self.id);
+        match self.close() {
+            Err(Error::WouldBlock) => Err(io::ErrorKind::WouldBlock.into()),
+            Err(_) => Err(io::ErrorKind::BrokenPipe.into()),
+            Ok(()) => Ok(Async::Ready(())),
+        }
+    }
+}
+
+impl Drop for StreamHandle {
+    fn drop(&mut self) {
+        // Mplex has no way to tell remote the stream is gone except reset
+        match self.state {
+            StreamState::Open | StreamState::LocalClosed | StreamState::RemoteClosed => {
+                self.reset()
+            }
+            StreamState::Reset | StreamState::Closed => (),
+        }
+        self.clear_read_buf();
+    }
+}
+
+/// Unread data of all streams of a session
+#[derive(Clone, Debug)]
+pub(crate) struct SessionBuffer {
+    used: Arc<AtomicUsize>,
+    limit: usize,
+}
+
+impl SessionBuffer {
+    pub(crate) fn new(limit: usize) -> Self {
+        SessionBuffer {
+            used: Arc::new(AtomicUsize::new(0)),
+            limit,
+        }
+    }
+
+    pub(crate) fn used(&self) -> usize {
+        self.used.load(Ordering::Acquire)
+    }
+
+    // Acquire only if it stays within the limit
+    fn try_acquire(&self, size: usize) -> bool {
+        let mut used = self.used();
+        loop {
+            if used + size > self.limit {
+                return false;
+            }
+            match self
+                .used
+                .compare_exchange(used, used + size, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => return true,
+                Err(current) => used = current,
+            }
+        }
+    }
+
+    fn release(&self, size: usize) {
+        self.used.fetch_sub(size, Ordering::AcqRel);
+    }
+}
+
+// Stream event
+#[derive(Debug)]
+pub(crate) enum StreamEvent {
+    Frame(Frame),
+    Closed(StreamKey),
+    // Flush stream's frames to remote stream
+    Flush(StreamKey),
+}
+
+/// The stream state
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StreamState {
+    /// Both sides can send data
+    Open,
+    /// We closed the stream, remote can still send data
+    LocalClosed,
+    /// Remote closed the stream, we can still send data
+    RemoteClosed,
+    /// Both side of the stream closed
+    Closed,
+    /// Stream reset by either side
+    Reset,
+}
@@ -0,0 +1,74 @@
+use env_logger;
+use log::{debug, info};
+
+use std::time::{Duration, Instant};
+
+use futures::{future::lazy, prelude::*};
+use p2p::{
+    builder::{MetaBuilder, ServiceBuilder},
+    context::ServiceContext,
+    secio::SecioKeyPair,
+    service::{DialProtocol, ProtocolHandle, ProtocolMeta, ServiceError, ServiceEvent},
+    traits::ServiceHandle,
+    ProtocolId,
+};
+use tentacle_autonat::{AutoNatProtocol, Config};
+use tokio::timer::Interval;
+
+fn main() {
+    env_logger::init();
+    // A single peer is enough to decide in this example
+    let config = Config {
+        confidence: 1,
+        ..Default::default()
+    };
+    let mut service = ServiceBuilder::default()
+        .insert_protocol(create_meta(1.into(), AutoNatProtocol::new(config)))
+        .key_pair(SecioKeyPair::secp256k1_generated())
+        .forever(true)
+        .build(SimpleHandler {});
+
+    if std::env::args().nth(1) == Some("server".to_string()) {
+        debug!("Starting server ......");
+        let _ = service.listen("/ip4/127.0.0.1/tcp/1337".parse().unwrap());
+    } else {
+        debug!("Starting client ......");
+        let _ = service.dial(
+            "/ip4/127.0.0.1/tcp/1337".parse().unwrap(),
+            DialProtocol::All,
+        );
+        let _ = service.listen("/ip4/127.0.0.1/tcp/1338".parse().unwrap());
+    }
+
+    let control = service.control().clone();
+    tokio::run(lazy(move || {
+        tokio::spawn(
+            Interval::new(Instant::now(), Duration::from_secs(3))
+                .map_err(|_| ())
+                .for_each(move |_| {
+                    info!("reachability: {:?}", control.reachability());
+                    Ok(())
+                }),
+        );
+        service.for_each(|_| Ok(()))
+    }))
+}
+
+fn create_meta(id: ProtocolId, autonat: AutoNatProtocol) -> ProtocolMeta {
+    MetaBuilder::new()
+        .id(id)
+        .service_handle(move || ProtocolHandle::Callback(Box::new(autonat)))
+        .build()
+}
+
+struct SimpleHandler {}
+
+impl ServiceHandle for SimpleHandler {
+    fn handle_error(&mut self, _env: &mut ServiceContext, error: ServiceError) {
+        debug!("service error: {:?}", error);
+    }
+
+    fn handle_event(&mut self, _env: &mut ServiceContext, event: ServiceEvent) {
+        debug!("service event: {:?}", event);
+    }
+}
@@ -0,0 +1,704 @@
+#[cfg(feature = "flatc")]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+#[allow(dead_code)]
+mod protocol_generated;
+#[cfg(feature = "flatc")]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+#[allow(dead_code)]
+mod protocol_generated_verifier;
+#[cfg(feature = "molc")]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+#[allow(dead_code)]
+mod protocol_mol;
+
+mod protocol;
+
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::{future, Future};
+use log::{debug, error, info, warn};
+use p2p::{
+    context::{ProtocolContext, ProtocolContextMutRef},
+    multiaddr::{Multiaddr, Protocol},
+    secio::{handshake::Config as SecioConfig, pnet::PreSharedKey, PeerId, SecioKeyPair},
+    service::Reachability,
+    traits::ServiceProtocol,
+    utils::{is_reachable, is_ws, multiaddr_to_socketaddr, netgroup},
+    SessionId,
+};
+use rand::seq::SliceRandom;
+use tokio::{net::TcpStream, timer::Timeout};
+
+pub use protocol::AutoNatMessage;
+
+const PROBE_TOKEN: u64 = 0;
+// Candidates of a request beyond this are ignored
+const MAX_CANDIDATES: usize = 8;
+// A peer is dialed back at most once per this, other requests are refused
+const SERVE_INTERVAL: Duration = Duration::from_secs(30);
+// A request without response is given up after this
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// AutoNAT parameters
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Default is 60 seconds, the first probe is sent on connection while the reachability
+    /// is unknown
+    pub probe_interval: Duration,
+    /// Number of peers asked to dial back at each probe, default is 3
+    pub probe_peers: usize,
+    /// Number of peers of distinct netgroups (ipv4 /16, ipv6 /32) agreeing on a reachability
+    /// before it is adopted, default is 2
+    pub confidence: usize,
+    /// Results of peers are forgotten after this, default is 30 minutes
+    pub result_ttl: Duration,
+    /// Timeout of dialing back a peer, default is 10 seconds
+    pub dial_timeout: Duration,
+    /// Whether to dial back peers asking for it, default is true
+    pub serve: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            probe_interval: Duration::from_secs(60),
+            probe_peers: 3,
+            confidence: 2,
+            result_ttl: Duration::from_secs(30 * 60),
+            dial_timeout: Duration::from_secs(10),
+            serve: true,
+        }
+    }
+}
+
+/// Handle to add candidate addresses found outside of the service, such as the external
+/// addresses confirmed by identify
+#[derive(Clone, Default)]
+pub struct AutoNatHandle {
+    candidates: Arc<Mutex<HashSet<Multiaddr>>>,
+}
+
+impl AutoNatHandle {
+    /// Probe this address too
+    pub fn add_candidate(&self, addr: Multiaddr) {
+        self.candidates
+            .lock()
+            .expect("candidates lock poisoned")
+            .insert(addr);
+    }
+
+    /// Stop probing this address
+    pub fn remove_candidate(&self, addr: &Multiaddr) {
+        self.candidates
+            .lock()
+            .expect("candidates lock poisoned")
+            .remove(addr);
+    }
+
+    fn candidates(&self) -> Vec<Multiaddr> {
+        self.candidates
+            .lock()
+            .expect("candidates lock poisoned")
+            .iter()
+            .cloned()
+            .collect()
+    }
+}
+
+struct RemoteInfo {
+    peer_id: PeerId,
+    peer_ip: Option<IpAddr>,
+    // Time of the request waiting for a response
+    requested_at: Option<Instant>,
+    // Candidates of the last request, only they may be in the response
+    requested: Vec<Multiaddr>,
+}
+
+struct ProbeResult {
+    reachable: bool,
+    received_at: Instant,
+}
+
+/// AutoNAT protocol, probes the reachability of the service by asking peers to dial it back
+///
+/// The reachability is exposed by `ServiceContext::reachability`. Peers only dial back
+/// addresses on the ip they see the requester on, so that nobody can make them dial a third
+/// party. Addresses on an unspecified or non-public ip are sent with an unspecified ip, peers
+/// dial back the port on the ip they see, which covers ports mapped by UPnP.
+pub struct AutoNatProtocol {
+    config: Config,
+    handle: AutoNatHandle,
+    remote_infos: HashMap<SessionId, RemoteInfo>,
+    // Latest result of each netgroup of peers, so that many peers of one operator count once
+    results: HashMap<Vec<u8>, ProbeResult>,
+    // Peers dialed back recently
+    served: HashMap<PeerId, Instant>,
+}
+
+impl AutoNatProtocol {
+    pub fn new(config: Config) -> Self {
+        AutoNatProtocol {
+            config,
+            handle: AutoNatHandle::default(),
+            remote_infos: HashMap::default(),
+            results: HashMap::default(),
+            served: HashMap::default(),
+        }
+    }
+
+    pub fn handle(&self) -> AutoNatHandle {
+        self.handle.clone()
+    }
+
+    fn candidates(&self, context: &ProtocolContext) -> Vec<Multiaddr> {
+        let mut candidates = Vec::new();
+        let addrs = context
+            .listens()
+            .iter()
+            .map(unspecify_private)
+            .chain(self.handle.candidates());
+        for addr in addrs {
+            if !is_ws(&addr)
+                && multiaddr_to_socketaddr(&addr).is_some()
+                && !candidates.contains(&addr)
+            {
+                candidates.push(addr);
+            }
+        }
+        candidates.truncate(MAX_CANDIDATES);
+        candidates
+    }
+
+    fn request(&mut self, context: &ProtocolContext, session_id: SessionId) {
+        let candidates = self.candidates(context);
+        if candidates.is_empty() {
+            return;
+        }
+        if let Some(info) = self.remote_infos.get_mut(&session_id) {
+            debug!("ask {:?} to dial back {:?}", info.peer_id, candidates);
+            let data = AutoNatMessage::DialRequest {
+                addresses: candidates.clone(),
+            }
+            .encode();
+            let _ = context.send_message_to(session_id, context.proto_id, data);
+            info.requested_at = Some(Instant::now());
+            info.requested = candidates;
+        }
+    }
+
+    fn probe(&mut self, context: &ProtocolContext) {
+        let now = Instant::now();
+        let mut idle = self
+            .remote_infos
+            .iter()
+            .filter(|(_, info)| {
+                info.requested_at
+                    .map(|at| now - at >= RESPONSE_TIMEOUT)
+                    .unwrap_or(true)
+            })
+            .map(|(session_id, info)| {
+                let received_at = info
+                    .peer_ip
+                    .and_then(|ip| self.results.get(&netgroup(ip)))
+                    .map(|r| r.received_at);
+                (*session_id, received_at)
+            })
+            .collect::<Vec<_>>();
+        // Peers of netgroups without a result first, then the ones with the oldest result
+        idle.shuffle(&mut rand::thread_rng());
+        idle.sort_by_key(|(_, received_at)| *received_at);
+        for (session_id, _) in idle.into_iter().take(self.config.probe_peers) {
+            self.request(context, session_id);
+        }
+    }
+
+    fn update_reachability(&mut self, context: &ProtocolContext) {
+        let result_ttl = self.config.result_ttl;
+        self.results
+            .retain(|_, result| result.received_at.elapsed() < result_ttl);
+
+        let public = self.results.values().filter(|r| r.reachable).count();
+        let private = self.results.len() - public;
+        // One peer reaching us is a proof, as long as enough peers agree
+        let reachability = if public >= self.config.confidence {
+            Reachability::Public
+        } else if private >= self.config.confidence {
+            Reachability::Private
+        } else {
+            Reachability::Unknown
+        };
+        if reachability != context.reachability() {
+            info!("reachability changes to {:?}", reachability);
+            context.set_reachability(reachability);
+        }
+    }
+
+    fn received_response(
+        &mut self,
+        context: &ProtocolContext,
+        session_id: SessionId,
+        reachable: Vec<Multiaddr>,
+        unreachable: Vec<Multiaddr>,
+    ) {
+        if self.record_result(session_id, reachable, unreachable) {
+            self.update_reachability(context);
+        }
+    }
+
+    // Keep the result of a response as the one of the netgroup of the peer, return false if
+    // the response has no result
+    fn record_result(
+        &mut self,
+        session_id: SessionId,
+        reachable: Vec<Multiaddr>,
+        unreachable: Vec<Multiaddr>,
+    ) -> bool {
+        let info = match self.remote_infos.get_mut(&session_id) {
+            Some(info) => info,
+            None => return false,
+        };
+        if info.requested_at.take().is_none() {
+            debug!("unrequested dial back response from {:?}", info.peer_id);
+            return false;
+        }
+        // Addresses which were not requested can't tell anything about us
+        let requested = std::mem::replace(&mut info.requested, Vec::new());
+        let reachable = reachable
+            .into_iter()
+            .filter(|addr| requested.contains(addr))
+            .collect::<Vec<_>>();
+        let unreachable = unreachable
+            .into_iter()
+            .filter(|addr| requested.contains(addr))
+            .collect::<Vec<_>>();
+        debug!(
+            "{:?} dialed back, reachable: {:?}, unreachable: {:?}",
+            info.peer_id, reachable, unreachable
+        );
+        // A peer refusing every address has no opinion
+        if reachable.is_empty() && unreachable.is_empty() {
+            return false;
+        }
+        let group = match info.peer_ip {
+            Some(ip) => netgroup(ip),
+            None => return false,
+        };
+        self.results.insert(
+            group,
+            ProbeResult {
+                reachable: !reachable.is_empty(),
+                received_at: Instant::now(),
+            },
+        );
+        true
+    }
+
+    // Addresses of the request the peer can be dialed back on, empty if refused
+    fn dial_back_targets(
+        &mut self,
+        session_id: SessionId,
+        addresses: Vec<Multiaddr>,
+    ) -> Vec<(Multiaddr, SocketAddr)> {
+        let info = match self.remote_infos.get(&session_id) {
+            Some(info) => info,
+            None => return Vec::new(),
+        };
+        let peer_ip = match info.peer_ip {
+            Some(peer_ip) if self.config.serve => peer_ip,
+            _ => return Vec::new(),
+        };
+        if self
+            .served
+            .get(&info.peer_id)
+            .map(|at| at.elapsed() < SERVE_INTERVAL)
+            .unwrap_or(false)
+        {
+            debug!("refuse to dial back {:?} again so soon", info.peer_id);
+            return Vec::new();
+        }
+
+        let targets = addresses
+            .into_iter()
+            .take(MAX_CANDIDATES)
+            .filter_map(|addr| {
+                let socket_addr = dial_back_addr(&addr, peer_ip)?;
+                Some((addr, socket_addr))
+            })
+            .collect::<Vec<_>>();
+        // A request without any address to dial back does not use up the interval
+        if !targets.is_empty() {
+            self.served.insert(info.peer_id.clone(), Instant::now());
+        }
+        targets
+    }
+
+    fn serve(&mut self, context: &ProtocolContextMutRef, addresses: Vec<Multiaddr>) {
+        let session_id = context.session.id;
+        let proto_id = context.proto_id;
+        let targets = self.dial_back_targets(session_id, addresses);
+        let (key_pair, peer_id) = match (context.key_pair(), self.remote_infos.get(&session_id)) {
+            (Some(key_pair), Some(info)) if !targets.is_empty() => {
+                (key_pair.clone(), info.peer_id.clone())
+            }
+            _ => {
+                let data = AutoNatMessage::DialResponse {
+                    reachable: Vec::new(),
+                    unreachable: Vec::new(),
+                }
+                .encode();
+                let _ = context.send_message(data);
+                return;
+            }
+        };
+
+        let dial_timeout = self.config.dial_timeout;
+        let pre_shared_key = context.pre_shared_key();
+        let dials = targets.into_iter().map(move |(addr, socket_addr)| {
+            dial_back(
+                socket_addr,
+                key_pair.clone(),
+                pre_shared_key,
+                peer_id.clone(),
+                dial_timeout,
+            )
+            .map(move |reachable| (addr, reachable))
+        });
+        let control = context.control().clone();
+        let task = future::join_all(dials).map(move |results| {
+            let (reachable, unreachable): (Vec<_>, Vec<_>) =
+                results.into_iter().partition(|(_, reachable)| *reachable);
+            let data = AutoNatMessage::DialResponse {
+                reachable: reachable.into_iter().map(|(addr, _)| addr).collect(),
+                unreachable: unreachable.into_iter().map(|(addr, _)| addr).collect(),
+            }
+            .encode();
+            let _ = control.send_message_to(session_id, proto_id, data);
+        });
+        if context.future_task(task).is_err() {
+            warn!("start dial back fail");
+        }
+    }
+}
+
+impl ServiceProtocol for AutoNatProtocol {
+    fn init(&mut self, context: &mut ProtocolContext) {
+        let proto_id = context.proto_id;
+        if context
+            .set_service_notify(proto_id, self.config.probe_interval, PROBE_TOKEN)
+            .is_err()
+        {
+            warn!("autonat start fail")
+        }
+    }
+
+    fn connected(&mut self, context: ProtocolContextMutRef, _version: &str) {
+        let session = context.session;
+        let peer_id = match session.remote_pubkey {
+            Some(ref pubkey) => pubkey.peer_id(),
+            None => {
+                error!("AutoNatProtocol require secio enabled!");
+                return;
+            }
+        };
+        self.remote_infos.insert(
+            session.id,
+            RemoteInfo {
+                peer_id,
+                peer_ip: multiaddr_to_socketaddr(&session.address).map(|addr| addr.ip()),
+                requested_at: None,
+                requested: Vec::new(),
+            },
+        );
+
+        if context.reachability() == Reachability::Unknown {
+            self.request(&context, session.id);
+        }
+    }
+
+    fn disconnected(&mut self, context: ProtocolContextMutRef) {
+        self.remote_infos.remove(&context.session.id);
+    }
+
+    fn received(&mut self, context: ProtocolContextMutRef, data: bytes::Bytes) {
+        let session = context.session;
+        if !self.remote_infos.contains_key(&session.id) {
+            return;
+        }
+
+        match AutoNatMessage::decode(&data) {
+            Some(AutoNatMessage::DialRequest { addresses }) => self.serve(&context, addresses),
+            Some(AutoNatMessage::DialResponse {
+                reachable,
+                unreachable,
+            }) => self.received_response(&context, session.id, reachable, unreachable),
+            None => {
+                debug!(
+                    "AutoNatProtocol received invalid data from session {}",
+                    session.id
+                );
+                let _ = context.disconnect(session.id);
+            }
+        }
+    }
+
+    fn notify(&mut self, context: &mut ProtocolContext, _token: u64) {
+        self.served
+            .retain(|_, served_at| served_at.elapsed() < SERVE_INTERVAL);
+        self.update_reachability(context);
+        self.probe(context);
+    }
+}
+
+// The address with an unspecified ip if its ip is not public, for the peer to fill in the ip
+// it sees us on
+fn unspecify_private(addr: &Multiaddr) -> Multiaddr {
+    addr.iter()
+        .map(|proto| match proto {
+            Protocol::Ip4(ip) if !is_reachable(ip.into()) => Protocol::Ip4(Ipv4Addr::UNSPECIFIED),
+            Protocol::Ip6(ip) if !is_reachable(ip.into()) => Protocol::Ip6(Ipv6Addr::UNSPECIFIED),
+            proto => proto,
+        })
+        .collect()
+}
+
+// Socket address to dial back, only on the ip of the peer and over plain tcp
+fn dial_back_addr(addr: &Multiaddr, peer_ip: IpAddr) -> Option<SocketAddr> {
+    if is_ws(addr) {
+        return None;
+    }
+    let mut socket_addr = multiaddr_to_socketaddr(addr)?;
+    if socket_addr.ip().is_unspecified() {
+        socket_addr.set_ip(peer_ip);
+    }
+    if socket_addr.ip() == peer_ip {
+        Some(socket_addr)
+    } else {
+        None
+    }
+}
+
+// Dial the address on a fresh connection, it is reachable if the handshake succeeds with the
+// expected peer, the pre-shared key handshake first as the service does
+fn dial_back(
+    addr: SocketAddr,
+    key_pair: SecioKeyPair,
+    pre_shared_key: Option<PreSharedKey>,
+    peer_id: PeerId,
+    timeout: Duration,
+) -> impl Future<Item = bool, Error = ()> {
+    let task = TcpStream::connect(&addr)
+        .map_err(move |err| debug!("dial back {} error: {:?}", addr, err))
+        .and_then(move |socket| {
+            let secio_config = SecioConfig::new(key_pair);
+            let handshake = match pre_shared_key {
+                Some(psk) => future::Either::A(
+                    psk.handshake(socket)
+                        .and_then(move |socket| secio_config.handshake(socket)),
+                ),
+                None => future::Either::B(secio_config.handshake(socket)),
+            };
+            handshake.map_err(move |err| debug!("dial back {} handshake error: {:?}", addr, err))
+        })
+        .map(move |(_, remote_pubkey, _)| remote_pubkey.peer_id() == peer_id);
+    Timeout::new(task, timeout).then(|result| Ok::<_, ()>(result.unwrap_or(false)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{dial_back_addr, unspecify_private, AutoNatProtocol, Config, RemoteInfo};
+    use futures::Stream;
+    use p2p::{
+        builder::{MetaBuilder, ServiceBuilder},
+        multiaddr::Multiaddr,
+        secio::{pnet::PreSharedKey, SecioKeyPair},
+        service::{DialProtocol, ProtocolHandle, Reachability, Service},
+        SessionId,
+    };
+    use std::{
+        net::TcpListener,
+        thread,
+        time::{Duration, Instant},
+    };
+
+    fn addr(s: &str) -> Multiaddr {
+        s.parse().unwrap()
+    }
+
+    fn create(autonat: AutoNatProtocol, psk: Option<PreSharedKey>) -> Service<()> {
+        let meta = MetaBuilder::new()
+            .id(1.into())
+            .service_handle(move || ProtocolHandle::Callback(Box::new(autonat)))
+            .build();
+        let mut builder = ServiceBuilder::default()
+            .insert_protocol(meta)
+            .key_pair(SecioKeyPair::secp256k1_generated())
+            .forever(true);
+        if let Some(psk) = psk {
+            builder = builder.pre_shared_key(psk);
+        }
+        builder.build(())
+    }
+
+    fn config() -> Config {
+        Config {
+            // Helpers on loopback are of one netgroup
+            confidence: 1,
+            probe_interval: Duration::from_millis(200),
+            dial_timeout: Duration::from_secs(3),
+            ..Default::default()
+        }
+    }
+
+    // Start two loopback services acting as helpers, and dial them from the service
+    fn probe(mut service: Service<()>, psk: Option<PreSharedKey>) -> Reachability {
+        let mut helpers = Vec::new();
+        for _ in 0..2 {
+            let mut helper = create(AutoNatProtocol::new(config()), psk);
+            helpers.push(helper.listen(addr("/ip4/127.0.0.1/tcp/0")).unwrap());
+            thread::spawn(|| tokio::run(helper.for_each(|_| Ok(()))));
+        }
+        for helper in helpers {
+            service.dial(helper, DialProtocol::All).unwrap();
+        }
+        let control = service.control().clone();
+        thread::spawn(|| tokio::run(service.for_each(|_| Ok(()))));
+
+        for _ in 0..100 {
+            if control.reachability() != Reachability::Unknown {
+                break;
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+        control.reachability()
+    }
+
+    #[test]
+    fn test_public_on_loopback() {
+        let mut service = create(AutoNatProtocol::new(config()), None);
+        service.listen(addr("/ip4/127.0.0.1/tcp/0")).unwrap();
+        assert_eq!(probe(service, None), Reachability::Public);
+    }
+
+    #[test]
+    fn test_public_with_pre_shared_key() {
+        let psk = Some(PreSharedKey::new([7; 32]));
+        let mut service = create(AutoNatProtocol::new(config()), psk);
+        service.listen(addr("/ip4/127.0.0.1/tcp/0")).unwrap();
+        assert_eq!(probe(service, psk), Reachability::Public);
+    }
+
+    #[test]
+    fn test_private_on_loopback() {
+        let autonat = AutoNatProtocol::new(config());
+        // Nobody listens on the candidate
+        let closed = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        autonat
+            .handle()
+            .add_candidate(addr(&format!("/ip4/127.0.0.1/tcp/{}", closed.port())));
+        assert_eq!(probe(create(autonat, None), None), Reachability::Private);
+    }
+
+    #[test]
+    fn test_served_only_with_targets() {
+        let mut autonat = AutoNatProtocol::new(config());
+        let peer_id = SecioKeyPair::secp256k1_generated().peer_id();
+        let session_id = SessionId::new(1);
+        autonat.remote_infos.insert(
+            session_id,
+            RemoteInfo {
+                peer_id: peer_id.clone(),
+                peer_ip: Some("8.8.8.8".parse().unwrap()),
+                requested_at: None,
+                requested: Vec::new(),
+            },
+        );
+
+        // Nothing to dial back, the peer may ask again
+        let targets = autonat.dial_back_targets(session_id, vec![addr("/ip4/1.1.1.1/tcp/1337")]);
+        assert!(targets.is_empty());
+        assert!(!autonat.served.contains_key(&peer_id));
+
+        let targets = autonat.dial_back_targets(session_id, vec![addr("/ip4/0.0.0.0/tcp/1337")]);
+        assert_eq!(targets.len(), 1);
+        assert!(autonat.served.contains_key(&peer_id));
+
+        // Refused within the interval
+        let targets = autonat.dial_back_targets(session_id, vec![addr("/ip4/0.0.0.0/tcp/1337")]);
+        assert!(targets.is_empty());
+    }
+
+    #[test]
+    fn test_results_of_requested_addrs_by_netgroup() {
+        let mut autonat = AutoNatProtocol::new(config());
+        let requested = addr("/ip4/0.0.0.0/tcp/1337");
+        for (id, ip) in (1..=4).zip(&["8.8.8.8", "8.8.4.4", "1.1.1.1", "9.9.9.9"]) {
+            autonat.remote_infos.insert(
+                SessionId::new(id),
+                RemoteInfo {
+                    peer_id: SecioKeyPair::secp256k1_generated().peer_id(),
+                    peer_ip: Some(ip.parse().unwrap()),
+                    requested_at: Some(Instant::now()),
+                    requested: vec![requested.clone()],
+                },
+            );
+        }
+
+        // Only the requested addresses count
+        let forged = vec![addr("/ip4/8.8.8.8/tcp/1337")];
+        assert!(!autonat.record_result(SessionId::new(4), forged, Vec::new()));
+        assert!(autonat.results.is_empty());
+
+        // Peers of one netgroup share a result
+        for id in 1..=3 {
+            assert!(autonat.record_result(SessionId::new(id), vec![requested.clone()], Vec::new()));
+        }
+        assert_eq!(autonat.results.len(), 2);
+    }
+
+    #[test]
+    fn test_unspecify_private() {
+        assert_eq!(
+            unspecify_private(&addr("/ip4/192.168.1.2/tcp/1337")),
+            addr("/ip4/0.0.0.0/tcp/1337")
+        );
+        assert_eq!(
+            unspecify_private(&addr("/ip6/::1/tcp/1337")),
+            addr("/ip6/::/tcp/1337")
+        );
+        assert_eq!(
+            unspecify_private(&addr("/ip4/8.8.8.8/tcp/1337")),
+            addr("/ip4/8.8.8.8/tcp/1337")
+        );
+    }
+
+    #[test]
+    fn test_dial_back_addr() {
+        let peer_ip = "8.8.8.8".parse().unwrap();
+        assert_eq!(
+            dial_back_addr(&addr("/ip4/0.0.0.0/tcp/1337"), peer_ip),
+            Some("8.8.8.8:1337".parse().unwrap())
+        );
+        assert_eq!(
+            dial_back_addr(&addr("/ip4/8.8.8.8/tcp/1337"), peer_ip),
+            Some("8.8.8.8:1337".parse().unwrap())
+        );
+        // Never dial a third party
+        assert_eq!(
+            dial_back_addr(&addr("/ip4/1.1.1.1/tcp/1337"), peer_ip),
+            None
+        );
+        assert_eq!(
+            dial_back_addr(&addr("/ip4/8.8.8.8/tcp/1337/ws"), peer_ip),
+            None
+        );
+    }
+}
@@ -0,0 +1,229 @@
+#[cfg(all(feature = "flatc", feature = "molc"))]
+compile_error!("features `flatc` and `molc` are mutually exclusive");
+#[cfg(all(not(feature = "flatc"), not(feature = "molc")))]
+compile_error!("Please choose a serialization format via feature. Possible choices: flatc, molc");
+
+use bytes::Bytes;
+use p2p::multiaddr::Multiaddr;
+use std::convert::TryFrom;
+
+#[cfg(feature = "flatc")]
+use crate::protocol_generated::p2p::auto_nat::{
+    AutoNatMessage as FbsAutoNatMessage, AutoNatMessageBuilder,
+    AutoNatPayload as FbsAutoNatPayload, Bytes as FbsBytes, BytesBuilder,
+    DialRequest as FbsDialRequest, DialRequestBuilder, DialResponse as FbsDialResponse,
+    DialResponseBuilder,
+};
+#[cfg(feature = "flatc")]
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, Vector, WIPOffset};
+
+#[cfg(feature = "molc")]
+use crate::protocol_mol;
+#[cfg(feature = "molc")]
+use molecule::prelude::{Builder, Entity, Reader};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AutoNatMessage {
+    /// Ask the peer to dial back the addresses on a fresh connection
+    DialRequest { addresses: Vec<Multiaddr> },
+    /// Result of the dial back, addresses refused by the peer are in neither list
+    DialResponse {
+        reachable: Vec<Multiaddr>,
+        unreachable: Vec<Multiaddr>,
+    },
+}
+
+#[cfg(feature = "flatc")]
+fn fbs_addrs<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    addrs: &[Multiaddr],
+) -> WIPOffset<Vector<'a, ForwardsUOffset<FbsBytes<'a>>>> {
+    let mut vec_bytes = Vec::with_capacity(addrs.len());
+    for addr in addrs {
+        let seq = fbb.create_vector(&addr.to_vec());
+        let mut bytes_builder = BytesBuilder::new(fbb);
+        bytes_builder.add_seq(seq);
+        vec_bytes.push(bytes_builder.finish());
+    }
+    fbb.create_vector(&vec_bytes)
+}
+
+#[cfg(feature = "flatc")]
+fn addrs_from_fbs(fbs_addrs: Option<Vector<ForwardsUOffset<FbsBytes>>>) -> Option<Vec<Multiaddr>> {
+    let fbs_addrs = fbs_addrs?;
+    let mut addrs = Vec::with_capacity(fbs_addrs.len());
+    for i in 0..fbs_addrs.len() {
+        addrs.push(Multiaddr::try_from(fbs_addrs.get(i).seq()?.to_vec()).ok()?);
+    }
+    Some(addrs)
+}
+
+#[cfg(feature = "molc")]
+fn mol_addrs(addrs: &[Multiaddr]) -> protocol_mol::BytesVec {
+    protocol_mol::BytesVec::new_builder()
+        .set(
+            addrs
+                .iter()
+                .map(|addr| {
+                    protocol_mol::Bytes::new_builder()
+                        .set(addr.to_vec().into_iter().map(Into::into).collect())
+                        .build()
+                })
+                .collect(),
+        )
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn addrs_from_mol(reader: protocol_mol::BytesVecReader) -> Option<Vec<Multiaddr>> {
+    let mut addrs = Vec::with_capacity(reader.len());
+    for addr_reader in reader.iter() {
+        addrs.push(Multiaddr::try_from(addr_reader.raw_data().to_vec()).ok()?);
+    }
+    Some(addrs)
+}
+
+impl AutoNatMessage {
+    #[cfg(feature = "flatc")]
+    pub fn encode(&self) -> Bytes {
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let (payload_type, payload) = match self {
+            AutoNatMessage::DialRequest { addresses } => {
+                let addresses = fbs_addrs(&mut fbb, addresses);
+                let mut builder = DialRequestBuilder::new(&mut fbb);
+                builder.add_addresses(addresses);
+                (
+                    FbsAutoNatPayload::DialRequest,
+                    builder.finish().as_union_value(),
+                )
+            }
+            AutoNatMessage::DialResponse {
+                reachable,
+                unreachable,
+            } => {
+                let reachable = fbs_addrs(&mut fbb, reachable);
+                let unreachable = fbs_addrs(&mut fbb, unreachable);
+                let mut builder = DialResponseBuilder::new(&mut fbb);
+                builder.add_reachable(reachable);
+                builder.add_unreachable(unreachable);
+                (
+                    FbsAutoNatPayload::DialResponse,
+                    builder.finish().as_union_value(),
+                )
+            }
+        };
+
+        let mut builder = AutoNatMessageBuilder::new(&mut fbb);
+        builder.add_payload_type(payload_type);
+        builder.add_payload(payload);
+        let data = builder.finish();
+        fbb.finish(data, None);
+        Bytes::from(fbb.finished_data())
+    }
+
+    #[cfg(feature = "flatc")]
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let fbs_message = flatbuffers_verifier::get_root::<FbsAutoNatMessage>(data).ok()?;
+        let payload = fbs_message.payload()?;
+        match fbs_message.payload_type() {
+            FbsAutoNatPayload::DialRequest => {
+                let fbs_request = FbsDialRequest::init_from_table(payload);
+                Some(AutoNatMessage::DialRequest {
+                    addresses: addrs_from_fbs(fbs_request.addresses())?,
+                })
+            }
+            FbsAutoNatPayload::DialResponse => {
+                let fbs_response = FbsDialResponse::init_from_table(payload);
+                Some(AutoNatMessage::DialResponse {
+                    reachable: addrs_from_fbs(fbs_response.reachable())?,
+                    unreachable: addrs_from_fbs(fbs_response.unreachable())?,
+                })
+            }
+            FbsAutoNatPayload::NONE => None,
+        }
+    }
+
+    #[cfg(feature = "molc")]
+    pub fn encode(&self) -> Bytes {
+        let payload = match self {
+            AutoNatMessage::DialRequest { addresses } => {
+                let request = protocol_mol::DialRequest::new_builder()
+                    .addresses(mol_addrs(addresses))
+                    .build();
+                protocol_mol::AutoNatPayload::new_builder()
+                    .set(request)
+                    .build()
+            }
+            AutoNatMessage::DialResponse {
+                reachable,
+                unreachable,
+            } => {
+                let response = protocol_mol::DialResponse::new_builder()
+                    .reachable(mol_addrs(reachable))
+                    .unreachable(mol_addrs(unreachable))
+                    .build();
+                protocol_mol::AutoNatPayload::new_builder()
+                    .set(response)
+                    .build()
+            }
+        };
+
+        protocol_mol::AutoNatMessage::new_builder()
+            .payload(payload)
+            .build()
+            .as_bytes()
+    }
+
+    #[cfg(feature = "molc")]
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let reader = protocol_mol::AutoNatMessageReader::from_compatible_slice(data).ok()?;
+        match reader.payload().to_enum() {
+            protocol_mol::AutoNatPayloadUnionReader::DialRequest(reader) => {
+                Some(AutoNatMessage::DialRequest {
+                    addresses: addrs_from_mol(reader.addresses())?,
+                })
+            }
+            protocol_mol::AutoNatPayloadUnionReader::DialResponse(reader) => {
+                Some(AutoNatMessage::DialResponse {
+                    reachable: addrs_from_mol(reader.reachable())?,
+                    unreachable: addrs_from_mol(reader.unreachable())?,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoNatMessage;
+    use p2p::multiaddr::Multiaddr;
+
+    #[test]
+    fn test_encode_decode() {
+        let addr: Multiaddr = "/ip4/8.8.8.8/tcp/1337".parse().unwrap();
+        let messages = vec![
+            AutoNatMessage::DialRequest {
+                addresses: vec![addr.clone(), "/ip6/::1/tcp/1337".parse().unwrap()],
+            },
+            AutoNatMessage::DialRequest {
+                addresses: Vec::new(),
+            },
+            AutoNatMessage::DialResponse {
+                reachable: vec![addr.clone()],
+                unreachable: Vec::new(),
+            },
+            AutoNatMessage::DialResponse {
+                reachable: Vec::new(),
+                unreachable: vec![addr],
+            },
+        ];
+        for message in messages {
+            assert_eq!(AutoNatMessage::decode(&message.encode()), Some(message));
+        }
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(AutoNatMessage::decode(b"invalid").is_none());
+    }
+}
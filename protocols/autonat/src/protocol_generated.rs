@@ -0,0 +1,437 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+pub mod p2p {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+pub mod auto_nat {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AutoNatPayload {
+  NONE = 0,
+  DialRequest = 1,
+  DialResponse = 2,
+
+}
+
+const ENUM_MIN_AUTO_NAT_PAYLOAD: u8 = 0;
+const ENUM_MAX_AUTO_NAT_PAYLOAD: u8 = 2;
+
+impl<'a> flatbuffers::Follow<'a> for AutoNatPayload {
+  type Inner = Self;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::read_scalar_at::<Self>(buf, loc)
+  }
+}
+
+impl flatbuffers::EndianScalar for AutoNatPayload {
+  #[inline]
+  fn to_little_endian(self) -> Self {
+    let n = u8::to_le(self as u8);
+    let p = &n as *const u8 as *const AutoNatPayload;
+    unsafe { *p }
+  }
+  #[inline]
+  fn from_little_endian(self) -> Self {
+    let n = u8::from_le(self as u8);
+    let p = &n as *const u8 as *const AutoNatPayload;
+    unsafe { *p }
+  }
+}
+
+impl flatbuffers::Push for AutoNatPayload {
+    type Output = AutoNatPayload;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        flatbuffers::emplace_scalar::<AutoNatPayload>(dst, *self);
+    }
+}
+
+#[allow(non_camel_case_types)]
+const ENUM_VALUES_AUTO_NAT_PAYLOAD:[AutoNatPayload; 3] = [
+  AutoNatPayload::NONE,
+  AutoNatPayload::DialRequest,
+  AutoNatPayload::DialResponse
+];
+
+#[allow(non_camel_case_types)]
+const ENUM_NAMES_AUTO_NAT_PAYLOAD:[&'static str; 3] = [
+    "NONE",
+    "DialRequest",
+    "DialResponse"
+];
+
+pub fn enum_name_auto_nat_payload(e: AutoNatPayload) -> &'static str {
+  let index: usize = e as usize;
+  ENUM_NAMES_AUTO_NAT_PAYLOAD[index]
+}
+
+pub struct AutoNatPayloadUnionTableOffset {}
+pub enum AutoNatMessageOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct AutoNatMessage<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AutoNatMessage<'a> {
+    type Inner = AutoNatMessage<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> AutoNatMessage<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        AutoNatMessage {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args AutoNatMessageArgs) -> flatbuffers::WIPOffset<AutoNatMessage<'bldr>> {
+      let mut builder = AutoNatMessageBuilder::new(_fbb);
+      if let Some(x) = args.payload { builder.add_payload(x); }
+      builder.add_payload_type(args.payload_type);
+      builder.finish()
+    }
+
+    pub const VT_PAYLOAD_TYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_PAYLOAD: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn payload_type(&self) -> AutoNatPayload {
+    self._tab.get::<AutoNatPayload>(AutoNatMessage::VT_PAYLOAD_TYPE, Some(AutoNatPayload::NONE)).unwrap()
+  }
+  #[inline]
+  pub fn payload(&self) -> Option<flatbuffers::Table<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(AutoNatMessage::VT_PAYLOAD, None)
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_dial_request(&'a self) -> Option<DialRequest> {
+    if self.payload_type() == AutoNatPayload::DialRequest {
+      self.payload().map(|u| DialRequest::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_dial_response(&'a self) -> Option<DialResponse> {
+    if self.payload_type() == AutoNatPayload::DialResponse {
+      self.payload().map(|u| DialResponse::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+}
+
+pub struct AutoNatMessageArgs {
+    pub payload_type: AutoNatPayload,
+    pub payload: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for AutoNatMessageArgs {
+    #[inline]
+    fn default() -> Self {
+        AutoNatMessageArgs {
+            payload_type: AutoNatPayload::NONE,
+            payload: None,
+        }
+    }
+}
+pub struct AutoNatMessageBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AutoNatMessageBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_payload_type(&mut self, payload_type: AutoNatPayload) {
+    self.fbb_.push_slot::<AutoNatPayload>(AutoNatMessage::VT_PAYLOAD_TYPE, payload_type, AutoNatPayload::NONE);
+  }
+  #[inline]
+  pub fn add_payload(&mut self, payload: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AutoNatMessage::VT_PAYLOAD, payload);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AutoNatMessageBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AutoNatMessageBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AutoNatMessage<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum DialRequestOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct DialRequest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DialRequest<'a> {
+    type Inner = DialRequest<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> DialRequest<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        DialRequest {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args DialRequestArgs<'args>) -> flatbuffers::WIPOffset<DialRequest<'bldr>> {
+      let mut builder = DialRequestBuilder::new(_fbb);
+      if let Some(x) = args.addresses { builder.add_addresses(x); }
+      builder.finish()
+    }
+
+    pub const VT_ADDRESSES: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn addresses(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>>>(DialRequest::VT_ADDRESSES, None)
+  }
+}
+
+pub struct DialRequestArgs<'a> {
+    pub addresses: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Bytes<'a >>>>>,
+}
+impl<'a> Default for DialRequestArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        DialRequestArgs {
+            addresses: None,
+        }
+    }
+}
+pub struct DialRequestBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DialRequestBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_addresses(&mut self, addresses: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Bytes<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DialRequest::VT_ADDRESSES, addresses);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DialRequestBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DialRequestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DialRequest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum DialResponseOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct DialResponse<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DialResponse<'a> {
+    type Inner = DialResponse<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> DialResponse<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        DialResponse {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args DialResponseArgs<'args>) -> flatbuffers::WIPOffset<DialResponse<'bldr>> {
+      let mut builder = DialResponseBuilder::new(_fbb);
+      if let Some(x) = args.unreachable { builder.add_unreachable(x); }
+      if let Some(x) = args.reachable { builder.add_reachable(x); }
+      builder.finish()
+    }
+
+    pub const VT_REACHABLE: flatbuffers::VOffsetT = 4;
+    pub const VT_UNREACHABLE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn reachable(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>>>(DialResponse::VT_REACHABLE, None)
+  }
+  #[inline]
+  pub fn unreachable(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>>>(DialResponse::VT_UNREACHABLE, None)
+  }
+}
+
+pub struct DialResponseArgs<'a> {
+    pub reachable: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Bytes<'a >>>>>,
+    pub unreachable: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Bytes<'a >>>>>,
+}
+impl<'a> Default for DialResponseArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        DialResponseArgs {
+            reachable: None,
+            unreachable: None,
+        }
+    }
+}
+pub struct DialResponseBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DialResponseBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_reachable(&mut self, reachable: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Bytes<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DialResponse::VT_REACHABLE, reachable);
+  }
+  #[inline]
+  pub fn add_unreachable(&mut self, unreachable: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Bytes<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(DialResponse::VT_UNREACHABLE, unreachable);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DialResponseBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    DialResponseBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<DialResponse<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum BytesOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Bytes<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Bytes<'a> {
+    type Inner = Bytes<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Bytes<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Bytes {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args BytesArgs<'args>) -> flatbuffers::WIPOffset<Bytes<'bldr>> {
+      let mut builder = BytesBuilder::new(_fbb);
+      if let Some(x) = args.seq { builder.add_seq(x); }
+      builder.finish()
+    }
+
+    pub const VT_SEQ: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn seq(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Bytes::VT_SEQ, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct BytesArgs<'a> {
+    pub seq: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for BytesArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        BytesArgs {
+            seq: None,
+        }
+    }
+}
+pub struct BytesBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BytesBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_seq(&mut self, seq: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Bytes::VT_SEQ, seq);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BytesBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BytesBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Bytes<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+}  // pub mod AutoNat
+}  // pub mod P2P
+
@@ -1,10 +1,7 @@
 use env_logger;
 use log::debug;
 
-use std::{
-    collections::{HashMap, HashSet},
-    time::Duration,
-};
+use std::time::Duration;
 
 use futures::prelude::*;
 
@@ -14,12 +11,10 @@ use p2p::{
     multiaddr::Multiaddr,
     service::{DialProtocol, ProtocolHandle, ProtocolMeta, ServiceError, ServiceEvent},
     traits::ServiceHandle,
-    ProtocolId, SessionId,
+    ProtocolId,
 };
 
-use tentacle_discovery::{
-    AddressManager, Discovery, DiscoveryProtocol, MisbehaveResult, Misbehavior,
-};
+use tentacle_discovery::{AddrBook, AddressManager, Discovery, DiscoveryProtocol};
 
 fn main() {
     env_logger::init();
@@ -47,12 +42,11 @@ fn main() {
 }
 
 fn create_meta(id: ProtocolId, start: u16) -> ProtocolMeta {
-    let addrs: HashSet<Multiaddr> = (start..start + 3333)
+    let addrs: Vec<Multiaddr> = (start..start + 3333)
         .map(|port| format!("/ip4/127.0.0.1/tcp/{}", port).parse().unwrap())
         .collect();
-    let mut peers = HashMap::default();
-    peers.insert(0.into(), (100, addrs));
-    let addr_mgr = SimpleAddressManager { peers };
+    let mut addr_mgr = AddrBook::new();
+    addr_mgr.add_new_addrs(0.into(), addrs);
     MetaBuilder::default()
         .id(id)
         .service_handle(move || {
@@ -74,47 +68,3 @@ impl ServiceHandle for SHandle {
         debug!("service event: {:?}", event);
     }
 }
-
-#[derive(Default, Clone, Debug)]
-pub struct SimpleAddressManager {
-    pub peers: HashMap<SessionId, (i32, HashSet<Multiaddr>)>,
-}
-
-impl AddressManager for SimpleAddressManager {
-    fn add_new_addr(&mut self, session_id: SessionId, addr: Multiaddr) {
-        log::info!("{:?}", addr);
-        let (_, addrs) = self
-            .peers
-            .entry(session_id)
-            .or_insert_with(|| (100, HashSet::default()));
-        addrs.insert(addr);
-    }
-
-    fn add_new_addrs(&mut self, session_id: SessionId, addrs: Vec<Multiaddr>) {
-        for addr in addrs.into_iter() {
-            self.add_new_addr(session_id, addr)
-        }
-    }
-
-    fn misbehave(&mut self, session_id: SessionId, _ty: Misbehavior) -> MisbehaveResult {
-        let (score, _) = self
-            .peers
-            .entry(session_id)
-            .or_insert((100, HashSet::default()));
-        *score -= 20;
-        if *score < 0 {
-            MisbehaveResult::Disconnect
-        } else {
-            MisbehaveResult::Continue
-        }
-    }
-
-    fn get_random(&mut self, n: usize) -> Vec<Multiaddr> {
-        self.peers
-            .values()
-            .flat_map(|(_, addrs)| addrs)
-            .take(n)
-            .cloned()
-            .collect()
-    }
-}
@@ -1,10 +1,13 @@
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 use p2p::{
-    multiaddr::Multiaddr,
-    utils::{is_reachable, multiaddr_to_socketaddr},
+    multiaddr::{multihash::Multihash, Multiaddr, Protocol},
+    secio::PeerId,
+    service::SessionType,
+    utils::{extract_peer_id, is_reachable},
     SessionId,
 };
 
@@ -25,6 +28,8 @@ pub enum Misbehavior {
     TooManyItems { announce: bool, length: usize },
     // Too many address in one item
     TooManyAddresses(usize),
+    // Peer record with a bad signature
+    InvalidRecord,
 }
 
 /// Misbehavior report result
@@ -56,14 +61,45 @@ pub trait AddressManager {
     fn add_new_addrs(&mut self, session_id: SessionId, addrs: Vec<Multiaddr>);
     fn misbehave(&mut self, session_id: SessionId, kind: Misbehavior) -> MisbehaveResult;
     fn get_random(&mut self, n: usize) -> Vec<Multiaddr>;
+    /// Discovery protocol opened on the session, `addr` is the remote address of the session
+    fn connected(&mut self, _session_id: SessionId, _addr: &Multiaddr, _ty: SessionType) {}
+    /// Discovery protocol closed on the session
+    fn disconnected(&mut self, _session_id: SessionId) {}
+}
+
+/// Share an address manager with the rest of the application
+impl<M: AddressManager> AddressManager for Arc<Mutex<M>> {
+    fn add_new_addr(&mut self, session_id: SessionId, addr: Multiaddr) {
+        self.lock().unwrap().add_new_addr(session_id, addr)
+    }
+
+    fn add_new_addrs(&mut self, session_id: SessionId, addrs: Vec<Multiaddr>) {
+        self.lock().unwrap().add_new_addrs(session_id, addrs)
+    }
+
+    fn misbehave(&mut self, session_id: SessionId, kind: Misbehavior) -> MisbehaveResult {
+        self.lock().unwrap().misbehave(session_id, kind)
+    }
+
+    fn get_random(&mut self, n: usize) -> Vec<Multiaddr> {
+        self.lock().unwrap().get_random(n)
+    }
+
+    fn connected(&mut self, session_id: SessionId, addr: &Multiaddr, ty: SessionType) {
+        self.lock().unwrap().connected(session_id, addr, ty)
+    }
+
+    fn disconnected(&mut self, session_id: SessionId) {
+        self.lock().unwrap().disconnected(session_id)
+    }
 }
 
 // bitcoin: bloom.h, bloom.cpp => CRollingBloomFilter
 pub struct AddrKnown {
     max_known: usize,
-    addrs: HashSet<RawAddr>,
-    addr_times: HashMap<RawAddr, Instant>,
-    time_addrs: BTreeMap<Instant, RawAddr>,
+    addrs: HashSet<AddrKey>,
+    addr_times: HashMap<AddrKey, Instant>,
+    time_addrs: BTreeMap<Instant, AddrKey>,
 }
 
 impl AddrKnown {
@@ -76,10 +112,10 @@ impl AddrKnown {
         }
     }
 
-    pub(crate) fn insert(&mut self, key: RawAddr) {
+    pub(crate) fn insert(&mut self, key: AddrKey) {
         let now = Instant::now();
-        self.addrs.insert(key);
-        self.time_addrs.insert(now, key);
+        self.addrs.insert(key.clone());
+        self.time_addrs.insert(now, key.clone());
         self.addr_times.insert(key, now);
 
         if self.addrs.len() > self.max_known {
@@ -93,11 +129,11 @@ impl AddrKnown {
         }
     }
 
-    pub(crate) fn contains(&self, addr: &RawAddr) -> bool {
+    pub(crate) fn contains(&self, addr: &AddrKey) -> bool {
         self.addrs.contains(addr)
     }
 
-    pub(crate) fn remove<'a>(&mut self, addrs: impl Iterator<Item = &'a RawAddr>) {
+    pub(crate) fn remove<'a>(&mut self, addrs: impl Iterator<Item = &'a AddrKey>) {
         addrs.for_each(|addr| {
             self.addrs.remove(addr);
             if let Some(time) = self.addr_times.remove(addr) {
@@ -113,6 +149,109 @@ impl Default for AddrKnown {
     }
 }
 
+/// Key of a known address: the address without its `p2p` part, and the peer id of it
+///
+/// Any kind of address has a key, the same address of two different peers has two keys.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AddrKey {
+    addr: Multiaddr,
+    peer_id: Option<PeerId>,
+}
+
+impl AddrKey {
+    pub fn peer_id(&self) -> Option<&PeerId> {
+        self.peer_id.as_ref()
+    }
+}
+
+impl From<&Multiaddr> for AddrKey {
+    fn from(addr: &Multiaddr) -> AddrKey {
+        AddrKey {
+            addr: addr
+                .iter()
+                .filter(|proto| match proto {
+                    Protocol::P2p(_) => false,
+                    _ => true,
+                })
+                .collect(),
+            peer_id: extract_peer_id(addr),
+        }
+    }
+}
+
+/// Check that the address can be shared by discovery
+///
+/// A valid address is `ip4`, `ip6`, `dns4` or `dns6`, then `tcp` with a non-zero port, then
+/// optionally `ws` or `wss`, and optionally a `p2p` peer id at the end. Unspecified and multicast
+/// ips and malformed domain names are rejected.
+pub fn is_valid_addr(addr: &Multiaddr) -> bool {
+    let mut iter = addr.iter();
+    let valid_host = match iter.next() {
+        Some(Protocol::Ip4(ip)) => !(ip.is_unspecified() || ip.is_multicast() || ip.is_broadcast()),
+        Some(Protocol::Ip6(ip)) => !(ip.is_unspecified() || ip.is_multicast()),
+        Some(Protocol::Dns4(name)) | Some(Protocol::Dns6(name)) => is_valid_domain(&name),
+        _ => false,
+    };
+    if !valid_host {
+        return false;
+    }
+    match iter.next() {
+        Some(Protocol::Tcp(port)) if port != 0 => (),
+        _ => return false,
+    }
+    let mut next = iter.next();
+    if let Some(Protocol::Ws) | Some(Protocol::Wss) = next {
+        next = iter.next();
+    }
+    if let Some(Protocol::P2p(hash)) = next {
+        if PeerId::from_bytes(hash.into_bytes()).is_err() {
+            return false;
+        }
+        next = iter.next();
+    }
+    next.is_none()
+}
+
+/// Check that the address can be reached from the internet
+///
+/// Ips must be global, domain names must not be local ones.
+pub fn is_reachable_addr(addr: &Multiaddr) -> bool {
+    match addr.iter().next() {
+        Some(Protocol::Ip4(ip)) => is_reachable(IpAddr::V4(ip)),
+        Some(Protocol::Ip6(ip)) => is_reachable(IpAddr::V6(ip)),
+        Some(Protocol::Dns4(name)) | Some(Protocol::Dns6(name)) => {
+            let name = name.to_lowercase();
+            name != "localhost" && !name.ends_with(".localhost") && !name.ends_with(".local")
+        }
+        _ => false,
+    }
+}
+
+/// Append the peer id to the address if it has none
+pub(crate) fn with_peer_id(mut addr: Multiaddr, peer_id: &PeerId) -> Multiaddr {
+    if extract_peer_id(&addr).is_none() {
+        addr.push(Protocol::P2p(
+            Multihash::from_bytes(peer_id.as_bytes().to_vec()).expect("Invalid peer id"),
+        ));
+    }
+    addr
+}
+
+// See: RFC 1035 section 2.3.1
+fn is_valid_domain(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 253
+        && name.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .bytes()
+                    .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+        })
+}
+
 #[derive(Copy, Clone, Debug, PartialOrd, Ord, Eq, PartialEq, Hash)]
 pub struct RawAddr(pub(crate) [u8; 18]);
 
@@ -125,13 +264,6 @@ impl From<&[u8]> for RawAddr {
     }
 }
 
-impl From<Multiaddr> for RawAddr {
-    fn from(addr: Multiaddr) -> RawAddr {
-        // FIXME: maybe not socket addr
-        RawAddr::from(multiaddr_to_socketaddr(&addr).unwrap())
-    }
-}
-
 impl From<SocketAddr> for RawAddr {
     // CService::GetKey()
     fn from(addr: SocketAddr) -> RawAddr {
@@ -184,3 +316,48 @@ impl RawAddr {
         is_reachable(self.socket_addr().ip())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{is_reachable_addr, is_valid_addr, AddrKey};
+    use p2p::{multiaddr::Multiaddr, secio::SecioKeyPair};
+
+    fn addr(s: &str) -> Multiaddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_valid_addr() {
+        let peer_id = SecioKeyPair::secp256k1_generated().peer_id();
+        assert!(is_valid_addr(&addr("/ip4/1.1.1.1/tcp/8000")));
+        assert!(is_valid_addr(&addr("/ip6/2400::1/tcp/8000/ws")));
+        assert!(is_valid_addr(&addr("/dns4/example.com/tcp/443/wss")));
+        assert!(is_valid_addr(&addr(&format!(
+            "/dns6/example.com/tcp/8000/p2p/{}",
+            peer_id.to_base58()
+        ))));
+
+        assert!(!is_valid_addr(&addr("/ip4/0.0.0.0/tcp/8000")));
+        assert!(!is_valid_addr(&addr("/ip4/1.1.1.1/tcp/0")));
+        assert!(!is_valid_addr(&addr("/ip4/1.1.1.1/udp/8000")));
+        assert!(!is_valid_addr(&addr("/dns4/-example.com/tcp/8000")));
+        assert!(!is_valid_addr(&addr("/ip4/1.1.1.1/tcp/8000/ws/ws")));
+
+        assert!(is_reachable_addr(&addr("/dns4/example.com/tcp/8000")));
+        assert!(!is_reachable_addr(&addr("/dns4/localhost/tcp/8000")));
+        assert!(!is_reachable_addr(&addr("/ip4/127.0.0.1/tcp/8000")));
+    }
+
+    #[test]
+    fn test_addr_key() {
+        let peer_a = SecioKeyPair::secp256k1_generated().peer_id();
+        let peer_b = SecioKeyPair::secp256k1_generated().peer_id();
+        let with_a = addr(&format!("/ip4/1.1.1.1/tcp/8000/p2p/{}", peer_a.to_base58()));
+        let with_b = addr(&format!("/ip4/1.1.1.1/tcp/8000/p2p/{}", peer_b.to_base58()));
+        let key_a = AddrKey::from(&with_a);
+
+        assert_eq!(key_a.peer_id(), Some(&peer_a));
+        assert_ne!(key_a, AddrKey::from(&with_b));
+        assert_ne!(key_a, AddrKey::from(&addr("/ip4/1.1.1.1/tcp/8000")));
+    }
+}
@@ -0,0 +1,683 @@
+use std::collections::{hash_map::DefaultHasher, HashMap};
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use log::{debug, warn};
+use p2p::{
+    multiaddr::{Multiaddr, Protocol},
+    service::SessionType,
+    utils::netgroup,
+    SessionId,
+};
+use rand::Rng;
+
+use crate::addr::{is_valid_addr, AddrKey, AddressManager, MisbehaveResult, Misbehavior};
+
+// See: bitcoin/addrman.h
+const NEW_BUCKET_COUNT: usize = 1024;
+const TRIED_BUCKET_COUNT: usize = 256;
+const BUCKET_SIZE: usize = 64;
+// Addresses told by one source group are spread over this many new buckets
+const NEW_BUCKETS_PER_SOURCE_GROUP: u64 = 64;
+// Addresses of one group are spread over this many tried buckets
+const TRIED_BUCKETS_PER_GROUP: u64 = 8;
+
+// An address is terrible if not seen for 30 days
+const HORIZON: u64 = 30 * 24 * 3600;
+// or failed 3 times without ever succeeding
+const RETRIES: u32 = 3;
+// or failed 10 times without succeeding in the last week
+const MAX_FAILURES: u32 = 10;
+const MIN_FAIL: u64 = 7 * 24 * 3600;
+// Addresses succeeded in the last day are preferred by `get_random`
+const RECENT_SUCCESS: u64 = 24 * 3600;
+
+// A session is disconnected and its ip banned when its score drops to zero
+const INIT_SCORE: i32 = 100;
+const BAN_DURATION: Duration = Duration::from_secs(24 * 3600);
+const SAVE_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+// Domain names are grouped by their last two labels
+fn addr_group(addr: &Multiaddr) -> Option<Vec<u8>> {
+    match addr.iter().next()? {
+        Protocol::Ip4(ip) => Some(netgroup(IpAddr::V4(ip))),
+        Protocol::Ip6(ip) => Some(netgroup(IpAddr::V6(ip))),
+        Protocol::Dns4(name) | Protocol::Dns6(name) => {
+            let name = name.to_lowercase();
+            let labels = name.rsplit('.').take(2).collect::<Vec<_>>();
+            let mut group = vec![b'd'];
+            group.extend(labels.join(".").into_bytes());
+            Some(group)
+        }
+        _ => None,
+    }
+}
+
+fn addr_ip(addr: &Multiaddr) -> Option<IpAddr> {
+    match addr.iter().next()? {
+        Protocol::Ip4(ip) => Some(IpAddr::V4(ip)),
+        Protocol::Ip6(ip) => Some(IpAddr::V6(ip)),
+        _ => None,
+    }
+}
+
+fn parse_group(hex: &str) -> Option<Vec<u8>> {
+    // Work on bytes, slicing the str may split a multi-byte char
+    let hex = hex.as_bytes();
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    hex.chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair).ok()?;
+            u8::from_str_radix(pair, 16).ok()
+        })
+        .collect()
+}
+
+struct AddrInfo {
+    addr: Multiaddr,
+    // Netgroup of the peer that told us the address
+    source_group: Vec<u8>,
+    // Unix time in seconds, zero is never
+    last_seen: u64,
+    last_try: u64,
+    last_success: u64,
+    // Failed attempts since last success
+    attempts: u32,
+    tried: bool,
+    bucket: usize,
+}
+
+impl AddrInfo {
+    fn is_terrible(&self, now: u64) -> bool {
+        // Never remove an address just tried
+        if self.last_try != 0 && now.saturating_sub(self.last_try) < 60 {
+            return false;
+        }
+        now.saturating_sub(self.last_seen) > HORIZON
+            || (self.last_success == 0 && self.attempts >= RETRIES)
+            || (now.saturating_sub(self.last_success) > MIN_FAIL && self.attempts >= MAX_FAILURES)
+    }
+
+    // See: bitcoin CAddrInfo::GetChance
+    fn weight(&self, now: u64) -> f64 {
+        let mut weight = 1.0;
+        if now.saturating_sub(self.last_try) < 600 {
+            weight *= 0.01;
+        }
+        weight *= 0.66f64.powi(std::cmp::min(self.attempts, 8) as i32);
+        if self.tried {
+            weight *= 2.0;
+        }
+        if self.last_success != 0 && now.saturating_sub(self.last_success) < RECENT_SUCCESS {
+            weight *= 2.0;
+        }
+        weight
+    }
+
+    fn to_line(&self) -> String {
+        let group = self
+            .source_group
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        format!(
+            "{} {} {} {} {} {} {}",
+            self.addr,
+            self.tried as u8,
+            self.last_seen,
+            self.last_try,
+            self.last_success,
+            self.attempts,
+            group
+        )
+    }
+
+    fn from_line(line: &str) -> Option<AddrInfo> {
+        let mut parts = line.split_whitespace();
+        let addr = parts.next()?.parse::<Multiaddr>().ok()?;
+        let tried = parts.next()? == "1";
+        let last_seen = parts.next()?.parse().ok()?;
+        let last_try = parts.next()?.parse().ok()?;
+        let last_success = parts.next()?.parse().ok()?;
+        let attempts = parts.next()?.parse().ok()?;
+        let source_group = parse_group(parts.next()?)?;
+        Some(AddrInfo {
+            addr,
+            source_group,
+            last_seen,
+            last_try,
+            last_success,
+            attempts,
+            tried,
+            bucket: 0,
+        })
+    }
+}
+
+struct SessionInfo {
+    ip: Option<IpAddr>,
+    score: i32,
+}
+
+/// Address manager with Bitcoin style new and tried tables
+///
+/// Addresses learned from peers go to the new table, bucketed by the netgroup of the peer that
+/// told us, so one source can only fill a few buckets. Addresses we connected to go to the tried
+/// table, bucketed by their own netgroup. `get_random` prefers tried and recently successful
+/// addresses, and a peer misbehaving too much gets its ip banned for a day.
+///
+/// To share it with the service handle, e.g. to report failed dials, wrap it in
+/// `Arc<Mutex<AddrBook>>` which is also an `AddressManager`.
+pub struct AddrBook {
+    // Random key of bucket positions, so that peers can't target a bucket
+    key: u64,
+    addrs: HashMap<AddrKey, AddrInfo>,
+    new_buckets: Vec<Vec<AddrKey>>,
+    tried_buckets: Vec<Vec<AddrKey>>,
+    sessions: HashMap<SessionId, SessionInfo>,
+    bans: HashMap<IpAddr, Instant>,
+    path: Option<PathBuf>,
+    last_save: Instant,
+}
+
+impl Default for AddrBook {
+    fn default() -> Self {
+        AddrBook {
+            key: rand::thread_rng().gen(),
+            addrs: HashMap::default(),
+            new_buckets: vec![Vec::new(); NEW_BUCKET_COUNT],
+            tried_buckets: vec![Vec::new(); TRIED_BUCKET_COUNT],
+            sessions: HashMap::default(),
+            bans: HashMap::default(),
+            path: None,
+            last_save: Instant::now(),
+        }
+    }
+}
+
+impl AddrBook {
+    /// Address book kept in memory only
+    pub fn new() -> Self {
+        AddrBook::default()
+    }
+
+    /// Load the address book from a file, an empty book is created if the file does not exist
+    ///
+    /// The book is saved back to the file every 15 minutes and when dropped
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut book = AddrBook::default();
+        let path = path.as_ref().to_path_buf();
+        match fs::File::open(&path) {
+            Ok(file) => {
+                let now = now_secs();
+                for line in BufReader::new(file).lines() {
+                    let line = line?;
+                    match AddrInfo::from_line(&line) {
+                        Some(info) => {
+                            if !info.is_terrible(now) {
+                                book.restore(info);
+                            }
+                        }
+                        None => debug!("invalid address book line: {}", line),
+                    }
+                }
+            }
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+        book.path = Some(path);
+        Ok(book)
+    }
+
+    /// Write all addresses to the file the book was loaded from
+    pub fn save(&self) -> io::Result<()> {
+        let path = match self.path {
+            Some(ref path) => path,
+            None => return Ok(()),
+        };
+        let tmp_path = path.with_extension("tmp");
+        {
+            let mut file = io::BufWriter::new(fs::File::create(&tmp_path)?);
+            for info in self.addrs.values() {
+                writeln!(file, "{}", info.to_line())?;
+            }
+            file.flush()?;
+        }
+        fs::rename(tmp_path, path)
+    }
+
+    fn maybe_save(&mut self) {
+        if self.path.is_some() && self.last_save.elapsed() >= SAVE_INTERVAL {
+            self.last_save = Instant::now();
+            if let Err(err) = self.save() {
+                warn!("save address book error: {:?}", err);
+            }
+        }
+    }
+
+    /// Number of addresses in the new and tried tables
+    pub fn len(&self) -> usize {
+        self.addrs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.addrs.is_empty()
+    }
+
+    /// Number of addresses in the tried table
+    pub fn tried_len(&self) -> usize {
+        self.tried_buckets.iter().map(Vec::len).sum()
+    }
+
+    fn hash<T: Hash>(&self, value: T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.key.hash(&mut hasher);
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn new_bucket(&self, group: &[u8], source_group: &[u8]) -> usize {
+        let slot = self.hash((group, source_group)) % NEW_BUCKETS_PER_SOURCE_GROUP;
+        (self.hash((source_group, slot)) % NEW_BUCKET_COUNT as u64) as usize
+    }
+
+    fn tried_bucket(&self, key: &AddrKey, group: &[u8]) -> usize {
+        let slot = self.hash(key) % TRIED_BUCKETS_PER_GROUP;
+        (self.hash((group, slot)) % TRIED_BUCKET_COUNT as u64) as usize
+    }
+
+    fn is_banned(&mut self, ip: &IpAddr) -> bool {
+        match self.bans.get(ip) {
+            Some(time) if time.elapsed() < BAN_DURATION => true,
+            Some(_) => {
+                self.bans.remove(ip);
+                false
+            }
+            None => false,
+        }
+    }
+
+    fn remove(&mut self, key: &AddrKey) -> Option<AddrInfo> {
+        let info = self.addrs.remove(key)?;
+        let bucket = if info.tried {
+            &mut self.tried_buckets[info.bucket]
+        } else {
+            &mut self.new_buckets[info.bucket]
+        };
+        bucket.retain(|addr| addr != key);
+        Some(info)
+    }
+
+    // Make room in a full bucket, a terrible address goes first, then the least recently seen
+    fn evict(&mut self, tried: bool, bucket: usize) -> Option<AddrInfo> {
+        let now = now_secs();
+        let entries = if tried {
+            &self.tried_buckets[bucket]
+        } else {
+            &self.new_buckets[bucket]
+        };
+        let victim = entries
+            .iter()
+            .find(|key| self.addrs[*key].is_terrible(now))
+            .or_else(|| {
+                entries.iter().min_by_key(|key| {
+                    let info = &self.addrs[*key];
+                    if tried {
+                        info.last_success
+                    } else {
+                        info.last_seen
+                    }
+                })
+            })
+            .cloned()?;
+        self.remove(&victim)
+    }
+
+    fn insert_new(&mut self, key: AddrKey, mut info: AddrInfo) {
+        let group = addr_group(&info.addr).unwrap_or_default();
+        let bucket = self.new_bucket(&group, &info.source_group);
+        if self.new_buckets[bucket].len() >= BUCKET_SIZE {
+            self.evict(false, bucket);
+        }
+        info.tried = false;
+        info.bucket = bucket;
+        self.new_buckets[bucket].push(key.clone());
+        self.addrs.insert(key, info);
+    }
+
+    fn insert_tried(&mut self, key: AddrKey, mut info: AddrInfo) {
+        let group = addr_group(&info.addr).unwrap_or_default();
+        let bucket = self.tried_bucket(&key, &group);
+        if self.tried_buckets[bucket].len() >= BUCKET_SIZE {
+            // The oldest tried address goes back to the new table
+            if let Some(evicted) = self.evict(true, bucket) {
+                let evicted_key = AddrKey::from(&evicted.addr);
+                self.insert_new(evicted_key, evicted);
+            }
+        }
+        info.tried = true;
+        info.bucket = bucket;
+        self.tried_buckets[bucket].push(key.clone());
+        self.addrs.insert(key, info);
+    }
+
+    fn restore(&mut self, info: AddrInfo) {
+        if !is_valid_addr(&info.addr) {
+            return;
+        }
+        let key = AddrKey::from(&info.addr);
+        if self.addrs.contains_key(&key) {
+            return;
+        }
+        if info.tried {
+            self.insert_tried(key, info);
+        } else {
+            self.insert_new(key, info);
+        }
+    }
+
+    fn add(&mut self, addr: Multiaddr, source_group: Option<Vec<u8>>) {
+        if !is_valid_addr(&addr) {
+            return;
+        }
+        let group = match addr_group(&addr) {
+            Some(group) => group,
+            None => return,
+        };
+        if let Some(ip) = addr_ip(&addr) {
+            if self.is_banned(&ip) {
+                return;
+            }
+        }
+        let key = AddrKey::from(&addr);
+        let now = now_secs();
+        if let Some(info) = self.addrs.get_mut(&key) {
+            info.last_seen = now;
+            return;
+        }
+        let info = AddrInfo {
+            addr,
+            source_group: source_group.unwrap_or(group),
+            last_seen: now,
+            last_try: 0,
+            last_success: 0,
+            attempts: 0,
+            tried: false,
+            bucket: 0,
+        };
+        self.insert_new(key, info);
+    }
+
+    /// Record a connection attempt, call it when a dial fails
+    pub fn mark_attempt(&mut self, addr: &Multiaddr) {
+        if let Some(info) = self.addrs.get_mut(&AddrKey::from(addr)) {
+            info.last_try = now_secs();
+            info.attempts += 1;
+        }
+    }
+
+    /// Record a successful connection, the address moves to the tried table
+    pub fn mark_good(&mut self, addr: &Multiaddr) {
+        if !is_valid_addr(addr) {
+            return;
+        }
+        let group = match addr_group(addr) {
+            Some(group) => group,
+            None => return,
+        };
+        let key = AddrKey::from(addr);
+        let now = now_secs();
+        let mut info = self.remove(&key).unwrap_or_else(|| AddrInfo {
+            addr: addr.clone(),
+            source_group: group,
+            last_seen: now,
+            last_try: 0,
+            last_success: 0,
+            attempts: 0,
+            tried: false,
+            bucket: 0,
+        });
+        info.last_seen = now;
+        info.last_try = now;
+        info.last_success = now;
+        info.attempts = 0;
+        self.insert_tried(key, info);
+    }
+
+    /// Ban the ip for a day, its addresses are removed and not accepted again until then
+    pub fn ban(&mut self, ip: IpAddr) {
+        debug!("ban ip: {}", ip);
+        self.bans.insert(ip, Instant::now());
+        let banned = self
+            .addrs
+            .iter()
+            .filter(|(_, info)| addr_ip(&info.addr) == Some(ip))
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        for key in banned {
+            self.remove(&key);
+        }
+    }
+}
+
+impl Drop for AddrBook {
+    fn drop(&mut self) {
+        if let Err(err) = self.save() {
+            warn!("save address book error: {:?}", err);
+        }
+    }
+}
+
+impl AddressManager for AddrBook {
+    fn add_new_addr(&mut self, session_id: SessionId, addr: Multiaddr) {
+        let source_group = self
+            .sessions
+            .get(&session_id)
+            .and_then(|session| session.ip)
+            .map(netgroup);
+        self.add(addr, source_group);
+        self.maybe_save();
+    }
+
+    fn add_new_addrs(&mut self, session_id: SessionId, addrs: Vec<Multiaddr>) {
+        for addr in addrs.into_iter() {
+            self.add_new_addr(session_id, addr)
+        }
+    }
+
+    fn misbehave(&mut self, session_id: SessionId, kind: Misbehavior) -> MisbehaveResult {
+        let penalty = match kind {
+            Misbehavior::DuplicateGetNodes | Misbehavior::DuplicateFirstNodes => 50,
+            Misbehavior::TooManyItems { .. } | Misbehavior::TooManyAddresses(_) => 20,
+            // Forging records is never a mistake
+            Misbehavior::InvalidRecord => INIT_SCORE,
+        };
+        // Closed or never connected sessions have nothing left to punish
+        let session = match self.sessions.get_mut(&session_id) {
+            Some(session) => session,
+            None => {
+                debug!("misbehave of unknown session [{}] ignored", session_id);
+                return MisbehaveResult::Continue;
+            }
+        };
+        session.score -= penalty;
+        if session.score > 0 {
+            return MisbehaveResult::Continue;
+        }
+        if let Some(ip) = session.ip {
+            self.ban(ip);
+        }
+        MisbehaveResult::Disconnect
+    }
+
+    fn get_random(&mut self, n: usize) -> Vec<Multiaddr> {
+        let now = now_secs();
+        let mut rng = rand::thread_rng();
+        // Weighted sampling without replacement, see: Efraimidis and Spirakis
+        let mut candidates = self
+            .addrs
+            .values()
+            .filter(|info| !info.is_terrible(now))
+            .map(|info| {
+                let sort_key = rng.gen::<f64>().powf(1.0 / info.weight(now));
+                (sort_key, &info.addr)
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        candidates
+            .into_iter()
+            .take(n)
+            .map(|(_, addr)| addr.clone())
+            .collect()
+    }
+
+    fn connected(&mut self, session_id: SessionId, addr: &Multiaddr, ty: SessionType) {
+        let ip = addr_ip(addr);
+        self.sessions.insert(
+            session_id,
+            SessionInfo {
+                ip,
+                score: INIT_SCORE,
+            },
+        );
+        // Only the address we dialed is known to be reachable
+        if ty.is_outbound() {
+            self.mark_good(addr);
+        }
+    }
+
+    fn disconnected(&mut self, session_id: SessionId) {
+        self.sessions.remove(&session_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_group, AddrBook, BUCKET_SIZE};
+    use crate::addr::{AddressManager, Misbehavior};
+    use p2p::{multiaddr::Multiaddr, service::SessionType, SessionId};
+
+    fn addr(a: u8, b: u8, c: u8, port: u16) -> Multiaddr {
+        format!("/ip4/{}.{}.{}.1/tcp/{}", a, b, c, port)
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_new_and_tried() {
+        let mut book = AddrBook::new();
+        let session_id = SessionId::new(1);
+        book.connected(session_id, &addr(1, 1, 1, 8000), SessionType::Inbound);
+        book.add_new_addrs(session_id, vec![addr(2, 2, 2, 8000), addr(3, 3, 3, 8000)]);
+        assert_eq!(book.len(), 2);
+        assert_eq!(book.tried_len(), 0);
+
+        // Outbound session proves the address good
+        book.connected(
+            SessionId::new(2),
+            &addr(2, 2, 2, 8000),
+            SessionType::Outbound,
+        );
+        assert_eq!(book.len(), 2);
+        assert_eq!(book.tried_len(), 1);
+        assert_eq!(book.get_random(10).len(), 2);
+    }
+
+    #[test]
+    fn test_source_limited_to_its_buckets() {
+        let mut book = AddrBook::new();
+        let session_id = SessionId::new(1);
+        book.connected(session_id, &addr(1, 1, 1, 8000), SessionType::Inbound);
+        for i in 0..=255u8 {
+            for j in 0..=255u8 {
+                book.add_new_addr(session_id, addr(10, i, j, 8000));
+            }
+        }
+        // One source group fills at most NEW_BUCKETS_PER_SOURCE_GROUP buckets
+        assert!(book.len() <= 64 * BUCKET_SIZE);
+    }
+
+    #[test]
+    fn test_misbehave_ban() {
+        let mut book = AddrBook::new();
+        let session_id = SessionId::new(1);
+        let remote = addr(1, 1, 1, 8000);
+        book.connected(session_id, &remote, SessionType::Outbound);
+        assert_eq!(book.tried_len(), 1);
+
+        assert!(book
+            .misbehave(session_id, Misbehavior::DuplicateGetNodes)
+            .is_continue());
+        assert!(book
+            .misbehave(session_id, Misbehavior::DuplicateFirstNodes)
+            .is_disconnect());
+        assert!(book.is_empty());
+
+        // Banned ip is not accepted again
+        book.add_new_addr(SessionId::new(2), addr(1, 1, 1, 8001));
+        assert!(book.is_empty());
+
+        // Unknown sessions are not tracked
+        assert!(book
+            .misbehave(SessionId::new(3), Misbehavior::InvalidRecord)
+            .is_continue());
+        assert!(!book.sessions.contains_key(&SessionId::new(3)));
+    }
+
+    #[test]
+    fn test_non_ip_addr() {
+        let mut book = AddrBook::new();
+        let session_id = SessionId::new(1);
+        book.connected(session_id, &addr(1, 1, 1, 8000), SessionType::Inbound);
+        book.add_new_addrs(
+            session_id,
+            vec![
+                "/dns4/example.com/tcp/8000".parse().unwrap(),
+                "/dns4/example.com/tcp/8000/ws".parse().unwrap(),
+                "/ip4/0.0.0.0/tcp/8000".parse().unwrap(),
+            ],
+        );
+        assert_eq!(book.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_group() {
+        assert_eq!(parse_group("04ff0a"), Some(vec![4, 255, 10]));
+        assert_eq!(parse_group(""), Some(Vec::new()));
+        assert_eq!(parse_group("0"), None);
+        assert_eq!(parse_group("zz"), None);
+        // Multi-byte chars are rejected without panic
+        assert_eq!(parse_group("é"), None);
+        assert_eq!(parse_group("0é0"), None);
+        assert_eq!(parse_group("0\u{1f600}0"), None);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("addr_book_{}", rand::random::<u64>()));
+        {
+            let mut book = AddrBook::load(&path).unwrap();
+            let session_id = SessionId::new(1);
+            book.connected(session_id, &addr(1, 1, 1, 8000), SessionType::Outbound);
+            book.add_new_addr(session_id, addr(2, 2, 2, 8000));
+        }
+        let book = AddrBook::load(&path).unwrap();
+        assert_eq!(book.len(), 2);
+        assert_eq!(book.tried_len(), 1);
+        drop(book);
+        std::fs::remove_file(path).unwrap();
+    }
+}
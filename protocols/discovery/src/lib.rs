@@ -10,8 +10,9 @@ use log::{debug, warn};
 use p2p::{
     context::{ProtocolContext, ProtocolContextMutRef},
     multiaddr::Multiaddr,
+    peer_record::{InsertResult, PeerRecordStore, SignedPeerRecord},
     traits::ServiceProtocol,
-    utils::{is_reachable, multiaddr_to_socketaddr},
+    utils::extract_peer_id,
     SessionId,
 };
 use rand::seq::SliceRandom;
@@ -20,8 +21,11 @@ use tokio::timer::Interval;
 use std::time::{Duration, Instant};
 
 const CHECK_INTERVAL: Duration = Duration::from_secs(3);
+// The maximum number of addresses in a peer record
+const MAX_RECORD_ADDRS: usize = 10;
 
 mod addr;
+mod addr_book;
 mod protocol;
 mod substream;
 
@@ -41,17 +45,25 @@ mod protocol_generated_verifier;
 mod protocol_mol;
 
 pub use crate::{
-    addr::{AddrKnown, AddressManager, MisbehaveResult, Misbehavior, RawAddr},
+    addr::{
+        is_reachable_addr, is_valid_addr, AddrKey, AddrKnown, AddressManager, MisbehaveResult,
+        Misbehavior, RawAddr,
+    },
+    addr_book::AddrBook,
     protocol::{DiscoveryMessage, Node, Nodes},
     substream::{Substream, SubstreamKey, SubstreamValue},
 };
 
-use crate::{addr::DEFAULT_MAX_KNOWN, substream::RemoteAddress};
+use crate::{
+    addr::{with_peer_id, DEFAULT_MAX_KNOWN},
+    substream::{group_nodes, RemoteAddress},
+};
 
 pub struct DiscoveryProtocol<M> {
     discovery: Option<Discovery<M>>,
     discovery_handle: DiscoveryHandle,
     discovery_senders: HashMap<SessionId, Sender<Vec<u8>>>,
+    local_record: Option<SignedPeerRecord>,
 }
 
 impl<M: AddressManager> DiscoveryProtocol<M> {
@@ -61,7 +73,37 @@ impl<M: AddressManager> DiscoveryProtocol<M> {
             discovery: Some(discovery),
             discovery_handle,
             discovery_senders: HashMap::default(),
+            local_record: None,
+        }
+    }
+
+    // Sign the listen addresses again only when they changed
+    fn local_record(&mut self, context: &ProtocolContextMutRef) -> Option<SignedPeerRecord> {
+        let key_pair = context.key_pair()?;
+        let addrs = context
+            .listens()
+            .iter()
+            .filter(|addr| is_valid_addr(addr))
+            .take(MAX_RECORD_ADDRS)
+            .cloned()
+            .collect::<Vec<_>>();
+        if addrs.is_empty() {
+            return None;
         }
+        let record = match self.local_record.take() {
+            Some(ref record) if record.addrs() == &addrs[..] => record.clone(),
+            Some(record) => match record.renew(key_pair, addrs) {
+                Ok(new) => new,
+                Err(err) => {
+                    debug!("renew local record error: {}", err);
+                    self.local_record = Some(record);
+                    return None;
+                }
+            },
+            None => SignedPeerRecord::new(key_pair, addrs),
+        };
+        self.local_record = Some(record.clone());
+        Some(record)
     }
 }
 
@@ -99,7 +141,9 @@ impl<M: AddressManager + Send + 'static> ServiceProtocol for DiscoveryProtocol<M
 
         let (sender, receiver) = channel(8);
         self.discovery_senders.insert(session.id, sender);
-        let substream = Substream::new(context, receiver);
+        let local_record = self.local_record(&context);
+        let mut substream = Substream::new(context, receiver);
+        substream.local_record = local_record;
         match self.discovery_handle.substream_sender.try_send(substream) {
             Ok(_) => {
                 debug!("Send substream success");
@@ -162,6 +206,11 @@ pub struct Discovery<M> {
     check_interval: Interval,
 
     global_ip_only: bool,
+
+    // Latest records received of other peers
+    records: PeerRecordStore,
+    // Only learn addresses from records
+    strict_records: bool,
 }
 
 #[derive(Clone)]
@@ -185,6 +234,8 @@ impl<M: AddressManager> Discovery<M> {
             dead_keys: HashSet::default(),
             dynamic_query_cycle: query_cycle,
             global_ip_only: true,
+            records: PeerRecordStore::default(),
+            strict_records: false,
         }
     }
 
@@ -194,6 +245,23 @@ impl<M: AddressManager> Discovery<M> {
         self
     }
 
+    /// Store of the records received, share it with identify so that both keep the newest record
+    /// of a peer, default is a store of its own
+    pub fn records(mut self, records: PeerRecordStore) -> Self {
+        self.records = records;
+        self
+    }
+
+    /// Only learn the addresses of peers from their signed records, default is false
+    ///
+    /// Plain addresses are dropped, so nothing is learned from peers not sending records.
+    /// When it's false, unsigned addresses of peers without a known record are still accepted
+    /// for older peers, and anyone can forge them.
+    pub fn strict_records(mut self, strict: bool) -> Self {
+        self.strict_records = strict;
+        self
+    }
+
     pub fn addr_mgr(&self) -> &M {
         &self.addr_mgr
     }
@@ -210,6 +278,8 @@ impl<M: AddressManager> Discovery<M> {
                 Ok(Async::Ready(Some(substream))) => {
                     let key = substream.key();
                     debug!("Received a substream: key={:?}", key);
+                    self.addr_mgr
+                        .connected(key.session_id, &substream.remote_addr, key.direction);
                     let value = SubstreamValue::new(
                         key.direction,
                         substream,
@@ -261,11 +331,7 @@ impl<M: AddressManager> Stream for Discovery<M> {
 
         let announce_fn =
             |announce_multiaddrs: &mut Vec<Multiaddr>, global_ip_only: bool, addr: &Multiaddr| {
-                if !global_ip_only
-                    || multiaddr_to_socketaddr(addr)
-                        .map(|addr| is_reachable(addr.ip()))
-                        .unwrap_or_default()
-                {
+                if is_valid_addr(addr) && (!global_ip_only || is_reachable_addr(addr)) {
                     announce_multiaddrs.push(addr.clone());
                 }
             };
@@ -274,7 +340,7 @@ impl<M: AddressManager> Stream for Discovery<M> {
         for (key, value) in self.substreams.iter_mut() {
             value.check_timer();
 
-            match value.receive_messages(&mut self.addr_mgr) {
+            match value.receive_messages(&mut self.addr_mgr, &self.records) {
                 Ok(Some((session_id, nodes_list))) => {
                     for nodes in nodes_list {
                         self.pending_nodes
@@ -313,7 +379,8 @@ impl<M: AddressManager> Stream for Discovery<M> {
         let mut dead_addr = Vec::default();
         for key in self.dead_keys.drain() {
             if let Some(addr) = self.substreams.remove(&key) {
-                dead_addr.push(RawAddr::from(addr.remote_addr.into_inner()));
+                self.addr_mgr.disconnected(key.session_id);
+                dead_addr.push(AddrKey::from(&addr.remote_addr.into_inner()));
             }
         }
 
@@ -327,7 +394,7 @@ impl<M: AddressManager> Stream for Discovery<M> {
         let mut remain_keys = self.substreams.keys().cloned().collect::<Vec<_>>();
         debug!("announce_multiaddrs: {:?}", announce_multiaddrs);
         for announce_multiaddr in announce_multiaddrs.into_iter() {
-            let announce_addr = RawAddr::from(announce_multiaddr.clone());
+            let announce_addr = AddrKey::from(&announce_multiaddr);
             remain_keys.shuffle(&mut rng);
             for i in 0..2 {
                 if let Some(key) = remain_keys.get(i) {
@@ -342,7 +409,7 @@ impl<M: AddressManager> Stream for Discovery<M> {
                             && !value.addr_known.contains(&announce_addr)
                         {
                             value.announce_multiaddrs.push(announce_multiaddr.clone());
-                            value.addr_known.insert(announce_addr);
+                            value.addr_known.insert(announce_addr.clone());
                         }
                     }
                 }
@@ -352,12 +419,7 @@ impl<M: AddressManager> Stream for Discovery<M> {
         for (key, value) in self.substreams.iter_mut() {
             let announce_multiaddrs = value.announce_multiaddrs.split_off(0);
             if !announce_multiaddrs.is_empty() {
-                let items = announce_multiaddrs
-                    .into_iter()
-                    .map(|addr| Node {
-                        addresses: vec![addr],
-                    })
-                    .collect::<Vec<_>>();
+                let items = group_nodes(announce_multiaddrs, &self.records);
                 let nodes = Nodes {
                     announce: true,
                     items,
@@ -378,12 +440,37 @@ impl<M: AddressManager> Stream for Discovery<M> {
         }
 
         match self.pending_nodes.pop_front() {
-            Some((_key, session_id, nodes)) => {
-                let addrs = nodes
-                    .items
-                    .into_iter()
-                    .flat_map(|node| node.addresses.into_iter())
-                    .collect::<Vec<_>>();
+            Some((key, session_id, nodes)) => {
+                let mut addrs = Vec::new();
+                for node in nodes.items {
+                    match node.record {
+                        Some(record) => {
+                            if !self.verify_record(&key, session_id, &record) {
+                                continue;
+                            }
+                            let peer_id = record.peer_id();
+                            addrs.extend(
+                                record
+                                    .addrs()
+                                    .iter()
+                                    .filter(|addr| is_valid_addr(addr))
+                                    .map(|addr| with_peer_id(addr.clone(), &peer_id)),
+                            );
+                        }
+                        None if self.strict_records => {
+                            debug!("drop {} addresses without record", node.addresses.len());
+                        }
+                        None => {
+                            // Addresses of a peer with a record come from the record only
+                            let records = &self.records;
+                            addrs.extend(node.addresses.into_iter().filter(|addr| {
+                                extract_peer_id(addr)
+                                    .map(|peer_id| records.get(&peer_id).is_none())
+                                    .unwrap_or(true)
+                            }));
+                        }
+                    }
+                }
                 self.addr_mgr.add_new_addrs(session_id, addrs);
                 Ok(Async::Ready(Some(())))
             }
@@ -391,3 +478,33 @@ impl<M: AddressManager> Stream for Discovery<M> {
         }
     }
 }
+
+impl<M: AddressManager> Discovery<M> {
+    // Return true if the record is newer than the known one
+    fn verify_record(
+        &mut self,
+        key: &SubstreamKey,
+        session_id: SessionId,
+        record: &SignedPeerRecord,
+    ) -> bool {
+        if record.addrs().len() > MAX_RECORD_ADDRS {
+            debug!("drop record with {} addresses", record.addrs().len());
+            return false;
+        }
+        match self.records.insert(record.clone()) {
+            InsertResult::Inserted => true,
+            InsertResult::Ignored => false,
+            InsertResult::Invalid => {
+                warn!("invalid record from session {}", session_id);
+                if self
+                    .addr_mgr
+                    .misbehave(session_id, Misbehavior::InvalidRecord)
+                    .is_disconnect()
+                {
+                    self.dead_keys.insert(key.clone());
+                }
+                false
+            }
+        }
+    }
+}
@@ -7,15 +7,16 @@ use std::{convert::TryFrom, io};
 
 use bytes::{Bytes, BytesMut};
 use log::debug;
-use p2p::multiaddr::Multiaddr;
+use p2p::{multiaddr::Multiaddr, peer_record::SignedPeerRecord, secio::PublicKey};
 use tokio::codec::length_delimited::LengthDelimitedCodec;
 use tokio::codec::{Decoder, Encoder};
 
 #[cfg(feature = "flatc")]
 use crate::protocol_generated::p2p::discovery::{
-    BytesBuilder, DiscoveryMessage as FbsDiscoveryMessage, DiscoveryMessageBuilder,
-    DiscoveryPayload as FbsDiscoveryPayload, GetNodes as FbsGetNodes, GetNodesBuilder, NodeBuilder,
-    Nodes as FbsNodes, NodesBuilder,
+    Bytes as FbsBytes, BytesBuilder, DiscoveryMessage as FbsDiscoveryMessage,
+    DiscoveryMessageBuilder, DiscoveryPayload as FbsDiscoveryPayload, GetNodes as FbsGetNodes,
+    GetNodesBuilder, NodeBuilder, Nodes as FbsNodes, NodesBuilder, PeerRecord as FbsPeerRecord,
+    PeerRecordBuilder,
 };
 #[cfg(feature = "molc")]
 use crate::protocol_mol;
@@ -72,6 +73,7 @@ pub enum DiscoveryMessage {
         version: u32,
         count: u32,
         listen_port: Option<u16>,
+        record: Option<SignedPeerRecord>,
     },
     Nodes(Nodes),
 }
@@ -85,11 +87,18 @@ impl DiscoveryMessage {
                 version,
                 count,
                 listen_port,
+                record,
             } => {
+                let record = record
+                    .as_ref()
+                    .map(|record| record_to_fbs(&mut fbb, record));
                 let mut get_nodes_builder = GetNodesBuilder::new(&mut fbb);
                 get_nodes_builder.add_version(*version);
                 get_nodes_builder.add_count(*count);
                 get_nodes_builder.add_listen_port(listen_port.unwrap_or(0));
+                if let Some(record) = record {
+                    get_nodes_builder.add_record(record);
+                }
 
                 let get_nodes = get_nodes_builder.finish();
 
@@ -101,16 +110,16 @@ impl DiscoveryMessage {
             DiscoveryMessage::Nodes(Nodes { announce, items }) => {
                 let mut vec_items = Vec::new();
                 for item in items {
-                    let mut vec_addrs = Vec::new();
-                    for address in &item.addresses {
-                        let seq = fbb.create_vector(address.as_ref());
-                        let mut bytes_builder = BytesBuilder::new(&mut fbb);
-                        bytes_builder.add_seq(seq);
-                        vec_addrs.push(bytes_builder.finish());
-                    }
-                    let fbs_addrs = fbb.create_vector(&vec_addrs);
+                    let fbs_addrs = addrs_to_fbs(&mut fbb, &item.addresses);
+                    let record = item
+                        .record
+                        .as_ref()
+                        .map(|record| record_to_fbs(&mut fbb, record));
                     let mut node_builder = NodeBuilder::new(&mut fbb);
                     node_builder.add_addresses(fbs_addrs);
+                    if let Some(record) = record {
+                        node_builder.add_record(record);
+                    }
                     vec_items.push(node_builder.finish());
                 }
                 let fbs_items = fbb.create_vector(&vec_items);
@@ -141,10 +150,15 @@ impl DiscoveryMessage {
                 } else {
                     Some(fbs_get_nodes.listen_port())
                 };
+                let record = match fbs_get_nodes.record() {
+                    Some(fbs_record) => Some(fbs_to_record(&fbs_record)?),
+                    None => None,
+                };
                 Some(DiscoveryMessage::GetNodes {
                     version: fbs_get_nodes.version(),
                     count: fbs_get_nodes.count(),
                     listen_port,
+                    record,
                 })
             }
             FbsDiscoveryPayload::Nodes => {
@@ -153,14 +167,12 @@ impl DiscoveryMessage {
                 let mut items = Vec::new();
                 for i in 0..fbs_items.len() {
                     let fbs_node = fbs_items.get(i);
-                    let fbs_addresses = fbs_node.addresses()?;
-                    let mut addresses = Vec::new();
-                    for j in 0..fbs_addresses.len() {
-                        let address = fbs_addresses.get(j);
-                        let multiaddr = Multiaddr::try_from(address.seq()?.to_vec()).ok()?;
-                        addresses.push(multiaddr);
-                    }
-                    items.push(Node { addresses });
+                    let addresses = fbs_to_addrs(&fbs_node.addresses()?)?;
+                    let record = match fbs_node.record() {
+                        Some(fbs_record) => Some(fbs_to_record(&fbs_record)?),
+                        None => None,
+                    };
+                    items.push(Node { addresses, record });
                 }
                 Some(DiscoveryMessage::Nodes(Nodes {
                     announce: fbs_nodes.announce(),
@@ -178,6 +190,7 @@ impl DiscoveryMessage {
                 version,
                 count,
                 listen_port,
+                record,
             } => {
                 let version_le = version.to_le_bytes();
                 let count_le = count.to_le_bytes();
@@ -202,10 +215,14 @@ impl DiscoveryMessage {
                             .build()
                     }))
                     .build();
+                let record = protocol_mol::PeerRecordOpt::new_builder()
+                    .set(record.map(record_to_mol))
+                    .build();
                 let get_node = protocol_mol::GetNodes::new_builder()
                     .listen_port(listen_port)
                     .count(count)
                     .version(version)
+                    .record(record)
                     .build();
                 protocol_mol::DiscoveryPayload::new_builder()
                     .set(get_node)
@@ -218,17 +235,12 @@ impl DiscoveryMessage {
                     .build();
                 let mut item_vec = Vec::with_capacity(items.len());
                 for item in items {
-                    let mut vec_addrs = Vec::with_capacity(item.addresses.len());
-                    for addr in item.addresses {
-                        vec_addrs.push(
-                            protocol_mol::Bytes::new_builder()
-                                .set(addr.to_vec().into_iter().map(Into::into).collect())
-                                .build(),
-                        )
-                    }
-                    let bytes_vec = protocol_mol::BytesVec::new_builder().set(vec_addrs).build();
+                    let record = protocol_mol::PeerRecordOpt::new_builder()
+                        .set(item.record.map(record_to_mol))
+                        .build();
                     let node = protocol_mol::Node::new_builder()
-                        .addresses(bytes_vec)
+                        .addresses(addrs_to_mol(&item.addresses))
+                        .record(record)
                         .build();
                     item_vec.push(node)
                 }
@@ -262,10 +274,15 @@ impl DiscoveryMessage {
                     let le = port_reader.raw_data().as_ptr() as *const u16;
                     u16::from_le(unsafe { *le })
                 });
+                let record = match reader.record().to_opt() {
+                    Some(record_reader) => Some(mol_to_record(record_reader)?),
+                    None => None,
+                };
                 Some(DiscoveryMessage::GetNodes {
                     version,
                     count,
                     listen_port,
+                    record,
                 })
             }
             protocol_mol::DiscoveryPayloadUnionReader::Nodes(reader) => {
@@ -276,12 +293,12 @@ impl DiscoveryMessage {
                 };
                 let mut items = Vec::with_capacity(reader.items().len());
                 for node_reader in reader.items().iter() {
-                    let mut addresses = Vec::with_capacity(node_reader.addresses().len());
-                    for address_reader in node_reader.addresses().iter() {
-                        addresses
-                            .push(Multiaddr::try_from(address_reader.raw_data().to_vec()).ok()?)
-                    }
-                    items.push(Node { addresses })
+                    let addresses = mol_to_addrs(node_reader.addresses())?;
+                    let record = match node_reader.record().to_opt() {
+                        Some(record_reader) => Some(mol_to_record(record_reader)?),
+                        None => None,
+                    };
+                    items.push(Node { addresses, record })
                 }
                 Some(DiscoveryMessage::Nodes(Nodes { announce, items }))
             }
@@ -298,6 +315,132 @@ pub struct Nodes {
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Node {
     pub(crate) addresses: Vec<Multiaddr>,
+    // Signed addresses of the peer, the addresses above are not trusted when present
+    pub(crate) record: Option<SignedPeerRecord>,
+}
+
+#[cfg(feature = "flatc")]
+fn addrs_to_fbs<'b>(
+    fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+    addrs: &[Multiaddr],
+) -> flatbuffers::WIPOffset<flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<FbsBytes<'b>>>> {
+    let mut vec_addrs = Vec::with_capacity(addrs.len());
+    for address in addrs {
+        let seq = fbb.create_vector(address.as_ref());
+        let mut bytes_builder = BytesBuilder::new(fbb);
+        bytes_builder.add_seq(seq);
+        vec_addrs.push(bytes_builder.finish());
+    }
+    fbb.create_vector(&vec_addrs)
+}
+
+#[cfg(feature = "flatc")]
+fn fbs_to_addrs(
+    fbs_addrs: &flatbuffers::Vector<flatbuffers::ForwardsUOffset<FbsBytes>>,
+) -> Option<Vec<Multiaddr>> {
+    let mut addresses = Vec::with_capacity(fbs_addrs.len());
+    for i in 0..fbs_addrs.len() {
+        let address = fbs_addrs.get(i);
+        addresses.push(Multiaddr::try_from(address.seq()?.to_vec()).ok()?);
+    }
+    Some(addresses)
+}
+
+#[cfg(feature = "flatc")]
+fn record_to_fbs<'b>(
+    fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+    record: &SignedPeerRecord,
+) -> flatbuffers::WIPOffset<FbsPeerRecord<'b>> {
+    let pubkey = fbb.create_vector(&record.public_key().encode()[..]);
+    let addresses = addrs_to_fbs(fbb, record.addrs());
+    let signature = fbb.create_vector(record.signature());
+    let mut builder = PeerRecordBuilder::new(fbb);
+    builder.add_pubkey(pubkey);
+    builder.add_seq(record.seq());
+    builder.add_timestamp(record.timestamp());
+    builder.add_addresses(addresses);
+    builder.add_signature(signature);
+    builder.finish()
+}
+
+#[cfg(feature = "flatc")]
+fn fbs_to_record(fbs_record: &FbsPeerRecord) -> Option<SignedPeerRecord> {
+    Some(SignedPeerRecord::from_parts(
+        PublicKey::decode(fbs_record.pubkey()?)?,
+        fbs_record.seq(),
+        fbs_record.timestamp(),
+        fbs_to_addrs(&fbs_record.addresses()?)?,
+        fbs_record.signature()?.to_vec(),
+    ))
+}
+
+#[cfg(feature = "molc")]
+fn bytes_to_mol(data: &[u8]) -> protocol_mol::Bytes {
+    protocol_mol::Bytes::new_builder()
+        .set(data.iter().cloned().map(Into::into).collect())
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn u64_to_mol(value: u64) -> protocol_mol::Uint64 {
+    let le = value.to_le_bytes();
+    protocol_mol::Uint64::new_builder()
+        .nth0(le[0].into())
+        .nth1(le[1].into())
+        .nth2(le[2].into())
+        .nth3(le[3].into())
+        .nth4(le[4].into())
+        .nth5(le[5].into())
+        .nth6(le[6].into())
+        .nth7(le[7].into())
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn mol_to_u64(reader: protocol_mol::Uint64Reader) -> u64 {
+    let mut le = [0u8; 8];
+    le.copy_from_slice(reader.raw_data());
+    u64::from_le_bytes(le)
+}
+
+#[cfg(feature = "molc")]
+fn addrs_to_mol(addrs: &[Multiaddr]) -> protocol_mol::BytesVec {
+    let vec_addrs = addrs
+        .iter()
+        .map(|addr| bytes_to_mol(addr.as_ref()))
+        .collect();
+    protocol_mol::BytesVec::new_builder().set(vec_addrs).build()
+}
+
+#[cfg(feature = "molc")]
+fn mol_to_addrs(reader: protocol_mol::BytesVecReader) -> Option<Vec<Multiaddr>> {
+    let mut addresses = Vec::with_capacity(reader.len());
+    for address_reader in reader.iter() {
+        addresses.push(Multiaddr::try_from(address_reader.raw_data().to_vec()).ok()?)
+    }
+    Some(addresses)
+}
+
+#[cfg(feature = "molc")]
+fn record_to_mol(record: SignedPeerRecord) -> protocol_mol::PeerRecord {
+    protocol_mol::PeerRecord::new_builder()
+        .pubkey(bytes_to_mol(&record.public_key().clone().encode()))
+        .seq(u64_to_mol(record.seq()))
+        .timestamp(u64_to_mol(record.timestamp()))
+        .addresses(addrs_to_mol(record.addrs()))
+        .signature(bytes_to_mol(record.signature()))
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn mol_to_record(reader: protocol_mol::PeerRecordReader) -> Option<SignedPeerRecord> {
+    Some(SignedPeerRecord::from_parts(
+        PublicKey::decode(reader.pubkey().raw_data())?,
+        mol_to_u64(reader.seq()),
+        mol_to_u64(reader.timestamp()),
+        mol_to_addrs(reader.addresses())?,
+        reader.signature().raw_data().to_vec(),
+    ))
 }
 
 impl std::fmt::Display for DiscoveryMessage {
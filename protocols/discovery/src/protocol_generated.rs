@@ -219,8 +219,9 @@ impl<'a> GetNodes<'a> {
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args GetNodesArgs) -> flatbuffers::WIPOffset<GetNodes<'bldr>> {
+        args: &'args GetNodesArgs<'args>) -> flatbuffers::WIPOffset<GetNodes<'bldr>> {
       let mut builder = GetNodesBuilder::new(_fbb);
+      if let Some(x) = args.record { builder.add_record(x); }
       builder.add_count(args.count);
       builder.add_version(args.version);
       builder.add_listen_port(args.listen_port);
@@ -230,6 +231,7 @@ impl<'a> GetNodes<'a> {
     pub const VT_VERSION: flatbuffers::VOffsetT = 4;
     pub const VT_COUNT: flatbuffers::VOffsetT = 6;
     pub const VT_LISTEN_PORT: flatbuffers::VOffsetT = 8;
+    pub const VT_RECORD: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub fn version(&self) -> u32 {
@@ -243,20 +245,26 @@ impl<'a> GetNodes<'a> {
   pub fn listen_port(&self) -> u16 {
     self._tab.get::<u16>(GetNodes::VT_LISTEN_PORT, Some(0)).unwrap()
   }
+  #[inline]
+  pub fn record(&self) -> Option<PeerRecord<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<PeerRecord<'a>>>(GetNodes::VT_RECORD, None)
+  }
 }
 
-pub struct GetNodesArgs {
+pub struct GetNodesArgs<'a> {
     pub version: u32,
     pub count: u32,
     pub listen_port: u16,
+    pub record: Option<flatbuffers::WIPOffset<PeerRecord<'a >>>,
 }
-impl<'a> Default for GetNodesArgs {
+impl<'a> Default for GetNodesArgs<'a> {
     #[inline]
     fn default() -> Self {
         GetNodesArgs {
             version: 0,
             count: 0,
             listen_port: 0,
+            record: None,
         }
     }
 }
@@ -278,6 +286,10 @@ impl<'a: 'b, 'b> GetNodesBuilder<'a, 'b> {
     self.fbb_.push_slot::<u16>(GetNodes::VT_LISTEN_PORT, listen_port, 0);
   }
   #[inline]
+  pub fn add_record(&mut self, record: flatbuffers::WIPOffset<PeerRecord<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<PeerRecord>>(GetNodes::VT_RECORD, record);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GetNodesBuilder<'a, 'b> {
     let start = _fbb.start_table();
     GetNodesBuilder {
@@ -409,26 +421,34 @@ impl<'a> Node<'a> {
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
         args: &'args NodeArgs<'args>) -> flatbuffers::WIPOffset<Node<'bldr>> {
       let mut builder = NodeBuilder::new(_fbb);
+      if let Some(x) = args.record { builder.add_record(x); }
       if let Some(x) = args.addresses { builder.add_addresses(x); }
       builder.finish()
     }
 
     pub const VT_ADDRESSES: flatbuffers::VOffsetT = 4;
+    pub const VT_RECORD: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub fn addresses(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>> {
     self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>>>(Node::VT_ADDRESSES, None)
   }
+  #[inline]
+  pub fn record(&self) -> Option<PeerRecord<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<PeerRecord<'a>>>(Node::VT_RECORD, None)
+  }
 }
 
 pub struct NodeArgs<'a> {
     pub addresses: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Bytes<'a >>>>>,
+    pub record: Option<flatbuffers::WIPOffset<PeerRecord<'a >>>,
 }
 impl<'a> Default for NodeArgs<'a> {
     #[inline]
     fn default() -> Self {
         NodeArgs {
             addresses: None,
+            record: None,
         }
     }
 }
@@ -442,6 +462,10 @@ impl<'a: 'b, 'b> NodeBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_ADDRESSES, addresses);
   }
   #[inline]
+  pub fn add_record(&mut self, record: flatbuffers::WIPOffset<PeerRecord<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<PeerRecord>>(Node::VT_RECORD, record);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NodeBuilder<'a, 'b> {
     let start = _fbb.start_table();
     NodeBuilder {
@@ -456,6 +480,130 @@ impl<'a: 'b, 'b> NodeBuilder<'a, 'b> {
   }
 }
 
+pub enum PeerRecordOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct PeerRecord<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PeerRecord<'a> {
+    type Inner = PeerRecord<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> PeerRecord<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        PeerRecord {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PeerRecordArgs<'args>) -> flatbuffers::WIPOffset<PeerRecord<'bldr>> {
+      let mut builder = PeerRecordBuilder::new(_fbb);
+      builder.add_timestamp(args.timestamp);
+      builder.add_seq(args.seq);
+      if let Some(x) = args.signature { builder.add_signature(x); }
+      if let Some(x) = args.addresses { builder.add_addresses(x); }
+      if let Some(x) = args.pubkey { builder.add_pubkey(x); }
+      builder.finish()
+    }
+
+    pub const VT_PUBKEY: flatbuffers::VOffsetT = 4;
+    pub const VT_SEQ: flatbuffers::VOffsetT = 6;
+    pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 8;
+    pub const VT_ADDRESSES: flatbuffers::VOffsetT = 10;
+    pub const VT_SIGNATURE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub fn pubkey(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(PeerRecord::VT_PUBKEY, None).map(|v| v.safe_slice())
+  }
+  #[inline]
+  pub fn seq(&self) -> u64 {
+    self._tab.get::<u64>(PeerRecord::VT_SEQ, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn timestamp(&self) -> u64 {
+    self._tab.get::<u64>(PeerRecord::VT_TIMESTAMP, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn addresses(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>>>(PeerRecord::VT_ADDRESSES, None)
+  }
+  #[inline]
+  pub fn signature(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(PeerRecord::VT_SIGNATURE, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct PeerRecordArgs<'a> {
+    pub pubkey: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+    pub seq: u64,
+    pub timestamp: u64,
+    pub addresses: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Bytes<'a >>>>>,
+    pub signature: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for PeerRecordArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        PeerRecordArgs {
+            pubkey: None,
+            seq: 0,
+            timestamp: 0,
+            addresses: None,
+            signature: None,
+        }
+    }
+}
+pub struct PeerRecordBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PeerRecordBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_pubkey(&mut self, pubkey: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PeerRecord::VT_PUBKEY, pubkey);
+  }
+  #[inline]
+  pub fn add_seq(&mut self, seq: u64) {
+    self.fbb_.push_slot::<u64>(PeerRecord::VT_SEQ, seq, 0);
+  }
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: u64) {
+    self.fbb_.push_slot::<u64>(PeerRecord::VT_TIMESTAMP, timestamp, 0);
+  }
+  #[inline]
+  pub fn add_addresses(&mut self, addresses: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Bytes<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PeerRecord::VT_ADDRESSES, addresses);
+  }
+  #[inline]
+  pub fn add_signature(&mut self, signature: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PeerRecord::VT_SIGNATURE, signature);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PeerRecordBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PeerRecordBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PeerRecord<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
 pub enum BytesOffset {}
 #[derive(Copy, Clone, Debug, PartialEq)]
 
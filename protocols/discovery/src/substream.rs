@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::io;
 use std::time::{Duration, Instant};
 
@@ -9,14 +9,16 @@ use p2p::multiaddr::{Multiaddr, Protocol};
 use p2p::{
     context::ProtocolContextMutRef,
     error::Error,
+    peer_record::{PeerRecordStore, SignedPeerRecord},
+    secio::PeerId,
     service::{ServiceControl, SessionType},
-    utils::multiaddr_to_socketaddr,
+    utils::{extract_peer_id, multiaddr_to_socketaddr},
     ProtocolId, SessionId,
 };
 use tokio::codec::Framed;
 use tokio::io::{AsyncRead, AsyncWrite};
 
-use crate::addr::{AddrKnown, AddressManager, Misbehavior, RawAddr};
+use crate::addr::{is_valid_addr, with_peer_id, AddrKey, AddrKnown, AddressManager, Misbehavior};
 use crate::protocol::{DiscoveryCodec, DiscoveryMessage, Node, Nodes};
 use bytes::Bytes;
 
@@ -116,6 +118,7 @@ pub struct SubstreamValue {
     pub(crate) announce: bool,
     pub(crate) last_announce: Option<Instant>,
     pub(crate) announce_multiaddrs: Vec<Multiaddr>,
+    local_record: Option<SignedPeerRecord>,
     session_id: SessionId,
     announce_interval: Duration,
     received_get_nodes: bool,
@@ -139,10 +142,9 @@ impl SubstreamValue {
                 version: VERSION,
                 count: MAX_ADDR_TO_SEND as u32,
                 listen_port: substream.listen_port,
+                record: substream.local_record.clone(),
             });
-            addr_known.insert(RawAddr::from(
-                multiaddr_to_socketaddr(&substream.remote_addr).unwrap(),
-            ));
+            addr_known.insert(AddrKey::from(&substream.remote_addr));
 
             RemoteAddress::Listen(substream.remote_addr)
         } else {
@@ -160,14 +162,15 @@ impl SubstreamValue {
             session_id,
             announce: false,
             announce_multiaddrs: Vec::new(),
+            local_record: substream.local_record,
             received_get_nodes: false,
             received_nodes: false,
             remote_closed: false,
         }
     }
 
-    fn remote_raw_addr(&self) -> Option<RawAddr> {
-        multiaddr_to_socketaddr(self.remote_addr.to_inner()).map(RawAddr::from)
+    fn remote_addr_key(&self) -> AddrKey {
+        AddrKey::from(self.remote_addr.to_inner())
     }
 
     pub(crate) fn check_timer(&mut self) {
@@ -200,9 +203,14 @@ impl SubstreamValue {
         &mut self,
         message: DiscoveryMessage,
         addr_mgr: &mut M,
+        records: &PeerRecordStore,
     ) -> Result<Option<Nodes>, io::Error> {
         match message {
-            DiscoveryMessage::GetNodes { listen_port, .. } => {
+            DiscoveryMessage::GetNodes {
+                listen_port,
+                record,
+                ..
+            } => {
                 if self.received_get_nodes {
                     // TODO: misbehavior
                     if addr_mgr
@@ -223,9 +231,8 @@ impl SubstreamValue {
                     debug!("listen port: {:?}", listen_port);
                     if let Some(port) = listen_port {
                         self.remote_addr.update_port(port);
-                        if let Some(raw_addr) = self.remote_raw_addr() {
-                            self.addr_known.insert(raw_addr);
-                        }
+                        let addr_key = self.remote_addr_key();
+                        self.addr_known.insert(addr_key);
                         // add client listen address to manager
                         if let RemoteAddress::Listen(ref addr) = self.remote_addr {
                             addr_mgr.add_new_addr(self.session_id, addr.clone());
@@ -238,12 +245,11 @@ impl SubstreamValue {
                             items[idx] = last_item;
                         }
                     }
-                    let items = items
-                        .into_iter()
-                        .map(|addr| Node {
-                            addresses: vec![addr],
-                        })
-                        .collect::<Vec<_>>();
+                    let mut items = group_nodes(items, records);
+                    if let Some(ref local_record) = self.local_record {
+                        items.insert(0, record_node(local_record));
+                        items.truncate(MAX_ADDR_TO_SEND);
+                    }
                     let nodes = Nodes {
                         announce: false,
                         items,
@@ -251,9 +257,18 @@ impl SubstreamValue {
                     self.pending_messages
                         .push_back(DiscoveryMessage::Nodes(nodes));
                     self.received_get_nodes = true;
+
+                    // The record of the remote peer goes the same way as the announced nodes
+                    return Ok(record.map(|record| Nodes {
+                        announce: true,
+                        items: vec![Node {
+                            addresses: Vec::new(),
+                            record: Some(record),
+                        }],
+                    }));
                 }
             }
-            DiscoveryMessage::Nodes(nodes) => {
+            DiscoveryMessage::Nodes(mut nodes) => {
                 for item in &nodes.items {
                     if item.addresses.len() > MAX_ADDRS {
                         let misbehavior = Misbehavior::TooManyAddresses(item.addresses.len());
@@ -267,6 +282,21 @@ impl SubstreamValue {
                     }
                 }
 
+                // Drop what can't be used, unknown address types may come from newer versions
+                for item in nodes.items.iter_mut() {
+                    item.addresses.retain(|addr| {
+                        let valid = is_valid_addr(addr);
+                        if !valid {
+                            debug!("drop invalid address: {}", addr);
+                        }
+                        valid
+                    });
+                    if !is_same_peer(&item.addresses) {
+                        debug!("drop node of different peers: {:?}", item.addresses);
+                        item.addresses.clear();
+                    }
+                }
+
                 if nodes.announce {
                     if nodes.items.len() > ANNOUNCE_THRESHOLD {
                         warn!("Nodes items more than {}", ANNOUNCE_THRESHOLD);
@@ -283,6 +313,9 @@ impl SubstreamValue {
                             return Err(io::ErrorKind::Other.into());
                         }
                     } else {
+                        nodes
+                            .items
+                            .retain(|item| !item.addresses.is_empty() || item.record.is_some());
                         return Ok(Some(nodes));
                     }
                 } else if self.received_nodes {
@@ -315,6 +348,9 @@ impl SubstreamValue {
                     }
                 } else {
                     self.received_nodes = true;
+                    nodes
+                        .items
+                        .retain(|item| !item.addresses.is_empty() || item.record.is_some());
                     return Ok(Some(nodes));
                 }
             }
@@ -325,6 +361,7 @@ impl SubstreamValue {
     pub(crate) fn receive_messages<M: AddressManager>(
         &mut self,
         addr_mgr: &mut M,
+        records: &PeerRecordStore,
     ) -> Result<Option<(SessionId, Vec<Nodes>)>, io::Error> {
         if self.remote_closed {
             return Ok(None);
@@ -335,12 +372,12 @@ impl SubstreamValue {
             match self.framed_stream.poll()? {
                 Async::Ready(Some(message)) => {
                     trace!("received message {}", message);
-                    if let Some(nodes) = self.handle_message(message, addr_mgr)? {
+                    if let Some(nodes) = self.handle_message(message, addr_mgr, records)? {
                         // Add to known address list
                         for node in &nodes.items {
                             for addr in &node.addresses {
                                 trace!("received address: {}", addr);
-                                self.addr_known.insert(RawAddr::from(addr.clone()));
+                                self.addr_known.insert(AddrKey::from(addr));
                             }
                         }
                         nodes_list.push(nodes);
@@ -360,11 +397,65 @@ impl SubstreamValue {
     }
 }
 
+/// Put the addresses of the same peer into one node, up to `MAX_ADDRS` addresses per node
+///
+/// An address without peer id is a node by itself. A peer with a known record is sent as the
+/// record only.
+pub(crate) fn group_nodes(addrs: Vec<Multiaddr>, records: &PeerRecordStore) -> Vec<Node> {
+    let mut nodes: Vec<Node> = Vec::new();
+    let mut peer_nodes: HashMap<PeerId, usize> = HashMap::new();
+    for addr in addrs {
+        match extract_peer_id(&addr) {
+            Some(peer_id) => match peer_nodes.get(&peer_id).cloned() {
+                Some(index) if nodes[index].record.is_some() => (),
+                Some(index) if nodes[index].addresses.len() < MAX_ADDRS => {
+                    nodes[index].addresses.push(addr)
+                }
+                _ => {
+                    let node = match records.get(&peer_id) {
+                        Some(record) => record_node(&record),
+                        None => Node {
+                            addresses: vec![addr],
+                            record: None,
+                        },
+                    };
+                    peer_nodes.insert(peer_id, nodes.len());
+                    nodes.push(node);
+                }
+            },
+            None => nodes.push(Node {
+                addresses: vec![addr],
+                record: None,
+            }),
+        }
+    }
+    nodes
+}
+
+// The addresses are kept for the peers not knowing records
+pub(crate) fn record_node(record: &SignedPeerRecord) -> Node {
+    Node {
+        addresses: record.addrs().iter().take(MAX_ADDRS).cloned().collect(),
+        record: Some(record.clone()),
+    }
+}
+
+// All addresses of a node must belong to the same peer
+fn is_same_peer(addrs: &[Multiaddr]) -> bool {
+    let mut peer_ids = addrs.iter().filter_map(extract_peer_id);
+    match peer_ids.next() {
+        Some(first) => peer_ids.all(|peer_id| peer_id == first),
+        None => true,
+    }
+}
+
 pub struct Substream {
     pub remote_addr: Multiaddr,
     pub direction: SessionType,
     pub stream: StreamHandle,
     pub listen_port: Option<u16>,
+    /// Signed listen addresses of the local peer, sent to the remote peer
+    pub local_record: Option<SignedPeerRecord>,
 }
 
 impl Substream {
@@ -380,16 +471,23 @@ impl Substream {
             context
                 .listens()
                 .iter()
-                .map(|address| multiaddr_to_socketaddr(address).unwrap().port())
+                .filter_map(multiaddr_to_socketaddr)
+                .map(|address| address.port())
                 .nth(0)
         } else {
             None
         };
+        // Addresses of the remote peer are known with its peer id
+        let remote_addr = match context.session.remote_pubkey {
+            Some(ref pubkey) => with_peer_id(context.session.address.clone(), &pubkey.peer_id()),
+            None => context.session.address.clone(),
+        };
         Substream {
-            remote_addr: context.session.address.clone(),
+            remote_addr,
             direction: context.session.ty,
             stream,
             listen_port,
+            local_record: None,
         }
     }
 
@@ -0,0 +1,90 @@
+use env_logger;
+use log::{debug, info};
+
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use futures::{future::lazy, prelude::*};
+use p2p::{
+    builder::{MetaBuilder, ServiceBuilder},
+    context::ServiceContext,
+    secio::SecioKeyPair,
+    service::{DialProtocol, ProtocolHandle, ProtocolMeta, ServiceError, ServiceEvent},
+    traits::ServiceHandle,
+    ProtocolId,
+};
+use tentacle_gossipsub::{Config, Gossipsub, GossipsubProtocol, ValidationResult};
+use tokio::timer::Interval;
+
+fn main() {
+    env_logger::init();
+    let key_pair = SecioKeyPair::secp256k1_generated();
+    let gossipsub = Gossipsub::new(key_pair.clone(), Config::default()).validator(
+        "greeting".to_owned(),
+        |message| {
+            if message.data.is_empty() {
+                ValidationResult::Reject
+            } else {
+                ValidationResult::Accept
+            }
+        },
+    );
+    let mut handle = gossipsub.handle();
+    let mut service = ServiceBuilder::default()
+        .insert_protocol(create_meta(1.into(), gossipsub))
+        .key_pair(key_pair)
+        .forever(true)
+        .build(SimpleHandler {});
+
+    let receiver = handle.subscribe("greeting".to_owned());
+    if std::env::args().nth(1) == Some("server".to_string()) {
+        debug!("Starting server ......");
+        let _ = service.listen("/ip4/127.0.0.1/tcp/1337".parse().unwrap());
+        tokio::run(lazy(|| {
+            tokio::spawn(
+                Interval::new(Instant::now(), Duration::from_secs(3))
+                    .map_err(|_| ())
+                    .for_each(move |_| {
+                        handle.publish("greeting".to_owned(), Bytes::from("hello"));
+                        Ok(())
+                    }),
+            );
+            service.for_each(|_| Ok(()))
+        }))
+    } else {
+        debug!("Starting client ......");
+        let _ = service.dial(
+            "/ip4/127.0.0.1/tcp/1337".parse().unwrap(),
+            DialProtocol::All,
+        );
+        let _ = service.listen("/ip4/127.0.0.1/tcp/1338".parse().unwrap());
+        tokio::run(lazy(|| {
+            tokio::spawn(receiver.for_each(|message| {
+                info!("client receive message: {:?}", message);
+                Ok(())
+            }));
+            service.for_each(|_| Ok(()))
+        }))
+    }
+}
+
+fn create_meta(id: ProtocolId, gossipsub: Gossipsub) -> ProtocolMeta {
+    MetaBuilder::new()
+        .id(id)
+        .service_handle(move || {
+            ProtocolHandle::Callback(Box::new(GossipsubProtocol::new(gossipsub)))
+        })
+        .build()
+}
+
+struct SimpleHandler {}
+
+impl ServiceHandle for SimpleHandler {
+    fn handle_error(&mut self, _env: &mut ServiceContext, error: ServiceError) {
+        debug!("service error: {:?}", error);
+    }
+
+    fn handle_event(&mut self, _env: &mut ServiceContext, event: ServiceEvent) {
+        debug!("service event: {:?}", event);
+    }
+}
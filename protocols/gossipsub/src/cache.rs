@@ -0,0 +1,145 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::protocol::{Message, MessageId, Topic};
+
+/// Recent messages kept to answer `IWant`, in a sliding window of heartbeats
+///
+/// Only the ids of the last `gossip` windows are announced with `IHave`, messages live for
+/// `history` windows
+pub(crate) struct MessageCache {
+    messages: HashMap<MessageId, Message>,
+    // The newest window first
+    history: VecDeque<Vec<(MessageId, Topic)>>,
+    gossip: usize,
+}
+
+impl MessageCache {
+    pub(crate) fn new(gossip: usize, history: usize) -> Self {
+        debug_assert!(gossip <= history);
+        let mut windows = VecDeque::with_capacity(history);
+        windows.resize_with(history, Vec::new);
+        MessageCache {
+            messages: HashMap::default(),
+            history: windows,
+            gossip,
+        }
+    }
+
+    pub(crate) fn put(&mut self, message: Message) {
+        let id = message.id();
+        if self.messages.contains_key(&id) {
+            return;
+        }
+        if let Some(window) = self.history.front_mut() {
+            window.push((id.clone(), message.topic.clone()));
+        }
+        self.messages.insert(id, message);
+    }
+
+    pub(crate) fn get(&self, id: &[u8]) -> Option<&Message> {
+        self.messages.get(id)
+    }
+
+    /// Ids of the messages of the topic in the gossip windows
+    pub(crate) fn gossip_ids(&self, topic: &str) -> Vec<MessageId> {
+        self.history
+            .iter()
+            .take(self.gossip)
+            .flat_map(|window| window.iter())
+            .filter(|(_, msg_topic)| msg_topic == topic)
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// Start a new window, messages of the oldest window are dropped
+    pub(crate) fn shift(&mut self) {
+        if let Some(window) = self.history.pop_back() {
+            for (id, _) in window {
+                self.messages.remove(&id);
+            }
+        }
+        self.history.push_front(Vec::new());
+    }
+}
+
+/// Ids of messages already received, duplicates are dropped without validation
+pub(crate) struct SeenCache {
+    seen: HashMap<MessageId, Instant>,
+    ttl: Duration,
+}
+
+impl SeenCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        SeenCache {
+            seen: HashMap::default(),
+            ttl,
+        }
+    }
+
+    /// Return false if the id was already seen
+    pub(crate) fn insert(&mut self, id: MessageId) -> bool {
+        self.seen.insert(id, Instant::now()).is_none()
+    }
+
+    pub(crate) fn contains(&self, id: &[u8]) -> bool {
+        self.seen.contains_key(id)
+    }
+
+    pub(crate) fn remove_expired(&mut self) {
+        let ttl = self.ttl;
+        self.seen.retain(|_, time| time.elapsed() < ttl);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MessageCache, SeenCache};
+    use crate::protocol::Message;
+    use bytes::Bytes;
+    use p2p::secio::SecioKeyPair;
+    use std::time::Duration;
+
+    fn message(seqno: u64, topic: &str) -> Message {
+        Message::new_signed(
+            &SecioKeyPair::secp256k1_generated(),
+            seqno,
+            topic.to_owned(),
+            Bytes::from("data"),
+        )
+    }
+
+    #[test]
+    fn test_message_cache_shift() {
+        let mut cache = MessageCache::new(2, 3);
+        let first = message(1, "a");
+        cache.put(first.clone());
+        cache.put(message(2, "b"));
+        assert_eq!(cache.gossip_ids("a"), vec![first.id()]);
+
+        cache.shift();
+        let second = message(3, "a");
+        cache.put(second.clone());
+        assert_eq!(cache.gossip_ids("a"), vec![second.id(), first.id()]);
+
+        // Out of the gossip windows, still kept in history
+        cache.shift();
+        assert_eq!(cache.gossip_ids("a"), vec![second.id()]);
+        assert!(cache.get(&first.id()).is_some());
+
+        // Out of history
+        cache.shift();
+        assert!(cache.get(&first.id()).is_none());
+        assert!(cache.get(&second.id()).is_some());
+    }
+
+    #[test]
+    fn test_seen_cache() {
+        let mut seen = SeenCache::new(Duration::from_secs(0));
+        let id = message(1, "a").id();
+        assert!(seen.insert(id.clone()));
+        assert!(!seen.insert(id.clone()));
+        seen.remove_expired();
+        assert!(!seen.contains(&id));
+    }
+}
@@ -0,0 +1,1214 @@
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use bytes::Bytes;
+use futures::{
+    prelude::*,
+    sync::mpsc::{channel, Receiver, Sender},
+    Async, Poll, Stream,
+};
+use log::{debug, warn};
+use p2p::{
+    context::{ProtocolContext, ProtocolContextMutRef},
+    secio::{PeerId, SecioKeyPair},
+    service::ServiceControl,
+    traits::ServiceProtocol,
+    ProtocolId, SessionId,
+};
+use rand::seq::SliceRandom;
+use tokio::timer::Interval;
+
+mod cache;
+mod protocol;
+mod score;
+
+#[cfg(feature = "flatc")]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+mod protocol_generated;
+#[cfg(feature = "flatc")]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+#[allow(dead_code)]
+mod protocol_generated_verifier;
+#[cfg(feature = "molc")]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+#[allow(dead_code)]
+mod protocol_mol;
+
+pub use crate::{
+    protocol::{GossipMessage, Message, MessageId, Topic, MESSAGE_SIGN_DOMAIN},
+    score::PeerScore,
+};
+
+use crate::{
+    cache::{MessageCache, SeenCache},
+    score::NoScore,
+};
+
+// Capacity of the channel of a local subscriber
+const SUBSCRIBER_CHANNEL_SIZE: usize = 256;
+
+/// Gossipsub parameters
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Target number of peers in the mesh of a topic, default is 6
+    pub mesh_n: usize,
+    /// Mesh is filled up to `mesh_n` below this, default is 4
+    pub mesh_n_low: usize,
+    /// Mesh is trimmed down to `mesh_n` above this, default is 12
+    pub mesh_n_high: usize,
+    /// Number of peers out of the mesh to send `IHave` to at heartbeat, default is 6
+    pub gossip_lazy: usize,
+    /// Default is 1 second
+    pub heartbeat_interval: Duration,
+    /// Fanout peers of a topic published to without subscription are forgot after this,
+    /// default is 60 seconds
+    pub fanout_ttl: Duration,
+    /// Number of heartbeats a message is kept to answer `IWant`, default is 5
+    pub history_length: usize,
+    /// Number of heartbeats a message is announced with `IHave`, default is 3
+    pub history_gossip: usize,
+    /// Duplicates are detected for this long, default is 2 minutes
+    pub seen_ttl: Duration,
+    /// Maximum size of a received frame and of published data, default is 1M
+    pub max_transmit_size: usize,
+    /// Maximum number of message ids in an `IHave`, and of message ids asked from a peer by
+    /// `IWant` in a heartbeat, default is 5000
+    pub max_ihave_length: usize,
+    /// Maximum number of `IHave` handled from a peer in a heartbeat, default is 10
+    pub max_ihave_messages: usize,
+    /// Maximum number of messages sent to a peer in answer to `IWant` in a heartbeat,
+    /// default is 5000
+    pub max_iwant_length: usize,
+    /// Maximum number of topics a peer is known to subscribe, further subscriptions are
+    /// ignored, default is 128
+    pub max_subscriptions_per_peer: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            mesh_n: 6,
+            mesh_n_low: 4,
+            mesh_n_high: 12,
+            gossip_lazy: 6,
+            heartbeat_interval: Duration::from_secs(1),
+            fanout_ttl: Duration::from_secs(60),
+            history_length: 5,
+            history_gossip: 3,
+            seen_ttl: Duration::from_secs(120),
+            max_transmit_size: 1024 * 1024,
+            max_ihave_length: 5000,
+            max_ihave_messages: 10,
+            max_iwant_length: 5000,
+            max_subscriptions_per_peer: 128,
+        }
+    }
+}
+
+/// Result of a topic validator
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ValidationResult {
+    /// Deliver and forward the message
+    Accept,
+    /// Drop the message and penalize the peer that sent it
+    Reject,
+    /// Drop the message silently
+    Ignore,
+}
+
+type Validator = Box<dyn Fn(&Message) -> ValidationResult + Send>;
+
+pub(crate) enum SessionEvent {
+    Connected {
+        session_id: SessionId,
+        peer_id: PeerId,
+    },
+    Disconnected {
+        session_id: SessionId,
+    },
+    Received {
+        session_id: SessionId,
+        data: Bytes,
+    },
+}
+
+pub(crate) enum Command {
+    Subscribe {
+        topic: Topic,
+        sender: Sender<Message>,
+    },
+    Unsubscribe {
+        topic: Topic,
+    },
+    Publish {
+        topic: Topic,
+        data: Bytes,
+    },
+}
+
+pub struct GossipsubProtocol {
+    gossipsub: Option<Gossipsub>,
+    event_sender: Sender<SessionEvent>,
+}
+
+impl GossipsubProtocol {
+    pub fn new(gossipsub: Gossipsub) -> GossipsubProtocol {
+        let event_sender = gossipsub.event_sender.clone();
+        GossipsubProtocol {
+            gossipsub: Some(gossipsub),
+            event_sender,
+        }
+    }
+
+    fn send_event(&mut self, event: SessionEvent) {
+        // TODO: handle channel is full (wait for poll API?)
+        if let Err(err) = self.event_sender.try_send(event) {
+            if err.is_full() {
+                warn!("channel is full");
+            } else if err.is_disconnected() {
+                warn!("channel is disconnected");
+            } else {
+                warn!("other channel error: {:?}", err);
+            }
+        }
+    }
+}
+
+impl ServiceProtocol for GossipsubProtocol {
+    fn init(&mut self, context: &mut ProtocolContext) {
+        debug!("protocol [gossipsub({})]: init", context.proto_id);
+
+        let mut gossipsub = self.gossipsub.take().unwrap();
+        gossipsub.proto_id = context.proto_id;
+        gossipsub.control = Some(context.control().clone());
+        let gossipsub_task = gossipsub
+            .for_each(|()| Ok(()))
+            .map_err(|err| {
+                warn!("gossipsub stream error: {:?}", err);
+            })
+            .then(|_| {
+                debug!("End of gossipsub");
+                Ok(())
+            });
+        if context.future_task(gossipsub_task).is_err() {
+            warn!("start gossipsub fail");
+        };
+    }
+
+    fn connected(&mut self, context: ProtocolContextMutRef, _: &str) {
+        let session = context.session;
+        debug!(
+            "protocol [gossipsub] open on session [{}], address: [{}], type: [{:?}]",
+            session.id, session.address, session.ty
+        );
+
+        let peer_id = match session.remote_pubkey {
+            Some(ref pubkey) => pubkey.peer_id(),
+            None => {
+                // Messages are identified and signed by the peer id of their source
+                if context.disconnect(session.id).is_err() {
+                    debug!("disconnect fail");
+                }
+                return;
+            }
+        };
+        self.send_event(SessionEvent::Connected {
+            session_id: session.id,
+            peer_id,
+        });
+    }
+
+    fn disconnected(&mut self, context: ProtocolContextMutRef) {
+        debug!(
+            "protocol [gossipsub] close on session [{}]",
+            context.session.id
+        );
+        self.send_event(SessionEvent::Disconnected {
+            session_id: context.session.id,
+        });
+    }
+
+    fn received(&mut self, context: ProtocolContextMutRef, data: bytes::Bytes) {
+        debug!("[received message]: length={}", data.len());
+        self.send_event(SessionEvent::Received {
+            session_id: context.session.id,
+            data,
+        });
+    }
+}
+
+/// Handle to subscribe and publish on a running `Gossipsub`
+#[derive(Clone)]
+pub struct GossipsubHandle {
+    command_sender: Sender<Command>,
+}
+
+impl GossipsubHandle {
+    fn send_command(&mut self, command: Command) {
+        if let Err(err) = self.command_sender.try_send(command) {
+            warn!("send gossipsub command error: {:?}", err.is_full());
+        }
+    }
+
+    /// Subscribe the topic, messages accepted by the validator are delivered on the receiver
+    ///
+    /// Dropping every receiver of a topic unsubscribes it
+    pub fn subscribe(&mut self, topic: Topic) -> Receiver<Message> {
+        let (sender, receiver) = channel(SUBSCRIBER_CHANNEL_SIZE);
+        self.send_command(Command::Subscribe { topic, sender });
+        receiver
+    }
+
+    pub fn unsubscribe(&mut self, topic: Topic) {
+        self.send_command(Command::Unsubscribe { topic });
+    }
+
+    /// Publish to the mesh of the topic, or to fanout peers if the topic is not subscribed
+    pub fn publish(&mut self, topic: Topic, data: Bytes) {
+        self.send_command(Command::Publish { topic, data });
+    }
+}
+
+struct PeerState {
+    peer_id: PeerId,
+    topics: HashSet<Topic>,
+    // Gossip exchanged with the peer in the current heartbeat
+    ihave_received: usize,
+    iwant_asked: usize,
+    iwant_served: usize,
+}
+
+impl PeerState {
+    fn new(peer_id: PeerId) -> Self {
+        PeerState {
+            peer_id,
+            topics: HashSet::default(),
+            ihave_received: 0,
+            iwant_asked: 0,
+            iwant_served: 0,
+        }
+    }
+}
+
+pub struct Gossipsub {
+    key_pair: SecioKeyPair,
+    config: Config,
+
+    proto_id: ProtocolId,
+    control: Option<ServiceControl>,
+
+    peers: HashMap<SessionId, PeerState>,
+    // Local subscribers of each subscribed topic
+    subscriptions: HashMap<Topic, Vec<Sender<Message>>>,
+    mesh: HashMap<Topic, HashSet<SessionId>>,
+    // Peers to publish to on topics not subscribed, with the last publish time
+    fanout: HashMap<Topic, (HashSet<SessionId>, Instant)>,
+
+    message_cache: MessageCache,
+    seen: SeenCache,
+    next_seqno: u64,
+
+    validators: HashMap<Topic, Validator>,
+    peer_score: Box<dyn PeerScore>,
+
+    event_sender: Sender<SessionEvent>,
+    event_receiver: Receiver<SessionEvent>,
+    command_sender: Sender<Command>,
+    command_receiver: Receiver<Command>,
+
+    heartbeat: Interval,
+
+    #[cfg(test)]
+    sent: std::cell::RefCell<Vec<(SessionId, GossipMessage)>>,
+}
+
+impl Gossipsub {
+    /// Published messages are signed with the key pair, it must be the one of the service
+    pub fn new(key_pair: SecioKeyPair, config: Config) -> Gossipsub {
+        let (event_sender, event_receiver) = channel(128);
+        let (command_sender, command_receiver) = channel(128);
+        // Start seqno from the current time so that a restarted node does not reuse ids
+        let next_seqno = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or_default();
+        Gossipsub {
+            key_pair,
+            proto_id: ProtocolId::default(),
+            control: None,
+            peers: HashMap::default(),
+            subscriptions: HashMap::default(),
+            mesh: HashMap::default(),
+            fanout: HashMap::default(),
+            message_cache: MessageCache::new(config.history_gossip, config.history_length),
+            seen: SeenCache::new(config.seen_ttl),
+            next_seqno,
+            validators: HashMap::default(),
+            peer_score: Box::new(NoScore),
+            event_sender,
+            event_receiver,
+            command_sender,
+            command_receiver,
+            heartbeat: Interval::new_interval(config.heartbeat_interval),
+            config,
+            #[cfg(test)]
+            sent: Default::default(),
+        }
+    }
+
+    /// Validate the messages received on the topic before they are delivered and forwarded
+    pub fn validator<F>(mut self, topic: Topic, validator: F) -> Self
+    where
+        F: Fn(&Message) -> ValidationResult + Send + 'static,
+    {
+        self.validators.insert(topic, Box::new(validator));
+        self
+    }
+
+    /// Peer scoring hooks, all peers score zero by default
+    pub fn peer_score<S: PeerScore + 'static>(mut self, peer_score: S) -> Self {
+        self.peer_score = Box::new(peer_score);
+        self
+    }
+
+    pub fn handle(&self) -> GossipsubHandle {
+        GossipsubHandle {
+            command_sender: self.command_sender.clone(),
+        }
+    }
+
+    fn send_message(&self, session_id: SessionId, message: &GossipMessage) {
+        debug!("send {} to session [{}]", message, session_id);
+        #[cfg(test)]
+        self.sent.borrow_mut().push((session_id, message.clone()));
+        if let Some(ref control) = self.control {
+            if control
+                .send_message_to(session_id, self.proto_id, message.encode())
+                .is_err()
+            {
+                debug!("send message fail");
+            }
+        }
+    }
+
+    fn is_acceptable(&self, session_id: SessionId) -> bool {
+        self.peers
+            .get(&session_id)
+            .map(|peer| self.peer_score.score(&peer.peer_id) >= 0.0)
+            .unwrap_or(false)
+    }
+
+    /// Random acceptable peers subscribed to the topic
+    fn random_peers(
+        &self,
+        topic: &str,
+        count: usize,
+        exclude: &HashSet<SessionId>,
+    ) -> Vec<SessionId> {
+        let mut peers = self
+            .peers
+            .iter()
+            .filter(|(session_id, peer)| {
+                peer.topics.contains(topic)
+                    && !exclude.contains(*session_id)
+                    && self.is_acceptable(**session_id)
+            })
+            .map(|(session_id, _)| *session_id)
+            .collect::<Vec<_>>();
+        peers.shuffle(&mut rand::thread_rng());
+        peers.truncate(count);
+        peers
+    }
+
+    fn graft(&mut self, session_id: SessionId, topic: &Topic) {
+        self.send_message(
+            session_id,
+            &GossipMessage::Graft {
+                topic: topic.clone(),
+            },
+        );
+        if let Some(peer) = self.peers.get(&session_id) {
+            self.peer_score.graft(&peer.peer_id, topic);
+        }
+    }
+
+    fn prune(&mut self, session_id: SessionId, topic: &Topic) {
+        self.send_message(
+            session_id,
+            &GossipMessage::Prune {
+                topic: topic.clone(),
+            },
+        );
+        if let Some(peer) = self.peers.get(&session_id) {
+            self.peer_score.prune(&peer.peer_id, topic);
+        }
+    }
+
+    fn join(&mut self, topic: Topic) {
+        if self.mesh.contains_key(&topic) {
+            return;
+        }
+        let mut peers = self
+            .fanout
+            .remove(&topic)
+            .map(|(peers, _)| peers)
+            .unwrap_or_default();
+        peers.retain(|session_id| self.is_acceptable(*session_id));
+        if peers.len() < self.config.mesh_n {
+            let more = self.random_peers(&topic, self.config.mesh_n - peers.len(), &peers);
+            peers.extend(more);
+        }
+        for session_id in peers.iter() {
+            self.graft(*session_id, &topic);
+        }
+        self.mesh.insert(topic, peers);
+    }
+
+    fn leave(&mut self, topic: Topic) {
+        self.subscriptions.remove(&topic);
+        if let Some(peers) = self.mesh.remove(&topic) {
+            for session_id in peers {
+                self.prune(session_id, &topic);
+            }
+        }
+        let message = GossipMessage::Subscription {
+            subscribe: false,
+            topics: vec![topic],
+        };
+        for session_id in self.peers.keys() {
+            self.send_message(*session_id, &message);
+        }
+    }
+
+    fn publish(&mut self, topic: Topic, data: Bytes) {
+        if data.len() > self.config.max_transmit_size {
+            warn!("publish data too large: {}", data.len());
+            return;
+        }
+        let message = Message::new_signed(&self.key_pair, self.next_seqno, topic, data);
+        self.next_seqno = self.next_seqno.wrapping_add(1);
+        self.seen.insert(message.id());
+        self.message_cache.put(message.clone());
+
+        let peers = match self.mesh.get(&message.topic) {
+            Some(peers) => peers.clone(),
+            None => {
+                let fanout_peers = match self.fanout.get(&message.topic) {
+                    Some((peers, _)) => peers.clone(),
+                    None => self
+                        .random_peers(&message.topic, self.config.mesh_n, &HashSet::new())
+                        .into_iter()
+                        .collect(),
+                };
+                self.fanout.insert(
+                    message.topic.clone(),
+                    (fanout_peers.clone(), Instant::now()),
+                );
+                fanout_peers
+            }
+        };
+        let message = GossipMessage::Publish(message);
+        for session_id in peers {
+            self.send_message(session_id, &message);
+        }
+    }
+
+    /// Deliver to local subscribers, topic is left when all of them are gone
+    fn deliver(&mut self, message: &Message) {
+        let closed = match self.subscriptions.get_mut(&message.topic) {
+            Some(senders) => {
+                let mut alive = Vec::with_capacity(senders.len());
+                for mut sender in senders.drain(..) {
+                    match sender.try_send(message.clone()) {
+                        Ok(_) => alive.push(sender),
+                        Err(err) => {
+                            if err.is_full() {
+                                warn!("subscriber channel is full, message dropped");
+                                alive.push(sender);
+                            }
+                        }
+                    }
+                }
+                *senders = alive;
+                senders.is_empty()
+            }
+            None => false,
+        };
+        if closed {
+            self.leave(message.topic.clone());
+        }
+    }
+
+    fn handle_publish(&mut self, session_id: SessionId, peer_id: PeerId, message: Message) {
+        let id = message.id();
+        if self.seen.contains(&id) {
+            self.peer_score.duplicate_message(&peer_id, &message.topic);
+            return;
+        }
+        // Checked before the id is seen, or a forged message could shadow the real one
+        if !message.verify() {
+            debug!("invalid message signature from session [{}]", session_id);
+            self.peer_score.reject_message(&peer_id, &message.topic);
+            return;
+        }
+        self.seen.insert(id);
+
+        let result = self
+            .validators
+            .get(&message.topic)
+            .map(|validator| validator(&message))
+            .unwrap_or(ValidationResult::Accept);
+        match result {
+            ValidationResult::Accept => (),
+            ValidationResult::Reject => {
+                debug!("message rejected from session [{}]", session_id);
+                self.peer_score.reject_message(&peer_id, &message.topic);
+                return;
+            }
+            ValidationResult::Ignore => return,
+        }
+        self.peer_score.deliver_message(&peer_id, &message.topic);
+        self.message_cache.put(message.clone());
+        self.deliver(&message);
+
+        if let Some(peers) = self.mesh.get(&message.topic) {
+            let forward = GossipMessage::Publish(message.clone());
+            for target in peers {
+                let is_source = self
+                    .peers
+                    .get(target)
+                    .map(|peer| peer.peer_id == message.source)
+                    .unwrap_or(false);
+                if *target != session_id && !is_source {
+                    self.send_message(*target, &forward);
+                }
+            }
+        }
+    }
+
+    fn handle_message(&mut self, session_id: SessionId, message: GossipMessage) {
+        let peer_id = match self.peers.get(&session_id) {
+            Some(peer) => peer.peer_id.clone(),
+            None => return,
+        };
+        match message {
+            GossipMessage::Subscription { subscribe, topics } => {
+                for topic in topics {
+                    if subscribe {
+                        if let Some(peer) = self.peers.get_mut(&session_id) {
+                            if peer.topics.len() < self.config.max_subscriptions_per_peer
+                                || peer.topics.contains(&topic)
+                            {
+                                peer.topics.insert(topic);
+                            } else {
+                                debug!("session [{}] subscribes too many topics", session_id);
+                            }
+                        }
+                    } else {
+                        if let Some(peer) = self.peers.get_mut(&session_id) {
+                            peer.topics.remove(&topic);
+                        }
+                        if let Some(peers) = self.mesh.get_mut(&topic) {
+                            peers.remove(&session_id);
+                        }
+                        if let Some((peers, _)) = self.fanout.get_mut(&topic) {
+                            peers.remove(&session_id);
+                        }
+                    }
+                }
+            }
+            GossipMessage::Publish(message) => self.handle_publish(session_id, peer_id, message),
+            GossipMessage::IHave { topic, message_ids } => {
+                if !self.is_acceptable(session_id) || !self.subscriptions.contains_key(&topic) {
+                    return;
+                }
+                let config = &self.config;
+                let seen = &self.seen;
+                let peer = match self.peers.get_mut(&session_id) {
+                    Some(peer) => peer,
+                    None => return,
+                };
+                peer.ihave_received += 1;
+                if peer.ihave_received > config.max_ihave_messages {
+                    debug!("too many IHave from session [{}], ignored", session_id);
+                    return;
+                }
+                let message_ids = message_ids
+                    .into_iter()
+                    .take(config.max_ihave_length)
+                    .filter(|id| !seen.contains(id))
+                    .take(config.max_ihave_length.saturating_sub(peer.iwant_asked))
+                    .collect::<Vec<_>>();
+                peer.iwant_asked += message_ids.len();
+                if !message_ids.is_empty() {
+                    self.send_message(session_id, &GossipMessage::IWant { message_ids });
+                }
+            }
+            GossipMessage::IWant { message_ids } => {
+                if !self.is_acceptable(session_id) {
+                    return;
+                }
+                let served = match self.peers.get(&session_id) {
+                    Some(peer) => peer.iwant_served,
+                    None => return,
+                };
+                let messages = message_ids
+                    .iter()
+                    .take(self.config.max_iwant_length)
+                    .filter_map(|id| self.message_cache.get(id))
+                    .take(self.config.max_iwant_length.saturating_sub(served))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if let Some(peer) = self.peers.get_mut(&session_id) {
+                    peer.iwant_served += messages.len();
+                }
+                for message in messages {
+                    self.send_message(session_id, &GossipMessage::Publish(message));
+                }
+            }
+            GossipMessage::Graft { topic } => {
+                if self.subscriptions.contains_key(&topic) && self.is_acceptable(session_id) {
+                    if let Some(peers) = self.mesh.get_mut(&topic) {
+                        if peers.insert(session_id) {
+                            self.peer_score.graft(&peer_id, &topic);
+                        }
+                    }
+                } else {
+                    self.send_message(session_id, &GossipMessage::Prune { topic });
+                }
+            }
+            GossipMessage::Prune { topic } => {
+                if let Some(peers) = self.mesh.get_mut(&topic) {
+                    if peers.remove(&session_id) {
+                        self.peer_score.prune(&peer_id, &topic);
+                    }
+                }
+            }
+        }
+    }
+
+    fn handle_command(&mut self, command: Command) {
+        match command {
+            Command::Subscribe { topic, sender } => {
+                let new_topic = !self.subscriptions.contains_key(&topic);
+                self.subscriptions
+                    .entry(topic.clone())
+                    .or_default()
+                    .push(sender);
+                if new_topic {
+                    let message = GossipMessage::Subscription {
+                        subscribe: true,
+                        topics: vec![topic.clone()],
+                    };
+                    for session_id in self.peers.keys() {
+                        self.send_message(*session_id, &message);
+                    }
+                    self.join(topic);
+                }
+            }
+            Command::Unsubscribe { topic } => {
+                if self.subscriptions.contains_key(&topic) {
+                    self.leave(topic);
+                }
+            }
+            Command::Publish { topic, data } => self.publish(topic, data),
+        }
+    }
+
+    fn handle_event(&mut self, event: SessionEvent) {
+        match event {
+            SessionEvent::Connected {
+                session_id,
+                peer_id,
+            } => {
+                self.peers.insert(session_id, PeerState::new(peer_id));
+                if !self.subscriptions.is_empty() {
+                    let message = GossipMessage::Subscription {
+                        subscribe: true,
+                        topics: self.subscriptions.keys().cloned().collect(),
+                    };
+                    self.send_message(session_id, &message);
+                }
+            }
+            SessionEvent::Disconnected { session_id } => {
+                self.peers.remove(&session_id);
+                for peers in self.mesh.values_mut() {
+                    peers.remove(&session_id);
+                }
+                for (peers, _) in self.fanout.values_mut() {
+                    peers.remove(&session_id);
+                }
+            }
+            SessionEvent::Received { session_id, data } => {
+                if data.len() > self.config.max_transmit_size {
+                    debug!("message too large from session [{}]", session_id);
+                    return;
+                }
+                match GossipMessage::decode(&data) {
+                    Some(message) => {
+                        debug!("received {} from session [{}]", message, session_id);
+                        self.handle_message(session_id, message);
+                    }
+                    None => {
+                        debug!("decode message error from session [{}]", session_id);
+                        if let Some(ref control) = self.control {
+                            let _ = control.disconnect(session_id);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn heartbeat(&mut self) {
+        let config = self.config.clone();
+
+        for peer in self.peers.values_mut() {
+            peer.ihave_received = 0;
+            peer.iwant_asked = 0;
+            peer.iwant_served = 0;
+        }
+
+        // Keep mesh degree between the bounds, peers with negative score are pruned
+        let topics = self.mesh.keys().cloned().collect::<Vec<_>>();
+        for topic in topics {
+            let mut peers = self.mesh.remove(&topic).unwrap_or_default();
+            let mut to_prune = peers
+                .iter()
+                .filter(|session_id| !self.is_acceptable(**session_id))
+                .cloned()
+                .collect::<Vec<_>>();
+            for session_id in to_prune.iter() {
+                peers.remove(session_id);
+            }
+            let mut to_graft = Vec::new();
+            if peers.len() < config.mesh_n_low {
+                to_graft = self.random_peers(&topic, config.mesh_n - peers.len(), &peers);
+                peers.extend(to_graft.iter().cloned());
+            } else if peers.len() > config.mesh_n_high {
+                let mut remain = peers.iter().cloned().collect::<Vec<_>>();
+                remain.shuffle(&mut rand::thread_rng());
+                let removed = remain.split_off(config.mesh_n);
+                for session_id in removed.iter() {
+                    peers.remove(session_id);
+                }
+                to_prune.extend(removed);
+            }
+            for session_id in to_prune {
+                self.prune(session_id, &topic);
+            }
+            for session_id in to_graft {
+                self.graft(session_id, &topic);
+            }
+            self.mesh.insert(topic, peers);
+        }
+
+        // Expire fanout and keep it filled
+        let topics = self.fanout.keys().cloned().collect::<Vec<_>>();
+        for topic in topics {
+            let (mut peers, last_pub) = match self.fanout.remove(&topic) {
+                Some(fanout) => fanout,
+                None => continue,
+            };
+            if last_pub.elapsed() >= config.fanout_ttl {
+                continue;
+            }
+            peers.retain(|session_id| {
+                self.is_acceptable(*session_id)
+                    && self
+                        .peers
+                        .get(session_id)
+                        .map(|peer| peer.topics.contains(&topic))
+                        .unwrap_or(false)
+            });
+            if peers.len() < config.mesh_n {
+                let more = self.random_peers(&topic, config.mesh_n - peers.len(), &peers);
+                peers.extend(more);
+            }
+            self.fanout.insert(topic, (peers, last_pub));
+        }
+
+        // Announce recent messages to peers out of the mesh
+        let gossip_topics = self
+            .mesh
+            .iter()
+            .map(|(topic, peers)| (topic.clone(), peers.clone()))
+            .chain(
+                self.fanout
+                    .iter()
+                    .map(|(topic, (peers, _))| (topic.clone(), peers.clone())),
+            )
+            .collect::<Vec<_>>();
+        for (topic, exclude) in gossip_topics {
+            let mut message_ids = self.message_cache.gossip_ids(&topic);
+            if message_ids.is_empty() {
+                continue;
+            }
+            // Newest first, the oldest are dropped
+            message_ids.truncate(config.max_ihave_length);
+            let peers = self.random_peers(&topic, config.gossip_lazy, &exclude);
+            let message = GossipMessage::IHave { topic, message_ids };
+            for session_id in peers {
+                self.send_message(session_id, &message);
+            }
+        }
+
+        self.message_cache.shift();
+        self.seen.remove_expired();
+        self.peer_score.heartbeat();
+    }
+
+    fn recv_events(&mut self) -> Result<bool, io::Error> {
+        loop {
+            match self.event_receiver.poll() {
+                Ok(Async::Ready(Some(event))) => self.handle_event(event),
+                Ok(Async::Ready(None)) => return Ok(false),
+                Ok(Async::NotReady) => return Ok(true),
+                Err(err) => {
+                    debug!("receive session event error: {:?}", err);
+                    return Err(io::ErrorKind::Other.into());
+                }
+            }
+        }
+    }
+
+    fn recv_commands(&mut self) -> Result<(), io::Error> {
+        loop {
+            match self.command_receiver.poll() {
+                Ok(Async::Ready(Some(command))) => self.handle_command(command),
+                Ok(Async::Ready(None)) => unreachable!(),
+                Ok(Async::NotReady) => return Ok(()),
+                Err(err) => {
+                    debug!("receive command error: {:?}", err);
+                    return Err(io::ErrorKind::Other.into());
+                }
+            }
+        }
+    }
+
+    fn check_heartbeat(&mut self) {
+        loop {
+            match self.heartbeat.poll() {
+                Ok(Async::Ready(Some(_))) => self.heartbeat(),
+                Ok(Async::Ready(None)) => {
+                    debug!("Gossipsub heartbeat poll finished");
+                    break;
+                }
+                Ok(Async::NotReady) => break,
+                Err(err) => {
+                    debug!("Gossipsub heartbeat poll error: {:?}", err);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+impl Stream for Gossipsub {
+    type Item = ();
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if !self.recv_events()? {
+            // All protocol handles are dropped
+            return Ok(Async::Ready(None));
+        }
+        self.recv_commands()?;
+        self.check_heartbeat();
+        Ok(Async::NotReady)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Command, Config, GossipMessage, Gossipsub, Message, PeerScore, SessionEvent, Topic,
+        ValidationResult,
+    };
+    use bytes::Bytes;
+    use futures::{
+        sync::mpsc::{channel, Receiver},
+        Stream,
+    };
+    use p2p::{
+        secio::{PeerId, SecioKeyPair},
+        SessionId,
+    };
+    use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+
+    const TOPIC: &str = "blocks";
+
+    fn topic() -> Topic {
+        TOPIC.to_owned()
+    }
+
+    fn config() -> Config {
+        Config {
+            mesh_n: 2,
+            mesh_n_low: 1,
+            mesh_n_high: 3,
+            max_ihave_length: 4,
+            max_ihave_messages: 2,
+            max_iwant_length: 2,
+            ..Config::default()
+        }
+    }
+
+    /// Connect a peer subscribed to the topic
+    fn connect(gossipsub: &mut Gossipsub, session_id: usize) {
+        gossipsub.handle_event(SessionEvent::Connected {
+            session_id: session_id.into(),
+            peer_id: PeerId::random(),
+        });
+        gossipsub.handle_message(
+            session_id.into(),
+            GossipMessage::Subscription {
+                subscribe: true,
+                topics: vec![topic()],
+            },
+        );
+    }
+
+    fn subscribe(gossipsub: &mut Gossipsub) -> Receiver<Message> {
+        let (sender, receiver) = channel(16);
+        gossipsub.handle_command(Command::Subscribe {
+            topic: topic(),
+            sender,
+        });
+        receiver
+    }
+
+    /// Messages sent since the last call
+    fn sent(gossipsub: &Gossipsub) -> Vec<(SessionId, GossipMessage)> {
+        gossipsub.sent.borrow_mut().drain(..).collect()
+    }
+
+    /// Sessions the messages matching the filter were sent to
+    fn sent_to<F>(gossipsub: &Gossipsub, filter: F) -> HashSet<SessionId>
+    where
+        F: Fn(&GossipMessage) -> bool,
+    {
+        sent(gossipsub)
+            .into_iter()
+            .filter(|(_, message)| filter(message))
+            .map(|(session_id, _)| session_id)
+            .collect()
+    }
+
+    fn mesh(gossipsub: &Gossipsub) -> HashSet<SessionId> {
+        gossipsub.mesh[TOPIC].clone()
+    }
+
+    #[test]
+    fn test_mesh_join_and_prune() {
+        let mut gossipsub = Gossipsub::new(SecioKeyPair::secp256k1_generated(), config());
+        for session_id in 1..=4 {
+            connect(&mut gossipsub, session_id);
+        }
+        let _receiver = subscribe(&mut gossipsub);
+
+        let grafted = sent_to(&gossipsub, |message| match message {
+            GossipMessage::Graft { .. } => true,
+            _ => false,
+        });
+        assert_eq!(grafted.len(), 2);
+        assert_eq!(grafted, mesh(&gossipsub));
+
+        // Remote peers grafting beyond `mesh_n_high` are pruned down to `mesh_n` at heartbeat
+        for session_id in 1..=4 {
+            gossipsub.handle_message(session_id.into(), GossipMessage::Graft { topic: topic() });
+        }
+        assert_eq!(mesh(&gossipsub).len(), 4);
+        sent(&gossipsub);
+        gossipsub.heartbeat();
+        let pruned = sent_to(&gossipsub, |message| match message {
+            GossipMessage::Prune { .. } => true,
+            _ => false,
+        });
+        assert_eq!(pruned.len(), 2);
+        assert_eq!(mesh(&gossipsub).len(), 2);
+        assert!(pruned.is_disjoint(&mesh(&gossipsub)));
+
+        let session_id = *mesh(&gossipsub).iter().next().unwrap();
+        gossipsub.handle_message(session_id, GossipMessage::Prune { topic: topic() });
+        assert!(!mesh(&gossipsub).contains(&session_id));
+
+        // Graft on a topic not subscribed is refused
+        let other = "other".to_owned();
+        gossipsub.handle_message(
+            1.into(),
+            GossipMessage::Graft {
+                topic: other.clone(),
+            },
+        );
+        assert_eq!(
+            sent(&gossipsub),
+            vec![(SessionId::from(1), GossipMessage::Prune { topic: other })]
+        );
+    }
+
+    #[test]
+    fn test_subscription_limit() {
+        let config = Config {
+            max_subscriptions_per_peer: 2,
+            ..config()
+        };
+        let mut gossipsub = Gossipsub::new(SecioKeyPair::secp256k1_generated(), config);
+        connect(&mut gossipsub, 1);
+        let subscription = |subscribe, topic: &str| GossipMessage::Subscription {
+            subscribe,
+            topics: vec![topic.to_owned()],
+        };
+
+        // Already subscribed topics still count once
+        gossipsub.handle_message(1.into(), subscription(true, TOPIC));
+        gossipsub.handle_message(1.into(), subscription(true, "a"));
+        gossipsub.handle_message(1.into(), subscription(true, "b"));
+        let topics = &gossipsub.peers[&SessionId::from(1)].topics;
+        assert_eq!(topics.len(), 2);
+        assert!(!topics.contains("b"));
+
+        gossipsub.handle_message(1.into(), subscription(false, "a"));
+        gossipsub.handle_message(1.into(), subscription(true, "b"));
+        assert!(gossipsub.peers[&SessionId::from(1)].topics.contains("b"));
+    }
+
+    #[test]
+    fn test_ihave_iwant() {
+        let mut gossipsub = Gossipsub::new(SecioKeyPair::secp256k1_generated(), config());
+        connect(&mut gossipsub, 1);
+        let _receiver = subscribe(&mut gossipsub);
+        let session_id = SessionId::from(1);
+        let key_pair = SecioKeyPair::secp256k1_generated();
+        let messages = (0..6)
+            .map(|seqno| Message::new_signed(&key_pair, seqno, topic(), Bytes::from("data")))
+            .collect::<Vec<_>>();
+        let ids = messages.iter().map(Message::id).collect::<Vec<_>>();
+
+        // Only unseen ids are asked, at most `max_ihave_length` of an `IHave`
+        gossipsub.handle_message(session_id, GossipMessage::Publish(messages[0].clone()));
+        sent(&gossipsub);
+        let ihave = GossipMessage::IHave {
+            topic: topic(),
+            message_ids: ids.clone(),
+        };
+        gossipsub.handle_message(session_id, ihave.clone());
+        let iwant = GossipMessage::IWant {
+            message_ids: ids[1..4].to_vec(),
+        };
+        assert_eq!(sent(&gossipsub), vec![(session_id, iwant.clone())]);
+
+        // At most `max_ihave_length` ids are asked from a peer in a heartbeat
+        gossipsub.handle_message(session_id, ihave.clone());
+        let message_ids = ids[1..2].to_vec();
+        assert_eq!(
+            sent(&gossipsub),
+            vec![(session_id, GossipMessage::IWant { message_ids })]
+        );
+
+        // At most `max_ihave_messages` are handled from a peer in a heartbeat
+        gossipsub.handle_message(session_id, ihave.clone());
+        assert!(sent(&gossipsub).is_empty());
+        gossipsub.heartbeat();
+        sent(&gossipsub);
+        gossipsub.handle_message(session_id, ihave);
+        assert_eq!(sent(&gossipsub), vec![(session_id, iwant)]);
+
+        // At most `max_iwant_length` messages are sent to a peer in a heartbeat
+        for message in messages[1..3].iter() {
+            gossipsub.handle_message(session_id, GossipMessage::Publish(message.clone()));
+        }
+        let iwant = GossipMessage::IWant {
+            message_ids: ids[..3].to_vec(),
+        };
+        gossipsub.handle_message(session_id, iwant.clone());
+        let served = messages[..2]
+            .iter()
+            .map(|message| (session_id, GossipMessage::Publish(message.clone())))
+            .collect::<Vec<_>>();
+        assert_eq!(sent(&gossipsub), served);
+        gossipsub.handle_message(session_id, iwant.clone());
+        assert!(sent(&gossipsub).is_empty());
+        gossipsub.heartbeat();
+        sent(&gossipsub);
+        gossipsub.handle_message(session_id, iwant);
+        assert_eq!(sent(&gossipsub), served);
+    }
+
+    /// Count of delivered, duplicate and rejected messages
+    #[derive(Clone, Default)]
+    struct CountScore(Arc<Mutex<(usize, usize, usize)>>);
+
+    impl PeerScore for CountScore {
+        fn deliver_message(&mut self, _peer_id: &PeerId, _topic: &Topic) {
+            self.0.lock().unwrap().0 += 1;
+        }
+
+        fn duplicate_message(&mut self, _peer_id: &PeerId, _topic: &Topic) {
+            self.0.lock().unwrap().1 += 1;
+        }
+
+        fn reject_message(&mut self, _peer_id: &PeerId, _topic: &Topic) {
+            self.0.lock().unwrap().2 += 1;
+        }
+    }
+
+    #[test]
+    fn test_validation() {
+        let score = CountScore::default();
+        let mut gossipsub = Gossipsub::new(SecioKeyPair::secp256k1_generated(), config())
+            .validator(topic(), |message| match &message.data[..] {
+                b"" => ValidationResult::Reject,
+                b"ignore" => ValidationResult::Ignore,
+                _ => ValidationResult::Accept,
+            })
+            .peer_score(score.clone());
+        connect(&mut gossipsub, 1);
+        connect(&mut gossipsub, 2);
+        let receiver = subscribe(&mut gossipsub);
+        sent(&gossipsub);
+        let key_pair = SecioKeyPair::secp256k1_generated();
+        let message = |seqno, data: &'static str| {
+            Message::new_signed(&key_pair, seqno, topic(), Bytes::from(data))
+        };
+
+        // Delivered and forwarded to the rest of the mesh
+        let first = GossipMessage::Publish(message(1, "first"));
+        gossipsub.handle_message(1.into(), first.clone());
+        assert_eq!(sent(&gossipsub), vec![(SessionId::from(2), first.clone())]);
+        gossipsub.handle_message(2.into(), first);
+
+        // Signed by another peer in the name of the source, it doesn't shadow the real one
+        let second = message(2, "second");
+        let mut forged = Message::new_signed(
+            &SecioKeyPair::secp256k1_generated(),
+            2,
+            topic(),
+            Bytes::from("forged"),
+        );
+        forged.source = second.source.clone();
+        gossipsub.handle_message(1.into(), GossipMessage::Publish(forged));
+
+        gossipsub.handle_message(1.into(), GossipMessage::Publish(message(3, "")));
+        gossipsub.handle_message(1.into(), GossipMessage::Publish(message(4, "ignore")));
+        assert!(sent(&gossipsub).is_empty());
+        assert_eq!(*score.0.lock().unwrap(), (1, 1, 2));
+
+        let second = GossipMessage::Publish(second);
+        gossipsub.handle_message(1.into(), second.clone());
+        assert_eq!(sent(&gossipsub), vec![(SessionId::from(2), second)]);
+        assert_eq!(*score.0.lock().unwrap(), (2, 1, 2));
+
+        let delivered = receiver
+            .wait()
+            .take(2)
+            .map(|message| message.unwrap().data)
+            .collect::<Vec<_>>();
+        assert_eq!(delivered, vec![Bytes::from("first"), Bytes::from("second")]);
+    }
+}
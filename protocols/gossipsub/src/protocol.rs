@@ -0,0 +1,532 @@
+#[cfg(all(feature = "flatc", feature = "molc"))]
+compile_error!("features `flatc` and `molc` are mutually exclusive");
+#[cfg(all(not(feature = "flatc"), not(feature = "molc")))]
+compile_error!("Please choose a serialization format via feature. Possible choices: flatc, molc");
+
+use bytes::Bytes;
+use p2p::secio::{PeerId, PublicKey, SecioKeyPair};
+
+#[cfg(feature = "flatc")]
+use crate::protocol_generated::p2p::gossipsub::{
+    Bytes as FbsBytes, BytesBuilder, GossipMessage as FbsGossipMessage, GossipMessageBuilder,
+    GossipPayload as FbsGossipPayload, Graft as FbsGraft, GraftBuilder, IHave as FbsIHave,
+    IHaveBuilder, IWant as FbsIWant, IWantBuilder, Prune as FbsPrune, PruneBuilder,
+    Publish as FbsPublish, PublishBuilder, Subscription as FbsSubscription, SubscriptionBuilder,
+};
+#[cfg(feature = "flatc")]
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, Vector, WIPOffset};
+
+#[cfg(feature = "molc")]
+use crate::protocol_mol;
+#[cfg(feature = "molc")]
+use molecule::prelude::{Builder, Entity, Reader};
+
+/// Topic name
+pub type Topic = String;
+/// Message id, the source peer id followed by the big endian seqno
+pub type MessageId = Vec<u8>;
+
+/// The domain separation tag of message signatures
+pub const MESSAGE_SIGN_DOMAIN: &[u8] = b"/tentacle/gossipsub/message";
+
+/// A message published on a topic, signed with the secio identity key of its source
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Message {
+    pub source: PeerId,
+    pub seqno: u64,
+    pub topic: Topic,
+    pub data: Bytes,
+    /// Public key of the source
+    pub pubkey: PublicKey,
+    /// Signature of the source on seqno, topic and data
+    pub signature: Vec<u8>,
+}
+
+impl Message {
+    /// Sign a message published by the owner of the key pair
+    pub fn new_signed(key_pair: &SecioKeyPair, seqno: u64, topic: Topic, data: Bytes) -> Self {
+        let signature =
+            key_pair.sign_with_domain(MESSAGE_SIGN_DOMAIN, &signing_data(seqno, &topic, &data));
+        Message {
+            source: key_pair.peer_id(),
+            seqno,
+            topic,
+            data,
+            pubkey: key_pair.public_key(),
+            signature,
+        }
+    }
+
+    pub fn id(&self) -> MessageId {
+        let mut id = self.source.as_bytes().to_vec();
+        id.extend_from_slice(&self.seqno.to_be_bytes());
+        id
+    }
+
+    /// Check that the message is signed by its source
+    pub fn verify(&self) -> bool {
+        self.pubkey.peer_id() == self.source
+            && self.pubkey.verify_with_domain(
+                MESSAGE_SIGN_DOMAIN,
+                &signing_data(self.seqno, &self.topic, &self.data),
+                &self.signature,
+            )
+    }
+}
+
+// seqno | topic length(u32) | topic | data, all in big endian
+fn signing_data(seqno: u64, topic: &str, data: &[u8]) -> Vec<u8> {
+    let mut signing_data = Vec::with_capacity(12 + topic.len() + data.len());
+    signing_data.extend_from_slice(&seqno.to_be_bytes());
+    signing_data.extend_from_slice(&(topic.len() as u32).to_be_bytes());
+    signing_data.extend_from_slice(topic.as_bytes());
+    signing_data.extend_from_slice(data);
+    signing_data
+}
+
+/// Build a received message, the source is the peer id of the public key
+fn message_from_parts(
+    pubkey: PublicKey,
+    seqno: u64,
+    topic: Topic,
+    data: Bytes,
+    signature: Vec<u8>,
+) -> Message {
+    Message {
+        source: pubkey.peer_id(),
+        seqno,
+        topic,
+        data,
+        pubkey,
+        signature,
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GossipMessage {
+    /// Subscribe or unsubscribe topics
+    Subscription { subscribe: bool, topics: Vec<Topic> },
+    /// Full message, sent to mesh peers
+    Publish(Message),
+    /// Ids of messages seen recently on a topic, sent to peers out of the mesh
+    IHave {
+        topic: Topic,
+        message_ids: Vec<MessageId>,
+    },
+    /// Request full messages announced by `IHave`
+    IWant { message_ids: Vec<MessageId> },
+    /// Join the mesh of a topic
+    Graft { topic: Topic },
+    /// Leave the mesh of a topic
+    Prune { topic: Topic },
+}
+
+#[cfg(feature = "flatc")]
+fn fbs_bytes_vec<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    items: &[&[u8]],
+) -> WIPOffset<Vector<'a, ForwardsUOffset<FbsBytes<'a>>>> {
+    let mut vec_bytes = Vec::with_capacity(items.len());
+    for item in items {
+        let seq = fbb.create_vector(*item);
+        let mut bytes_builder = BytesBuilder::new(fbb);
+        bytes_builder.add_seq(seq);
+        vec_bytes.push(bytes_builder.finish());
+    }
+    fbb.create_vector(&vec_bytes)
+}
+
+#[cfg(feature = "flatc")]
+fn bytes_vec_from_fbs(
+    fbs_bytes: Option<Vector<ForwardsUOffset<FbsBytes>>>,
+) -> Option<Vec<Vec<u8>>> {
+    let fbs_bytes = fbs_bytes?;
+    let mut items = Vec::with_capacity(fbs_bytes.len());
+    for i in 0..fbs_bytes.len() {
+        items.push(fbs_bytes.get(i).seq()?.to_vec());
+    }
+    Some(items)
+}
+
+#[cfg(feature = "flatc")]
+fn topic_from_fbs(topic: Option<&[u8]>) -> Option<Topic> {
+    String::from_utf8(topic?.to_vec()).ok()
+}
+
+#[cfg(feature = "molc")]
+fn mol_bytes(data: &[u8]) -> protocol_mol::Bytes {
+    protocol_mol::Bytes::new_builder()
+        .set(data.iter().cloned().map(Into::into).collect())
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn mol_bytes_vec(items: &[&[u8]]) -> protocol_mol::BytesVec {
+    protocol_mol::BytesVec::new_builder()
+        .set(items.iter().map(|item| mol_bytes(item)).collect())
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn topic_from_mol(reader: protocol_mol::BytesReader) -> Option<Topic> {
+    String::from_utf8(reader.raw_data().to_vec()).ok()
+}
+
+impl GossipMessage {
+    #[cfg(feature = "flatc")]
+    pub fn encode(&self) -> Bytes {
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let (payload_type, payload) = match self {
+            GossipMessage::Subscription { subscribe, topics } => {
+                let topics = topics.iter().map(String::as_bytes).collect::<Vec<_>>();
+                let topics = fbs_bytes_vec(&mut fbb, &topics);
+                let mut builder = SubscriptionBuilder::new(&mut fbb);
+                builder.add_subscribe(*subscribe);
+                builder.add_topics(topics);
+                (
+                    FbsGossipPayload::Subscription,
+                    builder.finish().as_union_value(),
+                )
+            }
+            GossipMessage::Publish(message) => {
+                let pubkey = fbb.create_vector(&message.pubkey.encode()[..]);
+                let topic = fbb.create_vector(message.topic.as_bytes());
+                let data = fbb.create_vector(&message.data[..]);
+                let signature = fbb.create_vector(&message.signature);
+                let mut builder = PublishBuilder::new(&mut fbb);
+                builder.add_pubkey(pubkey);
+                builder.add_seqno(message.seqno);
+                builder.add_topic(topic);
+                builder.add_data(data);
+                builder.add_signature(signature);
+                (FbsGossipPayload::Publish, builder.finish().as_union_value())
+            }
+            GossipMessage::IHave { topic, message_ids } => {
+                let topic = fbb.create_vector(topic.as_bytes());
+                let message_ids = message_ids.iter().map(Vec::as_slice).collect::<Vec<_>>();
+                let message_ids = fbs_bytes_vec(&mut fbb, &message_ids);
+                let mut builder = IHaveBuilder::new(&mut fbb);
+                builder.add_topic(topic);
+                builder.add_message_ids(message_ids);
+                (FbsGossipPayload::IHave, builder.finish().as_union_value())
+            }
+            GossipMessage::IWant { message_ids } => {
+                let message_ids = message_ids.iter().map(Vec::as_slice).collect::<Vec<_>>();
+                let message_ids = fbs_bytes_vec(&mut fbb, &message_ids);
+                let mut builder = IWantBuilder::new(&mut fbb);
+                builder.add_message_ids(message_ids);
+                (FbsGossipPayload::IWant, builder.finish().as_union_value())
+            }
+            GossipMessage::Graft { topic } => {
+                let topic = fbb.create_vector(topic.as_bytes());
+                let mut builder = GraftBuilder::new(&mut fbb);
+                builder.add_topic(topic);
+                (FbsGossipPayload::Graft, builder.finish().as_union_value())
+            }
+            GossipMessage::Prune { topic } => {
+                let topic = fbb.create_vector(topic.as_bytes());
+                let mut builder = PruneBuilder::new(&mut fbb);
+                builder.add_topic(topic);
+                (FbsGossipPayload::Prune, builder.finish().as_union_value())
+            }
+        };
+
+        let mut builder = GossipMessageBuilder::new(&mut fbb);
+        builder.add_payload_type(payload_type);
+        builder.add_payload(payload);
+        let data = builder.finish();
+        fbb.finish(data, None);
+        Bytes::from(fbb.finished_data())
+    }
+
+    #[cfg(feature = "flatc")]
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let fbs_message = flatbuffers_verifier::get_root::<FbsGossipMessage>(data).ok()?;
+        let payload = fbs_message.payload()?;
+        match fbs_message.payload_type() {
+            FbsGossipPayload::Subscription => {
+                let fbs_subscription = FbsSubscription::init_from_table(payload);
+                let mut topics = Vec::new();
+                for topic in bytes_vec_from_fbs(fbs_subscription.topics())? {
+                    topics.push(String::from_utf8(topic).ok()?);
+                }
+                Some(GossipMessage::Subscription {
+                    subscribe: fbs_subscription.subscribe(),
+                    topics,
+                })
+            }
+            FbsGossipPayload::Publish => {
+                let fbs_publish = FbsPublish::init_from_table(payload);
+                Some(GossipMessage::Publish(message_from_parts(
+                    PublicKey::decode(fbs_publish.pubkey()?)?,
+                    fbs_publish.seqno(),
+                    topic_from_fbs(fbs_publish.topic())?,
+                    Bytes::from(fbs_publish.data()?),
+                    fbs_publish.signature()?.to_vec(),
+                )))
+            }
+            FbsGossipPayload::IHave => {
+                let fbs_ihave = FbsIHave::init_from_table(payload);
+                Some(GossipMessage::IHave {
+                    topic: topic_from_fbs(fbs_ihave.topic())?,
+                    message_ids: bytes_vec_from_fbs(fbs_ihave.message_ids())?,
+                })
+            }
+            FbsGossipPayload::IWant => {
+                let fbs_iwant = FbsIWant::init_from_table(payload);
+                Some(GossipMessage::IWant {
+                    message_ids: bytes_vec_from_fbs(fbs_iwant.message_ids())?,
+                })
+            }
+            FbsGossipPayload::Graft => {
+                let fbs_graft = FbsGraft::init_from_table(payload);
+                Some(GossipMessage::Graft {
+                    topic: topic_from_fbs(fbs_graft.topic())?,
+                })
+            }
+            FbsGossipPayload::Prune => {
+                let fbs_prune = FbsPrune::init_from_table(payload);
+                Some(GossipMessage::Prune {
+                    topic: topic_from_fbs(fbs_prune.topic())?,
+                })
+            }
+            FbsGossipPayload::NONE => None,
+        }
+    }
+
+    #[cfg(feature = "molc")]
+    pub fn encode(&self) -> Bytes {
+        let payload = match self {
+            GossipMessage::Subscription { subscribe, topics } => {
+                let bool_ = if *subscribe { 1u8 } else { 0 };
+                let subscribe = protocol_mol::Bool::new_builder()
+                    .set([bool_.into()])
+                    .build();
+                let topics = topics.iter().map(String::as_bytes).collect::<Vec<_>>();
+                let subscription = protocol_mol::Subscription::new_builder()
+                    .subscribe(subscribe)
+                    .topics(mol_bytes_vec(&topics))
+                    .build();
+                protocol_mol::GossipPayload::new_builder()
+                    .set(subscription)
+                    .build()
+            }
+            GossipMessage::Publish(message) => {
+                let seqno_le = message.seqno.to_le_bytes();
+                let seqno = protocol_mol::Uint64::new_builder()
+                    .nth0(seqno_le[0].into())
+                    .nth1(seqno_le[1].into())
+                    .nth2(seqno_le[2].into())
+                    .nth3(seqno_le[3].into())
+                    .nth4(seqno_le[4].into())
+                    .nth5(seqno_le[5].into())
+                    .nth6(seqno_le[6].into())
+                    .nth7(seqno_le[7].into())
+                    .build();
+                let publish = protocol_mol::Publish::new_builder()
+                    .pubkey(mol_bytes(&message.pubkey.clone().encode()))
+                    .seqno(seqno)
+                    .topic(mol_bytes(message.topic.as_bytes()))
+                    .data(mol_bytes(&message.data))
+                    .signature(mol_bytes(&message.signature))
+                    .build();
+                protocol_mol::GossipPayload::new_builder()
+                    .set(publish)
+                    .build()
+            }
+            GossipMessage::IHave { topic, message_ids } => {
+                let message_ids = message_ids.iter().map(Vec::as_slice).collect::<Vec<_>>();
+                let ihave = protocol_mol::IHave::new_builder()
+                    .topic(mol_bytes(topic.as_bytes()))
+                    .message_ids(mol_bytes_vec(&message_ids))
+                    .build();
+                protocol_mol::GossipPayload::new_builder()
+                    .set(ihave)
+                    .build()
+            }
+            GossipMessage::IWant { message_ids } => {
+                let message_ids = message_ids.iter().map(Vec::as_slice).collect::<Vec<_>>();
+                let iwant = protocol_mol::IWant::new_builder()
+                    .message_ids(mol_bytes_vec(&message_ids))
+                    .build();
+                protocol_mol::GossipPayload::new_builder()
+                    .set(iwant)
+                    .build()
+            }
+            GossipMessage::Graft { topic } => {
+                let graft = protocol_mol::Graft::new_builder()
+                    .topic(mol_bytes(topic.as_bytes()))
+                    .build();
+                protocol_mol::GossipPayload::new_builder()
+                    .set(graft)
+                    .build()
+            }
+            GossipMessage::Prune { topic } => {
+                let prune = protocol_mol::Prune::new_builder()
+                    .topic(mol_bytes(topic.as_bytes()))
+                    .build();
+                protocol_mol::GossipPayload::new_builder()
+                    .set(prune)
+                    .build()
+            }
+        };
+
+        protocol_mol::GossipMessage::new_builder()
+            .payload(payload)
+            .build()
+            .as_bytes()
+    }
+
+    #[cfg(feature = "molc")]
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let reader = protocol_mol::GossipMessageReader::from_compatible_slice(data).ok()?;
+        match reader.payload().to_enum() {
+            protocol_mol::GossipPayloadUnionReader::Subscription(reader) => {
+                let subscribe = match reader.subscribe().as_slice()[0] {
+                    0 => false,
+                    1 => true,
+                    _ => return None,
+                };
+                let mut topics = Vec::with_capacity(reader.topics().len());
+                for topic_reader in reader.topics().iter() {
+                    topics.push(topic_from_mol(topic_reader)?);
+                }
+                Some(GossipMessage::Subscription { subscribe, topics })
+            }
+            protocol_mol::GossipPayloadUnionReader::Publish(reader) => {
+                let mut seqno_le = [0u8; 8];
+                seqno_le.copy_from_slice(reader.seqno().raw_data());
+                Some(GossipMessage::Publish(message_from_parts(
+                    PublicKey::decode(reader.pubkey().raw_data())?,
+                    u64::from_le_bytes(seqno_le),
+                    topic_from_mol(reader.topic())?,
+                    Bytes::from(reader.data().raw_data()),
+                    reader.signature().raw_data().to_vec(),
+                )))
+            }
+            protocol_mol::GossipPayloadUnionReader::IHave(reader) => Some(GossipMessage::IHave {
+                topic: topic_from_mol(reader.topic())?,
+                message_ids: reader
+                    .message_ids()
+                    .iter()
+                    .map(|id_reader| id_reader.raw_data().to_vec())
+                    .collect(),
+            }),
+            protocol_mol::GossipPayloadUnionReader::IWant(reader) => Some(GossipMessage::IWant {
+                message_ids: reader
+                    .message_ids()
+                    .iter()
+                    .map(|id_reader| id_reader.raw_data().to_vec())
+                    .collect(),
+            }),
+            protocol_mol::GossipPayloadUnionReader::Graft(reader) => Some(GossipMessage::Graft {
+                topic: topic_from_mol(reader.topic())?,
+            }),
+            protocol_mol::GossipPayloadUnionReader::Prune(reader) => Some(GossipMessage::Prune {
+                topic: topic_from_mol(reader.topic())?,
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for GossipMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            GossipMessage::Subscription { subscribe, topics } => write!(
+                f,
+                "GossipMessage::Subscription(subscribe:{}, topics:{:?})",
+                subscribe, topics
+            ),
+            GossipMessage::Publish(message) => write!(
+                f,
+                "GossipMessage::Publish(topic:{}, seqno:{}, data.length:{})",
+                message.topic,
+                message.seqno,
+                message.data.len()
+            ),
+            GossipMessage::IHave { topic, message_ids } => write!(
+                f,
+                "GossipMessage::IHave(topic:{}, message_ids.length:{})",
+                topic,
+                message_ids.len()
+            ),
+            GossipMessage::IWant { message_ids } => write!(
+                f,
+                "GossipMessage::IWant(message_ids.length:{})",
+                message_ids.len()
+            ),
+            GossipMessage::Graft { topic } => write!(f, "GossipMessage::Graft(topic:{})", topic),
+            GossipMessage::Prune { topic } => write!(f, "GossipMessage::Prune(topic:{})", topic),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GossipMessage, Message};
+    use bytes::Bytes;
+    use p2p::secio::SecioKeyPair;
+
+    #[test]
+    fn test_encode_decode() {
+        let message = Message::new_signed(
+            &SecioKeyPair::secp256k1_generated(),
+            u64::max_value(),
+            "blocks".to_owned(),
+            Bytes::from("block data"),
+        );
+        let messages = vec![
+            GossipMessage::Subscription {
+                subscribe: true,
+                topics: vec!["blocks".to_owned(), "transactions".to_owned()],
+            },
+            GossipMessage::Subscription {
+                subscribe: false,
+                topics: Vec::new(),
+            },
+            GossipMessage::IHave {
+                topic: "blocks".to_owned(),
+                message_ids: vec![message.id(), b"other".to_vec()],
+            },
+            GossipMessage::IWant {
+                message_ids: vec![message.id()],
+            },
+            GossipMessage::Publish(message),
+            GossipMessage::Graft {
+                topic: "blocks".to_owned(),
+            },
+            GossipMessage::Prune {
+                topic: "blocks".to_owned(),
+            },
+        ];
+        for message in messages {
+            assert_eq!(GossipMessage::decode(&message.encode()), Some(message));
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let key_pair = SecioKeyPair::secp256k1_generated();
+        let message = Message::new_signed(&key_pair, 1, "blocks".to_owned(), Bytes::from("data"));
+        assert!(message.verify());
+
+        let mut forged = message.clone();
+        forged.data = Bytes::from("forged");
+        assert!(!forged.verify());
+
+        // Signed by another peer in the name of the source
+        let mut forged = Message::new_signed(
+            &SecioKeyPair::secp256k1_generated(),
+            1,
+            "blocks".to_owned(),
+            Bytes::from("data"),
+        );
+        forged.source = message.source;
+        assert!(!forged.verify());
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(GossipMessage::decode(b"invalid").is_none());
+    }
+}
@@ -0,0 +1,853 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+pub mod p2p {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+pub mod gossipsub {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GossipPayload {
+  NONE = 0,
+  Subscription = 1,
+  Publish = 2,
+  IHave = 3,
+  IWant = 4,
+  Graft = 5,
+  Prune = 6,
+
+}
+
+const ENUM_MIN_GOSSIP_PAYLOAD: u8 = 0;
+const ENUM_MAX_GOSSIP_PAYLOAD: u8 = 6;
+
+impl<'a> flatbuffers::Follow<'a> for GossipPayload {
+  type Inner = Self;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::read_scalar_at::<Self>(buf, loc)
+  }
+}
+
+impl flatbuffers::EndianScalar for GossipPayload {
+  #[inline]
+  fn to_little_endian(self) -> Self {
+    let n = u8::to_le(self as u8);
+    let p = &n as *const u8 as *const GossipPayload;
+    unsafe { *p }
+  }
+  #[inline]
+  fn from_little_endian(self) -> Self {
+    let n = u8::from_le(self as u8);
+    let p = &n as *const u8 as *const GossipPayload;
+    unsafe { *p }
+  }
+}
+
+impl flatbuffers::Push for GossipPayload {
+    type Output = GossipPayload;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        flatbuffers::emplace_scalar::<GossipPayload>(dst, *self);
+    }
+}
+
+#[allow(non_camel_case_types)]
+const ENUM_VALUES_GOSSIP_PAYLOAD:[GossipPayload; 7] = [
+  GossipPayload::NONE,
+  GossipPayload::Subscription,
+  GossipPayload::Publish,
+  GossipPayload::IHave,
+  GossipPayload::IWant,
+  GossipPayload::Graft,
+  GossipPayload::Prune
+];
+
+#[allow(non_camel_case_types)]
+const ENUM_NAMES_GOSSIP_PAYLOAD:[&'static str; 7] = [
+    "NONE",
+    "Subscription",
+    "Publish",
+    "IHave",
+    "IWant",
+    "Graft",
+    "Prune"
+];
+
+pub fn enum_name_gossip_payload(e: GossipPayload) -> &'static str {
+  let index: usize = e as usize;
+  ENUM_NAMES_GOSSIP_PAYLOAD[index]
+}
+
+pub struct GossipPayloadUnionTableOffset {}
+pub enum GossipMessageOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct GossipMessage<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GossipMessage<'a> {
+    type Inner = GossipMessage<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> GossipMessage<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        GossipMessage {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args GossipMessageArgs) -> flatbuffers::WIPOffset<GossipMessage<'bldr>> {
+      let mut builder = GossipMessageBuilder::new(_fbb);
+      if let Some(x) = args.payload { builder.add_payload(x); }
+      builder.add_payload_type(args.payload_type);
+      builder.finish()
+    }
+
+    pub const VT_PAYLOAD_TYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_PAYLOAD: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn payload_type(&self) -> GossipPayload {
+    self._tab.get::<GossipPayload>(GossipMessage::VT_PAYLOAD_TYPE, Some(GossipPayload::NONE)).unwrap()
+  }
+  #[inline]
+  pub fn payload(&self) -> Option<flatbuffers::Table<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(GossipMessage::VT_PAYLOAD, None)
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_subscription(&'a self) -> Option<Subscription> {
+    if self.payload_type() == GossipPayload::Subscription {
+      self.payload().map(|u| Subscription::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_publish(&'a self) -> Option<Publish> {
+    if self.payload_type() == GossipPayload::Publish {
+      self.payload().map(|u| Publish::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_ihave(&'a self) -> Option<IHave> {
+    if self.payload_type() == GossipPayload::IHave {
+      self.payload().map(|u| IHave::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_iwant(&'a self) -> Option<IWant> {
+    if self.payload_type() == GossipPayload::IWant {
+      self.payload().map(|u| IWant::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_graft(&'a self) -> Option<Graft> {
+    if self.payload_type() == GossipPayload::Graft {
+      self.payload().map(|u| Graft::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_prune(&'a self) -> Option<Prune> {
+    if self.payload_type() == GossipPayload::Prune {
+      self.payload().map(|u| Prune::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+}
+
+pub struct GossipMessageArgs {
+    pub payload_type: GossipPayload,
+    pub payload: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for GossipMessageArgs {
+    #[inline]
+    fn default() -> Self {
+        GossipMessageArgs {
+            payload_type: GossipPayload::NONE,
+            payload: None,
+        }
+    }
+}
+pub struct GossipMessageBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GossipMessageBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_payload_type(&mut self, payload_type: GossipPayload) {
+    self.fbb_.push_slot::<GossipPayload>(GossipMessage::VT_PAYLOAD_TYPE, payload_type, GossipPayload::NONE);
+  }
+  #[inline]
+  pub fn add_payload(&mut self, payload: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GossipMessage::VT_PAYLOAD, payload);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GossipMessageBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    GossipMessageBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GossipMessage<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum SubscriptionOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Subscription<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Subscription<'a> {
+    type Inner = Subscription<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Subscription<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Subscription {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args SubscriptionArgs<'args>) -> flatbuffers::WIPOffset<Subscription<'bldr>> {
+      let mut builder = SubscriptionBuilder::new(_fbb);
+      if let Some(x) = args.topics { builder.add_topics(x); }
+      builder.add_subscribe(args.subscribe);
+      builder.finish()
+    }
+
+    pub const VT_SUBSCRIBE: flatbuffers::VOffsetT = 4;
+    pub const VT_TOPICS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn subscribe(&self) -> bool {
+    self._tab.get::<bool>(Subscription::VT_SUBSCRIBE, Some(false)).unwrap()
+  }
+  #[inline]
+  pub fn topics(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>>>(Subscription::VT_TOPICS, None)
+  }
+}
+
+pub struct SubscriptionArgs<'a> {
+    pub subscribe: bool,
+    pub topics: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Bytes<'a >>>>>,
+}
+impl<'a> Default for SubscriptionArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        SubscriptionArgs {
+            subscribe: false,
+            topics: None,
+        }
+    }
+}
+pub struct SubscriptionBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> SubscriptionBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_subscribe(&mut self, subscribe: bool) {
+    self.fbb_.push_slot::<bool>(Subscription::VT_SUBSCRIBE, subscribe, false);
+  }
+  #[inline]
+  pub fn add_topics(&mut self, topics: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Bytes<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Subscription::VT_TOPICS, topics);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SubscriptionBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    SubscriptionBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Subscription<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum PublishOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Publish<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Publish<'a> {
+    type Inner = Publish<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Publish<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Publish {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PublishArgs<'args>) -> flatbuffers::WIPOffset<Publish<'bldr>> {
+      let mut builder = PublishBuilder::new(_fbb);
+      builder.add_seqno(args.seqno);
+      if let Some(x) = args.signature { builder.add_signature(x); }
+      if let Some(x) = args.data { builder.add_data(x); }
+      if let Some(x) = args.topic { builder.add_topic(x); }
+      if let Some(x) = args.pubkey { builder.add_pubkey(x); }
+      builder.finish()
+    }
+
+    pub const VT_PUBKEY: flatbuffers::VOffsetT = 4;
+    pub const VT_SEQNO: flatbuffers::VOffsetT = 6;
+    pub const VT_TOPIC: flatbuffers::VOffsetT = 8;
+    pub const VT_DATA: flatbuffers::VOffsetT = 10;
+    pub const VT_SIGNATURE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub fn pubkey(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Publish::VT_PUBKEY, None).map(|v| v.safe_slice())
+  }
+  #[inline]
+  pub fn seqno(&self) -> u64 {
+    self._tab.get::<u64>(Publish::VT_SEQNO, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn topic(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Publish::VT_TOPIC, None).map(|v| v.safe_slice())
+  }
+  #[inline]
+  pub fn data(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Publish::VT_DATA, None).map(|v| v.safe_slice())
+  }
+  #[inline]
+  pub fn signature(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Publish::VT_SIGNATURE, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct PublishArgs<'a> {
+    pub pubkey: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+    pub seqno: u64,
+    pub topic: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+    pub signature: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for PublishArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        PublishArgs {
+            pubkey: None,
+            seqno: 0,
+            topic: None,
+            data: None,
+            signature: None,
+        }
+    }
+}
+pub struct PublishBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PublishBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_pubkey(&mut self, pubkey: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Publish::VT_PUBKEY, pubkey);
+  }
+  #[inline]
+  pub fn add_seqno(&mut self, seqno: u64) {
+    self.fbb_.push_slot::<u64>(Publish::VT_SEQNO, seqno, 0);
+  }
+  #[inline]
+  pub fn add_topic(&mut self, topic: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Publish::VT_TOPIC, topic);
+  }
+  #[inline]
+  pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Publish::VT_DATA, data);
+  }
+  #[inline]
+  pub fn add_signature(&mut self, signature: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Publish::VT_SIGNATURE, signature);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PublishBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PublishBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Publish<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum IHaveOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct IHave<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for IHave<'a> {
+    type Inner = IHave<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> IHave<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        IHave {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args IHaveArgs<'args>) -> flatbuffers::WIPOffset<IHave<'bldr>> {
+      let mut builder = IHaveBuilder::new(_fbb);
+      if let Some(x) = args.message_ids { builder.add_message_ids(x); }
+      if let Some(x) = args.topic { builder.add_topic(x); }
+      builder.finish()
+    }
+
+    pub const VT_TOPIC: flatbuffers::VOffsetT = 4;
+    pub const VT_MESSAGE_IDS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn topic(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(IHave::VT_TOPIC, None).map(|v| v.safe_slice())
+  }
+  #[inline]
+  pub fn message_ids(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>>>(IHave::VT_MESSAGE_IDS, None)
+  }
+}
+
+pub struct IHaveArgs<'a> {
+    pub topic: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+    pub message_ids: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Bytes<'a >>>>>,
+}
+impl<'a> Default for IHaveArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        IHaveArgs {
+            topic: None,
+            message_ids: None,
+        }
+    }
+}
+pub struct IHaveBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> IHaveBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_topic(&mut self, topic: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IHave::VT_TOPIC, topic);
+  }
+  #[inline]
+  pub fn add_message_ids(&mut self, message_ids: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Bytes<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IHave::VT_MESSAGE_IDS, message_ids);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> IHaveBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    IHaveBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<IHave<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum IWantOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct IWant<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for IWant<'a> {
+    type Inner = IWant<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> IWant<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        IWant {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args IWantArgs<'args>) -> flatbuffers::WIPOffset<IWant<'bldr>> {
+      let mut builder = IWantBuilder::new(_fbb);
+      if let Some(x) = args.message_ids { builder.add_message_ids(x); }
+      builder.finish()
+    }
+
+    pub const VT_MESSAGE_IDS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn message_ids(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>>>(IWant::VT_MESSAGE_IDS, None)
+  }
+}
+
+pub struct IWantArgs<'a> {
+    pub message_ids: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Bytes<'a >>>>>,
+}
+impl<'a> Default for IWantArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        IWantArgs {
+            message_ids: None,
+        }
+    }
+}
+pub struct IWantBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> IWantBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_message_ids(&mut self, message_ids: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Bytes<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IWant::VT_MESSAGE_IDS, message_ids);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> IWantBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    IWantBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<IWant<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum GraftOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Graft<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Graft<'a> {
+    type Inner = Graft<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Graft<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Graft {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args GraftArgs<'args>) -> flatbuffers::WIPOffset<Graft<'bldr>> {
+      let mut builder = GraftBuilder::new(_fbb);
+      if let Some(x) = args.topic { builder.add_topic(x); }
+      builder.finish()
+    }
+
+    pub const VT_TOPIC: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn topic(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Graft::VT_TOPIC, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct GraftArgs<'a> {
+    pub topic: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for GraftArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        GraftArgs {
+            topic: None,
+        }
+    }
+}
+pub struct GraftBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GraftBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_topic(&mut self, topic: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Graft::VT_TOPIC, topic);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GraftBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    GraftBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Graft<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum PruneOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Prune<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Prune<'a> {
+    type Inner = Prune<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Prune<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Prune {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PruneArgs<'args>) -> flatbuffers::WIPOffset<Prune<'bldr>> {
+      let mut builder = PruneBuilder::new(_fbb);
+      if let Some(x) = args.topic { builder.add_topic(x); }
+      builder.finish()
+    }
+
+    pub const VT_TOPIC: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn topic(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Prune::VT_TOPIC, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct PruneArgs<'a> {
+    pub topic: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for PruneArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        PruneArgs {
+            topic: None,
+        }
+    }
+}
+pub struct PruneBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PruneBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_topic(&mut self, topic: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Prune::VT_TOPIC, topic);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PruneBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PruneBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Prune<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum BytesOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Bytes<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Bytes<'a> {
+    type Inner = Bytes<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Bytes<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Bytes {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args BytesArgs<'args>) -> flatbuffers::WIPOffset<Bytes<'bldr>> {
+      let mut builder = BytesBuilder::new(_fbb);
+      if let Some(x) = args.seq { builder.add_seq(x); }
+      builder.finish()
+    }
+
+    pub const VT_SEQ: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn seq(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Bytes::VT_SEQ, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct BytesArgs<'a> {
+    pub seq: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for BytesArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        BytesArgs {
+            seq: None,
+        }
+    }
+}
+pub struct BytesBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BytesBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_seq(&mut self, seq: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Bytes::VT_SEQ, seq);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BytesBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BytesBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Bytes<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+}  // pub mod Gossipsub
+}  // pub mod P2P
+
@@ -0,0 +1,37 @@
+use p2p::secio::PeerId;
+
+use crate::protocol::Topic;
+
+/// Hooks to score peers on their behavior
+///
+/// Peers with a negative score are pruned from the mesh at heartbeat, never grafted, and neither
+/// gossip to nor gossip from them is handled. All hooks default to doing nothing and the default
+/// score is zero.
+pub trait PeerScore: Send {
+    /// Current score of the peer
+    fn score(&self, _peer_id: &PeerId) -> f64 {
+        0.0
+    }
+
+    /// The peer delivered a valid message first
+    fn deliver_message(&mut self, _peer_id: &PeerId, _topic: &Topic) {}
+
+    /// The peer delivered a message already seen
+    fn duplicate_message(&mut self, _peer_id: &PeerId, _topic: &Topic) {}
+
+    /// The peer delivered a message rejected by the topic validator
+    fn reject_message(&mut self, _peer_id: &PeerId, _topic: &Topic) {}
+
+    /// The peer joined the local mesh of the topic
+    fn graft(&mut self, _peer_id: &PeerId, _topic: &Topic) {}
+
+    /// The peer left the local mesh of the topic
+    fn prune(&mut self, _peer_id: &PeerId, _topic: &Topic) {}
+
+    /// Called on every heartbeat, the place to decay scores
+    fn heartbeat(&mut self) {}
+}
+
+pub(crate) struct NoScore;
+
+impl PeerScore for NoScore {}
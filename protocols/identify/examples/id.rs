@@ -6,11 +6,12 @@ use p2p::{
     builder::{MetaBuilder, ServiceBuilder},
     context::{ProtocolContextMutRef, ServiceContext},
     multiaddr::Multiaddr,
+    protocol_select::ProtocolInfo,
     secio::{PeerId, SecioKeyPair},
     service::{DialProtocol, ProtocolHandle, ServiceError, ServiceEvent, SessionType},
     traits::ServiceHandle,
 };
-use tentacle_identify::{Callback, IdentifyProtocol, MisbehaveResult, Misbehavior};
+use tentacle_identify::{Callback, IdentifyProtocol, MisbehaveResult, Misbehavior, PUSH_VERSION};
 
 fn main() {
     env_logger::init();
@@ -19,6 +20,7 @@ fn main() {
     };
     let protocol = MetaBuilder::default()
         .id(1.into())
+        .support_versions(vec!["0.0.1".to_owned(), PUSH_VERSION.to_owned()])
         .service_handle(move || {
             ProtocolHandle::Callback(
                 Box::new(IdentifyProtocol::new(callback)).global_ip_only(false),
@@ -79,6 +81,15 @@ impl Callback for IdentifyCallback {
     }
     /// Add remote peer's listen addresses
     fn add_remote_listen_addrs(&mut self, _peer: &PeerId, _addrs: Vec<Multiaddr>) {}
+    /// Add remote peer's supported protocols and agent version
+    fn add_remote_protocols(
+        &mut self,
+        peer: &PeerId,
+        agent_version: &str,
+        protocols: Vec<ProtocolInfo>,
+    ) {
+        log::info!("{:?} runs {} with {:?}", peer, agent_version, protocols);
+    }
     /// Add our address observed by remote peer
     fn add_observed_addr(
         &mut self,
@@ -15,15 +15,19 @@ mod protocol_generated_verifier;
 #[allow(dead_code)]
 mod protocol_mol;
 
+mod observed;
 mod protocol;
 
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
+use bytes::Bytes;
 use log::{debug, error, trace, warn};
 use p2p::{
     context::{ProtocolContext, ProtocolContextMutRef, SessionContext},
     multiaddr::{Multiaddr, Protocol},
+    peer_record::{InsertResult, PeerRecordStore, SignedPeerRecord},
+    protocol_select::ProtocolInfo,
     secio::PeerId,
     service::SessionType,
     traits::ServiceProtocol,
@@ -31,17 +35,46 @@ use p2p::{
     SessionId,
 };
 
+use observed::ObservedAddrs;
 use protocol::IdentifyMessage;
 
+pub use observed::ExternalAddrEvent;
+
 const CHECK_TIMEOUT_TOKEN: u64 = 100;
 // Check timeout interval (seconds)
 const CHECK_TIMEOUT_INTERVAL: u64 = 1;
 const DEFAULT_TIMEOUT: u64 = 8;
 const MAX_ADDRS: usize = 10;
+const DEFAULT_OBSERVED_QUORUM: usize = 3;
+// Observations of our address are forgotten after this
+const OBSERVED_TTL: Duration = Duration::from_secs(30 * 60);
+const DEFAULT_AGENT_VERSION: &str = concat!("tentacle-identify/", env!("CARGO_PKG_VERSION"));
+
+/// Version of the protocol pushing listen address changes, sessions of an older version get a
+/// single message
+///
+/// Register the protocol with `support_versions(vec!["0.0.1".to_owned(), PUSH_VERSION.to_owned()])`
+/// to push to the peers supporting it and still talk to the others. Listen addresses are checked
+/// every second, so a change is pushed up to a second after `ListenStarted` or `ListenClose`.
+pub const PUSH_VERSION: &str = "0.0.2";
+
+/// Whether the negotiated version is `PUSH_VERSION` or newer, comparing the numeric parts
+fn supports_push(version: &str) -> bool {
+    let parse = |version: &str| {
+        version
+            .split('.')
+            .map(|part| part.parse::<u64>().ok())
+            .collect::<Option<Vec<_>>>()
+    };
+    match (parse(version), parse(PUSH_VERSION)) {
+        (Some(version), Some(push_version)) => version >= push_version,
+        _ => false,
+    }
+}
 
 /// The misbehavior to report to underlying peer storage
 pub enum Misbehavior {
-    /// Repeat send listen addresses
+    /// Repeat send the same listen addresses
     DuplicateListenAddrs,
     /// Repeat send observed address
     DuplicateObservedAddr,
@@ -51,6 +84,8 @@ pub enum Misbehavior {
     InvalidData,
     /// Send too many addresses in listen addresses
     TooManyAddresses(usize),
+    /// Send a peer record with a bad signature or of another peer
+    InvalidRecord,
 }
 
 /// Misbehavior report result
@@ -88,8 +123,16 @@ pub trait Callback: Clone + Send {
     fn identify(&mut self) -> &[u8];
     /// Get local listen addresses
     fn local_listen_addrs(&mut self) -> Vec<Multiaddr>;
-    /// Add remote peer's listen addresses
+    /// Add remote peer's listen addresses, called again when the peer pushes new ones
     fn add_remote_listen_addrs(&mut self, peer: &PeerId, addrs: Vec<Multiaddr>);
+    /// Add remote peer's supported protocols and agent version
+    fn add_remote_protocols(
+        &mut self,
+        _peer: &PeerId,
+        _agent_version: &str,
+        _protocols: Vec<ProtocolInfo>,
+    ) {
+    }
     /// Add our address observed by remote peer
     fn add_observed_addr(
         &mut self,
@@ -97,6 +140,9 @@ pub trait Callback: Clone + Send {
         addr: Multiaddr,
         ty: SessionType,
     ) -> MisbehaveResult;
+    /// Our external address confirmed or retracted by the observations of a quorum of peers,
+    /// to be advertised, e.g. by discovery or a port mapping
+    fn external_addr_changed(&mut self, _event: ExternalAddrEvent) {}
     /// Report misbehavior
     fn misbehave(&mut self, peer: &PeerId, kind: Misbehavior) -> MisbehaveResult;
 }
@@ -107,6 +153,11 @@ pub struct IdentifyProtocol<T> {
     remote_infos: HashMap<SessionId, RemoteInfo>,
     secio_enabled: bool,
     global_ip_only: bool,
+    // Latest records received of remote peers
+    records: PeerRecordStore,
+    local_record: Option<SignedPeerRecord>,
+    agent_version: String,
+    observed: ObservedAddrs,
 }
 
 impl<T: Callback> IdentifyProtocol<T> {
@@ -116,6 +167,10 @@ impl<T: Callback> IdentifyProtocol<T> {
             remote_infos: HashMap::default(),
             secio_enabled: true,
             global_ip_only: true,
+            records: PeerRecordStore::default(),
+            local_record: None,
+            agent_version: DEFAULT_AGENT_VERSION.to_owned(),
+            observed: ObservedAddrs::new(DEFAULT_OBSERVED_QUORUM, OBSERVED_TTL),
         }
     }
 
@@ -125,10 +180,105 @@ impl<T: Callback> IdentifyProtocol<T> {
         self
     }
 
+    /// Name and version of the implementation sent to peers,
+    /// default is `tentacle-identify/<version>`
+    pub fn agent_version(mut self, agent_version: String) -> Self {
+        self.agent_version = agent_version;
+        self
+    }
+
+    /// Number of distinct netgroups of peers which must observe the same address before it is
+    /// confirmed as our external address, default is 3
+    pub fn observed_quorum(mut self, quorum: usize) -> Self {
+        self.observed.set_quorum(quorum);
+        self
+    }
+
+    /// Store of the records received, share it with discovery so that both keep the newest record
+    /// of a peer, default is a store of its own
+    pub fn records(mut self, records: PeerRecordStore) -> Self {
+        self.records = records;
+        self
+    }
+
+    // The listens of the service and the extra addresses of the callback, such as mapped ports
+    fn local_listen_addrs(&mut self, context: &ProtocolContext) -> Vec<Multiaddr> {
+        let mut addrs = context.listens().to_vec();
+        for addr in self.callback.local_listen_addrs() {
+            if !addrs.contains(&addr) {
+                addrs.push(addr);
+            }
+        }
+        let global_ip_only = self.global_ip_only;
+        addrs
+            .into_iter()
+            .filter(|addr| {
+                multiaddr_to_socketaddr(addr)
+                    .map(|socket_addr| !global_ip_only || is_reachable(socket_addr.ip()))
+                    .unwrap_or(false)
+            })
+            .take(MAX_ADDRS)
+            .collect()
+    }
+
+    fn identify_message(
+        &mut self,
+        context: &ProtocolContext,
+        session: &SessionContext,
+        listen_addrs: Vec<Multiaddr>,
+    ) -> Bytes {
+        let observed_addr = session
+            .address
+            .iter()
+            .filter(|proto| match proto {
+                Protocol::P2p(_) => false,
+                _ => true,
+            })
+            .collect::<Multiaddr>();
+        let mut protocols = context.protocols().values().cloned().collect::<Vec<_>>();
+        protocols.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let record = self.local_record(context, &listen_addrs);
+        let identify = self.callback.identify();
+        IdentifyMessage::new(
+            listen_addrs,
+            observed_addr,
+            identify,
+            record,
+            protocols,
+            self.agent_version.clone(),
+        )
+        .encode()
+    }
+
+    // Sign the listen addresses again only when they changed
+    fn local_record(
+        &mut self,
+        context: &ProtocolContext,
+        addrs: &[Multiaddr],
+    ) -> Option<SignedPeerRecord> {
+        let key_pair = context.key_pair()?;
+        let record = match self.local_record.take() {
+            Some(ref record) if record.addrs() == addrs => record.clone(),
+            Some(record) => match record.renew(key_pair, addrs.to_vec()) {
+                Ok(new) => new,
+                Err(err) => {
+                    debug!("renew local record error: {}", err);
+                    self.local_record = Some(record);
+                    return None;
+                }
+            },
+            None => SignedPeerRecord::new(key_pair, addrs.to_vec()),
+        };
+        self.local_record = Some(record.clone());
+        Some(record)
+    }
+
     fn process_listens(
         &mut self,
         context: &mut ProtocolContextMutRef,
         listens: Vec<Multiaddr>,
+        record: Option<SignedPeerRecord>,
     ) -> MisbehaveResult {
         let session = context.session;
         let info = self
@@ -136,14 +286,45 @@ impl<T: Callback> IdentifyProtocol<T> {
             .get_mut(&session.id)
             .expect("RemoteInfo must exists");
 
-        if info.listen_addrs.is_some() {
-            debug!("remote({:?}) repeat send observed address", info.peer_id);
+        // Listen addresses are pushed again only when they changed
+        if info.received_listens.as_ref() == Some(&listens) {
+            debug!("remote({:?}) repeat send listen addresses", info.peer_id);
             self.callback
                 .misbehave(&info.peer_id, Misbehavior::DuplicateListenAddrs)
         } else if listens.len() > MAX_ADDRS {
             self.callback
                 .misbehave(&info.peer_id, Misbehavior::TooManyAddresses(listens.len()))
         } else {
+            info.received_listens = Some(listens.clone());
+            // The signed addresses take the place of the plain ones
+            let listens = match record {
+                Some(record) => {
+                    if record.peer_id() != info.peer_id {
+                        debug!("remote({:?}) send record of another peer", info.peer_id);
+                        return self
+                            .callback
+                            .misbehave(&info.peer_id, Misbehavior::InvalidRecord);
+                    }
+                    if record.addrs().len() > MAX_ADDRS {
+                        return self.callback.misbehave(
+                            &info.peer_id,
+                            Misbehavior::TooManyAddresses(record.addrs().len()),
+                        );
+                    }
+                    // An older record is ignored, the addresses of the newest one are used
+                    if self.records.insert(record.clone()) == InsertResult::Invalid {
+                        debug!("remote({:?}) send invalid record", info.peer_id);
+                        return self
+                            .callback
+                            .misbehave(&info.peer_id, Misbehavior::InvalidRecord);
+                    }
+                    self.records
+                        .get(&info.peer_id)
+                        .map(|record| record.addrs().to_vec())
+                        .unwrap_or_default()
+                }
+                None => listens,
+            };
             trace!("received listen addresses: {:?}", listens);
             let global_ip_only = self.global_ip_only;
             let reachable_addrs = listens
@@ -180,10 +361,11 @@ impl<T: Callback> IdentifyProtocol<T> {
             trace!("received observed address: {}", observed);
 
             let global_ip_only = self.global_ip_only;
-            if multiaddr_to_socketaddr(&observed)
+            let reachable = multiaddr_to_socketaddr(&observed)
                 .map(|socket_addr| socket_addr.ip())
                 .filter(|ip_addr| !global_ip_only || is_reachable(*ip_addr))
-                .is_some()
+                .is_some();
+            if reachable
                 && self
                     .callback
                     .add_observed_addr(&info.peer_id, observed.clone(), info.session.ty)
@@ -191,6 +373,19 @@ impl<T: Callback> IdentifyProtocol<T> {
             {
                 return MisbehaveResult::Disconnect;
             }
+            // Only over an inbound session the observed port is the one of our listener
+            if reachable && !info.session.ty.is_outbound() {
+                if let Some(peer_addr) = multiaddr_to_socketaddr(&info.session.address) {
+                    let events = self.observed.observe(
+                        info.peer_id.clone(),
+                        peer_addr.ip(),
+                        observed.clone(),
+                    );
+                    for event in events {
+                        self.callback.external_addr_changed(event);
+                    }
+                }
+            }
             info.observed_addr = Some(observed.clone());
             MisbehaveResult::Continue
         }
@@ -204,10 +399,16 @@ pub(crate) struct RemoteInfo {
     timeout: Duration,
     listen_addrs: Option<Vec<Multiaddr>>,
     observed_addr: Option<Multiaddr>,
+    // Listen addresses as received in the last message, later messages are pushes
+    received_listens: Option<Vec<Multiaddr>>,
+    // Listen addresses last sent to the peer
+    sent_listens: Vec<Multiaddr>,
+    // Whether the negotiated version pushes listen address changes
+    push: bool,
 }
 
 impl RemoteInfo {
-    fn new(session: SessionContext, timeout: Duration) -> RemoteInfo {
+    fn new(session: SessionContext, timeout: Duration, push: bool) -> RemoteInfo {
         let peer_id = session
             .remote_pubkey
             .as_ref()
@@ -220,6 +421,9 @@ impl RemoteInfo {
             timeout,
             listen_addrs: None,
             observed_addr: None,
+            received_listens: None,
+            sent_listens: Vec::new(),
+            push,
         }
     }
 }
@@ -239,7 +443,7 @@ impl<T: Callback> ServiceProtocol for IdentifyProtocol<T> {
         }
     }
 
-    fn connected(&mut self, context: ProtocolContextMutRef, _version: &str) {
+    fn connected(&mut self, context: ProtocolContextMutRef, version: &str) {
         let session = context.session;
         if session.remote_pubkey.is_none() {
             error!("IdentifyProtocol require secio enabled!");
@@ -248,34 +452,18 @@ impl<T: Callback> ServiceProtocol for IdentifyProtocol<T> {
             return;
         }
 
-        let remote_info = RemoteInfo::new(session.clone(), Duration::from_secs(DEFAULT_TIMEOUT));
+        let mut remote_info = RemoteInfo::new(
+            session.clone(),
+            Duration::from_secs(DEFAULT_TIMEOUT),
+            supports_push(version),
+        );
         trace!("IdentifyProtocol sconnected from {:?}", remote_info.peer_id);
-        self.remote_infos.insert(session.id, remote_info);
-
-        let listen_addrs: Vec<Multiaddr> = self
-            .callback
-            .local_listen_addrs()
-            .iter()
-            .filter(|addr| {
-                multiaddr_to_socketaddr(addr)
-                    .map(|socket_addr| !self.global_ip_only || is_reachable(socket_addr.ip()))
-                    .unwrap_or(false)
-            })
-            .take(MAX_ADDRS)
-            .cloned()
-            .collect();
 
-        let observed_addr = session
-            .address
-            .iter()
-            .filter(|proto| match proto {
-                Protocol::P2p(_) => false,
-                _ => true,
-            })
-            .collect::<Multiaddr>();
+        let listen_addrs = self.local_listen_addrs(&context);
+        remote_info.sent_listens = listen_addrs.clone();
+        self.remote_infos.insert(session.id, remote_info);
 
-        let identify = self.callback.identify();
-        let data = IdentifyMessage::new(listen_addrs, observed_addr, identify).encode();
+        let data = self.identify_message(&context, session, listen_addrs);
         let _ = context.quick_send_message(data);
     }
 
@@ -298,19 +486,45 @@ impl<T: Callback> ServiceProtocol for IdentifyProtocol<T> {
 
         match IdentifyMessage::decode(&data) {
             Some(message) => {
-                // Need to interrupt processing, avoid pollution
-                if self
-                    .callback
-                    .received_identify(&mut context, message.identify)
-                    .is_disconnect()
-                    || self
-                        .process_listens(&mut context, message.listen_addrs)
+                let info = self
+                    .remote_infos
+                    .get(&session.id)
+                    .expect("RemoteInfo must exists");
+                // Messages after the first one push changed listen addresses
+                let push = info.received_listens.is_some();
+                let peer_id = info.peer_id.clone();
+                if push && !info.push {
+                    debug!("remote({:?}) repeat send identify message", peer_id);
+                    if self
+                        .callback
+                        .misbehave(&peer_id, Misbehavior::DuplicateListenAddrs)
                         .is_disconnect()
+                    {
+                        let _ = context.disconnect(session.id);
+                    }
+                    return;
+                }
+                // Need to interrupt processing, avoid pollution
+                if (!push
+                    && self
+                        .callback
+                        .received_identify(&mut context, message.identify)
+                        .is_disconnect())
                     || self
-                        .process_observed(&mut context, message.observed_addr)
+                        .process_listens(&mut context, message.listen_addrs, message.record)
                         .is_disconnect()
+                    || (!push
+                        && self
+                            .process_observed(&mut context, message.observed_addr)
+                            .is_disconnect())
                 {
                     let _ = context.disconnect(session.id);
+                } else {
+                    self.callback.add_remote_protocols(
+                        &peer_id,
+                        &message.agent_version,
+                        message.protocols,
+                    );
                 }
             }
             None => {
@@ -338,6 +552,28 @@ impl<T: Callback> ServiceProtocol for IdentifyProtocol<T> {
             return;
         }
 
+        // Push the listen addresses to the peers which support it and got older ones, after a
+        // listen started or closed
+        let listen_addrs = self.local_listen_addrs(context);
+        let outdated = self
+            .remote_infos
+            .values()
+            .filter(|info| info.push && info.sent_listens != listen_addrs)
+            .map(|info| info.session.clone())
+            .collect::<Vec<_>>();
+        for session in outdated {
+            trace!("push listen addresses to session {}", session.id);
+            let data = self.identify_message(context, &session, listen_addrs.clone());
+            let _ = context.send_message_to(session.id, context.proto_id, data);
+            if let Some(info) = self.remote_infos.get_mut(&session.id) {
+                info.sent_listens = listen_addrs.clone();
+            }
+        }
+
+        for event in self.observed.expire() {
+            self.callback.external_addr_changed(event);
+        }
+
         let now = Instant::now();
         for (session_id, info) in &self.remote_infos {
             if (info.listen_addrs.is_none() || info.observed_addr.is_none())
@@ -355,3 +591,18 @@ impl<T: Callback> ServiceProtocol for IdentifyProtocol<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::supports_push;
+
+    #[test]
+    fn test_supports_push() {
+        assert!(!supports_push("0.0.1"));
+        assert!(supports_push("0.0.2"));
+        assert!(supports_push("0.0.10"));
+        assert!(supports_push("0.1"));
+        assert!(!supports_push("0.0.2-beta"));
+        assert!(!supports_push(""));
+    }
+}
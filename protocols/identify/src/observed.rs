@@ -0,0 +1,170 @@
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+use p2p::{multiaddr::Multiaddr, secio::PeerId, utils::netgroup};
+
+/// Change of our external addresses
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExternalAddrEvent {
+    /// Peers of enough netgroups observed us on this address
+    Confirmed(Multiaddr),
+    /// Not enough netgroups observe us on this address any more
+    Retracted(Multiaddr),
+}
+
+struct Vote {
+    addr: Multiaddr,
+    group: Vec<u8>,
+    observed_at: Instant,
+}
+
+/// Votes of peers on our external address
+///
+/// Every peer has one vote, its latest observation. An address is confirmed when the voters for
+/// it come from `quorum` distinct netgroups, so that neither a single peer nor many peers of one
+/// network can make us advertise a wrong address.
+pub(crate) struct ObservedAddrs {
+    quorum: usize,
+    ttl: Duration,
+    votes: HashMap<PeerId, Vote>,
+    confirmed: HashSet<Multiaddr>,
+}
+
+impl ObservedAddrs {
+    pub(crate) fn new(quorum: usize, ttl: Duration) -> Self {
+        ObservedAddrs {
+            quorum,
+            ttl,
+            votes: HashMap::default(),
+            confirmed: HashSet::default(),
+        }
+    }
+
+    pub(crate) fn set_quorum(&mut self, quorum: usize) {
+        self.quorum = quorum;
+    }
+
+    /// The peer of the ip observed us on the address
+    pub(crate) fn observe(
+        &mut self,
+        peer_id: PeerId,
+        peer_ip: IpAddr,
+        addr: Multiaddr,
+    ) -> Vec<ExternalAddrEvent> {
+        self.votes.insert(
+            peer_id,
+            Vote {
+                addr,
+                group: netgroup(peer_ip),
+                observed_at: Instant::now(),
+            },
+        );
+        self.update()
+    }
+
+    /// Drop votes older than the ttl
+    pub(crate) fn expire(&mut self) -> Vec<ExternalAddrEvent> {
+        let ttl = self.ttl;
+        let before = self.votes.len();
+        self.votes
+            .retain(|_, vote| vote.observed_at.elapsed() < ttl);
+        if self.votes.len() == before {
+            return Vec::new();
+        }
+        self.update()
+    }
+
+    fn update(&mut self) -> Vec<ExternalAddrEvent> {
+        let mut groups: HashMap<&Multiaddr, HashSet<&[u8]>> = HashMap::default();
+        for vote in self.votes.values() {
+            groups
+                .entry(&vote.addr)
+                .or_insert_with(HashSet::default)
+                .insert(&vote.group);
+        }
+        let quorum = self.quorum;
+        let confirmed = groups
+            .into_iter()
+            .filter(|(_, groups)| groups.len() >= quorum)
+            .map(|(addr, _)| addr.clone())
+            .collect::<HashSet<_>>();
+
+        let mut events = self
+            .confirmed
+            .difference(&confirmed)
+            .cloned()
+            .map(ExternalAddrEvent::Retracted)
+            .collect::<Vec<_>>();
+        events.extend(
+            confirmed
+                .difference(&self.confirmed)
+                .cloned()
+                .map(ExternalAddrEvent::Confirmed),
+        );
+        self.confirmed = confirmed;
+        events
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ExternalAddrEvent, ObservedAddrs};
+    use p2p::{multiaddr::Multiaddr, secio::PeerId};
+    use std::time::Duration;
+
+    fn addr(s: &str) -> Multiaddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_quorum_of_netgroups() {
+        let mut observed = ObservedAddrs::new(3, Duration::from_secs(60));
+        let external = addr("/ip4/8.8.8.8/tcp/1337");
+
+        // Peers of one netgroup count once
+        for ip in &["1.1.1.1", "1.1.2.2", "1.1.3.3"] {
+            let events = observed.observe(PeerId::random(), ip.parse().unwrap(), external.clone());
+            assert!(events.is_empty());
+        }
+        // A liar alone changes nothing
+        let liar = PeerId::random();
+        let events = observed.observe(
+            liar.clone(),
+            "9.9.9.9".parse().unwrap(),
+            addr("/ip4/6.6.6.6/tcp/1337"),
+        );
+        assert!(events.is_empty());
+
+        let events = observed.observe(
+            PeerId::random(),
+            "2.2.2.2".parse().unwrap(),
+            external.clone(),
+        );
+        assert!(events.is_empty());
+        // The liar changes its mind
+        let events = observed.observe(liar, "9.9.9.9".parse().unwrap(), external.clone());
+        assert_eq!(events, vec![ExternalAddrEvent::Confirmed(external)]);
+    }
+
+    #[test]
+    fn test_retract() {
+        let mut observed = ObservedAddrs::new(2, Duration::from_secs(60));
+        let external = addr("/ip4/8.8.8.8/tcp/1337");
+        let moved = addr("/ip4/8.8.4.4/tcp/1337");
+        let peer_a = PeerId::random();
+        let peer_b = PeerId::random();
+
+        observed.observe(peer_a.clone(), "1.1.1.1".parse().unwrap(), external.clone());
+        let events = observed.observe(peer_b.clone(), "2.2.2.2".parse().unwrap(), external.clone());
+        assert_eq!(events, vec![ExternalAddrEvent::Confirmed(external.clone())]);
+
+        let events = observed.observe(peer_a, "1.1.1.1".parse().unwrap(), moved.clone());
+        assert_eq!(events, vec![ExternalAddrEvent::Retracted(external)]);
+        let events = observed.observe(peer_b, "2.2.2.2".parse().unwrap(), moved.clone());
+        assert_eq!(events, vec![ExternalAddrEvent::Confirmed(moved.clone())]);
+
+        observed.ttl = Duration::from_secs(0);
+        assert_eq!(observed.expire(), vec![ExternalAddrEvent::Retracted(moved)]);
+    }
+}
@@ -6,7 +6,8 @@ compile_error!("Please choose a serialization format via feature. Possible choic
 #[cfg(feature = "flatc")]
 use crate::protocol_generated::p2p::identify::{
     Address as FbsAddress, AddressBuilder, IdentifyMessage as FbsIdentifyMessage,
-    IdentifyMessageBuilder,
+    IdentifyMessageBuilder, PeerRecord as FbsPeerRecord, PeerRecordBuilder,
+    ProtocolInfo as FbsProtocolInfo, ProtocolInfoBuilder,
 };
 #[cfg(feature = "molc")]
 use crate::protocol_mol;
@@ -14,7 +15,10 @@ use crate::protocol_mol;
 use molecule::prelude::{Builder, Entity, Reader};
 
 use bytes::Bytes;
-use p2p::multiaddr::Multiaddr;
+use p2p::{
+    multiaddr::Multiaddr, peer_record::SignedPeerRecord, protocol_select::ProtocolInfo,
+    secio::PublicKey,
+};
 
 use std::convert::TryFrom;
 
@@ -23,6 +27,9 @@ pub struct IdentifyMessage<'a> {
     pub(crate) listen_addrs: Vec<Multiaddr>,
     pub(crate) observed_addr: Multiaddr,
     pub(crate) identify: &'a [u8],
+    pub(crate) record: Option<SignedPeerRecord>,
+    pub(crate) protocols: Vec<ProtocolInfo>,
+    pub(crate) agent_version: String,
 }
 
 impl<'a> IdentifyMessage<'a> {
@@ -30,11 +37,17 @@ impl<'a> IdentifyMessage<'a> {
         listen_addrs: Vec<Multiaddr>,
         observed_addr: Multiaddr,
         identify: &'a [u8],
+        record: Option<SignedPeerRecord>,
+        protocols: Vec<ProtocolInfo>,
+        agent_version: String,
     ) -> Self {
         IdentifyMessage {
             listen_addrs,
             observed_addr,
             identify,
+            record,
+            protocols,
+            agent_version,
         }
     }
 
@@ -52,11 +65,29 @@ impl<'a> IdentifyMessage<'a> {
 
         let identify = fbb.create_vector(self.identify);
 
+        let record = self
+            .record
+            .as_ref()
+            .map(|record| record_to_offset(&mut fbb, record));
+
+        let mut protocols = Vec::with_capacity(self.protocols.len());
+        for info in &self.protocols {
+            protocols.push(protocol_to_offset(&mut fbb, info));
+        }
+        let protocols = fbb.create_vector(&protocols);
+
+        let agent_version = fbb.create_string(&self.agent_version);
+
         let mut builder = IdentifyMessageBuilder::new(&mut fbb);
 
         builder.add_listen_addrs(listens_vec);
         builder.add_observed_addr(observed);
         builder.add_identify(identify);
+        if let Some(record) = record {
+            builder.add_record(record);
+        }
+        builder.add_protocols(protocols);
+        builder.add_agent_version(agent_version);
 
         let data = builder.finish();
 
@@ -81,10 +112,27 @@ impl<'a> IdentifyMessage<'a> {
 
                 let observed_addr = fbs_to_addr(&raw_observed)?;
 
+                let record = match fbs_message.record() {
+                    Some(fbs_record) => Some(fbs_to_record(&fbs_record)?),
+                    None => None,
+                };
+
+                // Both are missing in messages of older peers
+                let mut protocols = Vec::new();
+                if let Some(raw_protocols) = fbs_message.protocols() {
+                    for i in 0..raw_protocols.len() {
+                        protocols.push(fbs_to_protocol(&raw_protocols.get(i))?);
+                    }
+                }
+                let agent_version = fbs_message.agent_version().unwrap_or_default().to_owned();
+
                 Some(IdentifyMessage {
                     listen_addrs,
                     observed_addr,
                     identify,
+                    record,
+                    protocols,
+                    agent_version,
                 })
             }
             _ => None,
@@ -124,11 +172,20 @@ impl<'a> IdentifyMessage<'a> {
         let listen_addrs = protocol_mol::AddressVec::new_builder()
             .set(listen_addrs)
             .build();
+        let record = protocol_mol::PeerRecordOpt::new_builder()
+            .set(self.record.map(record_to_mol))
+            .build();
+        let protocols = protocol_mol::ProtocolInfoVec::new_builder()
+            .set(self.protocols.into_iter().map(protocol_to_mol).collect())
+            .build();
 
         protocol_mol::IdentifyMessage::new_builder()
             .listen_addrs(listen_addrs)
             .observed_addr(observed_addr)
             .identify(identify)
+            .record(record)
+            .protocols(protocols)
+            .agent_version(string_to_mol(&self.agent_version))
             .build()
             .as_bytes()
     }
@@ -140,15 +197,24 @@ impl<'a> IdentifyMessage<'a> {
         let identify = reader.identify().raw_data();
         let observed_addr =
             Multiaddr::try_from(reader.observed_addr().bytes().raw_data().to_vec()).ok()?;
-        let mut listen_addrs = Vec::with_capacity(reader.listen_addrs().len());
-        for addr in reader.listen_addrs().iter() {
-            listen_addrs.push(Multiaddr::try_from(addr.bytes().raw_data().to_vec()).ok()?)
+        let listen_addrs = mol_to_addrs(reader.listen_addrs())?;
+        let record = match reader.record().to_opt() {
+            Some(record_reader) => Some(mol_to_record(record_reader)?),
+            None => None,
+        };
+        let mut protocols = Vec::with_capacity(reader.protocols().len());
+        for info in reader.protocols().iter() {
+            protocols.push(mol_to_protocol(info)?);
         }
+        let agent_version = mol_to_string(reader.agent_version())?;
 
         Some(IdentifyMessage {
             identify,
             observed_addr,
             listen_addrs,
+            record,
+            protocols,
+            agent_version,
         })
     }
 }
@@ -168,3 +234,170 @@ fn addr_to_offset<'b>(
 fn fbs_to_addr(addr: &FbsAddress) -> Option<Multiaddr> {
     Multiaddr::try_from(addr.bytes()?.to_vec()).ok()
 }
+
+#[cfg(feature = "flatc")]
+fn record_to_offset<'b>(
+    fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+    record: &SignedPeerRecord,
+) -> flatbuffers::WIPOffset<FbsPeerRecord<'b>> {
+    let pubkey = fbb.create_vector(&record.public_key().encode()[..]);
+    let mut addrs = Vec::with_capacity(record.addrs().len());
+    for addr in record.addrs() {
+        addrs.push(addr_to_offset(fbb, addr));
+    }
+    let addrs = fbb.create_vector(&addrs);
+    let signature = fbb.create_vector(record.signature());
+    let mut builder = PeerRecordBuilder::new(fbb);
+    builder.add_pubkey(pubkey);
+    builder.add_seq(record.seq());
+    builder.add_timestamp(record.timestamp());
+    builder.add_addresses(addrs);
+    builder.add_signature(signature);
+    builder.finish()
+}
+
+#[cfg(feature = "flatc")]
+fn fbs_to_record(fbs_record: &FbsPeerRecord) -> Option<SignedPeerRecord> {
+    let raw_addrs = fbs_record.addresses()?;
+    let mut addrs = Vec::with_capacity(raw_addrs.len());
+    for i in 0..raw_addrs.len() {
+        addrs.push(fbs_to_addr(&raw_addrs.get(i))?);
+    }
+    Some(SignedPeerRecord::from_parts(
+        PublicKey::decode(fbs_record.pubkey()?)?,
+        fbs_record.seq(),
+        fbs_record.timestamp(),
+        addrs,
+        fbs_record.signature()?.to_vec(),
+    ))
+}
+
+#[cfg(feature = "flatc")]
+fn protocol_to_offset<'b>(
+    fbb: &mut flatbuffers::FlatBufferBuilder<'b>,
+    info: &ProtocolInfo,
+) -> flatbuffers::WIPOffset<FbsProtocolInfo<'b>> {
+    let name = fbb.create_string(&info.name);
+    let mut versions = Vec::with_capacity(info.support_versions.len());
+    for version in &info.support_versions {
+        versions.push(fbb.create_string(version));
+    }
+    let versions = fbb.create_vector(&versions);
+    let mut builder = ProtocolInfoBuilder::new(fbb);
+    builder.add_name(name);
+    builder.add_support_versions(versions);
+    builder.finish()
+}
+
+#[cfg(feature = "flatc")]
+fn fbs_to_protocol(fbs_info: &FbsProtocolInfo) -> Option<ProtocolInfo> {
+    let fbs_versions = fbs_info.support_versions()?;
+    let mut versions = Vec::with_capacity(fbs_versions.len());
+    for i in 0..fbs_versions.len() {
+        versions.push(fbs_versions.get(i).to_owned());
+    }
+    Some(ProtocolInfo::new(fbs_info.name()?, versions))
+}
+
+#[cfg(feature = "molc")]
+fn bytes_to_mol(data: &[u8]) -> protocol_mol::Bytes {
+    protocol_mol::Bytes::new_builder()
+        .set(data.iter().cloned().map(Into::into).collect())
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn u64_to_mol(value: u64) -> protocol_mol::Uint64 {
+    let le = value.to_le_bytes();
+    protocol_mol::Uint64::new_builder()
+        .nth0(le[0].into())
+        .nth1(le[1].into())
+        .nth2(le[2].into())
+        .nth3(le[3].into())
+        .nth4(le[4].into())
+        .nth5(le[5].into())
+        .nth6(le[6].into())
+        .nth7(le[7].into())
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn mol_to_u64(reader: protocol_mol::Uint64Reader) -> u64 {
+    let mut le = [0u8; 8];
+    le.copy_from_slice(reader.raw_data());
+    u64::from_le_bytes(le)
+}
+
+#[cfg(feature = "molc")]
+fn mol_to_addrs(reader: protocol_mol::AddressVecReader) -> Option<Vec<Multiaddr>> {
+    let mut addrs = Vec::with_capacity(reader.len());
+    for addr in reader.iter() {
+        addrs.push(Multiaddr::try_from(addr.bytes().raw_data().to_vec()).ok()?)
+    }
+    Some(addrs)
+}
+
+#[cfg(feature = "molc")]
+fn record_to_mol(record: SignedPeerRecord) -> protocol_mol::PeerRecord {
+    let addrs = record
+        .addrs()
+        .iter()
+        .map(|addr| {
+            protocol_mol::Address::new_builder()
+                .bytes(bytes_to_mol(addr.as_ref()))
+                .build()
+        })
+        .collect();
+    protocol_mol::PeerRecord::new_builder()
+        .pubkey(bytes_to_mol(&record.public_key().clone().encode()))
+        .seq(u64_to_mol(record.seq()))
+        .timestamp(u64_to_mol(record.timestamp()))
+        .addresses(protocol_mol::AddressVec::new_builder().set(addrs).build())
+        .signature(bytes_to_mol(record.signature()))
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn mol_to_record(reader: protocol_mol::PeerRecordReader) -> Option<SignedPeerRecord> {
+    Some(SignedPeerRecord::from_parts(
+        PublicKey::decode(reader.pubkey().raw_data())?,
+        mol_to_u64(reader.seq()),
+        mol_to_u64(reader.timestamp()),
+        mol_to_addrs(reader.addresses())?,
+        reader.signature().raw_data().to_vec(),
+    ))
+}
+
+#[cfg(feature = "molc")]
+fn string_to_mol(data: &str) -> protocol_mol::String {
+    protocol_mol::String::new_builder()
+        .set(data.bytes().map(Into::into).collect())
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn mol_to_string(reader: protocol_mol::StringReader) -> Option<String> {
+    String::from_utf8(reader.raw_data().to_vec()).ok()
+}
+
+#[cfg(feature = "molc")]
+fn protocol_to_mol(info: ProtocolInfo) -> protocol_mol::ProtocolInfo {
+    let versions = info
+        .support_versions
+        .iter()
+        .map(|version| string_to_mol(version))
+        .collect();
+    protocol_mol::ProtocolInfo::new_builder()
+        .name(string_to_mol(&info.name))
+        .support_versions(protocol_mol::StringVec::new_builder().set(versions).build())
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn mol_to_protocol(reader: protocol_mol::ProtocolInfoReader) -> Option<ProtocolInfo> {
+    let mut versions = Vec::with_capacity(reader.support_versions().len());
+    for version in reader.support_versions().iter() {
+        versions.push(mol_to_string(version)?);
+    }
+    Some(ProtocolInfo::new(&mol_to_string(reader.name())?, versions))
+}
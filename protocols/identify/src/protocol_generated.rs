@@ -130,6 +130,9 @@ impl<'a> IdentifyMessage<'a> {
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
         args: &'args IdentifyMessageArgs<'args>) -> flatbuffers::WIPOffset<IdentifyMessage<'bldr>> {
       let mut builder = IdentifyMessageBuilder::new(_fbb);
+      if let Some(x) = args.agent_version { builder.add_agent_version(x); }
+      if let Some(x) = args.protocols { builder.add_protocols(x); }
+      if let Some(x) = args.record { builder.add_record(x); }
       if let Some(x) = args.identify { builder.add_identify(x); }
       if let Some(x) = args.observed_addr { builder.add_observed_addr(x); }
       if let Some(x) = args.listen_addrs { builder.add_listen_addrs(x); }
@@ -139,6 +142,9 @@ impl<'a> IdentifyMessage<'a> {
     pub const VT_LISTEN_ADDRS: flatbuffers::VOffsetT = 4;
     pub const VT_OBSERVED_ADDR: flatbuffers::VOffsetT = 6;
     pub const VT_IDENTIFY: flatbuffers::VOffsetT = 8;
+    pub const VT_RECORD: flatbuffers::VOffsetT = 10;
+    pub const VT_PROTOCOLS: flatbuffers::VOffsetT = 12;
+    pub const VT_AGENT_VERSION: flatbuffers::VOffsetT = 14;
 
   #[inline]
   pub fn listen_addrs(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Address<'a>>>> {
@@ -152,12 +158,27 @@ impl<'a> IdentifyMessage<'a> {
   pub fn identify(&self) -> Option<&'a [u8]> {
     self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(IdentifyMessage::VT_IDENTIFY, None).map(|v| v.safe_slice())
   }
+  #[inline]
+  pub fn record(&self) -> Option<PeerRecord<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<PeerRecord<'a>>>(IdentifyMessage::VT_RECORD, None)
+  }
+  #[inline]
+  pub fn protocols(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ProtocolInfo<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<ProtocolInfo<'a>>>>>(IdentifyMessage::VT_PROTOCOLS, None)
+  }
+  #[inline]
+  pub fn agent_version(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(IdentifyMessage::VT_AGENT_VERSION, None)
+  }
 }
 
 pub struct IdentifyMessageArgs<'a> {
     pub listen_addrs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Address<'a >>>>>,
     pub observed_addr: Option<flatbuffers::WIPOffset<Address<'a >>>,
     pub identify: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+    pub record: Option<flatbuffers::WIPOffset<PeerRecord<'a >>>,
+    pub protocols: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<ProtocolInfo<'a >>>>>,
+    pub agent_version: Option<flatbuffers::WIPOffset<&'a  str>>,
 }
 impl<'a> Default for IdentifyMessageArgs<'a> {
     #[inline]
@@ -166,6 +187,9 @@ impl<'a> Default for IdentifyMessageArgs<'a> {
             listen_addrs: None,
             observed_addr: None,
             identify: None,
+            record: None,
+            protocols: None,
+            agent_version: None,
         }
     }
 }
@@ -187,6 +211,18 @@ impl<'a: 'b, 'b> IdentifyMessageBuilder<'a, 'b> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IdentifyMessage::VT_IDENTIFY, identify);
   }
   #[inline]
+  pub fn add_record(&mut self, record: flatbuffers::WIPOffset<PeerRecord<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<PeerRecord>>(IdentifyMessage::VT_RECORD, record);
+  }
+  #[inline]
+  pub fn add_protocols(&mut self, protocols: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ProtocolInfo<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IdentifyMessage::VT_PROTOCOLS, protocols);
+  }
+  #[inline]
+  pub fn add_agent_version(&mut self, agent_version: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IdentifyMessage::VT_AGENT_VERSION, agent_version);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> IdentifyMessageBuilder<'a, 'b> {
     let start = _fbb.start_table();
     IdentifyMessageBuilder {
@@ -201,6 +237,218 @@ impl<'a: 'b, 'b> IdentifyMessageBuilder<'a, 'b> {
   }
 }
 
+pub enum ProtocolInfoOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct ProtocolInfo<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ProtocolInfo<'a> {
+    type Inner = ProtocolInfo<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> ProtocolInfo<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ProtocolInfo {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ProtocolInfoArgs<'args>) -> flatbuffers::WIPOffset<ProtocolInfo<'bldr>> {
+      let mut builder = ProtocolInfoBuilder::new(_fbb);
+      if let Some(x) = args.support_versions { builder.add_support_versions(x); }
+      if let Some(x) = args.name { builder.add_name(x); }
+      builder.finish()
+    }
+
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_SUPPORT_VERSIONS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ProtocolInfo::VT_NAME, None)
+  }
+  #[inline]
+  pub fn support_versions(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<&'a str>>>>(ProtocolInfo::VT_SUPPORT_VERSIONS, None)
+  }
+}
+
+pub struct ProtocolInfoArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a  str>>,
+    pub support_versions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<&'a  str>>>>,
+}
+impl<'a> Default for ProtocolInfoArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ProtocolInfoArgs {
+            name: None,
+            support_versions: None,
+        }
+    }
+}
+pub struct ProtocolInfoBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ProtocolInfoBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ProtocolInfo::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_support_versions(&mut self, support_versions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ProtocolInfo::VT_SUPPORT_VERSIONS, support_versions);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ProtocolInfoBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ProtocolInfoBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ProtocolInfo<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum PeerRecordOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct PeerRecord<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PeerRecord<'a> {
+    type Inner = PeerRecord<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> PeerRecord<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        PeerRecord {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PeerRecordArgs<'args>) -> flatbuffers::WIPOffset<PeerRecord<'bldr>> {
+      let mut builder = PeerRecordBuilder::new(_fbb);
+      builder.add_timestamp(args.timestamp);
+      builder.add_seq(args.seq);
+      if let Some(x) = args.signature { builder.add_signature(x); }
+      if let Some(x) = args.addresses { builder.add_addresses(x); }
+      if let Some(x) = args.pubkey { builder.add_pubkey(x); }
+      builder.finish()
+    }
+
+    pub const VT_PUBKEY: flatbuffers::VOffsetT = 4;
+    pub const VT_SEQ: flatbuffers::VOffsetT = 6;
+    pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 8;
+    pub const VT_ADDRESSES: flatbuffers::VOffsetT = 10;
+    pub const VT_SIGNATURE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub fn pubkey(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(PeerRecord::VT_PUBKEY, None).map(|v| v.safe_slice())
+  }
+  #[inline]
+  pub fn seq(&self) -> u64 {
+    self._tab.get::<u64>(PeerRecord::VT_SEQ, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn timestamp(&self) -> u64 {
+    self._tab.get::<u64>(PeerRecord::VT_TIMESTAMP, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn addresses(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Address<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Address<'a>>>>>(PeerRecord::VT_ADDRESSES, None)
+  }
+  #[inline]
+  pub fn signature(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(PeerRecord::VT_SIGNATURE, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct PeerRecordArgs<'a> {
+    pub pubkey: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+    pub seq: u64,
+    pub timestamp: u64,
+    pub addresses: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Address<'a >>>>>,
+    pub signature: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for PeerRecordArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        PeerRecordArgs {
+            pubkey: None,
+            seq: 0,
+            timestamp: 0,
+            addresses: None,
+            signature: None,
+        }
+    }
+}
+pub struct PeerRecordBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PeerRecordBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_pubkey(&mut self, pubkey: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PeerRecord::VT_PUBKEY, pubkey);
+  }
+  #[inline]
+  pub fn add_seq(&mut self, seq: u64) {
+    self.fbb_.push_slot::<u64>(PeerRecord::VT_SEQ, seq, 0);
+  }
+  #[inline]
+  pub fn add_timestamp(&mut self, timestamp: u64) {
+    self.fbb_.push_slot::<u64>(PeerRecord::VT_TIMESTAMP, timestamp, 0);
+  }
+  #[inline]
+  pub fn add_addresses(&mut self, addresses: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Address<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PeerRecord::VT_ADDRESSES, addresses);
+  }
+  #[inline]
+  pub fn add_signature(&mut self, signature: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PeerRecord::VT_SIGNATURE, signature);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PeerRecordBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PeerRecordBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PeerRecord<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
 }  // pub mod Identify
 }  // pub mod P2P
 
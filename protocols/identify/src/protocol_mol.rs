@@ -239,8 +239,8 @@ impl ::std::iter::IntoIterator for Bytes {
     }
 }
 #[derive(Clone)]
-pub struct AddressVec(molecule::bytes::Bytes);
-impl ::std::fmt::LowerHex for AddressVec {
+pub struct String(molecule::bytes::Bytes);
+impl ::std::fmt::LowerHex for String {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         use molecule::faster_hex::hex_string;
         if f.alternate() {
@@ -249,12 +249,249 @@ impl ::std::fmt::LowerHex for AddressVec {
         write!(f, "{}", hex_string(self.as_slice()).unwrap())
     }
 }
-impl ::std::fmt::Debug for AddressVec {
+impl ::std::fmt::Debug for String {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::std::fmt::Display for AddressVec {
+impl ::std::fmt::Display for String {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        let raw_data = hex_string(&self.raw_data()).unwrap();
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::std::default::Default for String {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0];
+        String::new_unchecked(v.into())
+    }
+}
+impl String {
+    pub const ITEM_SIZE: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.item_count() + 1)
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Byte> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Byte {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Byte::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.0.slice_from(molecule::NUMBER_SIZE)
+    }
+    pub fn as_reader<'r>(&'r self) -> StringReader<'r> {
+        StringReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for String {
+    type Builder = StringBuilder;
+    const NAME: &'static str = "String";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        String(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        StringReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        StringReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::std::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct StringReader<'r>(&'r [u8]);
+impl<'r> ::std::fmt::LowerHex for StringReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl<'r> ::std::fmt::Debug for StringReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::std::fmt::Display for StringReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        let raw_data = hex_string(&self.raw_data()).unwrap();
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> StringReader<'r> {
+    pub const ITEM_SIZE: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.item_count() + 1)
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<ByteReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> ByteReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        &self.as_slice()[molecule::NUMBER_SIZE..]
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for StringReader<'r> {
+    type Entity = String;
+    const NAME: &'static str = "StringReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        StringReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct StringBuilder(pub(crate) Vec<Byte>);
+impl StringBuilder {
+    pub const ITEM_SIZE: usize = 1;
+    pub fn set(mut self, v: Vec<Byte>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Byte) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::std::iter::IntoIterator<Item = Byte>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for StringBuilder {
+    type Entity = String;
+    const NAME: &'static str = "StringBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        String::new_unchecked(inner.into())
+    }
+}
+pub struct StringIterator(String, usize, usize);
+impl ::std::iter::Iterator for StringIterator {
+    type Item = Byte;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::std::iter::ExactSizeIterator for StringIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::std::iter::IntoIterator for String {
+    type Item = Byte;
+    type IntoIter = StringIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        StringIterator(self, 0, len)
+    }
+}
+#[derive(Clone)]
+pub struct StringVec(molecule::bytes::Bytes);
+impl ::std::fmt::LowerHex for StringVec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl ::std::fmt::Debug for StringVec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::std::fmt::Display for StringVec {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
         for i in 0..self.len() {
@@ -267,13 +504,13 @@ impl ::std::fmt::Display for AddressVec {
         write!(f, "]")
     }
 }
-impl ::std::default::Default for AddressVec {
+impl ::std::default::Default for StringVec {
     fn default() -> Self {
         let v: Vec<u8> = vec![4, 0, 0, 0];
-        AddressVec::new_unchecked(v.into())
+        StringVec::new_unchecked(v.into())
     }
 }
-impl AddressVec {
+impl StringVec {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -293,32 +530,32 @@ impl AddressVec {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-    pub fn get(&self, idx: usize) -> Option<Address> {
+    pub fn get(&self, idx: usize) -> Option<String> {
         if idx >= self.len() {
             None
         } else {
             Some(self.get_unchecked(idx))
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> Address {
+    pub fn get_unchecked(&self, idx: usize) -> String {
         let offsets = self.item_offsets();
         let start = molecule::unpack_number(&offsets[idx][..]) as usize;
         if idx == self.len() - 1 {
-            Address::new_unchecked(self.0.slice_from(start))
+            String::new_unchecked(self.0.slice_from(start))
         } else {
             let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
-            Address::new_unchecked(self.0.slice(start, end))
+            String::new_unchecked(self.0.slice(start, end))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> AddressVecReader<'r> {
-        AddressVecReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> StringVecReader<'r> {
+        StringVecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for AddressVec {
-    type Builder = AddressVecBuilder;
-    const NAME: &'static str = "AddressVec";
+impl molecule::prelude::Entity for StringVec {
+    type Builder = StringVecBuilder;
+    const NAME: &'static str = "StringVec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        AddressVec(data)
+        StringVec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -327,10 +564,10 @@ impl molecule::prelude::Entity for AddressVec {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AddressVecReader::from_slice(slice).map(|reader| reader.to_entity())
+        StringVecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AddressVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        StringVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::std::default::Default::default()
@@ -340,8 +577,8 @@ impl molecule::prelude::Entity for AddressVec {
     }
 }
 #[derive(Clone, Copy)]
-pub struct AddressVecReader<'r>(&'r [u8]);
-impl<'r> ::std::fmt::LowerHex for AddressVecReader<'r> {
+pub struct StringVecReader<'r>(&'r [u8]);
+impl<'r> ::std::fmt::LowerHex for StringVecReader<'r> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         use molecule::faster_hex::hex_string;
         if f.alternate() {
@@ -350,12 +587,12 @@ impl<'r> ::std::fmt::LowerHex for AddressVecReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()).unwrap())
     }
 }
-impl<'r> ::std::fmt::Debug for AddressVecReader<'r> {
+impl<'r> ::std::fmt::Debug for StringVecReader<'r> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::std::fmt::Display for AddressVecReader<'r> {
+impl<'r> ::std::fmt::Display for StringVecReader<'r> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
         for i in 0..self.len() {
@@ -368,7 +605,7 @@ impl<'r> ::std::fmt::Display for AddressVecReader<'r> {
         write!(f, "]")
     }
 }
-impl<'r> AddressVecReader<'r> {
+impl<'r> StringVecReader<'r> {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -388,32 +625,32 @@ impl<'r> AddressVecReader<'r> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-    pub fn get(&self, idx: usize) -> Option<AddressReader<'r>> {
+    pub fn get(&self, idx: usize) -> Option<StringReader<'r>> {
         if idx >= self.len() {
             None
         } else {
             Some(self.get_unchecked(idx))
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> AddressReader<'r> {
+    pub fn get_unchecked(&self, idx: usize) -> StringReader<'r> {
         let offsets = self.item_offsets();
         let start = molecule::unpack_number(&offsets[idx][..]) as usize;
         if idx == self.len() - 1 {
-            AddressReader::new_unchecked(&self.as_slice()[start..])
+            StringReader::new_unchecked(&self.as_slice()[start..])
         } else {
             let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
-            AddressReader::new_unchecked(&self.as_slice()[start..end])
+            StringReader::new_unchecked(&self.as_slice()[start..end])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for AddressVecReader<'r> {
-    type Entity = AddressVec;
-    const NAME: &'static str = "AddressVecReader";
+impl<'r> molecule::prelude::Reader<'r> for StringVecReader<'r> {
+    type Entity = StringVec;
+    const NAME: &'static str = "StringVecReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        AddressVecReader(slice)
+        StringVecReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -460,32 +697,32 @@ impl<'r> molecule::prelude::Reader<'r> for AddressVecReader<'r> {
         for pair in offsets.windows(2) {
             let start = pair[0];
             let end = pair[1];
-            AddressReader::verify(&slice[start..end], compatible)?;
+            StringReader::verify(&slice[start..end], compatible)?;
         }
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct AddressVecBuilder(pub(crate) Vec<Address>);
-impl AddressVecBuilder {
-    pub fn set(mut self, v: Vec<Address>) -> Self {
+pub struct StringVecBuilder(pub(crate) Vec<String>);
+impl StringVecBuilder {
+    pub fn set(mut self, v: Vec<String>) -> Self {
         self.0 = v;
         self
     }
-    pub fn push(mut self, v: Address) -> Self {
+    pub fn push(mut self, v: String) -> Self {
         self.0.push(v);
         self
     }
-    pub fn extend<T: ::std::iter::IntoIterator<Item = Address>>(mut self, iter: T) -> Self {
+    pub fn extend<T: ::std::iter::IntoIterator<Item = String>>(mut self, iter: T) -> Self {
         for elem in iter {
             self.0.push(elem);
         }
         self
     }
 }
-impl molecule::prelude::Builder for AddressVecBuilder {
-    type Entity = AddressVec;
-    const NAME: &'static str = "AddressVecBuilder";
+impl molecule::prelude::Builder for StringVecBuilder {
+    type Entity = StringVec;
+    const NAME: &'static str = "StringVecBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (self.0.len() + 1)
             + self
@@ -521,65 +758,1781 @@ impl molecule::prelude::Builder for AddressVecBuilder {
         }
         Ok(())
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        AddressVec::new_unchecked(inner.into())
-    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        StringVec::new_unchecked(inner.into())
+    }
+}
+pub struct StringVecIterator(StringVec, usize, usize);
+impl ::std::iter::Iterator for StringVecIterator {
+    type Item = String;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::std::iter::ExactSizeIterator for StringVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::std::iter::IntoIterator for StringVec {
+    type Item = String;
+    type IntoIter = StringVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        StringVecIterator(self, 0, len)
+    }
+}
+impl<'r> StringVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> StringVecReaderIterator<'t, 'r> {
+        StringVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct StringVecReaderIterator<'t, 'r>(&'t StringVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::std::iter::Iterator for StringVecReaderIterator<'t, 'r> {
+    type Item = StringReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::std::iter::ExactSizeIterator for StringVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct AddressVec(molecule::bytes::Bytes);
+impl ::std::fmt::LowerHex for AddressVec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl ::std::fmt::Debug for AddressVec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::std::fmt::Display for AddressVec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::std::default::Default for AddressVec {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        AddressVec::new_unchecked(v.into())
+    }
+}
+impl AddressVec {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Address> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Address {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            Address::new_unchecked(self.0.slice_from(start))
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            Address::new_unchecked(self.0.slice(start, end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> AddressVecReader<'r> {
+        AddressVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for AddressVec {
+    type Builder = AddressVecBuilder;
+    const NAME: &'static str = "AddressVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        AddressVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AddressVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AddressVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::std::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct AddressVecReader<'r>(&'r [u8]);
+impl<'r> ::std::fmt::LowerHex for AddressVecReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl<'r> ::std::fmt::Debug for AddressVecReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::std::fmt::Display for AddressVecReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> AddressVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<AddressReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> AddressReader<'r> {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            AddressReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            AddressReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AddressVecReader<'r> {
+    type Entity = AddressVec;
+    const NAME: &'static str = "AddressVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        AddressVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let item_count = offset_first / 4 - 1;
+        let header_size = molecule::NUMBER_SIZE * (item_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..item_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            AddressReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct AddressVecBuilder(pub(crate) Vec<Address>);
+impl AddressVecBuilder {
+    pub fn set(mut self, v: Vec<Address>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Address) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::std::iter::IntoIterator<Item = Address>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for AddressVecBuilder {
+    type Entity = AddressVec;
+    const NAME: &'static str = "AddressVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        AddressVec::new_unchecked(inner.into())
+    }
+}
+pub struct AddressVecIterator(AddressVec, usize, usize);
+impl ::std::iter::Iterator for AddressVecIterator {
+    type Item = Address;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::std::iter::ExactSizeIterator for AddressVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::std::iter::IntoIterator for AddressVec {
+    type Item = Address;
+    type IntoIter = AddressVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        AddressVecIterator(self, 0, len)
+    }
+}
+impl<'r> AddressVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> AddressVecReaderIterator<'t, 'r> {
+        AddressVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct AddressVecReaderIterator<'t, 'r>(&'t AddressVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::std::iter::Iterator for AddressVecReaderIterator<'t, 'r> {
+    type Item = AddressReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::std::iter::ExactSizeIterator for AddressVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct ProtocolInfoVec(molecule::bytes::Bytes);
+impl ::std::fmt::LowerHex for ProtocolInfoVec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl ::std::fmt::Debug for ProtocolInfoVec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::std::fmt::Display for ProtocolInfoVec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::std::default::Default for ProtocolInfoVec {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        ProtocolInfoVec::new_unchecked(v.into())
+    }
+}
+impl ProtocolInfoVec {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<ProtocolInfo> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> ProtocolInfo {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            ProtocolInfo::new_unchecked(self.0.slice_from(start))
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            ProtocolInfo::new_unchecked(self.0.slice(start, end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ProtocolInfoVecReader<'r> {
+        ProtocolInfoVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ProtocolInfoVec {
+    type Builder = ProtocolInfoVecBuilder;
+    const NAME: &'static str = "ProtocolInfoVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ProtocolInfoVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProtocolInfoVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProtocolInfoVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::std::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ProtocolInfoVecReader<'r>(&'r [u8]);
+impl<'r> ::std::fmt::LowerHex for ProtocolInfoVecReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl<'r> ::std::fmt::Debug for ProtocolInfoVecReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::std::fmt::Display for ProtocolInfoVecReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> ProtocolInfoVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<ProtocolInfoReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> ProtocolInfoReader<'r> {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            ProtocolInfoReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            ProtocolInfoReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ProtocolInfoVecReader<'r> {
+    type Entity = ProtocolInfoVec;
+    const NAME: &'static str = "ProtocolInfoVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ProtocolInfoVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let item_count = offset_first / 4 - 1;
+        let header_size = molecule::NUMBER_SIZE * (item_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..item_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            ProtocolInfoReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ProtocolInfoVecBuilder(pub(crate) Vec<ProtocolInfo>);
+impl ProtocolInfoVecBuilder {
+    pub fn set(mut self, v: Vec<ProtocolInfo>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: ProtocolInfo) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::std::iter::IntoIterator<Item = ProtocolInfo>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for ProtocolInfoVecBuilder {
+    type Entity = ProtocolInfoVec;
+    const NAME: &'static str = "ProtocolInfoVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ProtocolInfoVec::new_unchecked(inner.into())
+    }
+}
+pub struct ProtocolInfoVecIterator(ProtocolInfoVec, usize, usize);
+impl ::std::iter::Iterator for ProtocolInfoVecIterator {
+    type Item = ProtocolInfo;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::std::iter::ExactSizeIterator for ProtocolInfoVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::std::iter::IntoIterator for ProtocolInfoVec {
+    type Item = ProtocolInfo;
+    type IntoIter = ProtocolInfoVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        ProtocolInfoVecIterator(self, 0, len)
+    }
+}
+impl<'r> ProtocolInfoVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> ProtocolInfoVecReaderIterator<'t, 'r> {
+        ProtocolInfoVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct ProtocolInfoVecReaderIterator<'t, 'r>(&'t ProtocolInfoVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::std::iter::Iterator for ProtocolInfoVecReaderIterator<'t, 'r> {
+    type Item = ProtocolInfoReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::std::iter::ExactSizeIterator for ProtocolInfoVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct Uint64(molecule::bytes::Bytes);
+impl ::std::fmt::LowerHex for Uint64 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl ::std::fmt::Debug for Uint64 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::std::fmt::Display for Uint64 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        let raw_data = hex_string(&self.raw_data()).unwrap();
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::std::default::Default for Uint64 {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0];
+        Uint64::new_unchecked(v.into())
+    }
+}
+impl Uint64 {
+    pub const TOTAL_SIZE: usize = 8;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 8;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0, 1))
+    }
+    pub fn nth1(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1, 2))
+    }
+    pub fn nth2(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(2, 3))
+    }
+    pub fn nth3(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(3, 4))
+    }
+    pub fn nth4(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(4, 5))
+    }
+    pub fn nth5(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(5, 6))
+    }
+    pub fn nth6(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(6, 7))
+    }
+    pub fn nth7(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(7, 8))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Uint64 {
+    type Builder = Uint64Builder;
+    const NAME: &'static str = "Uint64";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Uint64(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        Uint64Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        Uint64Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::std::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([
+            self.nth0(),
+            self.nth1(),
+            self.nth2(),
+            self.nth3(),
+            self.nth4(),
+            self.nth5(),
+            self.nth6(),
+            self.nth7(),
+        ])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct Uint64Reader<'r>(&'r [u8]);
+impl<'r> ::std::fmt::LowerHex for Uint64Reader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl<'r> ::std::fmt::Debug for Uint64Reader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::std::fmt::Display for Uint64Reader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        let raw_data = hex_string(&self.raw_data()).unwrap();
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> Uint64Reader<'r> {
+    pub const TOTAL_SIZE: usize = 8;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 8;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn nth1(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    }
+    pub fn nth2(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[2..3])
+    }
+    pub fn nth3(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[3..4])
+    }
+    pub fn nth4(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[4..5])
+    }
+    pub fn nth5(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[5..6])
+    }
+    pub fn nth6(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[6..7])
+    }
+    pub fn nth7(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[7..8])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for Uint64Reader<'r> {
+    type Entity = Uint64;
+    const NAME: &'static str = "Uint64Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        Uint64Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct Uint64Builder(pub(crate) [Byte; 8]);
+impl ::std::fmt::Debug for Uint64Builder {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::std::default::Default for Uint64Builder {
+    fn default() -> Self {
+        Uint64Builder([
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+        ])
+    }
+}
+impl Uint64Builder {
+    pub const TOTAL_SIZE: usize = 8;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 8;
+    pub fn set(mut self, v: [Byte; 8]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+    pub fn nth1(mut self, v: Byte) -> Self {
+        self.0[1] = v;
+        self
+    }
+    pub fn nth2(mut self, v: Byte) -> Self {
+        self.0[2] = v;
+        self
+    }
+    pub fn nth3(mut self, v: Byte) -> Self {
+        self.0[3] = v;
+        self
+    }
+    pub fn nth4(mut self, v: Byte) -> Self {
+        self.0[4] = v;
+        self
+    }
+    pub fn nth5(mut self, v: Byte) -> Self {
+        self.0[5] = v;
+        self
+    }
+    pub fn nth6(mut self, v: Byte) -> Self {
+        self.0[6] = v;
+        self
+    }
+    pub fn nth7(mut self, v: Byte) -> Self {
+        self.0[7] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for Uint64Builder {
+    type Entity = Uint64;
+    const NAME: &'static str = "Uint64Builder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(self.0[2].as_slice())?;
+        writer.write_all(self.0[3].as_slice())?;
+        writer.write_all(self.0[4].as_slice())?;
+        writer.write_all(self.0[5].as_slice())?;
+        writer.write_all(self.0[6].as_slice())?;
+        writer.write_all(self.0[7].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Uint64::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct PeerRecordOpt(molecule::bytes::Bytes);
+impl ::std::fmt::LowerHex for PeerRecordOpt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl ::std::fmt::Debug for PeerRecordOpt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::std::fmt::Display for PeerRecordOpt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::std::default::Default for PeerRecordOpt {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        PeerRecordOpt::new_unchecked(v.into())
+    }
+}
+impl PeerRecordOpt {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<PeerRecord> {
+        if self.is_none() {
+            None
+        } else {
+            Some(PeerRecord::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> PeerRecordOptReader<'r> {
+        PeerRecordOptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for PeerRecordOpt {
+    type Builder = PeerRecordOptBuilder;
+    const NAME: &'static str = "PeerRecordOpt";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        PeerRecordOpt(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        PeerRecordOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        PeerRecordOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::std::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct PeerRecordOptReader<'r>(&'r [u8]);
+impl<'r> ::std::fmt::LowerHex for PeerRecordOptReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl<'r> ::std::fmt::Debug for PeerRecordOptReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::std::fmt::Display for PeerRecordOptReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> PeerRecordOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<PeerRecordReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(PeerRecordReader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for PeerRecordOptReader<'r> {
+    type Entity = PeerRecordOpt;
+    const NAME: &'static str = "PeerRecordOptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        PeerRecordOptReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            PeerRecordReader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct PeerRecordOptBuilder(pub(crate) Option<PeerRecord>);
+impl PeerRecordOptBuilder {
+    pub fn set(mut self, v: Option<PeerRecord>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for PeerRecordOptBuilder {
+    type Entity = PeerRecordOpt;
+    const NAME: &'static str = "PeerRecordOptBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        PeerRecordOpt::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Address(molecule::bytes::Bytes);
+impl ::std::fmt::LowerHex for Address {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl ::std::fmt::Debug for Address {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::std::fmt::Display for Address {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "bytes", self.bytes())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::std::default::Default for Address {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
+        Address::new_unchecked(v.into())
+    }
+}
+impl Address {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn bytes(&self) -> Bytes {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[1][..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start, end))
+        } else {
+            Bytes::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> AddressReader<'r> {
+        AddressReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Address {
+    type Builder = AddressBuilder;
+    const NAME: &'static str = "Address";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Address(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AddressReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AddressReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::std::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().bytes(self.bytes())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct AddressReader<'r>(&'r [u8]);
+impl<'r> ::std::fmt::LowerHex for AddressReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl<'r> ::std::fmt::Debug for AddressReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::std::fmt::Display for AddressReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "bytes", self.bytes())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> AddressReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn bytes(&self) -> BytesReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[1][..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AddressReader<'r> {
+    type Entity = Address;
+    const NAME: &'static str = "AddressReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        AddressReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct AddressBuilder {
+    pub(crate) bytes: Bytes,
+}
+impl AddressBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn bytes(mut self, v: Bytes) -> Self {
+        self.bytes = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for AddressBuilder {
+    type Entity = Address;
+    const NAME: &'static str = "AddressBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.bytes.as_slice().len()
+    }
+    fn write<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.bytes.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.bytes.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Address::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct IdentifyMessage(molecule::bytes::Bytes);
+impl ::std::fmt::LowerHex for IdentifyMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl ::std::fmt::Debug for IdentifyMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::std::fmt::Display for IdentifyMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "listen_addrs", self.listen_addrs())?;
+        write!(f, ", {}: {}", "observed_addr", self.observed_addr())?;
+        write!(f, ", {}: {}", "identify", self.identify())?;
+        write!(f, ", {}: {}", "record", self.record())?;
+        write!(f, ", {}: {}", "protocols", self.protocols())?;
+        write!(f, ", {}: {}", "agent_version", self.agent_version())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::std::default::Default for IdentifyMessage {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            56, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 48, 0, 0, 0, 52, 0, 0,
+            0, 4, 0, 0, 0, 12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        IdentifyMessage::new_unchecked(v.into())
+    }
+}
+impl IdentifyMessage {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn listen_addrs(&self) -> AddressVec {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        AddressVec::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn observed_addr(&self) -> Address {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        Address::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn identify(&self) -> Bytes {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn record(&self) -> PeerRecordOpt {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        PeerRecordOpt::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn protocols(&self) -> ProtocolInfoVec {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
+        let end = molecule::unpack_number(&offsets[5][..]) as usize;
+        ProtocolInfoVec::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn agent_version(&self) -> String {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[5][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[6][..]) as usize;
+            String::new_unchecked(self.0.slice(start, end))
+        } else {
+            String::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> IdentifyMessageReader<'r> {
+        IdentifyMessageReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for IdentifyMessage {
+    type Builder = IdentifyMessageBuilder;
+    const NAME: &'static str = "IdentifyMessage";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        IdentifyMessage(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IdentifyMessageReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IdentifyMessageReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::std::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .listen_addrs(self.listen_addrs())
+            .observed_addr(self.observed_addr())
+            .identify(self.identify())
+            .record(self.record())
+            .protocols(self.protocols())
+            .agent_version(self.agent_version())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct IdentifyMessageReader<'r>(&'r [u8]);
+impl<'r> ::std::fmt::LowerHex for IdentifyMessageReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use molecule::faster_hex::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()).unwrap())
+    }
+}
+impl<'r> ::std::fmt::Debug for IdentifyMessageReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::std::fmt::Display for IdentifyMessageReader<'r> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "listen_addrs", self.listen_addrs())?;
+        write!(f, ", {}: {}", "observed_addr", self.observed_addr())?;
+        write!(f, ", {}: {}", "identify", self.identify())?;
+        write!(f, ", {}: {}", "record", self.record())?;
+        write!(f, ", {}: {}", "protocols", self.protocols())?;
+        write!(f, ", {}: {}", "agent_version", self.agent_version())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> IdentifyMessageReader<'r> {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn listen_addrs(&self) -> AddressVecReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        AddressVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn observed_addr(&self) -> AddressReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        AddressReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn identify(&self) -> BytesReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn record(&self) -> PeerRecordOptReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        PeerRecordOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn protocols(&self) -> ProtocolInfoVecReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
+        let end = molecule::unpack_number(&offsets[5][..]) as usize;
+        ProtocolInfoVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn agent_version(&self) -> StringReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[5][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[6][..]) as usize;
+            StringReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            StringReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for IdentifyMessageReader<'r> {
+    type Entity = IdentifyMessage;
+    const NAME: &'static str = "IdentifyMessageReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        IdentifyMessageReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        AddressVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        AddressReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        PeerRecordOptReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ProtocolInfoVecReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        StringReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Ok(())
+    }
 }
-pub struct AddressVecIterator(AddressVec, usize, usize);
-impl ::std::iter::Iterator for AddressVecIterator {
-    type Item = Address;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
+#[derive(Debug, Default)]
+pub struct IdentifyMessageBuilder {
+    pub(crate) listen_addrs: AddressVec,
+    pub(crate) observed_addr: Address,
+    pub(crate) identify: Bytes,
+    pub(crate) record: PeerRecordOpt,
+    pub(crate) protocols: ProtocolInfoVec,
+    pub(crate) agent_version: String,
 }
-impl ::std::iter::ExactSizeIterator for AddressVecIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
+impl IdentifyMessageBuilder {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn listen_addrs(mut self, v: AddressVec) -> Self {
+        self.listen_addrs = v;
+        self
     }
-}
-impl ::std::iter::IntoIterator for AddressVec {
-    type Item = Address;
-    type IntoIter = AddressVecIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        AddressVecIterator(self, 0, len)
+    pub fn observed_addr(mut self, v: Address) -> Self {
+        self.observed_addr = v;
+        self
     }
-}
-impl<'r> AddressVecReader<'r> {
-    pub fn iter<'t>(&'t self) -> AddressVecReaderIterator<'t, 'r> {
-        AddressVecReaderIterator(&self, 0, self.len())
+    pub fn identify(mut self, v: Bytes) -> Self {
+        self.identify = v;
+        self
+    }
+    pub fn record(mut self, v: PeerRecordOpt) -> Self {
+        self.record = v;
+        self
+    }
+    pub fn protocols(mut self, v: ProtocolInfoVec) -> Self {
+        self.protocols = v;
+        self
+    }
+    pub fn agent_version(mut self, v: String) -> Self {
+        self.agent_version = v;
+        self
     }
 }
-pub struct AddressVecReaderIterator<'t, 'r>(&'t AddressVecReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::std::iter::Iterator for AddressVecReaderIterator<'t, 'r> {
-    type Item = AddressReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
+impl molecule::prelude::Builder for IdentifyMessageBuilder {
+    type Entity = IdentifyMessage;
+    const NAME: &'static str = "IdentifyMessageBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.listen_addrs.as_slice().len()
+            + self.observed_addr.as_slice().len()
+            + self.identify.as_slice().len()
+            + self.record.as_slice().len()
+            + self.protocols.as_slice().len()
+            + self.agent_version.as_slice().len()
+    }
+    fn write<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.listen_addrs.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.observed_addr.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.identify.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.record.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.protocols.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.agent_version.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.listen_addrs.as_slice())?;
+        writer.write_all(self.observed_addr.as_slice())?;
+        writer.write_all(self.identify.as_slice())?;
+        writer.write_all(self.record.as_slice())?;
+        writer.write_all(self.protocols.as_slice())?;
+        writer.write_all(self.agent_version.as_slice())?;
+        Ok(())
     }
-}
-impl<'t: 'r, 'r> ::std::iter::ExactSizeIterator for AddressVecReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        IdentifyMessage::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Address(molecule::bytes::Bytes);
-impl ::std::fmt::LowerHex for Address {
+pub struct ProtocolInfo(molecule::bytes::Bytes);
+impl ::std::fmt::LowerHex for ProtocolInfo {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         use molecule::faster_hex::hex_string;
         if f.alternate() {
@@ -588,15 +2541,16 @@ impl ::std::fmt::LowerHex for Address {
         write!(f, "{}", hex_string(self.as_slice()).unwrap())
     }
 }
-impl ::std::fmt::Debug for Address {
+impl ::std::fmt::Debug for ProtocolInfo {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::std::fmt::Display for Address {
+impl ::std::fmt::Display for ProtocolInfo {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "bytes", self.bytes())?;
+        write!(f, "{}: {}", "name", self.name())?;
+        write!(f, ", {}: {}", "support_versions", self.support_versions())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -604,14 +2558,16 @@ impl ::std::fmt::Display for Address {
         write!(f, " }}")
     }
 }
-impl ::std::default::Default for Address {
+impl ::std::default::Default for ProtocolInfo {
     fn default() -> Self {
-        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
-        Address::new_unchecked(v.into())
+        let v: Vec<u8> = vec![
+            20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+        ];
+        ProtocolInfo::new_unchecked(v.into())
     }
 }
-impl Address {
-    pub const FIELD_COUNT: usize = 1;
+impl ProtocolInfo {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -631,25 +2587,31 @@ impl Address {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn bytes(&self) -> Bytes {
+    pub fn name(&self) -> String {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        String::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn support_versions(&self) -> StringVec {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[1][..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start, end))
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            StringVec::new_unchecked(self.0.slice(start, end))
         } else {
-            Bytes::new_unchecked(self.0.slice_from(start))
+            StringVec::new_unchecked(self.0.slice_from(start))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> AddressReader<'r> {
-        AddressReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ProtocolInfoReader<'r> {
+        ProtocolInfoReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Address {
-    type Builder = AddressBuilder;
-    const NAME: &'static str = "Address";
+impl molecule::prelude::Entity for ProtocolInfo {
+    type Builder = ProtocolInfoBuilder;
+    const NAME: &'static str = "ProtocolInfo";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Address(data)
+        ProtocolInfo(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -658,21 +2620,23 @@ impl molecule::prelude::Entity for Address {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AddressReader::from_slice(slice).map(|reader| reader.to_entity())
+        ProtocolInfoReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AddressReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ProtocolInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::std::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().bytes(self.bytes())
+        Self::new_builder()
+            .name(self.name())
+            .support_versions(self.support_versions())
     }
 }
 #[derive(Clone, Copy)]
-pub struct AddressReader<'r>(&'r [u8]);
-impl<'r> ::std::fmt::LowerHex for AddressReader<'r> {
+pub struct ProtocolInfoReader<'r>(&'r [u8]);
+impl<'r> ::std::fmt::LowerHex for ProtocolInfoReader<'r> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         use molecule::faster_hex::hex_string;
         if f.alternate() {
@@ -681,15 +2645,16 @@ impl<'r> ::std::fmt::LowerHex for AddressReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()).unwrap())
     }
 }
-impl<'r> ::std::fmt::Debug for AddressReader<'r> {
+impl<'r> ::std::fmt::Debug for ProtocolInfoReader<'r> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::std::fmt::Display for AddressReader<'r> {
+impl<'r> ::std::fmt::Display for ProtocolInfoReader<'r> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "bytes", self.bytes())?;
+        write!(f, "{}: {}", "name", self.name())?;
+        write!(f, ", {}: {}", "support_versions", self.support_versions())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -697,8 +2662,8 @@ impl<'r> ::std::fmt::Display for AddressReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> AddressReader<'r> {
-    pub const FIELD_COUNT: usize = 1;
+impl<'r> ProtocolInfoReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -718,25 +2683,31 @@ impl<'r> AddressReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn bytes(&self) -> BytesReader<'r> {
+    pub fn name(&self) -> StringReader<'r> {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        StringReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn support_versions(&self) -> StringVecReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[1][..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            StringVecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            StringVecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for AddressReader<'r> {
-    type Entity = Address;
-    const NAME: &'static str = "AddressReader";
+impl<'r> molecule::prelude::Reader<'r> for ProtocolInfoReader<'r> {
+    type Entity = ProtocolInfo;
+    const NAME: &'static str = "ProtocolInfoReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        AddressReader(slice)
+        ProtocolInfoReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -780,49 +2751,60 @@ impl<'r> molecule::prelude::Reader<'r> for AddressReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        StringReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        StringVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct AddressBuilder {
-    pub(crate) bytes: Bytes,
+pub struct ProtocolInfoBuilder {
+    pub(crate) name: String,
+    pub(crate) support_versions: StringVec,
 }
-impl AddressBuilder {
-    pub const FIELD_COUNT: usize = 1;
-    pub fn bytes(mut self, v: Bytes) -> Self {
-        self.bytes = v;
+impl ProtocolInfoBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn name(mut self, v: String) -> Self {
+        self.name = v;
+        self
+    }
+    pub fn support_versions(mut self, v: StringVec) -> Self {
+        self.support_versions = v;
         self
     }
 }
-impl molecule::prelude::Builder for AddressBuilder {
-    type Entity = Address;
-    const NAME: &'static str = "AddressBuilder";
+impl molecule::prelude::Builder for ProtocolInfoBuilder {
+    type Entity = ProtocolInfo;
+    const NAME: &'static str = "ProtocolInfoBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.bytes.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.name.as_slice().len()
+            + self.support_versions.as_slice().len()
     }
     fn write<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.bytes.as_slice().len();
+        total_size += self.name.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.support_versions.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.bytes.as_slice())?;
+        writer.write_all(self.name.as_slice())?;
+        writer.write_all(self.support_versions.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Address::new_unchecked(inner.into())
+        ProtocolInfo::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct IdentifyMessage(molecule::bytes::Bytes);
-impl ::std::fmt::LowerHex for IdentifyMessage {
+pub struct PeerRecord(molecule::bytes::Bytes);
+impl ::std::fmt::LowerHex for PeerRecord {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         use molecule::faster_hex::hex_string;
         if f.alternate() {
@@ -831,17 +2813,19 @@ impl ::std::fmt::LowerHex for IdentifyMessage {
         write!(f, "{}", hex_string(self.as_slice()).unwrap())
     }
 }
-impl ::std::fmt::Debug for IdentifyMessage {
+impl ::std::fmt::Debug for PeerRecord {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::std::fmt::Display for IdentifyMessage {
+impl ::std::fmt::Display for PeerRecord {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "listen_addrs", self.listen_addrs())?;
-        write!(f, ", {}: {}", "observed_addr", self.observed_addr())?;
-        write!(f, ", {}: {}", "identify", self.identify())?;
+        write!(f, "{}: {}", "pubkey", self.pubkey())?;
+        write!(f, ", {}: {}", "seq", self.seq())?;
+        write!(f, ", {}: {}", "timestamp", self.timestamp())?;
+        write!(f, ", {}: {}", "addresses", self.addresses())?;
+        write!(f, ", {}: {}", "signature", self.signature())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -849,17 +2833,17 @@ impl ::std::fmt::Display for IdentifyMessage {
         write!(f, " }}")
     }
 }
-impl ::std::default::Default for IdentifyMessage {
+impl ::std::default::Default for PeerRecord {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            36, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0, 32, 0, 0, 0, 4, 0, 0, 0, 12, 0, 0, 0, 8, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0,
+            52, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 36, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
         ];
-        IdentifyMessage::new_unchecked(v.into())
+        PeerRecord::new_unchecked(v.into())
     }
 }
-impl IdentifyMessage {
-    pub const FIELD_COUNT: usize = 3;
+impl PeerRecord {
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -879,37 +2863,49 @@ impl IdentifyMessage {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn listen_addrs(&self) -> AddressVec {
+    pub fn pubkey(&self) -> Bytes {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[0][..]) as usize;
         let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        AddressVec::new_unchecked(self.0.slice(start, end))
+        Bytes::new_unchecked(self.0.slice(start, end))
     }
-    pub fn observed_addr(&self) -> Address {
+    pub fn seq(&self) -> Uint64 {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[1][..]) as usize;
         let end = molecule::unpack_number(&offsets[2][..]) as usize;
-        Address::new_unchecked(self.0.slice(start, end))
+        Uint64::new_unchecked(self.0.slice(start, end))
     }
-    pub fn identify(&self) -> Bytes {
+    pub fn timestamp(&self) -> Uint64 {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn addresses(&self) -> AddressVec {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        AddressVec::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn signature(&self) -> Bytes {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[3][..]) as usize;
+            let end = molecule::unpack_number(&offsets[5][..]) as usize;
             Bytes::new_unchecked(self.0.slice(start, end))
         } else {
             Bytes::new_unchecked(self.0.slice_from(start))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> IdentifyMessageReader<'r> {
-        IdentifyMessageReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> PeerRecordReader<'r> {
+        PeerRecordReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for IdentifyMessage {
-    type Builder = IdentifyMessageBuilder;
-    const NAME: &'static str = "IdentifyMessage";
+impl molecule::prelude::Entity for PeerRecord {
+    type Builder = PeerRecordBuilder;
+    const NAME: &'static str = "PeerRecord";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        IdentifyMessage(data)
+        PeerRecord(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -918,24 +2914,26 @@ impl molecule::prelude::Entity for IdentifyMessage {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        IdentifyMessageReader::from_slice(slice).map(|reader| reader.to_entity())
+        PeerRecordReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        IdentifyMessageReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        PeerRecordReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::std::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .listen_addrs(self.listen_addrs())
-            .observed_addr(self.observed_addr())
-            .identify(self.identify())
+            .pubkey(self.pubkey())
+            .seq(self.seq())
+            .timestamp(self.timestamp())
+            .addresses(self.addresses())
+            .signature(self.signature())
     }
 }
 #[derive(Clone, Copy)]
-pub struct IdentifyMessageReader<'r>(&'r [u8]);
-impl<'r> ::std::fmt::LowerHex for IdentifyMessageReader<'r> {
+pub struct PeerRecordReader<'r>(&'r [u8]);
+impl<'r> ::std::fmt::LowerHex for PeerRecordReader<'r> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         use molecule::faster_hex::hex_string;
         if f.alternate() {
@@ -944,17 +2942,19 @@ impl<'r> ::std::fmt::LowerHex for IdentifyMessageReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()).unwrap())
     }
 }
-impl<'r> ::std::fmt::Debug for IdentifyMessageReader<'r> {
+impl<'r> ::std::fmt::Debug for PeerRecordReader<'r> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::std::fmt::Display for IdentifyMessageReader<'r> {
+impl<'r> ::std::fmt::Display for PeerRecordReader<'r> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "listen_addrs", self.listen_addrs())?;
-        write!(f, ", {}: {}", "observed_addr", self.observed_addr())?;
-        write!(f, ", {}: {}", "identify", self.identify())?;
+        write!(f, "{}: {}", "pubkey", self.pubkey())?;
+        write!(f, ", {}: {}", "seq", self.seq())?;
+        write!(f, ", {}: {}", "timestamp", self.timestamp())?;
+        write!(f, ", {}: {}", "addresses", self.addresses())?;
+        write!(f, ", {}: {}", "signature", self.signature())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -962,8 +2962,8 @@ impl<'r> ::std::fmt::Display for IdentifyMessageReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> IdentifyMessageReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+impl<'r> PeerRecordReader<'r> {
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -983,37 +2983,49 @@ impl<'r> IdentifyMessageReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn listen_addrs(&self) -> AddressVecReader<'r> {
+    pub fn pubkey(&self) -> BytesReader<'r> {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[0][..]) as usize;
         let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        AddressVecReader::new_unchecked(&self.as_slice()[start..end])
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn observed_addr(&self) -> AddressReader<'r> {
+    pub fn seq(&self) -> Uint64Reader<'r> {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[1][..]) as usize;
         let end = molecule::unpack_number(&offsets[2][..]) as usize;
-        AddressReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn identify(&self) -> BytesReader<'r> {
+    pub fn timestamp(&self) -> Uint64Reader<'r> {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn addresses(&self) -> AddressVecReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        AddressVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn signature(&self) -> BytesReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[3][..]) as usize;
+            let end = molecule::unpack_number(&offsets[5][..]) as usize;
             BytesReader::new_unchecked(&self.as_slice()[start..end])
         } else {
             BytesReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for IdentifyMessageReader<'r> {
-    type Entity = IdentifyMessage;
-    const NAME: &'static str = "IdentifyMessageReader";
+impl<'r> molecule::prelude::Reader<'r> for PeerRecordReader<'r> {
+    type Entity = PeerRecord;
+    const NAME: &'static str = "PeerRecordReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        IdentifyMessageReader(slice)
+        PeerRecordReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -1057,64 +3069,84 @@ impl<'r> molecule::prelude::Reader<'r> for IdentifyMessageReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        AddressVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        AddressReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        AddressVecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        BytesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct IdentifyMessageBuilder {
-    pub(crate) listen_addrs: AddressVec,
-    pub(crate) observed_addr: Address,
-    pub(crate) identify: Bytes,
+pub struct PeerRecordBuilder {
+    pub(crate) pubkey: Bytes,
+    pub(crate) seq: Uint64,
+    pub(crate) timestamp: Uint64,
+    pub(crate) addresses: AddressVec,
+    pub(crate) signature: Bytes,
 }
-impl IdentifyMessageBuilder {
-    pub const FIELD_COUNT: usize = 3;
-    pub fn listen_addrs(mut self, v: AddressVec) -> Self {
-        self.listen_addrs = v;
+impl PeerRecordBuilder {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn pubkey(mut self, v: Bytes) -> Self {
+        self.pubkey = v;
         self
     }
-    pub fn observed_addr(mut self, v: Address) -> Self {
-        self.observed_addr = v;
+    pub fn seq(mut self, v: Uint64) -> Self {
+        self.seq = v;
         self
     }
-    pub fn identify(mut self, v: Bytes) -> Self {
-        self.identify = v;
+    pub fn timestamp(mut self, v: Uint64) -> Self {
+        self.timestamp = v;
+        self
+    }
+    pub fn addresses(mut self, v: AddressVec) -> Self {
+        self.addresses = v;
+        self
+    }
+    pub fn signature(mut self, v: Bytes) -> Self {
+        self.signature = v;
         self
     }
 }
-impl molecule::prelude::Builder for IdentifyMessageBuilder {
-    type Entity = IdentifyMessage;
-    const NAME: &'static str = "IdentifyMessageBuilder";
+impl molecule::prelude::Builder for PeerRecordBuilder {
+    type Entity = PeerRecord;
+    const NAME: &'static str = "PeerRecordBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.listen_addrs.as_slice().len()
-            + self.observed_addr.as_slice().len()
-            + self.identify.as_slice().len()
+            + self.pubkey.as_slice().len()
+            + self.seq.as_slice().len()
+            + self.timestamp.as_slice().len()
+            + self.addresses.as_slice().len()
+            + self.signature.as_slice().len()
     }
     fn write<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.listen_addrs.as_slice().len();
+        total_size += self.pubkey.as_slice().len();
         offsets.push(total_size);
-        total_size += self.observed_addr.as_slice().len();
+        total_size += self.seq.as_slice().len();
         offsets.push(total_size);
-        total_size += self.identify.as_slice().len();
+        total_size += self.timestamp.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.addresses.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.signature.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.listen_addrs.as_slice())?;
-        writer.write_all(self.observed_addr.as_slice())?;
-        writer.write_all(self.identify.as_slice())?;
+        writer.write_all(self.pubkey.as_slice())?;
+        writer.write_all(self.seq.as_slice())?;
+        writer.write_all(self.timestamp.as_slice())?;
+        writer.write_all(self.addresses.as_slice())?;
+        writer.write_all(self.signature.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        IdentifyMessage::new_unchecked(inner.into())
+        PeerRecord::new_unchecked(inner.into())
     }
 }
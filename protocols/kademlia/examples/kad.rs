@@ -0,0 +1,79 @@
+use env_logger;
+use log::{debug, info};
+
+use std::time::{Duration, Instant};
+
+use futures::{future::lazy, prelude::*};
+use p2p::{
+    builder::{MetaBuilder, ServiceBuilder},
+    context::ServiceContext,
+    secio::SecioKeyPair,
+    service::{DialProtocol, ProtocolHandle, ProtocolMeta, ServiceError, ServiceEvent},
+    traits::ServiceHandle,
+    ProtocolId,
+};
+use tentacle_kademlia::{Kademlia, KademliaHandle, KademliaProtocol, Record};
+use tokio::timer::Delay;
+
+fn main() {
+    env_logger::init();
+    let key_pair = SecioKeyPair::secp256k1_generated();
+    let kademlia = Kademlia::new(key_pair.peer_id());
+    let mut handle = kademlia.handle();
+    let mut service = ServiceBuilder::default()
+        .insert_protocol(create_meta(1.into(), kademlia))
+        .key_pair(key_pair)
+        .forever(true)
+        .build(SimpleHandler {});
+
+    if std::env::args().nth(1) == Some("server".to_string()) {
+        debug!("Starting server ......");
+        let _ = service.listen("/ip4/127.0.0.1/tcp/1337".parse().unwrap());
+        tokio::run(service.for_each(|_| Ok(())))
+    } else {
+        debug!("Starting client ......");
+        let _ = service.dial(
+            "/ip4/127.0.0.1/tcp/1337".parse().unwrap(),
+            DialProtocol::All,
+        );
+        let _ = service.listen("/ip4/127.0.0.1/tcp/1338".parse().unwrap());
+        tokio::run(lazy(move || {
+            tokio::spawn(
+                Delay::new(Instant::now() + Duration::from_secs(3))
+                    .map_err(|_| ())
+                    .and_then(move |_| lookup(&mut handle)),
+            );
+            service.for_each(|_| Ok(()))
+        }))
+    }
+}
+
+fn lookup(handle: &mut KademliaHandle) -> impl Future<Item = (), Error = ()> {
+    let record = Record::new(b"hello".to_vec(), b"kademlia".to_vec());
+    let put = handle.put_value(record).map(|count| {
+        info!("record stored on {} peers", count);
+    });
+    let get = handle.get_value(b"hello".to_vec()).map(|record| {
+        info!("get record: {:?}", record);
+    });
+    put.join(get).map(|_| ()).map_err(|_| ())
+}
+
+fn create_meta(id: ProtocolId, kademlia: Kademlia) -> ProtocolMeta {
+    MetaBuilder::new()
+        .id(id)
+        .service_handle(move || ProtocolHandle::Callback(Box::new(KademliaProtocol::new(kademlia))))
+        .build()
+}
+
+struct SimpleHandler {}
+
+impl ServiceHandle for SimpleHandler {
+    fn handle_error(&mut self, _env: &mut ServiceContext, error: ServiceError) {
+        debug!("service error: {:?}", error);
+    }
+
+    fn handle_event(&mut self, _env: &mut ServiceContext, event: ServiceEvent) {
+        debug!("service event: {:?}", event);
+    }
+}
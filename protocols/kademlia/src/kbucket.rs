@@ -0,0 +1,366 @@
+use std::{cmp::Ordering, fmt, time::Instant};
+
+use p2p::{multiaddr::Multiaddr, secio::PeerId};
+use ring::digest;
+
+use crate::protocol::PeerInfo;
+
+const KEY_LEN: usize = 32;
+const NUM_BUCKETS: usize = KEY_LEN * 8;
+// The maximum number addresses kept for one peer
+const MAX_ADDRS: usize = 3;
+
+/// A point in the Kademlia key space, the sha256 hash of a peer id or a record key
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Key([u8; KEY_LEN]);
+
+impl Key {
+    /// Hash raw bytes into the key space
+    pub fn new(data: &[u8]) -> Self {
+        let mut inner = [0u8; KEY_LEN];
+        inner.copy_from_slice(digest::digest(&digest::SHA256, data).as_ref());
+        Key(inner)
+    }
+
+    /// The XOR distance between two keys
+    pub fn distance(&self, other: &Key) -> Distance {
+        let mut inner = [0u8; KEY_LEN];
+        for (i, byte) in inner.iter_mut().enumerate() {
+            *byte = self.0[i] ^ other.0[i];
+        }
+        Distance(inner)
+    }
+}
+
+impl<'a> From<&'a PeerId> for Key {
+    fn from(peer_id: &'a PeerId) -> Self {
+        Key::new(peer_id.as_bytes())
+    }
+}
+
+impl AsRef<[u8]> for Key {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Key(")?;
+        for byte in &self.0[..4] {
+            write!(f, "{:02x}", byte)?;
+        }
+        write!(f, "..)")
+    }
+}
+
+/// XOR distance between two keys, compared as a 256 bits big endian integer
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Distance([u8; KEY_LEN]);
+
+impl Distance {
+    /// The index of the bucket this distance falls into, `None` for a zero distance
+    ///
+    /// Bucket `i` covers distances in `[2^i, 2^(i+1))`
+    pub fn bucket_index(&self) -> Option<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .find(|(_, byte)| **byte != 0)
+            .map(|(i, byte)| NUM_BUCKETS - 1 - (i * 8 + byte.leading_zeros() as usize))
+    }
+}
+
+/// A peer known to the routing table
+#[derive(Clone, Debug)]
+pub struct Entry {
+    pub(crate) key: Key,
+    pub(crate) peer_id: PeerId,
+    pub(crate) addresses: Vec<Multiaddr>,
+    pub(crate) connected: bool,
+    pub(crate) last_seen: Instant,
+}
+
+impl Entry {
+    pub fn peer_id(&self) -> &PeerId {
+        &self.peer_id
+    }
+
+    pub fn addresses(&self) -> &[Multiaddr] {
+        &self.addresses
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    pub(crate) fn to_peer_info(&self) -> PeerInfo {
+        PeerInfo {
+            id: self.peer_id.clone(),
+            addresses: self.addresses.clone(),
+        }
+    }
+
+    fn add_address(&mut self, addr: Multiaddr) {
+        if !self.addresses.contains(&addr) {
+            if self.addresses.len() >= MAX_ADDRS {
+                self.addresses.remove(0);
+            }
+            self.addresses.push(addr);
+        }
+    }
+}
+
+/// Result of inserting a peer into the routing table
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InsertResult {
+    /// A new entry was added
+    Inserted,
+    /// The peer was already known, its entry is refreshed
+    Updated,
+    /// The least recently seen peer was disconnected and got replaced
+    Replaced(PeerId),
+    /// The bucket is full of connected peers, the new peer is dropped
+    Full,
+    /// Our own id is never inserted
+    SelfEntry,
+}
+
+// Entries ordered from the least recently seen to the most recently seen
+#[derive(Clone, Default)]
+struct KBucket {
+    entries: Vec<Entry>,
+}
+
+impl KBucket {
+    fn position(&self, peer_id: &PeerId) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| &entry.peer_id == peer_id)
+    }
+}
+
+/// Kademlia routing table, peers are kept in k-buckets by their XOR distance to the local key
+pub struct KBucketsTable {
+    local_key: Key,
+    buckets: Vec<KBucket>,
+    bucket_size: usize,
+}
+
+impl KBucketsTable {
+    pub fn new(local_key: Key, bucket_size: usize) -> Self {
+        KBucketsTable {
+            local_key,
+            buckets: vec![KBucket::default(); NUM_BUCKETS],
+            bucket_size,
+        }
+    }
+
+    pub fn local_key(&self) -> &Key {
+        &self.local_key
+    }
+
+    /// Insert or refresh a peer, the address is merged into the known addresses
+    ///
+    /// When the bucket is full, the least recently seen peer is replaced only if it is not
+    /// connected, otherwise the new peer is dropped
+    pub fn insert(
+        &mut self,
+        peer_id: PeerId,
+        address: Option<Multiaddr>,
+        connected: bool,
+    ) -> InsertResult {
+        let key = Key::from(&peer_id);
+        let index = match self.local_key.distance(&key).bucket_index() {
+            Some(index) => index,
+            None => return InsertResult::SelfEntry,
+        };
+        let bucket_size = self.bucket_size;
+        let bucket = &mut self.buckets[index];
+
+        if let Some(position) = bucket.position(&peer_id) {
+            let mut entry = bucket.entries.remove(position);
+            if let Some(addr) = address {
+                entry.add_address(addr);
+            }
+            entry.connected |= connected;
+            entry.last_seen = Instant::now();
+            bucket.entries.push(entry);
+            return InsertResult::Updated;
+        }
+
+        let mut entry = Entry {
+            key,
+            peer_id,
+            addresses: Vec::new(),
+            connected,
+            last_seen: Instant::now(),
+        };
+        if let Some(addr) = address {
+            entry.add_address(addr);
+        }
+
+        if bucket.entries.len() < bucket_size {
+            bucket.entries.push(entry);
+            InsertResult::Inserted
+        } else if bucket
+            .entries
+            .first()
+            .map(|oldest| !oldest.connected)
+            .unwrap_or(false)
+        {
+            let oldest = bucket.entries.remove(0);
+            bucket.entries.push(entry);
+            InsertResult::Replaced(oldest.peer_id)
+        } else {
+            InsertResult::Full
+        }
+    }
+
+    /// Mark a peer as disconnected, it becomes a candidate for replacement
+    pub fn disconnected(&mut self, peer_id: &PeerId) {
+        if let Some(entry) = self.get_mut(peer_id) {
+            entry.connected = false;
+        }
+    }
+
+    pub fn remove(&mut self, peer_id: &PeerId) -> Option<Entry> {
+        let index = self.bucket_index(peer_id)?;
+        let bucket = &mut self.buckets[index];
+        bucket
+            .position(peer_id)
+            .map(|position| bucket.entries.remove(position))
+    }
+
+    pub fn get(&self, peer_id: &PeerId) -> Option<&Entry> {
+        let bucket = &self.buckets[self.bucket_index(peer_id)?];
+        bucket
+            .position(peer_id)
+            .map(|position| &bucket.entries[position])
+    }
+
+    fn get_mut(&mut self, peer_id: &PeerId) -> Option<&mut Entry> {
+        let index = self.bucket_index(peer_id)?;
+        let bucket = &mut self.buckets[index];
+        match bucket.position(peer_id) {
+            Some(position) => Some(&mut bucket.entries[position]),
+            None => None,
+        }
+    }
+
+    fn bucket_index(&self, peer_id: &PeerId) -> Option<usize> {
+        self.local_key.distance(&Key::from(peer_id)).bucket_index()
+    }
+
+    /// The `count` known peers closest to `target`, nearest first
+    pub fn closest(&self, target: &Key, count: usize) -> Vec<&Entry> {
+        let mut entries = self.iter().collect::<Vec<_>>();
+        entries.sort_by(|a, b| cmp_distance(target, &a.key, &b.key));
+        entries.truncate(count);
+        entries
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.buckets.iter().flat_map(|bucket| bucket.entries.iter())
+    }
+
+    pub fn len(&self) -> usize {
+        self.buckets.iter().map(|bucket| bucket.entries.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+pub(crate) fn cmp_distance(target: &Key, a: &Key, b: &Key) -> Ordering {
+    target.distance(a).cmp(&target.distance(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InsertResult, KBucketsTable, Key};
+    use p2p::secio::PeerId;
+
+    #[test]
+    fn test_bucket_index() {
+        let key = Key::new(b"local");
+        assert_eq!(key.distance(&key).bucket_index(), None);
+
+        let mut other = key.clone();
+        other.0[31] ^= 1;
+        assert_eq!(key.distance(&other).bucket_index(), Some(0));
+
+        let mut other = key.clone();
+        other.0[0] ^= 0x80;
+        assert_eq!(key.distance(&other).bucket_index(), Some(255));
+    }
+
+    #[test]
+    fn test_closest_order() {
+        let local = PeerId::random();
+        let mut table = KBucketsTable::new(Key::from(&local), 20);
+        let peers = (0..50).map(|_| PeerId::random()).collect::<Vec<_>>();
+        for peer in peers.iter() {
+            table.insert(peer.clone(), None, true);
+        }
+        assert_eq!(
+            table.insert(local.clone(), None, true),
+            InsertResult::SelfEntry
+        );
+
+        let target = Key::new(b"target");
+        let closest = table.closest(&target, 10);
+        assert_eq!(closest.len(), 10);
+        for pair in closest.windows(2) {
+            assert!(target.distance(&pair[0].key) <= target.distance(&pair[1].key));
+        }
+
+        let mut expected = peers.clone();
+        expected.sort_by_key(|peer| target.distance(&Key::from(peer)));
+        assert_eq!(
+            closest
+                .iter()
+                .map(|entry| entry.peer_id.clone())
+                .collect::<Vec<_>>(),
+            expected[..10].to_vec()
+        );
+    }
+
+    #[test]
+    fn test_full_bucket_replace_disconnected() {
+        let local = PeerId::random();
+        let local_key = Key::from(&local);
+        let mut table = KBucketsTable::new(local_key.clone(), 2);
+
+        // Collect three peers in the farthest bucket
+        let mut peers = Vec::new();
+        while peers.len() < 3 {
+            let peer = PeerId::random();
+            if local_key.distance(&Key::from(&peer)).bucket_index() == Some(255) {
+                peers.push(peer);
+            }
+        }
+
+        assert_eq!(
+            table.insert(peers[0].clone(), None, true),
+            InsertResult::Inserted
+        );
+        assert_eq!(
+            table.insert(peers[1].clone(), None, true),
+            InsertResult::Inserted
+        );
+        assert_eq!(
+            table.insert(peers[2].clone(), None, true),
+            InsertResult::Full
+        );
+
+        table.disconnected(&peers[0]);
+        assert_eq!(
+            table.insert(peers[2].clone(), None, true),
+            InsertResult::Replaced(peers[0].clone())
+        );
+        assert!(table.get(&peers[0]).is_none());
+        assert_eq!(table.len(), 2);
+    }
+}
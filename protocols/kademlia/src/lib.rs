@@ -0,0 +1,1139 @@
+use std::collections::HashMap;
+use std::io;
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use futures::{
+    prelude::*,
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        oneshot,
+    },
+    Async, Poll, Stream,
+};
+use log::{debug, warn};
+use p2p::{
+    context::{ProtocolContext, ProtocolContextMutRef},
+    multiaddr::Multiaddr,
+    secio::PeerId,
+    service::{DialProtocol, ServiceControl},
+    traits::ServiceProtocol,
+    ProtocolId, SessionId,
+};
+use tokio::timer::Interval;
+
+mod kbucket;
+mod protocol;
+mod query;
+mod record;
+
+#[cfg(feature = "flatc")]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+mod protocol_generated;
+#[cfg(feature = "flatc")]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+#[allow(dead_code)]
+mod protocol_generated_verifier;
+#[cfg(feature = "molc")]
+#[rustfmt::skip]
+#[allow(clippy::all)]
+#[allow(dead_code)]
+mod protocol_mol;
+
+pub use crate::{
+    kbucket::{Distance, Entry, InsertResult, KBucketsTable, Key},
+    protocol::{KademliaMessage, KademliaPayload, PeerInfo},
+    record::{MemoryStore, Record, StoreError},
+};
+
+use crate::query::{Query, QueryConfig, QueryId, QueryKind};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+// The k of Kademlia
+const DEFAULT_REPLICATION: usize = 20;
+// The alpha of Kademlia
+const DEFAULT_PARALLELISM: usize = 3;
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_QUERY_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Decide whether a record from a remote peer is valid, given the record stored locally
+type Validator = Box<dyn Fn(&Record, Option<&Record>) -> bool + Send>;
+
+pub(crate) enum SessionEvent {
+    Connected {
+        session_id: SessionId,
+        peer_id: PeerId,
+        // Only the address of outbound session can be dialed
+        address: Option<Multiaddr>,
+        listens: Vec<Multiaddr>,
+    },
+    Disconnected {
+        session_id: SessionId,
+    },
+    Received {
+        session_id: SessionId,
+        data: Bytes,
+    },
+}
+
+pub(crate) enum Command {
+    AddAddress {
+        peer_id: PeerId,
+        address: Multiaddr,
+    },
+    FindNode {
+        peer_id: PeerId,
+        sender: oneshot::Sender<Vec<PeerInfo>>,
+    },
+    GetValue {
+        key: Vec<u8>,
+        sender: oneshot::Sender<Option<Record>>,
+    },
+    PutValue {
+        record: Record,
+        sender: oneshot::Sender<usize>,
+    },
+    StartProviding {
+        key: Vec<u8>,
+        sender: oneshot::Sender<usize>,
+    },
+    GetProviders {
+        key: Vec<u8>,
+        sender: oneshot::Sender<Vec<PeerInfo>>,
+    },
+}
+
+pub struct KademliaProtocol {
+    kademlia: Option<Kademlia>,
+    event_sender: Sender<SessionEvent>,
+}
+
+impl KademliaProtocol {
+    pub fn new(kademlia: Kademlia) -> KademliaProtocol {
+        let event_sender = kademlia.event_sender.clone();
+        KademliaProtocol {
+            kademlia: Some(kademlia),
+            event_sender,
+        }
+    }
+
+    fn send_event(&mut self, event: SessionEvent) {
+        // TODO: handle channel is full (wait for poll API?)
+        if let Err(err) = self.event_sender.try_send(event) {
+            if err.is_full() {
+                warn!("channel is full");
+            } else if err.is_disconnected() {
+                warn!("channel is disconnected");
+            } else {
+                warn!("other channel error: {:?}", err);
+            }
+        }
+    }
+}
+
+impl ServiceProtocol for KademliaProtocol {
+    fn init(&mut self, context: &mut ProtocolContext) {
+        debug!("protocol [kademlia({})]: init", context.proto_id);
+
+        let mut kademlia = self.kademlia.take().unwrap();
+        kademlia.proto_id = context.proto_id;
+        kademlia.control = Some(context.control().clone());
+        let kademlia_task = kademlia
+            .for_each(|()| Ok(()))
+            .map_err(|err| {
+                warn!("kademlia stream error: {:?}", err);
+            })
+            .then(|_| {
+                debug!("End of kademlia");
+                Ok(())
+            });
+        if context.future_task(kademlia_task).is_err() {
+            warn!("start kademlia fail");
+        };
+    }
+
+    fn connected(&mut self, context: ProtocolContextMutRef, _: &str) {
+        let session = context.session;
+        debug!(
+            "protocol [kademlia] open on session [{}], address: [{}], type: [{:?}]",
+            session.id, session.address, session.ty
+        );
+
+        let peer_id = match session.remote_pubkey {
+            Some(ref pubkey) => pubkey.peer_id(),
+            None => {
+                // Routing table is keyed on peer id
+                if context.disconnect(session.id).is_err() {
+                    debug!("disconnect fail");
+                }
+                return;
+            }
+        };
+        let address = if session.ty.is_outbound() {
+            Some(session.address.clone())
+        } else {
+            None
+        };
+        self.send_event(SessionEvent::Connected {
+            session_id: session.id,
+            peer_id,
+            address,
+            listens: context.listens().to_vec(),
+        });
+    }
+
+    fn disconnected(&mut self, context: ProtocolContextMutRef) {
+        debug!(
+            "protocol [kademlia] close on session [{}]",
+            context.session.id
+        );
+        self.send_event(SessionEvent::Disconnected {
+            session_id: context.session.id,
+        });
+    }
+
+    fn received(&mut self, context: ProtocolContextMutRef, data: bytes::Bytes) {
+        debug!("[received message]: length={}", data.len());
+        self.send_event(SessionEvent::Received {
+            session_id: context.session.id,
+            data,
+        });
+    }
+}
+
+/// Handle to start queries on a running `Kademlia`
+///
+/// Results are delivered on the returned receivers, a receiver is canceled if the command
+/// could not be sent
+#[derive(Clone)]
+pub struct KademliaHandle {
+    command_sender: Sender<Command>,
+}
+
+impl KademliaHandle {
+    fn send_command(&mut self, command: Command) {
+        if let Err(err) = self.command_sender.try_send(command) {
+            warn!("send kademlia command error: {:?}", err.is_full());
+        }
+    }
+
+    /// Add a known address of a peer, used to bootstrap the routing table
+    pub fn add_address(&mut self, peer_id: PeerId, address: Multiaddr) {
+        self.send_command(Command::AddAddress { peer_id, address });
+    }
+
+    /// Lookup the peers closest to the peer id
+    pub fn find_node(&mut self, peer_id: PeerId) -> oneshot::Receiver<Vec<PeerInfo>> {
+        let (sender, receiver) = oneshot::channel();
+        self.send_command(Command::FindNode { peer_id, sender });
+        receiver
+    }
+
+    /// Lookup the record of the key, local store first
+    pub fn get_value(&mut self, key: Vec<u8>) -> oneshot::Receiver<Option<Record>> {
+        let (sender, receiver) = oneshot::channel();
+        self.send_command(Command::GetValue { key, sender });
+        receiver
+    }
+
+    /// Store the record locally and on the peers closest to its key,
+    /// resolves to the number of peers it was sent to
+    pub fn put_value(&mut self, record: Record) -> oneshot::Receiver<usize> {
+        let (sender, receiver) = oneshot::channel();
+        self.send_command(Command::PutValue { record, sender });
+        receiver
+    }
+
+    /// Announce local node as a provider of the key to the peers closest to it,
+    /// resolves to the number of peers it was sent to
+    pub fn start_providing(&mut self, key: Vec<u8>) -> oneshot::Receiver<usize> {
+        let (sender, receiver) = oneshot::channel();
+        self.send_command(Command::StartProviding { key, sender });
+        receiver
+    }
+
+    /// Lookup the providers of the key
+    pub fn get_providers(&mut self, key: Vec<u8>) -> oneshot::Receiver<Vec<PeerInfo>> {
+        let (sender, receiver) = oneshot::channel();
+        self.send_command(Command::GetProviders { key, sender });
+        receiver
+    }
+}
+
+pub struct Kademlia {
+    local_id: PeerId,
+    listens: Vec<Multiaddr>,
+
+    proto_id: ProtocolId,
+    control: Option<ServiceControl>,
+
+    routing_table: KBucketsTable,
+    store: MemoryStore,
+    validator: Option<Validator>,
+    config: QueryConfig,
+
+    sessions: HashMap<SessionId, PeerId>,
+    // A peer may be connected by more than one session, messages go to the first one
+    connected_peers: HashMap<PeerId, Vec<SessionId>>,
+
+    queries: HashMap<QueryId, Query>,
+    next_query_id: QueryId,
+    // Request id -> the query and the peer it was sent to
+    pending_requests: HashMap<u64, (QueryId, PeerId)>,
+    next_request_id: u64,
+    // Messages waiting for the dialed peer to open the protocol
+    pending_dials: HashMap<PeerId, (Instant, Vec<KademliaMessage>)>,
+
+    event_sender: Sender<SessionEvent>,
+    event_receiver: Receiver<SessionEvent>,
+    command_sender: Sender<Command>,
+    command_receiver: Receiver<Command>,
+
+    check_interval: Interval,
+}
+
+impl Kademlia {
+    pub fn new(local_id: PeerId) -> Kademlia {
+        let (event_sender, event_receiver) = channel(128);
+        let (command_sender, command_receiver) = channel(32);
+        Kademlia {
+            routing_table: KBucketsTable::new(Key::from(&local_id), DEFAULT_REPLICATION),
+            local_id,
+            listens: Vec::new(),
+            proto_id: ProtocolId::default(),
+            control: None,
+            store: MemoryStore::default(),
+            validator: None,
+            config: QueryConfig {
+                replication: DEFAULT_REPLICATION,
+                parallelism: DEFAULT_PARALLELISM,
+                request_timeout: DEFAULT_REQUEST_TIMEOUT,
+                timeout: DEFAULT_QUERY_TIMEOUT,
+            },
+            sessions: HashMap::default(),
+            connected_peers: HashMap::default(),
+            queries: HashMap::default(),
+            next_query_id: 0,
+            pending_requests: HashMap::default(),
+            next_request_id: 0,
+            pending_dials: HashMap::default(),
+            event_sender,
+            event_receiver,
+            command_sender,
+            command_receiver,
+            check_interval: Interval::new_interval(CHECK_INTERVAL),
+        }
+    }
+
+    /// Record store, default is a `MemoryStore` with default limits
+    pub fn store(mut self, store: MemoryStore) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Validate the records received from remote peers
+    ///
+    /// The validator is given the remote record and the one stored locally for the key.
+    /// Without a validator, remote peers can't overwrite a stored record, and `get_value`
+    /// returns the first record a peer responds with.
+    pub fn record_validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&Record, Option<&Record>) -> bool + Send + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Bucket size and number of peers a record is replicated to, default is 20
+    pub fn replication(mut self, replication: usize) -> Self {
+        self.config.replication = replication;
+        self.routing_table = KBucketsTable::new(Key::from(&self.local_id), replication);
+        self
+    }
+
+    /// Number of requests in flight for one query, default is 3
+    pub fn parallelism(mut self, parallelism: usize) -> Self {
+        self.config.parallelism = parallelism;
+        self
+    }
+
+    /// A query finishes with what it found after this time, default is 60 seconds
+    pub fn query_timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = timeout;
+        self
+    }
+
+    /// A peer not responding in this time is skipped by a query, default is 10 seconds
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.config.request_timeout = timeout;
+        self
+    }
+
+    pub fn routing_table(&self) -> &KBucketsTable {
+        &self.routing_table
+    }
+
+    pub fn handle(&self) -> KademliaHandle {
+        KademliaHandle {
+            command_sender: self.command_sender.clone(),
+        }
+    }
+
+    fn local_peer_info(&self) -> PeerInfo {
+        PeerInfo {
+            id: self.local_id.clone(),
+            addresses: self.listens.clone(),
+        }
+    }
+
+    fn next_request_id(&mut self) -> u64 {
+        let id = self.next_request_id;
+        self.next_request_id = self.next_request_id.wrapping_add(1);
+        id
+    }
+
+    /// The closest known peers that can be reached by others
+    fn closer_peers(&self, key: &[u8], exclude: &PeerId) -> Vec<PeerInfo> {
+        self.routing_table
+            .closest(&Key::new(key), self.config.replication + 1)
+            .into_iter()
+            .filter(|entry| entry.peer_id() != exclude && !entry.addresses().is_empty())
+            .take(self.config.replication)
+            .map(Entry::to_peer_info)
+            .collect()
+    }
+
+    fn send_message(&self, session_id: SessionId, message: &KademliaMessage) {
+        debug!("send {} to session [{}]", message, session_id);
+        if let Some(ref control) = self.control {
+            if control
+                .send_message_to(session_id, self.proto_id, message.encode())
+                .is_err()
+            {
+                debug!("send message fail");
+            }
+        }
+    }
+
+    /// Send to a connected peer, or dial it and send once the protocol is open
+    fn send_to_peer(&mut self, peer: PeerInfo, message: KademliaMessage) -> bool {
+        if let Some(session_id) = self
+            .connected_peers
+            .get(&peer.id)
+            .and_then(|sessions| sessions.first())
+        {
+            self.send_message(*session_id, &message);
+            return true;
+        }
+        if let Some((_, messages)) = self.pending_dials.get_mut(&peer.id) {
+            messages.push(message);
+            return true;
+        }
+
+        let address = peer.addresses.into_iter().next().or_else(|| {
+            self.routing_table
+                .get(&peer.id)
+                .and_then(|entry| entry.addresses().first().cloned())
+        });
+        match (address, self.control.as_ref()) {
+            (Some(address), Some(control)) => {
+                debug!("dial {:?} on {}", peer.id, address);
+                if control
+                    .dial(address, DialProtocol::Single(self.proto_id))
+                    .is_err()
+                {
+                    debug!("dial fail");
+                    return false;
+                }
+                self.pending_dials
+                    .insert(peer.id, (Instant::now(), vec![message]));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn send_request(&mut self, query_id: QueryId, peer: PeerInfo, payload: KademliaPayload) {
+        let request_id = self.next_request_id();
+        let peer_id = peer.id.clone();
+        self.pending_requests
+            .insert(request_id, (query_id, peer_id.clone()));
+        if !self.send_to_peer(peer, KademliaMessage::new(request_id, payload)) {
+            self.pending_requests.remove(&request_id);
+            if let Some(query) = self.queries.get_mut(&query_id) {
+                query.peers.on_failure(&peer_id);
+            }
+        }
+    }
+
+    fn start_query(&mut self, key: Vec<u8>, kind: QueryKind) {
+        let known_peers = self
+            .routing_table
+            .closest(&Key::new(&key), self.config.replication)
+            .into_iter()
+            .filter(|entry| entry.is_connected() || !entry.addresses().is_empty())
+            .map(Entry::to_peer_info)
+            .collect();
+        let query_id = self.next_query_id;
+        self.next_query_id = self.next_query_id.wrapping_add(1);
+        self.queries
+            .insert(query_id, Query::new(key, kind, known_peers, self.config));
+    }
+
+    fn finish_query(&mut self, query_id: QueryId) {
+        let query = match self.queries.remove(&query_id) {
+            Some(query) => query,
+            None => return,
+        };
+        self.pending_requests.retain(|_, (id, _)| *id != query_id);
+
+        let key = query.key;
+        let peers = query.peers.into_result();
+        debug!("query [{}] finished with {} peers", query_id, peers.len());
+        match query.kind {
+            QueryKind::FindNode { sender } => {
+                let _ = sender.send(peers);
+            }
+            QueryKind::GetValue { sender } => {
+                let _ = sender.send(None);
+            }
+            QueryKind::PutValue { record, sender } => {
+                let mut count = 0;
+                for peer in peers {
+                    let request_id = self.next_request_id();
+                    let payload = KademliaPayload::PutValue {
+                        record: record.clone(),
+                    };
+                    if self.send_to_peer(peer, KademliaMessage::new(request_id, payload)) {
+                        count += 1;
+                    }
+                }
+                let _ = sender.send(count);
+            }
+            QueryKind::AddProvider { sender } => {
+                let provider = self.local_peer_info();
+                let mut count = 0;
+                for peer in peers {
+                    let request_id = self.next_request_id();
+                    let payload = KademliaPayload::AddProvider {
+                        key: key.clone(),
+                        provider: provider.clone(),
+                    };
+                    if self.send_to_peer(peer, KademliaMessage::new(request_id, payload)) {
+                        count += 1;
+                    }
+                }
+                let _ = sender.send(count);
+            }
+            QueryKind::GetProviders { providers, sender } => {
+                let _ = sender.send(providers);
+            }
+        }
+    }
+
+    fn handle_command(&mut self, command: Command) {
+        match command {
+            Command::AddAddress { peer_id, address } => {
+                self.routing_table.insert(peer_id, Some(address), false);
+            }
+            Command::FindNode { peer_id, sender } => {
+                self.start_query(peer_id.into_bytes(), QueryKind::FindNode { sender });
+            }
+            Command::GetValue { key, sender } => {
+                if let Some(record) = self.store.get(&key) {
+                    let _ = sender.send(Some(record.clone()));
+                } else {
+                    self.start_query(key, QueryKind::GetValue { sender });
+                }
+            }
+            Command::PutValue { record, sender } => {
+                if let Err(err) = self.store.put(record.clone()) {
+                    debug!("store record error: {:?}", err);
+                }
+                self.start_query(record.key.clone(), QueryKind::PutValue { record, sender });
+            }
+            Command::StartProviding { key, sender } => {
+                let provider = self.local_peer_info();
+                if let Err(err) = self.store.add_provider(key.clone(), provider) {
+                    debug!("store provider error: {:?}", err);
+                }
+                self.start_query(key, QueryKind::AddProvider { sender });
+            }
+            Command::GetProviders { key, sender } => {
+                let providers = self.store.providers(&key);
+                self.start_query(key, QueryKind::GetProviders { providers, sender });
+            }
+        }
+    }
+
+    fn handle_event(&mut self, event: SessionEvent) {
+        match event {
+            SessionEvent::Connected {
+                session_id,
+                peer_id,
+                address,
+                listens,
+            } => {
+                self.listens = listens;
+                self.routing_table.insert(peer_id.clone(), address, true);
+                self.sessions.insert(session_id, peer_id.clone());
+                self.connected_peers
+                    .entry(peer_id.clone())
+                    .or_default()
+                    .push(session_id);
+                if let Some((_, messages)) = self.pending_dials.remove(&peer_id) {
+                    for message in messages {
+                        self.send_message(session_id, &message);
+                    }
+                }
+            }
+            SessionEvent::Disconnected { session_id } => {
+                if let Some(peer_id) = self.sessions.remove(&session_id) {
+                    if let Some(sessions) = self.connected_peers.get_mut(&peer_id) {
+                        sessions.retain(|id| *id != session_id);
+                        if !sessions.is_empty() {
+                            // Requests sent on the closed session fail by request timeout
+                            return;
+                        }
+                    }
+                    self.connected_peers.remove(&peer_id);
+                    self.routing_table.disconnected(&peer_id);
+                    let failed = self
+                        .pending_requests
+                        .iter()
+                        .filter(|(_, (_, id))| id == &peer_id)
+                        .map(|(request_id, (query_id, _))| (*request_id, *query_id))
+                        .collect::<Vec<_>>();
+                    for (request_id, query_id) in failed {
+                        self.pending_requests.remove(&request_id);
+                        if let Some(query) = self.queries.get_mut(&query_id) {
+                            query.peers.on_failure(&peer_id);
+                        }
+                    }
+                }
+            }
+            SessionEvent::Received { session_id, data } => {
+                let peer_id = match self.sessions.get(&session_id) {
+                    Some(peer_id) => peer_id.clone(),
+                    None => return,
+                };
+                match KademliaMessage::decode(&data) {
+                    Some(message) => {
+                        debug!("received {} from session [{}]", message, session_id);
+                        if message.payload.is_response() {
+                            self.handle_response(peer_id, message);
+                        } else if let Some(response) = self.handle_request(peer_id, message) {
+                            self.send_message(session_id, &response);
+                        }
+                    }
+                    None => {
+                        debug!("decode message error from session [{}]", session_id);
+                        if let Some(ref control) = self.control {
+                            let _ = control.disconnect(session_id);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn handle_request(
+        &mut self,
+        peer_id: PeerId,
+        message: KademliaMessage,
+    ) -> Option<KademliaMessage> {
+        let response = match message.payload {
+            KademliaPayload::FindNode { key } => Some(KademliaPayload::Nodes {
+                closer_peers: self.closer_peers(&key, &peer_id),
+            }),
+            KademliaPayload::GetValue { key } => {
+                let record = self.store.get(&key).cloned();
+                let closer_peers = if record.is_some() {
+                    Vec::new()
+                } else {
+                    self.closer_peers(&key, &peer_id)
+                };
+                Some(KademliaPayload::Value {
+                    record,
+                    closer_peers,
+                })
+            }
+            KademliaPayload::PutValue { record } => {
+                let current = self.store.get(&record.key);
+                let valid = match self.validator {
+                    Some(ref validator) => validator(&record, current),
+                    None => current.is_none(),
+                };
+                if !valid {
+                    debug!("record from {:?} rejected", peer_id);
+                } else if let Err(err) = self.store.put(record) {
+                    debug!("store record error: {:?}", err);
+                }
+                None
+            }
+            KademliaPayload::AddProvider { key, provider } => {
+                if provider.id == peer_id {
+                    if let Err(err) = self.store.add_provider(key, provider) {
+                        debug!("store provider error: {:?}", err);
+                    }
+                } else {
+                    debug!("provider is not the sender, ignored");
+                }
+                None
+            }
+            KademliaPayload::GetProviders { key } => Some(KademliaPayload::Providers {
+                providers: self.store.providers(&key),
+                closer_peers: self.closer_peers(&key, &peer_id),
+            }),
+            _ => None,
+        };
+        response.map(|payload| KademliaMessage::new(message.request_id, payload))
+    }
+
+    fn handle_response(&mut self, peer_id: PeerId, message: KademliaMessage) {
+        let query_id = match self.pending_requests.remove(&message.request_id) {
+            Some((query_id, expected)) => {
+                if expected != peer_id {
+                    debug!("response from unexpected peer {:?}", peer_id);
+                    self.pending_requests
+                        .insert(message.request_id, (query_id, expected));
+                    return;
+                }
+                query_id
+            }
+            None => return,
+        };
+
+        let local_id = self.local_id.clone();
+        let without_local = |peers: Vec<PeerInfo>| {
+            peers
+                .into_iter()
+                .filter(|peer| peer.id != local_id)
+                .collect::<Vec<_>>()
+        };
+
+        let mut payload = message.payload;
+        if let KademliaPayload::Value { ref mut record, .. } = payload {
+            if let (Some(validator), Some(value)) = (self.validator.as_ref(), record.as_ref()) {
+                if !validator(value, None) {
+                    // The query goes on as if the peer didn't have the record
+                    debug!("record from {:?} rejected", peer_id);
+                    *record = None;
+                }
+            }
+        }
+
+        match payload {
+            KademliaPayload::Value {
+                record: Some(record),
+                ..
+            } => {
+                if let Some(query) = self.queries.remove(&query_id) {
+                    self.pending_requests.retain(|_, (id, _)| *id != query_id);
+                    if let QueryKind::GetValue { sender } = query.kind {
+                        let _ = sender.send(Some(record));
+                    }
+                }
+            }
+            KademliaPayload::Nodes { closer_peers }
+            | KademliaPayload::Value { closer_peers, .. } => {
+                if let Some(query) = self.queries.get_mut(&query_id) {
+                    query
+                        .peers
+                        .on_success(&peer_id, without_local(closer_peers));
+                }
+            }
+            KademliaPayload::Providers {
+                providers,
+                closer_peers,
+            } => {
+                if let Some(query) = self.queries.get_mut(&query_id) {
+                    if let QueryKind::GetProviders {
+                        providers: ref mut found,
+                        ..
+                    } = query.kind
+                    {
+                        for provider in providers {
+                            if !found.iter().any(|info| info.id == provider.id) {
+                                found.push(provider);
+                            }
+                        }
+                    }
+                    query
+                        .peers
+                        .on_success(&peer_id, without_local(closer_peers));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn advance_queries(&mut self) {
+        let now = Instant::now();
+        let mut requests = Vec::new();
+        let mut finished = Vec::new();
+        for (query_id, query) in self.queries.iter_mut() {
+            let peers = query.peers.next(now);
+            if peers.is_empty() && query.peers.is_finished(now) {
+                finished.push(*query_id);
+            }
+            for peer in peers {
+                requests.push((*query_id, peer, query.request()));
+            }
+        }
+        for (query_id, peer, payload) in requests {
+            self.send_request(query_id, peer, payload);
+        }
+        for query_id in finished {
+            self.finish_query(query_id);
+        }
+    }
+
+    fn recv_events(&mut self) -> Result<bool, io::Error> {
+        loop {
+            match self.event_receiver.poll() {
+                Ok(Async::Ready(Some(event))) => self.handle_event(event),
+                Ok(Async::Ready(None)) => return Ok(false),
+                Ok(Async::NotReady) => return Ok(true),
+                Err(err) => {
+                    debug!("receive session event error: {:?}", err);
+                    return Err(io::ErrorKind::Other.into());
+                }
+            }
+        }
+    }
+
+    fn recv_commands(&mut self) -> Result<(), io::Error> {
+        loop {
+            match self.command_receiver.poll() {
+                Ok(Async::Ready(Some(command))) => self.handle_command(command),
+                Ok(Async::Ready(None)) => unreachable!(),
+                Ok(Async::NotReady) => return Ok(()),
+                Err(err) => {
+                    debug!("receive command error: {:?}", err);
+                    return Err(io::ErrorKind::Other.into());
+                }
+            }
+        }
+    }
+
+    fn check_interval(&mut self) {
+        let mut checked = false;
+        loop {
+            match self.check_interval.poll() {
+                Ok(Async::Ready(Some(_))) => checked = true,
+                Ok(Async::Ready(None)) => {
+                    debug!("Kademlia check_interval poll finished");
+                    break;
+                }
+                Ok(Async::NotReady) => break,
+                Err(err) => {
+                    debug!("Kademlia check_interval poll error: {:?}", err);
+                    break;
+                }
+            }
+        }
+        if checked {
+            self.store.remove_expired();
+            let request_timeout = self.config.request_timeout;
+            self.pending_dials
+                .retain(|_, (time, _)| time.elapsed() < request_timeout);
+        }
+    }
+}
+
+impl Stream for Kademlia {
+    type Item = ();
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if !self.recv_events()? {
+            // All protocol handles are dropped
+            return Ok(Async::Ready(None));
+        }
+        self.recv_commands()?;
+        self.check_interval();
+        self.advance_queries();
+        Ok(Async::NotReady)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Command, Kademlia, KademliaMessage, KademliaPayload, Key, PeerInfo, Record, SessionEvent,
+    };
+    use futures::{sync::oneshot, Future};
+    use p2p::{multiaddr::Multiaddr, secio::PeerId, SessionId};
+
+    fn address(port: usize) -> Multiaddr {
+        format!("/ip4/127.0.0.1/tcp/{}", port).parse().unwrap()
+    }
+
+    fn connect(kademlia: &mut Kademlia, session_id: usize, peer_id: &PeerId) {
+        kademlia.handle_event(SessionEvent::Connected {
+            session_id: session_id.into(),
+            peer_id: peer_id.clone(),
+            address: Some(address(session_id)),
+            listens: Vec::new(),
+        });
+    }
+
+    fn respond(
+        kademlia: &mut Kademlia,
+        session_id: usize,
+        request_id: u64,
+        payload: KademliaPayload,
+    ) {
+        kademlia.handle_event(SessionEvent::Received {
+            session_id: SessionId::from(session_id),
+            data: KademliaMessage::new(request_id, payload).encode(),
+        });
+    }
+
+    /// The id of the request in flight to the peer
+    fn request_to(kademlia: &Kademlia, peer_id: &PeerId) -> u64 {
+        kademlia
+            .pending_requests
+            .iter()
+            .find(|(_, (_, id))| id == peer_id)
+            .map(|(request_id, _)| *request_id)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_handle_request() {
+        let mut kademlia = Kademlia::new(PeerId::random());
+        let requester = PeerId::random();
+        connect(&mut kademlia, 1, &requester);
+        for port in 2..5 {
+            kademlia.handle_command(Command::AddAddress {
+                peer_id: PeerId::random(),
+                address: address(port),
+            });
+        }
+
+        // The requester is never returned to itself
+        let request = KademliaMessage::new(
+            1,
+            KademliaPayload::FindNode {
+                key: b"key".to_vec(),
+            },
+        );
+        match kademlia.handle_request(requester.clone(), request) {
+            Some(KademliaMessage {
+                request_id: 1,
+                payload: KademliaPayload::Nodes { closer_peers },
+            }) => {
+                assert_eq!(closer_peers.len(), 3);
+                assert!(closer_peers.iter().all(|peer| peer.id != requester));
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+
+        let record = Record::new(b"key".to_vec(), b"value".to_vec());
+        let request = KademliaMessage::new(
+            2,
+            KademliaPayload::PutValue {
+                record: record.clone(),
+            },
+        );
+        assert!(kademlia
+            .handle_request(requester.clone(), request)
+            .is_none());
+        let request = KademliaMessage::new(
+            3,
+            KademliaPayload::GetValue {
+                key: b"key".to_vec(),
+            },
+        );
+        assert_eq!(
+            kademlia.handle_request(requester.clone(), request),
+            Some(KademliaMessage::new(
+                3,
+                KademliaPayload::Value {
+                    record: Some(record),
+                    closer_peers: Vec::new(),
+                }
+            ))
+        );
+
+        // A peer can only announce itself as a provider
+        for provider in &[PeerId::random(), requester.clone()] {
+            let request = KademliaMessage::new(
+                4,
+                KademliaPayload::AddProvider {
+                    key: b"key".to_vec(),
+                    provider: PeerInfo {
+                        id: provider.clone(),
+                        addresses: Vec::new(),
+                    },
+                },
+            );
+            assert!(kademlia
+                .handle_request(requester.clone(), request)
+                .is_none());
+        }
+        let request = KademliaMessage::new(
+            5,
+            KademliaPayload::GetProviders {
+                key: b"key".to_vec(),
+            },
+        );
+        match kademlia.handle_request(requester.clone(), request) {
+            Some(KademliaMessage {
+                request_id: 5,
+                payload: KademliaPayload::Providers { providers, .. },
+            }) => {
+                assert_eq!(providers.len(), 1);
+                assert_eq!(providers[0].id, requester);
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+
+        // Responses are not requests
+        let request = KademliaMessage::new(
+            6,
+            KademliaPayload::Nodes {
+                closer_peers: Vec::new(),
+            },
+        );
+        assert!(kademlia.handle_request(requester, request).is_none());
+    }
+
+    #[test]
+    fn test_record_validator() {
+        let requester = PeerId::random();
+        let put = |kademlia: &mut Kademlia, value: &[u8]| {
+            let request = KademliaMessage::new(
+                1,
+                KademliaPayload::PutValue {
+                    record: Record::new(b"key".to_vec(), value.to_vec()),
+                },
+            );
+            kademlia.handle_request(requester.clone(), request);
+            kademlia.store.get(b"key").unwrap().value.clone()
+        };
+
+        // Without a validator, the first record stays
+        let mut kademlia = Kademlia::new(PeerId::random());
+        assert_eq!(put(&mut kademlia, b"first"), b"first".to_vec());
+        assert_eq!(put(&mut kademlia, b"second"), b"first".to_vec());
+
+        let mut kademlia = Kademlia::new(PeerId::random())
+            .record_validator(|record, _| record.value.as_slice() != b"bad");
+        assert_eq!(put(&mut kademlia, b"first"), b"first".to_vec());
+        assert_eq!(put(&mut kademlia, b"second"), b"second".to_vec());
+        assert_eq!(put(&mut kademlia, b"bad"), b"second".to_vec());
+
+        // Invalid records found by a query are skipped
+        let (first, second) = (PeerId::random(), PeerId::random());
+        connect(&mut kademlia, 1, &first);
+        connect(&mut kademlia, 2, &second);
+        let (sender, receiver) = oneshot::channel();
+        kademlia.handle_command(Command::GetValue {
+            key: b"other".to_vec(),
+            sender,
+        });
+        kademlia.advance_queries();
+        let responses = vec![(1, first, b"bad".to_vec()), (2, second, b"good".to_vec())];
+        for (session_id, peer_id, value) in responses {
+            let request_id = request_to(&kademlia, &peer_id);
+            respond(
+                &mut kademlia,
+                session_id,
+                request_id,
+                KademliaPayload::Value {
+                    record: Some(Record::new(b"other".to_vec(), value)),
+                    closer_peers: Vec::new(),
+                },
+            );
+        }
+        assert_eq!(
+            receiver.wait().unwrap(),
+            Some(Record::new(b"other".to_vec(), b"good".to_vec()))
+        );
+    }
+
+    #[test]
+    fn test_iterative_find_node() {
+        let local_id = PeerId::random();
+        let mut kademlia = Kademlia::new(local_id.clone());
+        let first = PeerId::random();
+        connect(&mut kademlia, 1, &first);
+
+        let target = PeerId::random();
+        let (sender, receiver) = oneshot::channel();
+        kademlia.handle_command(Command::FindNode {
+            peer_id: target.clone(),
+            sender,
+        });
+        kademlia.advance_queries();
+        assert_eq!(kademlia.pending_requests.len(), 1);
+
+        // The second peer is only known from the response of the first one,
+        // local node in the response is ignored
+        let second = PeerId::random();
+        connect(&mut kademlia, 2, &second);
+        let request_id = request_to(&kademlia, &first);
+        let closer_peers = vec![second.clone(), local_id]
+            .into_iter()
+            .map(|id| PeerInfo {
+                id,
+                addresses: Vec::new(),
+            })
+            .collect();
+        respond(
+            &mut kademlia,
+            1,
+            request_id,
+            KademliaPayload::Nodes { closer_peers },
+        );
+        kademlia.advance_queries();
+        assert_eq!(kademlia.pending_requests.len(), 1);
+
+        let request_id = request_to(&kademlia, &second);
+        let closer_peers = Vec::new();
+        respond(
+            &mut kademlia,
+            2,
+            request_id,
+            KademliaPayload::Nodes { closer_peers },
+        );
+        kademlia.advance_queries();
+        assert!(kademlia.queries.is_empty());
+
+        let target = Key::from(&target);
+        let mut expected = vec![first, second];
+        expected.sort_by_key(|id| target.distance(&Key::from(id)));
+        let found = receiver
+            .wait()
+            .unwrap()
+            .into_iter()
+            .map(|peer| peer.id)
+            .collect::<Vec<_>>();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_peer_with_multiple_sessions() {
+        let mut kademlia = Kademlia::new(PeerId::random());
+        let peer_id = PeerId::random();
+        connect(&mut kademlia, 1, &peer_id);
+        connect(&mut kademlia, 2, &peer_id);
+
+        kademlia.handle_event(SessionEvent::Disconnected {
+            session_id: 1.into(),
+        });
+        assert_eq!(kademlia.connected_peers[&peer_id], vec![SessionId::from(2)]);
+        assert!(kademlia.routing_table.get(&peer_id).unwrap().is_connected());
+
+        kademlia.handle_event(SessionEvent::Disconnected {
+            session_id: 2.into(),
+        });
+        assert!(kademlia.connected_peers.is_empty());
+        assert!(!kademlia.routing_table.get(&peer_id).unwrap().is_connected());
+    }
+}
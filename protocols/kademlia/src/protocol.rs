@@ -0,0 +1,644 @@
+#[cfg(all(feature = "flatc", feature = "molc"))]
+compile_error!("features `flatc` and `molc` are mutually exclusive");
+#[cfg(all(not(feature = "flatc"), not(feature = "molc")))]
+compile_error!("Please choose a serialization format via feature. Possible choices: flatc, molc");
+
+use std::convert::TryFrom;
+
+use bytes::Bytes;
+use p2p::{multiaddr::Multiaddr, secio::PeerId};
+
+#[cfg(feature = "flatc")]
+use crate::protocol_generated::p2p::kademlia::{
+    AddProvider as FbsAddProvider, AddProviderBuilder, BytesBuilder, FindNode as FbsFindNode,
+    FindNodeBuilder, GetProviders as FbsGetProviders, GetProvidersBuilder, GetValue as FbsGetValue,
+    GetValueBuilder, KademliaMessage as FbsKademliaMessage, KademliaMessageBuilder,
+    KademliaPayload as FbsKademliaPayload, Nodes as FbsNodes, NodesBuilder, Peer as FbsPeer,
+    PeerBuilder, Providers as FbsProviders, ProvidersBuilder, PutValue as FbsPutValue,
+    PutValueBuilder, Record as FbsRecord, RecordBuilder, Value as FbsValue, ValueBuilder,
+};
+#[cfg(feature = "flatc")]
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, Vector, WIPOffset};
+
+#[cfg(feature = "molc")]
+use crate::protocol_mol;
+#[cfg(feature = "molc")]
+use molecule::prelude::{Builder, Entity, Reader};
+
+use crate::record::Record;
+
+/// A peer id with the addresses it can be dialed on
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PeerInfo {
+    pub id: PeerId,
+    pub addresses: Vec<Multiaddr>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KademliaMessage {
+    /// Responses carry the id of the request they answer
+    pub request_id: u64,
+    pub payload: KademliaPayload,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum KademliaPayload {
+    /// Request the peers closest to the key
+    FindNode { key: Vec<u8> },
+    /// Response to `FindNode`
+    Nodes { closer_peers: Vec<PeerInfo> },
+    /// Request the record of the key
+    GetValue { key: Vec<u8> },
+    /// Response to `GetValue`, closer peers are returned when the record is not found
+    Value {
+        record: Option<Record>,
+        closer_peers: Vec<PeerInfo>,
+    },
+    /// Store the record, no response
+    PutValue { record: Record },
+    /// Announce the provider of the key, no response
+    AddProvider { key: Vec<u8>, provider: PeerInfo },
+    /// Request the providers of the key
+    GetProviders { key: Vec<u8> },
+    /// Response to `GetProviders`
+    Providers {
+        providers: Vec<PeerInfo>,
+        closer_peers: Vec<PeerInfo>,
+    },
+}
+
+impl KademliaPayload {
+    /// Whether this payload answers a request
+    pub fn is_response(&self) -> bool {
+        match self {
+            KademliaPayload::Nodes { .. }
+            | KademliaPayload::Value { .. }
+            | KademliaPayload::Providers { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "flatc")]
+fn fbs_bytes_vec<'a>(fbb: &mut FlatBufferBuilder<'a>, data: &[u8]) -> WIPOffset<Vector<'a, u8>> {
+    fbb.create_vector(data)
+}
+
+#[cfg(feature = "flatc")]
+fn fbs_peer<'a>(fbb: &mut FlatBufferBuilder<'a>, peer: &PeerInfo) -> WIPOffset<FbsPeer<'a>> {
+    let id = fbs_bytes_vec(fbb, peer.id.as_bytes());
+    let mut vec_addrs = Vec::with_capacity(peer.addresses.len());
+    for address in &peer.addresses {
+        let seq = fbs_bytes_vec(fbb, address.as_ref());
+        let mut bytes_builder = BytesBuilder::new(fbb);
+        bytes_builder.add_seq(seq);
+        vec_addrs.push(bytes_builder.finish());
+    }
+    let addresses = fbb.create_vector(&vec_addrs);
+    let mut peer_builder = PeerBuilder::new(fbb);
+    peer_builder.add_id(id);
+    peer_builder.add_addresses(addresses);
+    peer_builder.finish()
+}
+
+#[cfg(feature = "flatc")]
+fn fbs_peers<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    peers: &[PeerInfo],
+) -> WIPOffset<Vector<'a, ForwardsUOffset<FbsPeer<'a>>>> {
+    let vec_peers = peers
+        .iter()
+        .map(|peer| fbs_peer(fbb, peer))
+        .collect::<Vec<_>>();
+    fbb.create_vector(&vec_peers)
+}
+
+#[cfg(feature = "flatc")]
+fn fbs_record<'a>(fbb: &mut FlatBufferBuilder<'a>, record: &Record) -> WIPOffset<FbsRecord<'a>> {
+    let key = fbs_bytes_vec(fbb, &record.key);
+    let value = fbs_bytes_vec(fbb, &record.value);
+    let mut record_builder = RecordBuilder::new(fbb);
+    record_builder.add_key(key);
+    record_builder.add_value(value);
+    record_builder.finish()
+}
+
+#[cfg(feature = "flatc")]
+fn peer_from_fbs(fbs_peer: FbsPeer) -> Option<PeerInfo> {
+    let id = PeerId::from_bytes(fbs_peer.id()?.to_vec()).ok()?;
+    let fbs_addresses = fbs_peer.addresses()?;
+    let mut addresses = Vec::with_capacity(fbs_addresses.len());
+    for i in 0..fbs_addresses.len() {
+        let address = fbs_addresses.get(i);
+        addresses.push(Multiaddr::try_from(address.seq()?.to_vec()).ok()?);
+    }
+    Some(PeerInfo { id, addresses })
+}
+
+#[cfg(feature = "flatc")]
+fn peers_from_fbs(fbs_peers: Option<Vector<ForwardsUOffset<FbsPeer>>>) -> Option<Vec<PeerInfo>> {
+    let fbs_peers = fbs_peers?;
+    let mut peers = Vec::with_capacity(fbs_peers.len());
+    for i in 0..fbs_peers.len() {
+        peers.push(peer_from_fbs(fbs_peers.get(i))?);
+    }
+    Some(peers)
+}
+
+#[cfg(feature = "flatc")]
+fn record_from_fbs(fbs_record: FbsRecord) -> Option<Record> {
+    Some(Record {
+        key: fbs_record.key()?.to_vec(),
+        value: fbs_record.value()?.to_vec(),
+    })
+}
+
+#[cfg(feature = "molc")]
+fn mol_bytes(data: &[u8]) -> protocol_mol::Bytes {
+    protocol_mol::Bytes::new_builder()
+        .set(data.iter().cloned().map(Into::into).collect())
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn mol_peer(peer: &PeerInfo) -> protocol_mol::Peer {
+    let addresses = protocol_mol::BytesVec::new_builder()
+        .set(
+            peer.addresses
+                .iter()
+                .map(|addr| mol_bytes(addr.as_ref()))
+                .collect(),
+        )
+        .build();
+    protocol_mol::Peer::new_builder()
+        .id(mol_bytes(peer.id.as_bytes()))
+        .addresses(addresses)
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn mol_peers(peers: &[PeerInfo]) -> protocol_mol::PeerVec {
+    protocol_mol::PeerVec::new_builder()
+        .set(peers.iter().map(mol_peer).collect())
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn mol_record(record: &Record) -> protocol_mol::Record {
+    protocol_mol::Record::new_builder()
+        .key(mol_bytes(&record.key))
+        .value(mol_bytes(&record.value))
+        .build()
+}
+
+#[cfg(feature = "molc")]
+fn peer_from_mol(reader: protocol_mol::PeerReader) -> Option<PeerInfo> {
+    let id = PeerId::from_bytes(reader.id().raw_data().to_vec()).ok()?;
+    let mut addresses = Vec::with_capacity(reader.addresses().len());
+    for address_reader in reader.addresses().iter() {
+        addresses.push(Multiaddr::try_from(address_reader.raw_data().to_vec()).ok()?)
+    }
+    Some(PeerInfo { id, addresses })
+}
+
+#[cfg(feature = "molc")]
+fn peers_from_mol(reader: protocol_mol::PeerVecReader) -> Option<Vec<PeerInfo>> {
+    let mut peers = Vec::with_capacity(reader.len());
+    for peer_reader in reader.iter() {
+        peers.push(peer_from_mol(peer_reader)?);
+    }
+    Some(peers)
+}
+
+#[cfg(feature = "molc")]
+fn record_from_mol(reader: protocol_mol::RecordReader) -> Record {
+    Record {
+        key: reader.key().raw_data().to_vec(),
+        value: reader.value().raw_data().to_vec(),
+    }
+}
+
+impl KademliaMessage {
+    pub fn new(request_id: u64, payload: KademliaPayload) -> Self {
+        KademliaMessage {
+            request_id,
+            payload,
+        }
+    }
+
+    #[cfg(feature = "flatc")]
+    pub fn encode(&self) -> Bytes {
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let (payload_type, payload) = match &self.payload {
+            KademliaPayload::FindNode { key } => {
+                let key = fbs_bytes_vec(&mut fbb, key);
+                let mut builder = FindNodeBuilder::new(&mut fbb);
+                builder.add_key(key);
+                (
+                    FbsKademliaPayload::FindNode,
+                    builder.finish().as_union_value(),
+                )
+            }
+            KademliaPayload::Nodes { closer_peers } => {
+                let closer_peers = fbs_peers(&mut fbb, closer_peers);
+                let mut builder = NodesBuilder::new(&mut fbb);
+                builder.add_closer_peers(closer_peers);
+                (FbsKademliaPayload::Nodes, builder.finish().as_union_value())
+            }
+            KademliaPayload::GetValue { key } => {
+                let key = fbs_bytes_vec(&mut fbb, key);
+                let mut builder = GetValueBuilder::new(&mut fbb);
+                builder.add_key(key);
+                (
+                    FbsKademliaPayload::GetValue,
+                    builder.finish().as_union_value(),
+                )
+            }
+            KademliaPayload::Value {
+                record,
+                closer_peers,
+            } => {
+                let record = record.as_ref().map(|record| fbs_record(&mut fbb, record));
+                let closer_peers = fbs_peers(&mut fbb, closer_peers);
+                let mut builder = ValueBuilder::new(&mut fbb);
+                if let Some(record) = record {
+                    builder.add_record(record);
+                }
+                builder.add_closer_peers(closer_peers);
+                (FbsKademliaPayload::Value, builder.finish().as_union_value())
+            }
+            KademliaPayload::PutValue { record } => {
+                let record = fbs_record(&mut fbb, record);
+                let mut builder = PutValueBuilder::new(&mut fbb);
+                builder.add_record(record);
+                (
+                    FbsKademliaPayload::PutValue,
+                    builder.finish().as_union_value(),
+                )
+            }
+            KademliaPayload::AddProvider { key, provider } => {
+                let key = fbs_bytes_vec(&mut fbb, key);
+                let provider = fbs_peer(&mut fbb, provider);
+                let mut builder = AddProviderBuilder::new(&mut fbb);
+                builder.add_key(key);
+                builder.add_provider(provider);
+                (
+                    FbsKademliaPayload::AddProvider,
+                    builder.finish().as_union_value(),
+                )
+            }
+            KademliaPayload::GetProviders { key } => {
+                let key = fbs_bytes_vec(&mut fbb, key);
+                let mut builder = GetProvidersBuilder::new(&mut fbb);
+                builder.add_key(key);
+                (
+                    FbsKademliaPayload::GetProviders,
+                    builder.finish().as_union_value(),
+                )
+            }
+            KademliaPayload::Providers {
+                providers,
+                closer_peers,
+            } => {
+                let providers = fbs_peers(&mut fbb, providers);
+                let closer_peers = fbs_peers(&mut fbb, closer_peers);
+                let mut builder = ProvidersBuilder::new(&mut fbb);
+                builder.add_providers(providers);
+                builder.add_closer_peers(closer_peers);
+                (
+                    FbsKademliaPayload::Providers,
+                    builder.finish().as_union_value(),
+                )
+            }
+        };
+
+        let mut builder = KademliaMessageBuilder::new(&mut fbb);
+        builder.add_request_id(self.request_id);
+        builder.add_payload_type(payload_type);
+        builder.add_payload(payload);
+        let data = builder.finish();
+        fbb.finish(data, None);
+        Bytes::from(fbb.finished_data())
+    }
+
+    #[cfg(feature = "flatc")]
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let fbs_message = flatbuffers_verifier::get_root::<FbsKademliaMessage>(data).ok()?;
+        let payload = fbs_message.payload()?;
+        let payload = match fbs_message.payload_type() {
+            FbsKademliaPayload::FindNode => {
+                let fbs_find_node = FbsFindNode::init_from_table(payload);
+                KademliaPayload::FindNode {
+                    key: fbs_find_node.key()?.to_vec(),
+                }
+            }
+            FbsKademliaPayload::Nodes => {
+                let fbs_nodes = FbsNodes::init_from_table(payload);
+                KademliaPayload::Nodes {
+                    closer_peers: peers_from_fbs(fbs_nodes.closer_peers())?,
+                }
+            }
+            FbsKademliaPayload::GetValue => {
+                let fbs_get_value = FbsGetValue::init_from_table(payload);
+                KademliaPayload::GetValue {
+                    key: fbs_get_value.key()?.to_vec(),
+                }
+            }
+            FbsKademliaPayload::Value => {
+                let fbs_value = FbsValue::init_from_table(payload);
+                let record = match fbs_value.record() {
+                    Some(fbs_record) => Some(record_from_fbs(fbs_record)?),
+                    None => None,
+                };
+                KademliaPayload::Value {
+                    record,
+                    closer_peers: peers_from_fbs(fbs_value.closer_peers())?,
+                }
+            }
+            FbsKademliaPayload::PutValue => {
+                let fbs_put_value = FbsPutValue::init_from_table(payload);
+                KademliaPayload::PutValue {
+                    record: record_from_fbs(fbs_put_value.record()?)?,
+                }
+            }
+            FbsKademliaPayload::AddProvider => {
+                let fbs_add_provider = FbsAddProvider::init_from_table(payload);
+                KademliaPayload::AddProvider {
+                    key: fbs_add_provider.key()?.to_vec(),
+                    provider: peer_from_fbs(fbs_add_provider.provider()?)?,
+                }
+            }
+            FbsKademliaPayload::GetProviders => {
+                let fbs_get_providers = FbsGetProviders::init_from_table(payload);
+                KademliaPayload::GetProviders {
+                    key: fbs_get_providers.key()?.to_vec(),
+                }
+            }
+            FbsKademliaPayload::Providers => {
+                let fbs_providers = FbsProviders::init_from_table(payload);
+                KademliaPayload::Providers {
+                    providers: peers_from_fbs(fbs_providers.providers())?,
+                    closer_peers: peers_from_fbs(fbs_providers.closer_peers())?,
+                }
+            }
+            FbsKademliaPayload::NONE => return None,
+        };
+        Some(KademliaMessage {
+            request_id: fbs_message.request_id(),
+            payload,
+        })
+    }
+
+    #[cfg(feature = "molc")]
+    pub fn encode(&self) -> Bytes {
+        let payload = match &self.payload {
+            KademliaPayload::FindNode { key } => {
+                let find_node = protocol_mol::FindNode::new_builder()
+                    .key(mol_bytes(key))
+                    .build();
+                protocol_mol::KademliaPayload::new_builder()
+                    .set(find_node)
+                    .build()
+            }
+            KademliaPayload::Nodes { closer_peers } => {
+                let nodes = protocol_mol::Nodes::new_builder()
+                    .closer_peers(mol_peers(closer_peers))
+                    .build();
+                protocol_mol::KademliaPayload::new_builder()
+                    .set(nodes)
+                    .build()
+            }
+            KademliaPayload::GetValue { key } => {
+                let get_value = protocol_mol::GetValue::new_builder()
+                    .key(mol_bytes(key))
+                    .build();
+                protocol_mol::KademliaPayload::new_builder()
+                    .set(get_value)
+                    .build()
+            }
+            KademliaPayload::Value {
+                record,
+                closer_peers,
+            } => {
+                let record = protocol_mol::RecordOpt::new_builder()
+                    .set(record.as_ref().map(mol_record))
+                    .build();
+                let value = protocol_mol::Value::new_builder()
+                    .record(record)
+                    .closer_peers(mol_peers(closer_peers))
+                    .build();
+                protocol_mol::KademliaPayload::new_builder()
+                    .set(value)
+                    .build()
+            }
+            KademliaPayload::PutValue { record } => {
+                let put_value = protocol_mol::PutValue::new_builder()
+                    .record(mol_record(record))
+                    .build();
+                protocol_mol::KademliaPayload::new_builder()
+                    .set(put_value)
+                    .build()
+            }
+            KademliaPayload::AddProvider { key, provider } => {
+                let add_provider = protocol_mol::AddProvider::new_builder()
+                    .key(mol_bytes(key))
+                    .provider(mol_peer(provider))
+                    .build();
+                protocol_mol::KademliaPayload::new_builder()
+                    .set(add_provider)
+                    .build()
+            }
+            KademliaPayload::GetProviders { key } => {
+                let get_providers = protocol_mol::GetProviders::new_builder()
+                    .key(mol_bytes(key))
+                    .build();
+                protocol_mol::KademliaPayload::new_builder()
+                    .set(get_providers)
+                    .build()
+            }
+            KademliaPayload::Providers {
+                providers,
+                closer_peers,
+            } => {
+                let providers = protocol_mol::Providers::new_builder()
+                    .providers(mol_peers(providers))
+                    .closer_peers(mol_peers(closer_peers))
+                    .build();
+                protocol_mol::KademliaPayload::new_builder()
+                    .set(providers)
+                    .build()
+            }
+        };
+
+        let request_id_le = self.request_id.to_le_bytes();
+        let request_id = protocol_mol::Uint64::new_builder()
+            .nth0(request_id_le[0].into())
+            .nth1(request_id_le[1].into())
+            .nth2(request_id_le[2].into())
+            .nth3(request_id_le[3].into())
+            .nth4(request_id_le[4].into())
+            .nth5(request_id_le[5].into())
+            .nth6(request_id_le[6].into())
+            .nth7(request_id_le[7].into())
+            .build();
+        protocol_mol::KademliaMessage::new_builder()
+            .request_id(request_id)
+            .payload(payload)
+            .build()
+            .as_bytes()
+    }
+
+    #[cfg(feature = "molc")]
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let reader = protocol_mol::KademliaMessageReader::from_compatible_slice(data).ok()?;
+        let mut request_id_le = [0u8; 8];
+        request_id_le.copy_from_slice(reader.request_id().raw_data());
+        let payload = match reader.payload().to_enum() {
+            protocol_mol::KademliaPayloadUnionReader::FindNode(reader) => {
+                KademliaPayload::FindNode {
+                    key: reader.key().raw_data().to_vec(),
+                }
+            }
+            protocol_mol::KademliaPayloadUnionReader::Nodes(reader) => KademliaPayload::Nodes {
+                closer_peers: peers_from_mol(reader.closer_peers())?,
+            },
+            protocol_mol::KademliaPayloadUnionReader::GetValue(reader) => {
+                KademliaPayload::GetValue {
+                    key: reader.key().raw_data().to_vec(),
+                }
+            }
+            protocol_mol::KademliaPayloadUnionReader::Value(reader) => KademliaPayload::Value {
+                record: reader.record().to_opt().map(record_from_mol),
+                closer_peers: peers_from_mol(reader.closer_peers())?,
+            },
+            protocol_mol::KademliaPayloadUnionReader::PutValue(reader) => {
+                KademliaPayload::PutValue {
+                    record: record_from_mol(reader.record()),
+                }
+            }
+            protocol_mol::KademliaPayloadUnionReader::AddProvider(reader) => {
+                KademliaPayload::AddProvider {
+                    key: reader.key().raw_data().to_vec(),
+                    provider: peer_from_mol(reader.provider())?,
+                }
+            }
+            protocol_mol::KademliaPayloadUnionReader::GetProviders(reader) => {
+                KademliaPayload::GetProviders {
+                    key: reader.key().raw_data().to_vec(),
+                }
+            }
+            protocol_mol::KademliaPayloadUnionReader::Providers(reader) => {
+                KademliaPayload::Providers {
+                    providers: peers_from_mol(reader.providers())?,
+                    closer_peers: peers_from_mol(reader.closer_peers())?,
+                }
+            }
+        };
+        Some(KademliaMessage {
+            request_id: u64::from_le_bytes(request_id_le),
+            payload,
+        })
+    }
+}
+
+impl std::fmt::Display for KademliaMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match &self.payload {
+            KademliaPayload::FindNode { .. } => {
+                write!(f, "KademliaMessage::FindNode(id:{})", self.request_id)
+            }
+            KademliaPayload::Nodes { closer_peers } => write!(
+                f,
+                "KademliaMessage::Nodes(id:{}, closer_peers.length:{})",
+                self.request_id,
+                closer_peers.len()
+            ),
+            KademliaPayload::GetValue { .. } => {
+                write!(f, "KademliaMessage::GetValue(id:{})", self.request_id)
+            }
+            KademliaPayload::Value {
+                record,
+                closer_peers,
+            } => write!(
+                f,
+                "KademliaMessage::Value(id:{}, found:{}, closer_peers.length:{})",
+                self.request_id,
+                record.is_some(),
+                closer_peers.len()
+            ),
+            KademliaPayload::PutValue { .. } => {
+                write!(f, "KademliaMessage::PutValue(id:{})", self.request_id)
+            }
+            KademliaPayload::AddProvider { .. } => {
+                write!(f, "KademliaMessage::AddProvider(id:{})", self.request_id)
+            }
+            KademliaPayload::GetProviders { .. } => {
+                write!(f, "KademliaMessage::GetProviders(id:{})", self.request_id)
+            }
+            KademliaPayload::Providers {
+                providers,
+                closer_peers,
+            } => write!(
+                f,
+                "KademliaMessage::Providers(id:{}, providers.length:{}, closer_peers.length:{})",
+                self.request_id,
+                providers.len(),
+                closer_peers.len()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KademliaMessage, KademliaPayload, PeerInfo};
+    use crate::record::Record;
+    use p2p::secio::PeerId;
+
+    fn peer() -> PeerInfo {
+        PeerInfo {
+            id: PeerId::random(),
+            addresses: vec!["/ip4/127.0.0.1/tcp/1337".parse().unwrap()],
+        }
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let record = Record::new(b"key".to_vec(), b"value".to_vec());
+        let payloads = vec![
+            KademliaPayload::FindNode {
+                key: b"key".to_vec(),
+            },
+            KademliaPayload::Nodes {
+                closer_peers: vec![peer(), peer()],
+            },
+            KademliaPayload::GetValue {
+                key: b"key".to_vec(),
+            },
+            KademliaPayload::Value {
+                record: Some(record.clone()),
+                closer_peers: Vec::new(),
+            },
+            KademliaPayload::Value {
+                record: None,
+                closer_peers: vec![peer()],
+            },
+            KademliaPayload::PutValue { record },
+            KademliaPayload::AddProvider {
+                key: b"key".to_vec(),
+                provider: peer(),
+            },
+            KademliaPayload::GetProviders {
+                key: b"key".to_vec(),
+            },
+            KademliaPayload::Providers {
+                providers: vec![peer()],
+                closer_peers: vec![peer()],
+            },
+        ];
+        for (i, payload) in payloads.into_iter().enumerate() {
+            let message = KademliaMessage::new(u64::max_value() - i as u64, payload);
+            assert_eq!(KademliaMessage::decode(&message.encode()), Some(message));
+        }
+    }
+}
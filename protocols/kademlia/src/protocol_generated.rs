@@ -0,0 +1,1183 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+pub mod p2p {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+pub mod kademlia {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+
+  use std::mem;
+  use std::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::EndianScalar;
+
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum KademliaPayload {
+  NONE = 0,
+  FindNode = 1,
+  Nodes = 2,
+  GetValue = 3,
+  Value = 4,
+  PutValue = 5,
+  AddProvider = 6,
+  GetProviders = 7,
+  Providers = 8,
+
+}
+
+const ENUM_MIN_KADEMLIA_PAYLOAD: u8 = 0;
+const ENUM_MAX_KADEMLIA_PAYLOAD: u8 = 8;
+
+impl<'a> flatbuffers::Follow<'a> for KademliaPayload {
+  type Inner = Self;
+  #[inline]
+  fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::read_scalar_at::<Self>(buf, loc)
+  }
+}
+
+impl flatbuffers::EndianScalar for KademliaPayload {
+  #[inline]
+  fn to_little_endian(self) -> Self {
+    let n = u8::to_le(self as u8);
+    let p = &n as *const u8 as *const KademliaPayload;
+    unsafe { *p }
+  }
+  #[inline]
+  fn from_little_endian(self) -> Self {
+    let n = u8::from_le(self as u8);
+    let p = &n as *const u8 as *const KademliaPayload;
+    unsafe { *p }
+  }
+}
+
+impl flatbuffers::Push for KademliaPayload {
+    type Output = KademliaPayload;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        flatbuffers::emplace_scalar::<KademliaPayload>(dst, *self);
+    }
+}
+
+#[allow(non_camel_case_types)]
+const ENUM_VALUES_KADEMLIA_PAYLOAD:[KademliaPayload; 9] = [
+  KademliaPayload::NONE,
+  KademliaPayload::FindNode,
+  KademliaPayload::Nodes,
+  KademliaPayload::GetValue,
+  KademliaPayload::Value,
+  KademliaPayload::PutValue,
+  KademliaPayload::AddProvider,
+  KademliaPayload::GetProviders,
+  KademliaPayload::Providers
+];
+
+#[allow(non_camel_case_types)]
+const ENUM_NAMES_KADEMLIA_PAYLOAD:[&'static str; 9] = [
+    "NONE",
+    "FindNode",
+    "Nodes",
+    "GetValue",
+    "Value",
+    "PutValue",
+    "AddProvider",
+    "GetProviders",
+    "Providers"
+];
+
+pub fn enum_name_kademlia_payload(e: KademliaPayload) -> &'static str {
+  let index: usize = e as usize;
+  ENUM_NAMES_KADEMLIA_PAYLOAD[index]
+}
+
+pub struct KademliaPayloadUnionTableOffset {}
+pub enum KademliaMessageOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct KademliaMessage<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for KademliaMessage<'a> {
+    type Inner = KademliaMessage<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> KademliaMessage<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        KademliaMessage {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args KademliaMessageArgs) -> flatbuffers::WIPOffset<KademliaMessage<'bldr>> {
+      let mut builder = KademliaMessageBuilder::new(_fbb);
+      builder.add_request_id(args.request_id);
+      if let Some(x) = args.payload { builder.add_payload(x); }
+      builder.add_payload_type(args.payload_type);
+      builder.finish()
+    }
+
+    pub const VT_REQUEST_ID: flatbuffers::VOffsetT = 4;
+    pub const VT_PAYLOAD_TYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_PAYLOAD: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub fn request_id(&self) -> u64 {
+    self._tab.get::<u64>(KademliaMessage::VT_REQUEST_ID, Some(0)).unwrap()
+  }
+  #[inline]
+  pub fn payload_type(&self) -> KademliaPayload {
+    self._tab.get::<KademliaPayload>(KademliaMessage::VT_PAYLOAD_TYPE, Some(KademliaPayload::NONE)).unwrap()
+  }
+  #[inline]
+  pub fn payload(&self) -> Option<flatbuffers::Table<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(KademliaMessage::VT_PAYLOAD, None)
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_find_node(&'a self) -> Option<FindNode> {
+    if self.payload_type() == KademliaPayload::FindNode {
+      self.payload().map(|u| FindNode::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_nodes(&'a self) -> Option<Nodes> {
+    if self.payload_type() == KademliaPayload::Nodes {
+      self.payload().map(|u| Nodes::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_value(&'a self) -> Option<GetValue> {
+    if self.payload_type() == KademliaPayload::GetValue {
+      self.payload().map(|u| GetValue::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_value(&'a self) -> Option<Value> {
+    if self.payload_type() == KademliaPayload::Value {
+      self.payload().map(|u| Value::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_put_value(&'a self) -> Option<PutValue> {
+    if self.payload_type() == KademliaPayload::PutValue {
+      self.payload().map(|u| PutValue::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_add_provider(&'a self) -> Option<AddProvider> {
+    if self.payload_type() == KademliaPayload::AddProvider {
+      self.payload().map(|u| AddProvider::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_get_providers(&'a self) -> Option<GetProviders> {
+    if self.payload_type() == KademliaPayload::GetProviders {
+      self.payload().map(|u| GetProviders::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn payload_as_providers(&'a self) -> Option<Providers> {
+    if self.payload_type() == KademliaPayload::Providers {
+      self.payload().map(|u| Providers::init_from_table(u))
+    } else {
+      None
+    }
+  }
+
+}
+
+pub struct KademliaMessageArgs {
+    pub request_id: u64,
+    pub payload_type: KademliaPayload,
+    pub payload: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+}
+impl<'a> Default for KademliaMessageArgs {
+    #[inline]
+    fn default() -> Self {
+        KademliaMessageArgs {
+            request_id: 0,
+            payload_type: KademliaPayload::NONE,
+            payload: None,
+        }
+    }
+}
+pub struct KademliaMessageBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> KademliaMessageBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_request_id(&mut self, request_id: u64) {
+    self.fbb_.push_slot::<u64>(KademliaMessage::VT_REQUEST_ID, request_id, 0);
+  }
+  #[inline]
+  pub fn add_payload_type(&mut self, payload_type: KademliaPayload) {
+    self.fbb_.push_slot::<KademliaPayload>(KademliaMessage::VT_PAYLOAD_TYPE, payload_type, KademliaPayload::NONE);
+  }
+  #[inline]
+  pub fn add_payload(&mut self, payload: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(KademliaMessage::VT_PAYLOAD, payload);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> KademliaMessageBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    KademliaMessageBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<KademliaMessage<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum PeerOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Peer<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Peer<'a> {
+    type Inner = Peer<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Peer<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Peer {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PeerArgs<'args>) -> flatbuffers::WIPOffset<Peer<'bldr>> {
+      let mut builder = PeerBuilder::new(_fbb);
+      if let Some(x) = args.addresses { builder.add_addresses(x); }
+      if let Some(x) = args.id { builder.add_id(x); }
+      builder.finish()
+    }
+
+    pub const VT_ID: flatbuffers::VOffsetT = 4;
+    pub const VT_ADDRESSES: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn id(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Peer::VT_ID, None).map(|v| v.safe_slice())
+  }
+  #[inline]
+  pub fn addresses(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Bytes<'a>>>>>(Peer::VT_ADDRESSES, None)
+  }
+}
+
+pub struct PeerArgs<'a> {
+    pub id: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+    pub addresses: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Bytes<'a >>>>>,
+}
+impl<'a> Default for PeerArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        PeerArgs {
+            id: None,
+            addresses: None,
+        }
+    }
+}
+pub struct PeerBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PeerBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Peer::VT_ID, id);
+  }
+  #[inline]
+  pub fn add_addresses(&mut self, addresses: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Bytes<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Peer::VT_ADDRESSES, addresses);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PeerBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PeerBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Peer<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum RecordOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Record<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Record<'a> {
+    type Inner = Record<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Record<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Record {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args RecordArgs<'args>) -> flatbuffers::WIPOffset<Record<'bldr>> {
+      let mut builder = RecordBuilder::new(_fbb);
+      if let Some(x) = args.value { builder.add_value(x); }
+      if let Some(x) = args.key { builder.add_key(x); }
+      builder.finish()
+    }
+
+    pub const VT_KEY: flatbuffers::VOffsetT = 4;
+    pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn key(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Record::VT_KEY, None).map(|v| v.safe_slice())
+  }
+  #[inline]
+  pub fn value(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Record::VT_VALUE, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct RecordArgs<'a> {
+    pub key: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+    pub value: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for RecordArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        RecordArgs {
+            key: None,
+            value: None,
+        }
+    }
+}
+pub struct RecordBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> RecordBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_key(&mut self, key: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Record::VT_KEY, key);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Record::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> RecordBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    RecordBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Record<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum FindNodeOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct FindNode<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FindNode<'a> {
+    type Inner = FindNode<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> FindNode<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        FindNode {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args FindNodeArgs<'args>) -> flatbuffers::WIPOffset<FindNode<'bldr>> {
+      let mut builder = FindNodeBuilder::new(_fbb);
+      if let Some(x) = args.key { builder.add_key(x); }
+      builder.finish()
+    }
+
+    pub const VT_KEY: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn key(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(FindNode::VT_KEY, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct FindNodeArgs<'a> {
+    pub key: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for FindNodeArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        FindNodeArgs {
+            key: None,
+        }
+    }
+}
+pub struct FindNodeBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FindNodeBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_key(&mut self, key: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FindNode::VT_KEY, key);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FindNodeBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    FindNodeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FindNode<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum NodesOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Nodes<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Nodes<'a> {
+    type Inner = Nodes<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Nodes<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Nodes {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args NodesArgs<'args>) -> flatbuffers::WIPOffset<Nodes<'bldr>> {
+      let mut builder = NodesBuilder::new(_fbb);
+      if let Some(x) = args.closer_peers { builder.add_closer_peers(x); }
+      builder.finish()
+    }
+
+    pub const VT_CLOSER_PEERS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn closer_peers(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Peer<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Peer<'a>>>>>(Nodes::VT_CLOSER_PEERS, None)
+  }
+}
+
+pub struct NodesArgs<'a> {
+    pub closer_peers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Peer<'a >>>>>,
+}
+impl<'a> Default for NodesArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        NodesArgs {
+            closer_peers: None,
+        }
+    }
+}
+pub struct NodesBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> NodesBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_closer_peers(&mut self, closer_peers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Peer<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Nodes::VT_CLOSER_PEERS, closer_peers);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> NodesBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    NodesBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Nodes<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum GetValueOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct GetValue<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetValue<'a> {
+    type Inner = GetValue<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> GetValue<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        GetValue {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args GetValueArgs<'args>) -> flatbuffers::WIPOffset<GetValue<'bldr>> {
+      let mut builder = GetValueBuilder::new(_fbb);
+      if let Some(x) = args.key { builder.add_key(x); }
+      builder.finish()
+    }
+
+    pub const VT_KEY: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn key(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(GetValue::VT_KEY, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct GetValueArgs<'a> {
+    pub key: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for GetValueArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        GetValueArgs {
+            key: None,
+        }
+    }
+}
+pub struct GetValueBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GetValueBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_key(&mut self, key: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GetValue::VT_KEY, key);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GetValueBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    GetValueBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetValue<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum ValueOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Value<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Value<'a> {
+    type Inner = Value<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Value<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Value {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ValueArgs<'args>) -> flatbuffers::WIPOffset<Value<'bldr>> {
+      let mut builder = ValueBuilder::new(_fbb);
+      if let Some(x) = args.closer_peers { builder.add_closer_peers(x); }
+      if let Some(x) = args.record { builder.add_record(x); }
+      builder.finish()
+    }
+
+    pub const VT_RECORD: flatbuffers::VOffsetT = 4;
+    pub const VT_CLOSER_PEERS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn record(&self) -> Option<Record<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<Record<'a>>>(Value::VT_RECORD, None)
+  }
+  #[inline]
+  pub fn closer_peers(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Peer<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Peer<'a>>>>>(Value::VT_CLOSER_PEERS, None)
+  }
+}
+
+pub struct ValueArgs<'a> {
+    pub record: Option<flatbuffers::WIPOffset<Record<'a >>>,
+    pub closer_peers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Peer<'a >>>>>,
+}
+impl<'a> Default for ValueArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ValueArgs {
+            record: None,
+            closer_peers: None,
+        }
+    }
+}
+pub struct ValueBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ValueBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_record(&mut self, record: flatbuffers::WIPOffset<Record<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Record>>(Value::VT_RECORD, record);
+  }
+  #[inline]
+  pub fn add_closer_peers(&mut self, closer_peers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Peer<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Value::VT_CLOSER_PEERS, closer_peers);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ValueBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ValueBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Value<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum PutValueOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct PutValue<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PutValue<'a> {
+    type Inner = PutValue<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> PutValue<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        PutValue {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PutValueArgs<'args>) -> flatbuffers::WIPOffset<PutValue<'bldr>> {
+      let mut builder = PutValueBuilder::new(_fbb);
+      if let Some(x) = args.record { builder.add_record(x); }
+      builder.finish()
+    }
+
+    pub const VT_RECORD: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn record(&self) -> Option<Record<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<Record<'a>>>(PutValue::VT_RECORD, None)
+  }
+}
+
+pub struct PutValueArgs<'a> {
+    pub record: Option<flatbuffers::WIPOffset<Record<'a >>>,
+}
+impl<'a> Default for PutValueArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        PutValueArgs {
+            record: None,
+        }
+    }
+}
+pub struct PutValueBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PutValueBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_record(&mut self, record: flatbuffers::WIPOffset<Record<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Record>>(PutValue::VT_RECORD, record);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PutValueBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    PutValueBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PutValue<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum AddProviderOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct AddProvider<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AddProvider<'a> {
+    type Inner = AddProvider<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> AddProvider<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        AddProvider {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args AddProviderArgs<'args>) -> flatbuffers::WIPOffset<AddProvider<'bldr>> {
+      let mut builder = AddProviderBuilder::new(_fbb);
+      if let Some(x) = args.provider { builder.add_provider(x); }
+      if let Some(x) = args.key { builder.add_key(x); }
+      builder.finish()
+    }
+
+    pub const VT_KEY: flatbuffers::VOffsetT = 4;
+    pub const VT_PROVIDER: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn key(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(AddProvider::VT_KEY, None).map(|v| v.safe_slice())
+  }
+  #[inline]
+  pub fn provider(&self) -> Option<Peer<'a>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<Peer<'a>>>(AddProvider::VT_PROVIDER, None)
+  }
+}
+
+pub struct AddProviderArgs<'a> {
+    pub key: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+    pub provider: Option<flatbuffers::WIPOffset<Peer<'a >>>,
+}
+impl<'a> Default for AddProviderArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        AddProviderArgs {
+            key: None,
+            provider: None,
+        }
+    }
+}
+pub struct AddProviderBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> AddProviderBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_key(&mut self, key: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AddProvider::VT_KEY, key);
+  }
+  #[inline]
+  pub fn add_provider(&mut self, provider: flatbuffers::WIPOffset<Peer<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Peer>>(AddProvider::VT_PROVIDER, provider);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AddProviderBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    AddProviderBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AddProvider<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum GetProvidersOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct GetProviders<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GetProviders<'a> {
+    type Inner = GetProviders<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> GetProviders<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        GetProviders {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args GetProvidersArgs<'args>) -> flatbuffers::WIPOffset<GetProviders<'bldr>> {
+      let mut builder = GetProvidersBuilder::new(_fbb);
+      if let Some(x) = args.key { builder.add_key(x); }
+      builder.finish()
+    }
+
+    pub const VT_KEY: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn key(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(GetProviders::VT_KEY, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct GetProvidersArgs<'a> {
+    pub key: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for GetProvidersArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        GetProvidersArgs {
+            key: None,
+        }
+    }
+}
+pub struct GetProvidersBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> GetProvidersBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_key(&mut self, key: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(GetProviders::VT_KEY, key);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> GetProvidersBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    GetProvidersBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GetProviders<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum ProvidersOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Providers<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Providers<'a> {
+    type Inner = Providers<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Providers<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Providers {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ProvidersArgs<'args>) -> flatbuffers::WIPOffset<Providers<'bldr>> {
+      let mut builder = ProvidersBuilder::new(_fbb);
+      if let Some(x) = args.closer_peers { builder.add_closer_peers(x); }
+      if let Some(x) = args.providers { builder.add_providers(x); }
+      builder.finish()
+    }
+
+    pub const VT_PROVIDERS: flatbuffers::VOffsetT = 4;
+    pub const VT_CLOSER_PEERS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub fn providers(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Peer<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Peer<'a>>>>>(Providers::VT_PROVIDERS, None)
+  }
+  #[inline]
+  pub fn closer_peers(&self) -> Option<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Peer<'a>>>> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<Peer<'a>>>>>(Providers::VT_CLOSER_PEERS, None)
+  }
+}
+
+pub struct ProvidersArgs<'a> {
+    pub providers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Peer<'a >>>>>,
+    pub closer_peers: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a , flatbuffers::ForwardsUOffset<Peer<'a >>>>>,
+}
+impl<'a> Default for ProvidersArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ProvidersArgs {
+            providers: None,
+            closer_peers: None,
+        }
+    }
+}
+pub struct ProvidersBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ProvidersBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_providers(&mut self, providers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Peer<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Providers::VT_PROVIDERS, providers);
+  }
+  #[inline]
+  pub fn add_closer_peers(&mut self, closer_peers: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Peer<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Providers::VT_CLOSER_PEERS, closer_peers);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ProvidersBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    ProvidersBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Providers<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+pub enum BytesOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Bytes<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Bytes<'a> {
+    type Inner = Bytes<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Bytes<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Bytes {
+            _tab: table,
+        }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args BytesArgs<'args>) -> flatbuffers::WIPOffset<Bytes<'bldr>> {
+      let mut builder = BytesBuilder::new(_fbb);
+      if let Some(x) = args.seq { builder.add_seq(x); }
+      builder.finish()
+    }
+
+    pub const VT_SEQ: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub fn seq(&self) -> Option<&'a [u8]> {
+    self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(Bytes::VT_SEQ, None).map(|v| v.safe_slice())
+  }
+}
+
+pub struct BytesArgs<'a> {
+    pub seq: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a ,  u8>>>,
+}
+impl<'a> Default for BytesArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        BytesArgs {
+            seq: None,
+        }
+    }
+}
+pub struct BytesBuilder<'a: 'b, 'b> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BytesBuilder<'a, 'b> {
+  #[inline]
+  pub fn add_seq(&mut self, seq: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Bytes::VT_SEQ, seq);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BytesBuilder<'a, 'b> {
+    let start = _fbb.start_table();
+    BytesBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Bytes<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+}  // pub mod Kademlia
+}  // pub mod P2P
+
@@ -0,0 +1,304 @@
+use std::time::{Duration, Instant};
+
+use futures::sync::oneshot;
+use p2p::secio::PeerId;
+
+use crate::{
+    kbucket::{cmp_distance, Key},
+    protocol::{KademliaPayload, PeerInfo},
+    record::Record,
+};
+
+pub(crate) type QueryId = u64;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct QueryConfig {
+    /// The number of closest peers a query looks for, the `k` of Kademlia
+    pub(crate) replication: usize,
+    /// The number of requests in flight for a query, the `alpha` of Kademlia
+    pub(crate) parallelism: usize,
+    /// A peer not responding in time is considered failed
+    pub(crate) request_timeout: Duration,
+    /// A query is finished with what it found after this time
+    pub(crate) timeout: Duration,
+}
+
+/// What to do with the peers found by a query
+pub(crate) enum QueryKind {
+    FindNode {
+        sender: oneshot::Sender<Vec<PeerInfo>>,
+    },
+    GetValue {
+        sender: oneshot::Sender<Option<Record>>,
+    },
+    PutValue {
+        record: Record,
+        sender: oneshot::Sender<usize>,
+    },
+    AddProvider {
+        sender: oneshot::Sender<usize>,
+    },
+    GetProviders {
+        providers: Vec<PeerInfo>,
+        sender: oneshot::Sender<Vec<PeerInfo>>,
+    },
+}
+
+pub(crate) struct Query {
+    pub(crate) key: Vec<u8>,
+    pub(crate) kind: QueryKind,
+    pub(crate) peers: ClosestPeersIter,
+}
+
+impl Query {
+    pub(crate) fn new(
+        key: Vec<u8>,
+        kind: QueryKind,
+        known_peers: Vec<PeerInfo>,
+        config: QueryConfig,
+    ) -> Self {
+        let peers = ClosestPeersIter::new(Key::new(&key), known_peers, config);
+        Query { key, kind, peers }
+    }
+
+    /// The request sent to peers during the lookup
+    pub(crate) fn request(&self) -> KademliaPayload {
+        let key = self.key.clone();
+        match self.kind {
+            QueryKind::GetValue { .. } => KademliaPayload::GetValue { key },
+            QueryKind::GetProviders { .. } => KademliaPayload::GetProviders { key },
+            _ => KademliaPayload::FindNode { key },
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum PeerState {
+    NotContacted,
+    Waiting(Instant),
+    Succeeded,
+    Failed,
+}
+
+struct QueryPeer {
+    info: PeerInfo,
+    key: Key,
+    state: PeerState,
+}
+
+/// Iterative lookup of the peers closest to a target
+///
+/// Peers are contacted closest first, at most `parallelism` at a time, and the
+/// lookup finishes once the `replication` closest peers not failed have all responded
+pub(crate) struct ClosestPeersIter {
+    target: Key,
+    peers: Vec<QueryPeer>,
+    config: QueryConfig,
+    started: Instant,
+}
+
+impl ClosestPeersIter {
+    pub(crate) fn new(target: Key, known_peers: Vec<PeerInfo>, config: QueryConfig) -> Self {
+        let mut iter = ClosestPeersIter {
+            target,
+            peers: Vec::with_capacity(known_peers.len()),
+            config,
+            started: Instant::now(),
+        };
+        iter.insert_peers(known_peers);
+        iter
+    }
+
+    fn insert_peers(&mut self, peers: Vec<PeerInfo>) {
+        for info in peers {
+            if self.peers.iter().any(|peer| peer.info.id == info.id) {
+                continue;
+            }
+            let key = Key::from(&info.id);
+            let target = &self.target;
+            let position = self
+                .peers
+                .binary_search_by(|peer| cmp_distance(target, &peer.key, &key))
+                .unwrap_or_else(|position| position);
+            self.peers.insert(
+                position,
+                QueryPeer {
+                    info,
+                    key,
+                    state: PeerState::NotContacted,
+                },
+            );
+        }
+    }
+
+    /// Peer responded, the closer peers it knows join the lookup
+    ///
+    /// At most `replication` peers of a response are taken, or a peer could flood the lookup
+    pub(crate) fn on_success(&mut self, peer_id: &PeerId, mut closer_peers: Vec<PeerInfo>) {
+        if let Some(peer) = self.peers.iter_mut().find(|peer| &peer.info.id == peer_id) {
+            peer.state = PeerState::Succeeded;
+        }
+        closer_peers.truncate(self.config.replication);
+        self.insert_peers(closer_peers);
+    }
+
+    pub(crate) fn on_failure(&mut self, peer_id: &PeerId) {
+        if let Some(peer) = self.peers.iter_mut().find(|peer| &peer.info.id == peer_id) {
+            peer.state = PeerState::Failed;
+        }
+    }
+
+    /// The peers to send a request to now
+    pub(crate) fn next(&mut self, now: Instant) -> Vec<PeerInfo> {
+        if self.is_timeout(now) {
+            return Vec::new();
+        }
+
+        let request_timeout = self.config.request_timeout;
+        let mut waiting = 0;
+        for peer in self.peers.iter_mut() {
+            if let PeerState::Waiting(start) = peer.state {
+                if now.duration_since(start) >= request_timeout {
+                    peer.state = PeerState::Failed;
+                } else {
+                    waiting += 1;
+                }
+            }
+        }
+
+        let parallelism = self.config.parallelism;
+        let mut to_contact = Vec::new();
+        for peer in self
+            .peers
+            .iter_mut()
+            .filter(|peer| peer.state != PeerState::Failed)
+            .take(self.config.replication)
+        {
+            if waiting >= parallelism {
+                break;
+            }
+            if peer.state == PeerState::NotContacted {
+                peer.state = PeerState::Waiting(now);
+                waiting += 1;
+                to_contact.push(peer.info.clone());
+            }
+        }
+        to_contact
+    }
+
+    fn is_timeout(&self, now: Instant) -> bool {
+        now.duration_since(self.started) >= self.config.timeout
+    }
+
+    pub(crate) fn is_finished(&self, now: Instant) -> bool {
+        self.is_timeout(now)
+            || self
+                .peers
+                .iter()
+                .filter(|peer| peer.state != PeerState::Failed)
+                .take(self.config.replication)
+                .all(|peer| peer.state == PeerState::Succeeded)
+    }
+
+    /// The closest peers that responded, nearest first
+    pub(crate) fn into_result(self) -> Vec<PeerInfo> {
+        let replication = self.config.replication;
+        self.peers
+            .into_iter()
+            .filter(|peer| peer.state == PeerState::Succeeded)
+            .take(replication)
+            .map(|peer| peer.info)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClosestPeersIter, QueryConfig};
+    use crate::{kbucket::Key, protocol::PeerInfo};
+    use p2p::secio::PeerId;
+    use std::time::{Duration, Instant};
+
+    fn config() -> QueryConfig {
+        QueryConfig {
+            replication: 3,
+            parallelism: 2,
+            request_timeout: Duration::from_secs(10),
+            timeout: Duration::from_secs(60),
+        }
+    }
+
+    fn peers(n: usize) -> Vec<PeerInfo> {
+        (0..n)
+            .map(|_| PeerInfo {
+                id: PeerId::random(),
+                addresses: Vec::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_lookup_converge() {
+        let target = Key::new(b"target");
+        let mut iter = ClosestPeersIter::new(target.clone(), peers(4), config());
+        let now = Instant::now();
+
+        let first = iter.next(now);
+        assert_eq!(first.len(), 2);
+        // parallelism reached
+        assert!(iter.next(now).is_empty());
+        assert!(!iter.is_finished(now));
+
+        // the first peer knows closer peers, they are contacted before farther ones
+        let mut closer = peers(10);
+        closer.sort_by_key(|peer| target.distance(&Key::from(&peer.id)));
+        closer.truncate(3);
+        iter.on_success(&first[0].id, closer.clone());
+        iter.on_failure(&first[1].id);
+
+        let mut contacted = iter.next(now);
+        assert_eq!(contacted.len(), 2);
+        loop {
+            for peer in contacted.iter() {
+                iter.on_success(&peer.id, Vec::new());
+            }
+            contacted = iter.next(now);
+            if contacted.is_empty() {
+                break;
+            }
+        }
+        assert!(iter.is_finished(now));
+
+        let result = iter.into_result();
+        assert_eq!(result.len(), 3);
+        for pair in result.windows(2) {
+            assert!(
+                target.distance(&Key::from(&pair[0].id))
+                    <= target.distance(&Key::from(&pair[1].id))
+            );
+        }
+    }
+
+    #[test]
+    fn test_closer_peers_truncated() {
+        let mut iter = ClosestPeersIter::new(Key::new(b"target"), peers(1), config());
+        let now = Instant::now();
+        let first = iter.next(now);
+
+        iter.on_success(&first[0].id, peers(100));
+        assert_eq!(iter.peers.len(), 1 + config().replication);
+    }
+
+    #[test]
+    fn test_request_timeout() {
+        let mut iter = ClosestPeersIter::new(Key::new(b"target"), peers(1), config());
+        let now = Instant::now();
+        assert_eq!(iter.next(now).len(), 1);
+        assert!(!iter.is_finished(now));
+
+        let later = now + Duration::from_secs(11);
+        assert!(iter.next(later).is_empty());
+        assert!(iter.is_finished(later));
+        assert!(iter.into_result().is_empty());
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use p2p::secio::PeerId;
+
+use crate::protocol::PeerInfo;
+
+// Default: 1024
+const DEFAULT_MAX_RECORDS: usize = 1024;
+// Default: 64k
+const DEFAULT_MAX_VALUE_SIZE: usize = 64 * 1024;
+// Default: 20
+const DEFAULT_MAX_PROVIDERS_PER_KEY: usize = 20;
+// Default: 1024
+const DEFAULT_MAX_PROVIDER_KEYS: usize = 1024;
+// Default: 256
+const DEFAULT_MAX_KEY_LEN: usize = 256;
+// Records and provider records expire 36 hours after they were last stored
+const DEFAULT_RECORD_TTL: Duration = Duration::from_secs(36 * 3600);
+
+/// A value stored in the DHT
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Record {
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+impl Record {
+    pub fn new(key: Vec<u8>, value: Vec<u8>) -> Self {
+        Record { key, value }
+    }
+}
+
+/// Why a record was not stored
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StoreError {
+    /// The store reached its maximum number of records
+    MaxRecords,
+    /// The record value is larger than allowed
+    ValueTooLarge,
+    /// The key reached its maximum number of providers
+    MaxProviders,
+    /// The store reached its maximum number of keys with providers
+    MaxProviderKeys,
+    /// The key is longer than allowed
+    KeyTooLong,
+}
+
+/// In memory storage of records and provider records
+pub struct MemoryStore {
+    records: HashMap<Vec<u8>, (Record, Instant)>,
+    providers: HashMap<Vec<u8>, Vec<(PeerInfo, Instant)>>,
+    max_records: usize,
+    max_value_size: usize,
+    max_providers_per_key: usize,
+    max_provider_keys: usize,
+    max_key_len: usize,
+    ttl: Duration,
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        MemoryStore {
+            records: HashMap::default(),
+            providers: HashMap::default(),
+            max_records: DEFAULT_MAX_RECORDS,
+            max_value_size: DEFAULT_MAX_VALUE_SIZE,
+            max_providers_per_key: DEFAULT_MAX_PROVIDERS_PER_KEY,
+            max_provider_keys: DEFAULT_MAX_PROVIDER_KEYS,
+            max_key_len: DEFAULT_MAX_KEY_LEN,
+            ttl: DEFAULT_RECORD_TTL,
+        }
+    }
+}
+
+impl MemoryStore {
+    /// Maximum number of records stored, default is 1024
+    pub fn max_records(mut self, max_records: usize) -> Self {
+        self.max_records = max_records;
+        self
+    }
+
+    /// Maximum size of a record value, default is 64k
+    pub fn max_value_size(mut self, max_value_size: usize) -> Self {
+        self.max_value_size = max_value_size;
+        self
+    }
+
+    /// Maximum number of providers kept for one key, default is 20
+    pub fn max_providers_per_key(mut self, max_providers_per_key: usize) -> Self {
+        self.max_providers_per_key = max_providers_per_key;
+        self
+    }
+
+    /// Maximum number of keys with providers, default is 1024
+    pub fn max_provider_keys(mut self, max_provider_keys: usize) -> Self {
+        self.max_provider_keys = max_provider_keys;
+        self
+    }
+
+    /// Maximum length of a record or provider key, default is 256
+    pub fn max_key_len(mut self, max_key_len: usize) -> Self {
+        self.max_key_len = max_key_len;
+        self
+    }
+
+    /// How long records and provider records live after they were last stored, default is 36 hours
+    ///
+    /// Nothing is republished automatically, the owner has to put the record or start providing
+    /// again before it expires.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn get(&self, key: &[u8]) -> Option<&Record> {
+        self.records.get(key).map(|(record, _)| record)
+    }
+
+    /// Store a record, replacing the record with the same key
+    pub fn put(&mut self, record: Record) -> Result<(), StoreError> {
+        if record.key.len() > self.max_key_len {
+            return Err(StoreError::KeyTooLong);
+        }
+        if record.value.len() > self.max_value_size {
+            return Err(StoreError::ValueTooLarge);
+        }
+        if self.records.len() >= self.max_records && !self.records.contains_key(&record.key) {
+            return Err(StoreError::MaxRecords);
+        }
+        self.records
+            .insert(record.key.clone(), (record, Instant::now()));
+        Ok(())
+    }
+
+    pub fn remove(&mut self, key: &[u8]) -> Option<Record> {
+        self.records.remove(key).map(|(record, _)| record)
+    }
+
+    /// Add or refresh a provider of the key
+    pub fn add_provider(&mut self, key: Vec<u8>, provider: PeerInfo) -> Result<(), StoreError> {
+        if key.len() > self.max_key_len {
+            return Err(StoreError::KeyTooLong);
+        }
+        if self.providers.len() >= self.max_provider_keys && !self.providers.contains_key(&key) {
+            return Err(StoreError::MaxProviderKeys);
+        }
+        let max_providers_per_key = self.max_providers_per_key;
+        let providers = self.providers.entry(key).or_default();
+        if let Some(position) = providers
+            .iter()
+            .position(|(info, _)| info.id == provider.id)
+        {
+            providers[position] = (provider, Instant::now());
+            return Ok(());
+        }
+        if providers.len() >= max_providers_per_key {
+            return Err(StoreError::MaxProviders);
+        }
+        providers.push((provider, Instant::now()));
+        Ok(())
+    }
+
+    pub fn remove_provider(&mut self, key: &[u8], peer_id: &PeerId) {
+        if let Some(providers) = self.providers.get_mut(key) {
+            providers.retain(|(info, _)| &info.id != peer_id);
+            if providers.is_empty() {
+                self.providers.remove(key);
+            }
+        }
+    }
+
+    pub fn providers(&self, key: &[u8]) -> Vec<PeerInfo> {
+        self.providers
+            .get(key)
+            .map(|providers| providers.iter().map(|(info, _)| info.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Remove records and provider records older than ttl
+    pub fn remove_expired(&mut self) {
+        let ttl = self.ttl;
+        self.records.retain(|_, (_, time)| time.elapsed() < ttl);
+        self.providers.retain(|_, providers| {
+            providers.retain(|(_, time)| time.elapsed() < ttl);
+            !providers.is_empty()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemoryStore, Record, StoreError};
+    use crate::protocol::PeerInfo;
+    use p2p::secio::PeerId;
+    use std::time::Duration;
+
+    #[test]
+    fn test_put_limits() {
+        let mut store = MemoryStore::default().max_records(1).max_value_size(4);
+        assert_eq!(
+            store.put(Record::new(b"a".to_vec(), b"large value".to_vec())),
+            Err(StoreError::ValueTooLarge)
+        );
+        assert!(store.put(Record::new(b"a".to_vec(), b"1".to_vec())).is_ok());
+        assert_eq!(
+            store.put(Record::new(b"b".to_vec(), b"2".to_vec())),
+            Err(StoreError::MaxRecords)
+        );
+        // replace the same key is fine
+        assert!(store.put(Record::new(b"a".to_vec(), b"3".to_vec())).is_ok());
+        assert_eq!(store.get(b"a").unwrap().value, b"3".to_vec());
+
+        let mut store = MemoryStore::default().max_key_len(2);
+        assert_eq!(
+            store.put(Record::new(b"abc".to_vec(), b"1".to_vec())),
+            Err(StoreError::KeyTooLong)
+        );
+    }
+
+    #[test]
+    fn test_providers_and_expire() {
+        let mut store = MemoryStore::default().max_providers_per_key(1);
+        let provider = PeerInfo {
+            id: PeerId::random(),
+            addresses: Vec::new(),
+        };
+        let other = PeerInfo {
+            id: PeerId::random(),
+            addresses: Vec::new(),
+        };
+        assert!(store.add_provider(b"k".to_vec(), provider.clone()).is_ok());
+        assert!(store.add_provider(b"k".to_vec(), provider.clone()).is_ok());
+        assert_eq!(
+            store.add_provider(b"k".to_vec(), other),
+            Err(StoreError::MaxProviders)
+        );
+        assert_eq!(store.providers(b"k"), vec![provider.clone()]);
+
+        let mut limited = MemoryStore::default().max_provider_keys(1).max_key_len(2);
+        assert!(limited
+            .add_provider(b"k".to_vec(), provider.clone())
+            .is_ok());
+        assert_eq!(
+            limited.add_provider(b"j".to_vec(), provider.clone()),
+            Err(StoreError::MaxProviderKeys)
+        );
+        assert_eq!(
+            limited.add_provider(b"abc".to_vec(), provider),
+            Err(StoreError::KeyTooLong)
+        );
+
+        let mut store = store.ttl(Duration::from_secs(0));
+        store
+            .put(Record::new(b"a".to_vec(), b"1".to_vec()))
+            .unwrap();
+        store.remove_expired();
+        assert!(store.get(b"a").is_none());
+        assert!(store.providers(b"k").is_empty());
+    }
+}
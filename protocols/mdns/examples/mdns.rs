@@ -0,0 +1,64 @@
+use env_logger;
+use log::{debug, info};
+
+use std::net::Ipv4Addr;
+use std::sync::{Arc, Mutex};
+
+use futures::prelude::*;
+
+use p2p::{
+    builder::{MetaBuilder, ServiceBuilder},
+    context::ServiceContext,
+    secio::SecioKeyPair,
+    service::{ProtocolHandle, ProtocolMeta, ServiceError, ServiceEvent},
+    traits::ServiceHandle,
+    ProtocolId,
+};
+
+use discovery::AddrBook;
+use tentacle_mdns::{Config, Mdns, MdnsProtocol};
+
+// Run it twice with different ports, each process finds and dials the other on loopback:
+//   cargo run --example mdns --features molc -- 1337
+//   cargo run --example mdns --features molc -- 1338
+fn main() {
+    env_logger::init();
+    let port = std::env::args().nth(1).unwrap_or_else(|| "1337".to_owned());
+    let addr_book = Arc::new(Mutex::new(AddrBook::new()));
+    let mut service = ServiceBuilder::default()
+        .insert_protocol(create_meta(1.into(), Arc::clone(&addr_book)))
+        .key_pair(SecioKeyPair::secp256k1_generated())
+        .forever(true)
+        .build(SHandle {});
+
+    debug!("Starting on port {} ......", port);
+    let _ = service.listen(format!("/ip4/127.0.0.1/tcp/{}", port).parse().unwrap());
+    tokio::run(service.for_each(|_| Ok(())))
+}
+
+fn create_meta(id: ProtocolId, addr_book: Arc<Mutex<AddrBook>>) -> ProtocolMeta {
+    MetaBuilder::default()
+        .id(id)
+        .service_handle(move || {
+            let config = Config {
+                interface: Ipv4Addr::LOCALHOST,
+                dial: true,
+                ..Default::default()
+            };
+            let mdns = Mdns::new(addr_book, config);
+            ProtocolHandle::Callback(Box::new(MdnsProtocol::new(mdns)))
+        })
+        .build()
+}
+
+struct SHandle {}
+
+impl ServiceHandle for SHandle {
+    fn handle_error(&mut self, _env: &mut ServiceContext, error: ServiceError) {
+        debug!("service error: {:?}", error);
+    }
+
+    fn handle_event(&mut self, _env: &mut ServiceContext, event: ServiceEvent) {
+        info!("service event: {:?}", event);
+    }
+}
@@ -0,0 +1,369 @@
+//! The subset of DNS messages used by mDNS service discovery
+//!
+//! A query asks for the `PTR` records of the service name. A response answers with a `PTR`
+//! record pointing to the instance `<peer id>.<service name>`, and a `TXT` record of the instance
+//! holding one `dnsaddr=<multiaddr>` string per listen address.
+
+use std::str::FromStr;
+
+use p2p::{
+    multiaddr::{multihash::Multihash, Multiaddr, Protocol},
+    secio::PeerId,
+    utils::extract_peer_id,
+};
+
+const TYPE_PTR: u16 = 12;
+const TYPE_TXT: u16 = 16;
+const TYPE_ANY: u16 = 255;
+const CLASS_IN: u16 = 1;
+// The top bit of the class is the unicast response bit in questions and the cache flush bit
+// in records
+const CLASS_MASK: u16 = 0x7fff;
+const FLAG_RESPONSE: u16 = 0x8000;
+const FLAG_AUTHORITATIVE: u16 = 0x0400;
+
+const MAX_LABEL_LEN: usize = 63;
+const MAX_TXT_LEN: usize = 255;
+// Compression pointers followed in one name, bounds the loops of a malicious packet
+const MAX_POINTERS: usize = 16;
+const TXT_PREFIX: &str = "dnsaddr=";
+
+/// A decoded mDNS packet concerning the service
+#[derive(Debug, PartialEq)]
+pub enum Packet {
+    /// Someone is looking for the peers of the service
+    Query,
+    /// Peers of the service announced their addresses
+    Response(Vec<Peer>),
+}
+
+/// A peer found in a response
+#[derive(Debug, PartialEq)]
+pub struct Peer {
+    pub peer_id: PeerId,
+    /// Addresses of the peer, all ending with its peer id
+    pub addrs: Vec<Multiaddr>,
+    /// Seconds the addresses are valid, zero means the peer is leaving
+    pub ttl: u32,
+}
+
+/// Query the peers of the service
+pub fn encode_query(service: &str) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(64);
+    write_header(&mut buf, 0, 1, 0);
+    write_name(&mut buf, service);
+    buf.extend_from_slice(&TYPE_PTR.to_be_bytes());
+    buf.extend_from_slice(&CLASS_IN.to_be_bytes());
+    buf
+}
+
+/// Announce the addresses of the peer
+///
+/// Addresses longer than a `TXT` string are left out.
+pub fn encode_response(service: &str, peer_id: &PeerId, addrs: &[Multiaddr], ttl: u32) -> Vec<u8> {
+    let instance = format!("{}.{}", peer_id.to_base58(), service);
+    let mut buf = Vec::with_capacity(512);
+    write_header(&mut buf, FLAG_RESPONSE | FLAG_AUTHORITATIVE, 0, 2);
+
+    write_name(&mut buf, service);
+    write_record_header(&mut buf, TYPE_PTR, ttl);
+    let mut rdata = Vec::new();
+    write_name(&mut rdata, &instance);
+    write_rdata(&mut buf, &rdata);
+
+    write_name(&mut buf, &instance);
+    write_record_header(&mut buf, TYPE_TXT, ttl);
+    let mut rdata = Vec::new();
+    for addr in addrs {
+        let text = format!("{}{}", TXT_PREFIX, with_peer_id(addr.clone(), peer_id));
+        if text.len() <= MAX_TXT_LEN {
+            rdata.push(text.len() as u8);
+            rdata.extend_from_slice(text.as_bytes());
+        }
+    }
+    // A TXT record holds at least one string
+    if rdata.is_empty() {
+        rdata.push(0);
+    }
+    write_rdata(&mut buf, &rdata);
+    buf
+}
+
+/// Decode a packet, return None if it is malformed or not about the service
+pub fn decode(packet: &[u8], service: &str) -> Option<Packet> {
+    let mut reader = Reader { packet, pos: 0 };
+    let _id = reader.u16()?;
+    let flags = reader.u16()?;
+    let questions = reader.u16()?;
+    let records = reader.u16()? as usize + reader.u16()? as usize + reader.u16()? as usize;
+
+    if flags & FLAG_RESPONSE == 0 {
+        for _ in 0..questions {
+            let name = reader.name()?;
+            let ty = reader.u16()?;
+            let class = reader.u16()?;
+            if name.eq_ignore_ascii_case(service)
+                && (ty == TYPE_PTR || ty == TYPE_ANY)
+                && class & CLASS_MASK == CLASS_IN
+            {
+                return Some(Packet::Query);
+            }
+        }
+        return None;
+    }
+
+    for _ in 0..questions {
+        reader.name()?;
+        reader.skip(4)?;
+    }
+    let mut instances = Vec::new();
+    let mut txts = Vec::new();
+    for _ in 0..records {
+        let name = reader.name()?;
+        let ty = reader.u16()?;
+        let class = reader.u16()?;
+        let ttl = reader.u32()?;
+        let len = reader.u16()? as usize;
+        let start = reader.pos;
+        let rdata = reader.skip(len)?;
+        if class & CLASS_MASK != CLASS_IN {
+            continue;
+        }
+        match ty {
+            TYPE_PTR if name.eq_ignore_ascii_case(service) => {
+                // The target name may point anywhere in the packet
+                let target = Reader { packet, pos: start }.name()?;
+                instances.push((target, ttl));
+            }
+            TYPE_TXT => txts.push((name, rdata)),
+            _ => (),
+        }
+    }
+    if instances.is_empty() {
+        return None;
+    }
+
+    let peers = instances
+        .into_iter()
+        .filter_map(|(instance, ttl)| {
+            let peer_id = instance_peer_id(&instance, service)?;
+            let addrs = txts
+                .iter()
+                .filter(|(name, _)| name.eq_ignore_ascii_case(&instance))
+                .flat_map(|(_, rdata)| txt_strings(rdata))
+                .filter_map(|text| parse_addr(text, &peer_id))
+                .collect();
+            Some(Peer {
+                peer_id,
+                addrs,
+                ttl,
+            })
+        })
+        .collect();
+    Some(Packet::Response(peers))
+}
+
+fn write_header(buf: &mut Vec<u8>, flags: u16, questions: u16, answers: u16) {
+    // The id is zero in multicast messages
+    buf.extend_from_slice(&0u16.to_be_bytes());
+    buf.extend_from_slice(&flags.to_be_bytes());
+    buf.extend_from_slice(&questions.to_be_bytes());
+    buf.extend_from_slice(&answers.to_be_bytes());
+    // Authority and additional records
+    buf.extend_from_slice(&[0, 0, 0, 0]);
+}
+
+fn write_name(buf: &mut Vec<u8>, name: &str) {
+    for label in name.split('.').filter(|label| !label.is_empty()) {
+        debug_assert!(label.len() <= MAX_LABEL_LEN, "dns label too long");
+        buf.push(label.len() as u8);
+        buf.extend_from_slice(label.as_bytes());
+    }
+    buf.push(0);
+}
+
+fn write_record_header(buf: &mut Vec<u8>, ty: u16, ttl: u32) {
+    buf.extend_from_slice(&ty.to_be_bytes());
+    buf.extend_from_slice(&CLASS_IN.to_be_bytes());
+    buf.extend_from_slice(&ttl.to_be_bytes());
+}
+
+fn write_rdata(buf: &mut Vec<u8>, rdata: &[u8]) {
+    buf.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+    buf.extend_from_slice(rdata);
+}
+
+// `<peer id>.<service>` to peer id
+fn instance_peer_id(instance: &str, service: &str) -> Option<PeerId> {
+    let split = instance.len().checked_sub(service.len() + 1)?;
+    let suffix = instance.get(split..)?;
+    if !suffix.starts_with('.') || !suffix[1..].eq_ignore_ascii_case(service) {
+        return None;
+    }
+    PeerId::from_str(&instance[..split]).ok()
+}
+
+fn txt_strings(rdata: &[u8]) -> Vec<&[u8]> {
+    let mut strings = Vec::new();
+    let mut pos = 0;
+    while let Some(&len) = rdata.get(pos) {
+        match rdata.get(pos + 1..pos + 1 + len as usize) {
+            Some(text) => strings.push(text),
+            None => break,
+        }
+        pos += 1 + len as usize;
+    }
+    strings
+}
+
+fn parse_addr(text: &[u8], peer_id: &PeerId) -> Option<Multiaddr> {
+    let text = std::str::from_utf8(text).ok()?;
+    if !text.starts_with(TXT_PREFIX) {
+        return None;
+    }
+    let addr = text[TXT_PREFIX.len()..].parse::<Multiaddr>().ok()?;
+    match extract_peer_id(&addr) {
+        Some(ref id) if id != peer_id => None,
+        _ => Some(with_peer_id(addr, peer_id)),
+    }
+}
+
+fn with_peer_id(mut addr: Multiaddr, peer_id: &PeerId) -> Multiaddr {
+    if extract_peer_id(&addr).is_none() {
+        addr.push(Protocol::P2p(
+            Multihash::from_bytes(peer_id.as_bytes().to_vec()).expect("Invalid peer id"),
+        ));
+    }
+    addr
+}
+
+struct Reader<'a> {
+    packet: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn skip(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.packet.get(self.pos..self.pos + len)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.skip(2)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from(self.u16()?) << 16 | u32::from(self.u16()?))
+    }
+
+    // See: RFC 1035 section 4.1.4, message compression
+    fn name(&mut self) -> Option<String> {
+        let mut name = String::new();
+        let mut pos = self.pos;
+        let mut pointers = 0;
+        loop {
+            let len = *self.packet.get(pos)? as usize;
+            match len & 0xc0 {
+                0x00 if len == 0 => {
+                    if pointers == 0 {
+                        self.pos = pos + 1;
+                    }
+                    return Some(name);
+                }
+                0x00 => {
+                    let label = self.packet.get(pos + 1..pos + 1 + len)?;
+                    if !name.is_empty() {
+                        name.push('.');
+                    }
+                    name.push_str(std::str::from_utf8(label).ok()?);
+                    pos += 1 + len;
+                }
+                0xc0 => {
+                    if pointers == MAX_POINTERS {
+                        return None;
+                    }
+                    let offset = (len & 0x3f) << 8 | *self.packet.get(pos + 1)? as usize;
+                    if pointers == 0 {
+                        self.pos = pos + 2;
+                    }
+                    pointers += 1;
+                    pos = offset;
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, encode_query, encode_response, Packet, Peer};
+    use p2p::{multiaddr::Multiaddr, secio::SecioKeyPair};
+
+    const SERVICE: &str = "_p2p._udp.local";
+
+    #[test]
+    fn test_query() {
+        let query = encode_query(SERVICE);
+        assert_eq!(decode(&query, SERVICE), Some(Packet::Query));
+        assert_eq!(decode(&query, "_other._udp.local"), None);
+        assert_eq!(decode(&query[..query.len() - 1], SERVICE), None);
+    }
+
+    #[test]
+    fn test_response() {
+        let peer_id = SecioKeyPair::secp256k1_generated().peer_id();
+        let addrs: Vec<Multiaddr> = vec![
+            "/ip4/192.168.1.2/tcp/1337".parse().unwrap(),
+            format!("/ip6/::1/tcp/1337/ws/p2p/{}", peer_id.to_base58())
+                .parse()
+                .unwrap(),
+        ];
+        let response = encode_response(SERVICE, &peer_id, &addrs, 120);
+        let expected: Vec<Multiaddr> = vec![
+            format!("/ip4/192.168.1.2/tcp/1337/p2p/{}", peer_id.to_base58())
+                .parse()
+                .unwrap(),
+            addrs[1].clone(),
+        ];
+        assert_eq!(
+            decode(&response, SERVICE),
+            Some(Packet::Response(vec![Peer {
+                peer_id,
+                addrs: expected,
+                ttl: 120,
+            }]))
+        );
+        assert_eq!(decode(&response, "_other._udp.local"), None);
+    }
+
+    #[test]
+    fn test_compressed_name() {
+        let peer_id = SecioKeyPair::secp256k1_generated().peer_id();
+        let mut response = encode_response(SERVICE, &peer_id, &[], 120);
+        // Replace the PTR target suffix with a pointer to the service name at offset 12
+        let label = peer_id.to_base58();
+        let target = 12 + SERVICE.len() + 2 + 10;
+        let end = target + 1 + label.len();
+        let tail = response.split_off(end + SERVICE.len() + 2);
+        response.truncate(end);
+        response.extend_from_slice(&[0xc0, 12]);
+        response.extend_from_slice(&tail);
+        let rdata_len = (1 + label.len() + 2) as u16;
+        response[target - 2..target].copy_from_slice(&rdata_len.to_be_bytes());
+
+        match decode(&response, SERVICE) {
+            Some(Packet::Response(peers)) => assert_eq!(peers[0].peer_id, peer_id),
+            other => panic!("unexpected packet: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_pointer_loop() {
+        let mut packet = vec![0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0];
+        packet.extend_from_slice(&[0xc0, 12, 0, 12, 0, 1]);
+        assert_eq!(decode(&packet, SERVICE), None);
+    }
+}
@@ -0,0 +1,560 @@
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::{Duration, Instant};
+
+use discovery::{is_valid_addr, AddressManager};
+use futures::{
+    sync::mpsc::{channel, Receiver, Sender},
+    task, Async, Future, Poll, Stream,
+};
+use log::{debug, error, warn};
+use p2p::{
+    context::{ProtocolContext, ProtocolContextMutRef},
+    multiaddr::{Multiaddr, Protocol},
+    secio::PeerId,
+    service::{DialProtocol, ServiceControl},
+    traits::ServiceProtocol,
+    SessionId,
+};
+use socket2::{Domain, Protocol as SocketProtocol, Socket, Type};
+use tokio::{net::UdpSocket, reactor::Handle, timer::Interval};
+
+mod dns;
+
+use crate::dns::{Packet, Peer};
+
+/// The mDNS multicast group of IPv4
+pub const MDNS_GROUP: Ipv4Addr = Ipv4Addr::new(224, 0, 0, 251);
+
+const CHECK_LISTENS_TOKEN: u64 = 0;
+const CHECK_LISTENS_INTERVAL: Duration = Duration::from_secs(3);
+// The maximum number of listen addresses announced
+const MAX_ANNOUNCE_ADDRS: usize = 10;
+// Packets waiting for the socket to be writable, more are dropped
+const MAX_SEND_QUEUE: usize = 32;
+// Largest mDNS packet, see: RFC 6762 section 17
+const MAX_PACKET_SIZE: usize = 9000;
+// Packets handled in one poll before yielding to other tasks
+const MAX_RECV_PER_POLL: usize = 64;
+// Found peers remembered until they expire, more are ignored
+const MAX_FOUND_PEERS: usize = 1024;
+
+/// mDNS parameters
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Service name queried and announced, only peers using the same name find each other,
+    /// default is `_p2p._udp.local`
+    pub service_name: String,
+    /// Default is 5353
+    pub port: u16,
+    /// Interface joining the multicast group and sending packets, default is unspecified which
+    /// lets the system choose. Set it to `127.0.0.1` to run on the loopback interface
+    pub interface: Ipv4Addr,
+    /// Default is 60 seconds
+    pub query_interval: Duration,
+    /// Announced addresses are valid for this long, a peer found again after that is reported
+    /// again, default is 2 minutes
+    pub ttl: Duration,
+    /// Dial found peers besides adding their addresses to the address manager, default is false
+    pub dial: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            service_name: "_p2p._udp.local".to_owned(),
+            port: 5353,
+            interface: Ipv4Addr::UNSPECIFIED,
+            query_interval: Duration::from_secs(60),
+            ttl: Duration::from_secs(120),
+            dial: false,
+        }
+    }
+}
+
+/// Announce the listen addresses of the service and find peers on the local network
+///
+/// It does not open any substream, the protocol only runs `Mdns` and keeps it up to date with
+/// the listen addresses of the service.
+pub struct MdnsProtocol<M> {
+    mdns: Option<Mdns<M>>,
+    handle: MdnsHandle,
+    // Listen addresses last sent to `Mdns`
+    listens: Vec<Multiaddr>,
+}
+
+impl<M: AddressManager> MdnsProtocol<M> {
+    pub fn new(mdns: Mdns<M>) -> MdnsProtocol<M> {
+        let handle = mdns.handle();
+        MdnsProtocol {
+            mdns: Some(mdns),
+            handle,
+            listens: Vec::new(),
+        }
+    }
+
+    fn update_listens(&mut self, context: &ProtocolContext) {
+        let peer_id = match context.key_pair() {
+            Some(key_pair) => key_pair.peer_id(),
+            None => return,
+        };
+        let listens = context
+            .listens()
+            .iter()
+            .take(MAX_ANNOUNCE_ADDRS)
+            .cloned()
+            .collect::<Vec<_>>();
+        if listens == self.listens {
+            return;
+        }
+        if self.handle.announce(peer_id, listens.clone()) {
+            self.listens = listens;
+        }
+    }
+}
+
+impl<M: AddressManager + Send + 'static> ServiceProtocol for MdnsProtocol<M> {
+    fn init(&mut self, context: &mut ProtocolContext) {
+        debug!("protocol [mdns({})]: init", context.proto_id);
+
+        if context.key_pair().is_none() {
+            error!("MdnsProtocol require secio enabled, peers are found but not announced!");
+        }
+        let mdns_task = self
+            .mdns
+            .take()
+            .map(|mut mdns| {
+                mdns.control = Some(context.control().clone());
+                mdns.for_each(|()| Ok(()))
+                    .map_err(|err| {
+                        warn!("mdns stream error: {:?}", err);
+                    })
+                    .then(|_| {
+                        debug!("End of mdns");
+                        Ok(())
+                    })
+            })
+            .unwrap();
+        if context.future_task(mdns_task).is_err() {
+            warn!("start mdns fail");
+        }
+
+        let proto_id = context.proto_id;
+        if context
+            .set_service_notify(proto_id, CHECK_LISTENS_INTERVAL, CHECK_LISTENS_TOKEN)
+            .is_err()
+        {
+            warn!("mdns start fail")
+        }
+        self.update_listens(context);
+    }
+
+    fn connected(&mut self, context: ProtocolContextMutRef, _version: &str) {
+        debug!(
+            "protocol [mdns] open on session [{}], nothing to exchange",
+            context.session.id
+        );
+    }
+
+    fn notify(&mut self, context: &mut ProtocolContext, _token: u64) {
+        self.update_listens(context);
+    }
+}
+
+/// Update the addresses announced by `Mdns`
+#[derive(Clone)]
+pub struct MdnsHandle {
+    sender: Sender<(PeerId, Vec<Multiaddr>)>,
+}
+
+impl MdnsHandle {
+    /// Announce the addresses from now on, to answer queries and once right away
+    ///
+    /// Return false if the update is dropped because `Mdns` is busy or gone
+    pub fn announce(&mut self, peer_id: PeerId, addrs: Vec<Multiaddr>) -> bool {
+        match self.sender.try_send((peer_id, addrs)) {
+            Ok(_) => true,
+            Err(err) => {
+                debug!("mdns announce update failed: {:?}", err);
+                false
+            }
+        }
+    }
+}
+
+/// Queries and answers on the mDNS multicast group
+///
+/// Found peers are added to the address manager with the default session id, which no session
+/// ever has, since they are not learnt from a session.
+pub struct Mdns<M> {
+    config: Config,
+
+    // Address Manager
+    addr_mgr: M,
+
+    // Dial found peers with it if `config.dial`
+    control: Option<ServiceControl>,
+
+    // Bound on the first poll, inside the runtime
+    socket: Option<UdpSocket>,
+
+    query_interval: Interval,
+
+    // Peer id and listen addresses announced
+    local: Option<(PeerId, Vec<Multiaddr>)>,
+
+    local_sender: Sender<(PeerId, Vec<Multiaddr>)>,
+    local_receiver: Receiver<(PeerId, Vec<Multiaddr>)>,
+
+    send_queue: VecDeque<Vec<u8>>,
+
+    // Found peers and when they expire, they are not reported again before
+    found: HashMap<PeerId, Instant>,
+
+    recv_buffer: Vec<u8>,
+}
+
+impl<M: AddressManager> Mdns<M> {
+    pub fn new(addr_mgr: M, config: Config) -> Mdns<M> {
+        let (local_sender, local_receiver) = channel(8);
+        let query_interval = Interval::new(Instant::now(), config.query_interval);
+        Mdns {
+            config,
+            addr_mgr,
+            control: None,
+            socket: None,
+            query_interval,
+            local: None,
+            local_sender,
+            local_receiver,
+            send_queue: VecDeque::new(),
+            found: HashMap::default(),
+            recv_buffer: vec![0; MAX_PACKET_SIZE],
+        }
+    }
+
+    pub fn handle(&self) -> MdnsHandle {
+        MdnsHandle {
+            sender: self.local_sender.clone(),
+        }
+    }
+
+    fn push_packet(&mut self, packet: Vec<u8>) {
+        if self.send_queue.len() < MAX_SEND_QUEUE {
+            self.send_queue.push_back(packet);
+        } else {
+            debug!("mdns send queue is full");
+        }
+    }
+
+    fn announce(&mut self) {
+        let packet = match self.local {
+            Some((ref peer_id, ref addrs)) if !addrs.is_empty() => dns::encode_response(
+                &self.config.service_name,
+                peer_id,
+                addrs,
+                self.config.ttl.as_secs() as u32,
+            ),
+            _ => return,
+        };
+        self.push_packet(packet);
+    }
+
+    fn handle_packet(&mut self, len: usize, source: SocketAddr) {
+        match dns::decode(&self.recv_buffer[..len], &self.config.service_name) {
+            Some(Packet::Query) => self.announce(),
+            Some(Packet::Response(peers)) => {
+                for peer in peers {
+                    self.found_peer(peer, source.ip());
+                }
+            }
+            None => (),
+        }
+    }
+
+    fn found_peer(&mut self, peer: Peer, source: IpAddr) {
+        if self
+            .local
+            .as_ref()
+            .map_or(false, |(peer_id, _)| peer_id == &peer.peer_id)
+        {
+            return;
+        }
+        if peer.ttl == 0 {
+            self.found.remove(&peer.peer_id);
+            return;
+        }
+        let addrs = peer
+            .addrs
+            .into_iter()
+            .filter_map(|addr| resolve_addr(addr, source))
+            .filter(is_valid_addr)
+            .collect::<Vec<_>>();
+        if addrs.is_empty() {
+            return;
+        }
+
+        let now = Instant::now();
+        if self.found.len() >= MAX_FOUND_PEERS && !self.found.contains_key(&peer.peer_id) {
+            self.found.retain(|_, expire| *expire > now);
+            if self.found.len() >= MAX_FOUND_PEERS {
+                debug!("mdns found too many peers, ignore {:?}", peer.peer_id);
+                return;
+            }
+        }
+        let expired = self
+            .found
+            .insert(peer.peer_id.clone(), now + self.config.ttl)
+            .map_or(true, |expire| expire <= now);
+        if !expired {
+            return;
+        }
+        debug!("mdns found peer {:?}: {:?}", peer.peer_id, addrs);
+        if self.config.dial {
+            if let Some(ref control) = self.control {
+                if let Err(err) = control.dial(addrs[0].clone(), DialProtocol::All) {
+                    debug!("mdns dial {} failed: {:?}", addrs[0], err);
+                }
+            }
+        }
+        self.addr_mgr.add_new_addrs(SessionId::default(), addrs);
+    }
+
+    fn send(&mut self) {
+        let target = SocketAddr::from((MDNS_GROUP, self.config.port));
+        let socket = self.socket.as_mut().expect("mdns socket is bound");
+        while let Some(packet) = self.send_queue.front() {
+            match socket.poll_send_to(packet, &target) {
+                Ok(Async::Ready(_)) => (),
+                Ok(Async::NotReady) => break,
+                Err(err) => debug!("mdns send failed: {}", err),
+            }
+            self.send_queue.pop_front();
+        }
+    }
+}
+
+impl<M: AddressManager> Stream for Mdns<M> {
+    type Item = ();
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if self.socket.is_none() {
+            self.socket = Some(bind(&self.config)?);
+        }
+
+        while let Ok(Async::Ready(Some(local))) = self.local_receiver.poll() {
+            self.local = Some(local);
+            self.announce();
+        }
+
+        let mut query = false;
+        while let Ok(Async::Ready(Some(_))) = self.query_interval.poll() {
+            query = true;
+        }
+        if query {
+            let now = Instant::now();
+            self.found.retain(|_, expire| *expire > now);
+            let packet = dns::encode_query(&self.config.service_name);
+            self.push_packet(packet);
+        }
+
+        let mut received = 0;
+        loop {
+            if received >= MAX_RECV_PER_POLL {
+                // The rest is received on the next poll
+                task::current().notify();
+                break;
+            }
+            let result = self
+                .socket
+                .as_mut()
+                .expect("mdns socket is bound")
+                .poll_recv_from(&mut self.recv_buffer);
+            match result {
+                Ok(Async::Ready((len, source))) => {
+                    received += 1;
+                    self.handle_packet(len, source);
+                }
+                Ok(Async::NotReady) => break,
+                // Errors such as an ICMP unreachable of a previous send are reported once by the
+                // socket, receiving goes on when the task is woken up again, at the latest by the
+                // next query
+                Err(err) => {
+                    debug!("mdns receive failed: {}", err);
+                    break;
+                }
+            }
+        }
+
+        self.send();
+
+        Ok(Async::NotReady)
+    }
+}
+
+fn bind(config: &Config) -> io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::ipv4(), Type::dgram(), Some(SocketProtocol::udp()))?;
+    // Share the port with other mDNS responders of the host
+    socket.set_reuse_address(true)?;
+    #[cfg(unix)]
+    socket.set_reuse_port(true)?;
+    socket.bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, config.port)).into())?;
+    socket.join_multicast_v4(&MDNS_GROUP, &config.interface)?;
+    if !config.interface.is_unspecified() {
+        socket.set_multicast_if_v4(&config.interface)?;
+    }
+    // Peers of the same host are found too
+    socket.set_multicast_loop_v4(true)?;
+    socket.set_multicast_ttl_v4(255)?;
+    socket.set_nonblocking(true)?;
+    UdpSocket::from_std(socket.into_udp_socket(), &Handle::default())
+}
+
+// Peers listening on an unspecified address are reachable on the source address of the packet,
+// loopback addresses are only of use on the same host
+fn resolve_addr(addr: Multiaddr, source: IpAddr) -> Option<Multiaddr> {
+    addr.iter()
+        .map(|proto| match proto {
+            Protocol::Ip4(ip) if ip.is_unspecified() => match source {
+                IpAddr::V4(source) => Some(Protocol::Ip4(source)),
+                IpAddr::V6(_) => None,
+            },
+            Protocol::Ip6(ip) if ip.is_unspecified() => match source {
+                IpAddr::V4(_) => None,
+                IpAddr::V6(source) => Some(Protocol::Ip6(source)),
+            },
+            Protocol::Ip4(ip) if ip.is_loopback() && !source.is_loopback() => None,
+            Protocol::Ip6(ip) if ip.is_loopback() && !source.is_loopback() => None,
+            proto => Some(proto),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{dns::Peer, resolve_addr, Config, Mdns, MAX_FOUND_PEERS};
+    use discovery::{AddressManager, MisbehaveResult, Misbehavior};
+    use futures::{Future, Stream};
+    use p2p::{
+        multiaddr::Multiaddr,
+        secio::{PeerId, SecioKeyPair},
+        SessionId,
+    };
+    use std::net::Ipv4Addr;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Clone, Default)]
+    struct Found(Arc<Mutex<Vec<Multiaddr>>>);
+
+    impl AddressManager for Found {
+        fn add_new_addr(&mut self, _session_id: SessionId, addr: Multiaddr) {
+            self.0.lock().unwrap().push(addr);
+        }
+
+        fn add_new_addrs(&mut self, _session_id: SessionId, addrs: Vec<Multiaddr>) {
+            self.0.lock().unwrap().extend(addrs);
+        }
+
+        fn misbehave(&mut self, _session_id: SessionId, _kind: Misbehavior) -> MisbehaveResult {
+            MisbehaveResult::Continue
+        }
+
+        fn get_random(&mut self, _n: usize) -> Vec<Multiaddr> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_resolve_addr() {
+        let lan = "192.168.1.2".parse().unwrap();
+        let loopback = "127.0.0.1".parse().unwrap();
+        let unspecified: Multiaddr = "/ip4/0.0.0.0/tcp/1337".parse().unwrap();
+        assert_eq!(
+            resolve_addr(unspecified.clone(), lan),
+            Some("/ip4/192.168.1.2/tcp/1337".parse().unwrap())
+        );
+        assert_eq!(
+            resolve_addr(unspecified, loopback),
+            Some("/ip4/127.0.0.1/tcp/1337".parse().unwrap())
+        );
+        let local: Multiaddr = "/ip4/127.0.0.1/tcp/1337".parse().unwrap();
+        assert_eq!(resolve_addr(local.clone(), lan), None);
+        assert_eq!(resolve_addr(local.clone(), loopback), Some(local));
+    }
+
+    #[test]
+    fn test_found_limit() {
+        let found = Found::default();
+        let mut mdns = Mdns::new(found.clone(), Config::default());
+        let lan = "192.168.1.2".parse().unwrap();
+        for _ in 0..=MAX_FOUND_PEERS {
+            let peer_id = PeerId::random();
+            let addr = format!("/ip4/0.0.0.0/tcp/1337/p2p/{}", peer_id.to_base58())
+                .parse()
+                .unwrap();
+            let peer = Peer {
+                peer_id,
+                addrs: vec![addr],
+                ttl: 120,
+            };
+            mdns.found_peer(peer, lan);
+        }
+        assert_eq!(mdns.found.len(), MAX_FOUND_PEERS);
+        assert_eq!(found.0.lock().unwrap().len(), MAX_FOUND_PEERS);
+    }
+
+    #[test]
+    fn test_find_on_loopback() {
+        let config = Config {
+            service_name: "_tentacle-test._udp.local".to_owned(),
+            port: 15353,
+            interface: Ipv4Addr::LOCALHOST,
+            query_interval: Duration::from_millis(200),
+            ..Default::default()
+        };
+        let peer_a = SecioKeyPair::secp256k1_generated().peer_id();
+        let peer_b = SecioKeyPair::secp256k1_generated().peer_id();
+        let found_a = Found::default();
+        let found_b = Found::default();
+        let mdns_a = Mdns::new(found_a.clone(), config.clone());
+        let mdns_b = Mdns::new(found_b.clone(), config);
+        assert!(mdns_a.handle().announce(
+            peer_a.clone(),
+            vec!["/ip4/0.0.0.0/tcp/1337".parse().unwrap()]
+        ));
+        assert!(mdns_b.handle().announce(
+            peer_b.clone(),
+            vec!["/ip4/127.0.0.1/tcp/1338".parse().unwrap()]
+        ));
+
+        let mut rt = tokio::runtime::Runtime::new().unwrap();
+        rt.spawn(
+            mdns_a
+                .for_each(|()| Ok(()))
+                .map_err(|err| panic!("{}", err)),
+        );
+        rt.spawn(
+            mdns_b
+                .for_each(|()| Ok(()))
+                .map_err(|err| panic!("{}", err)),
+        );
+        for _ in 0..50 {
+            if !found_a.0.lock().unwrap().is_empty() && !found_b.0.lock().unwrap().is_empty() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        let expected_a: Multiaddr = format!("/ip4/127.0.0.1/tcp/1337/p2p/{}", peer_a.to_base58())
+            .parse()
+            .unwrap();
+        let expected_b: Multiaddr = format!("/ip4/127.0.0.1/tcp/1338/p2p/{}", peer_b.to_base58())
+            .parse()
+            .unwrap();
+        assert_eq!(*found_a.0.lock().unwrap(), vec![expected_b]);
+        assert_eq!(*found_b.0.lock().unwrap(), vec![expected_a]);
+    }
+}
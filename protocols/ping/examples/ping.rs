@@ -77,7 +77,11 @@ pub fn create_meta<S: Sender<Event> + Send + Clone + 'static>(
     MetaBuilder::new()
         .id(id)
         .service_handle(move || {
-            let handle = Box::new(PingHandler::new(interval, timeout, event_sender));
+            let handle = Box::new(
+                PingHandler::new(interval, timeout, event_sender)
+                    .active_interval(interval * 4)
+                    .max_timeouts(3),
+            );
             ProtocolHandle::Callback(handle)
         })
         .build()
@@ -21,24 +21,30 @@ mod protocol_mol;
 #[cfg(feature = "molc")]
 use molecule::prelude::{Builder, Entity, Reader};
 
+mod stats;
+
 use bytes::Bytes;
 use generic_channel::Sender;
 use log::{debug, error, warn};
 use p2p::{
-    context::{ProtocolContext, ProtocolContextMutRef},
+    context::{ProtocolContext, ProtocolContextMutRef, SessionContext},
     secio::PeerId,
-    service::TargetSession,
     traits::ServiceProtocol,
-    SessionId,
+    ProtocolId, SessionId,
 };
+use rand::Rng;
 use std::{
     collections::HashMap,
     str,
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
 };
 
+pub use stats::PingStats;
+
 const SEND_PING_TOKEN: u64 = 0;
 const CHECK_TIMEOUT_TOKEN: u64 = 1;
+const DEFAULT_STATS_WINDOW: usize = 16;
 
 /// Ping protocol events
 #[derive(Debug)]
@@ -53,6 +59,59 @@ pub enum Event {
     UnexpectedError(PeerId),
 }
 
+/// Ping statistics of the connected peers, shared with the handler
+#[derive(Clone, Default)]
+pub struct PingStatsHandle {
+    inner: Arc<RwLock<HashMap<PeerId, PingStats>>>,
+}
+
+impl PingStatsHandle {
+    /// Statistics of a connected peer
+    pub fn get(&self, peer_id: &PeerId) -> Option<PingStats> {
+        self.inner
+            .read()
+            .expect("ping stats lock poisoned")
+            .get(peer_id)
+            .cloned()
+    }
+
+    /// Statistics of all connected peers
+    pub fn all(&self) -> Vec<(PeerId, PingStats)> {
+        self.inner
+            .read()
+            .expect("ping stats lock poisoned")
+            .iter()
+            .map(|(peer_id, stats)| (peer_id.clone(), stats.clone()))
+            .collect()
+    }
+
+    fn insert(&self, peer_id: PeerId, window: usize) {
+        self.inner
+            .write()
+            .expect("ping stats lock poisoned")
+            .entry(peer_id)
+            .or_insert_with(|| PingStats::new(window));
+    }
+
+    fn remove(&self, peer_id: &PeerId) {
+        self.inner
+            .write()
+            .expect("ping stats lock poisoned")
+            .remove(peer_id);
+    }
+
+    fn update<F, R>(&self, peer_id: &PeerId, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut PingStats) -> R,
+    {
+        self.inner
+            .write()
+            .expect("ping stats lock poisoned")
+            .get_mut(peer_id)
+            .map(f)
+    }
+}
+
 /// Ping protocol handler.
 ///
 /// The interval means that we send ping to peers.
@@ -60,7 +119,11 @@ pub enum Event {
 pub struct PingHandler<S: Sender<Event>> {
     interval: Duration,
     timeout: Duration,
+    active_interval: Option<Duration>,
+    max_timeouts: Option<u32>,
+    stats_window: usize,
     connected_session_ids: HashMap<SessionId, PingStatus>,
+    stats: PingStatsHandle,
     event_sender: S,
 }
 
@@ -69,11 +132,46 @@ impl<S: Sender<Event>> PingHandler<S> {
         PingHandler {
             interval,
             timeout,
+            active_interval: None,
+            max_timeouts: None,
+            stats_window: DEFAULT_STATS_WINDOW,
             connected_session_ids: Default::default(),
+            stats: PingStatsHandle::default(),
             event_sender,
         }
     }
 
+    /// Ping sessions which received other data since the last pong at this longer interval,
+    /// the data already shows they are alive. By default every session is pinged at the interval
+    pub fn active_interval(mut self, active_interval: Duration) -> Self {
+        self.active_interval = Some(active_interval);
+        self
+    }
+
+    /// Disconnect a peer after this many consecutive timeouts,
+    /// by default only `Event::Timeout` is sent
+    pub fn max_timeouts(mut self, max_timeouts: u32) -> Self {
+        self.max_timeouts = Some(max_timeouts);
+        self
+    }
+
+    /// Number of last pings the statistics are computed on, default is 16
+    pub fn stats_window(mut self, window: usize) -> Self {
+        self.stats_window = window.max(1);
+        self
+    }
+
+    /// Statistics of the connected peers, still readable after the handler is moved into the
+    /// service
+    pub fn stats_handle(&self) -> PingStatsHandle {
+        self.stats.clone()
+    }
+
+    /// Statistics of a connected peer
+    pub fn stats(&self, peer_id: &PeerId) -> Option<PingStats> {
+        self.stats.get(peer_id)
+    }
+
     pub fn send_event(&mut self, event: Event) {
         if let Err(err) = self.event_sender.try_send(event) {
             error!("send ping event error: {}", err);
@@ -87,22 +185,26 @@ struct PingStatus {
     /// Are we currently pinging this peer?
     processing: bool,
     /// The time we last send ping to this peer.
-    last_ping: SystemTime,
+    last_ping: Instant,
+    /// The time we last received pong from this peer, or connected to it
+    last_pong: Instant,
+    /// Random value, peer must send a pong has same nonce to respond a ping.
+    nonce: u32,
+    /// Nonce of the ping before, its pong may still come after a timeout
+    previous_nonce: Option<u32>,
     peer_id: PeerId,
+    session: SessionContext,
 }
 
 impl PingStatus {
-    /// A meaningless value, peer must send a pong has same nonce to respond a ping.
-    fn nonce(&self) -> u32 {
-        self.last_ping
-            .duration_since(UNIX_EPOCH)
-            .map(|dur| dur.as_secs())
-            .unwrap_or(0) as u32
-    }
-
     /// Time duration since we last send ping.
     fn elapsed(&self) -> Duration {
-        self.last_ping.elapsed().unwrap_or(Duration::from_secs(0))
+        self.last_ping.elapsed()
+    }
+
+    /// Whether the session received data of other protocols since the last pong
+    fn active(&self, proto_id: ProtocolId) -> bool {
+        self.session.last_received_except(proto_id) > self.last_pong
     }
 }
 
@@ -132,12 +234,18 @@ where
         match session.remote_pubkey {
             Some(ref pubkey) => {
                 let peer_id = pubkey.peer_id();
+                self.stats.insert(peer_id.clone(), self.stats_window);
+                let now = Instant::now();
                 self.connected_session_ids
                     .entry(session.id)
                     .or_insert_with(|| PingStatus {
-                        last_ping: SystemTime::now(),
+                        last_ping: now,
+                        last_pong: now,
                         processing: false,
+                        nonce: rand::random(),
+                        previous_nonce: None,
                         peer_id,
+                        session: session.clone(),
                     });
                 debug!(
                     "proto id [{}] open on session [{}], address: [{}], type: [{:?}], version: {}",
@@ -155,7 +263,16 @@ where
 
     fn disconnected(&mut self, context: ProtocolContextMutRef) {
         let session = context.session;
-        self.connected_session_ids.remove(&session.id);
+        if let Some(ps) = self.connected_session_ids.remove(&session.id) {
+            // Statistics are kept while another session to the peer is open
+            if self
+                .connected_session_ids
+                .values()
+                .all(|other| other.peer_id != ps.peer_id)
+            {
+                self.stats.remove(&ps.peer_id);
+            }
+        }
         debug!(
             "proto id [{}] close on session [{}]",
             context.proto_id, session.id
@@ -164,10 +281,15 @@ where
 
     fn received(&mut self, context: ProtocolContextMutRef, data: bytes::Bytes) {
         let session = context.session;
-        if let Some(peer_id) = self
-            .connected_session_ids
-            .get(&session.id)
-            .map(|ps| ps.peer_id.clone())
+        if let Some((peer_id, processing, expected_nonce, previous_nonce)) =
+            self.connected_session_ids.get(&session.id).map(|ps| {
+                (
+                    ps.peer_id.clone(),
+                    ps.processing,
+                    ps.nonce,
+                    ps.previous_nonce,
+                )
+            })
         {
             match PingMessage::decode(data.as_ref()) {
                 None => {
@@ -187,24 +309,23 @@ where
                         }
                         PingPayload::Pong(nonce) => {
                             // check pong
-                            if self
-                                .connected_session_ids
-                                .get(&session.id)
-                                .map(|ps| (ps.processing, ps.nonce()))
-                                == Some((true, nonce))
-                            {
+                            if nonce != expected_nonce && Some(nonce) != previous_nonce {
+                                // ignore if nonce is incorrect
+                                self.send_event(Event::UnexpectedError(peer_id));
+                            } else if nonce != expected_nonce || !processing {
+                                debug!("ignore late pong from {:?}", peer_id);
+                            } else {
                                 let ping_time =
                                     match self.connected_session_ids.get_mut(&session.id) {
                                         Some(ps) => {
                                             ps.processing = false;
+                                            ps.last_pong = Instant::now();
                                             ps.elapsed()
                                         }
                                         None => return,
                                     };
+                                self.stats.update(&peer_id, |stats| stats.pong(ping_time));
                                 self.send_event(Event::Pong(peer_id, ping_time));
-                            } else {
-                                // ignore if nonce is incorrect
-                                self.send_event(Event::UnexpectedError(peer_id));
                             }
                         }
                     }
@@ -217,29 +338,32 @@ where
         match token {
             SEND_PING_TOKEN => {
                 debug!("proto [{}] start ping peers", context.proto_id);
-                let now = SystemTime::now();
+                let now = Instant::now();
+                let proto_id = context.proto_id;
+                let active_interval = self.active_interval;
+                let mut rng = rand::thread_rng();
                 let peers: Vec<(SessionId, u32)> = self
                     .connected_session_ids
                     .iter_mut()
                     .filter_map(|(session_id, ps)| {
-                        if ps.processing {
+                        // Sessions receiving other data are alive, ping them less often
+                        let skip = active_interval
+                            .map(|interval| ps.active(proto_id) && now - ps.last_ping < interval)
+                            .unwrap_or(false);
+                        if ps.processing || skip {
                             None
                         } else {
                             ps.processing = true;
                             ps.last_ping = now;
-                            Some((*session_id, ps.nonce()))
+                            ps.previous_nonce = Some(ps.nonce);
+                            ps.nonce = rng.gen();
+                            Some((*session_id, ps.nonce))
                         }
                     })
                     .collect();
-                if !peers.is_empty() {
-                    let ping_msg = PingMessage::build_ping(peers[0].1);
-                    let peer_ids: Vec<SessionId> = peers
-                        .into_iter()
-                        .map(|(session_id, _)| session_id)
-                        .collect();
-                    let proto_id = context.proto_id;
+                for (session_id, nonce) in peers {
                     if context
-                        .filter_broadcast(TargetSession::Multi(peer_ids), proto_id, ping_msg)
+                        .send_message_to(session_id, proto_id, PingMessage::build_ping(nonce))
                         .is_err()
                     {
                         debug!("send message fail");
@@ -249,14 +373,33 @@ where
             CHECK_TIMEOUT_TOKEN => {
                 debug!("proto [{}] check ping timeout", context.proto_id);
                 let timeout = self.timeout;
-                for peer_id in self
+                for (session_id, peer_id) in self
                     .connected_session_ids
-                    .values()
-                    .filter(|ps| ps.processing && ps.elapsed() >= timeout)
-                    .map(|ps| ps.peer_id.clone())
-                    .collect::<Vec<PeerId>>()
+                    .iter_mut()
+                    .filter(|(_, ps)| ps.processing && ps.elapsed() >= timeout)
+                    .map(|(session_id, ps)| {
+                        // The ping is lost, the next one is sent at the next interval
+                        ps.processing = false;
+                        (*session_id, ps.peer_id.clone())
+                    })
+                    .collect::<Vec<_>>()
                 {
+                    let timeouts = self.stats.update(&peer_id, |stats| {
+                        stats.timeout();
+                        stats.consecutive_timeouts()
+                    });
                     self.send_event(Event::Timeout(peer_id));
+                    if let (Some(timeouts), Some(max_timeouts)) = (timeouts, self.max_timeouts) {
+                        if timeouts >= max_timeouts {
+                            debug!(
+                                "disconnect session [{}] after {} timeouts",
+                                session_id, timeouts
+                            );
+                            if context.disconnect(session_id).is_err() {
+                                debug!("disconnect fail");
+                            }
+                        }
+                    }
                 }
             }
             _ => panic!("unknown token {}", token),
@@ -371,3 +514,211 @@ impl PingMessage {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Event, PingHandler, PingMessage, PingPayload, PingStatsHandle};
+    use bytes::Bytes;
+    use futures::{
+        sync::mpsc::{channel, Sender},
+        Future, Stream,
+    };
+    use p2p::{
+        builder::{MetaBuilder, ServiceBuilder},
+        context::{ProtocolContext, ProtocolContextMutRef},
+        multiaddr::Multiaddr,
+        secio::SecioKeyPair,
+        service::{DialProtocol, ProtocolHandle, ProtocolMeta, TargetSession},
+        traits::ServiceProtocol,
+    };
+    use std::{
+        sync::{
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+        time::{Duration, Instant},
+    };
+    use tokio::timer::Delay;
+
+    // Never answers
+    struct Silent;
+
+    impl ServiceProtocol for Silent {
+        fn init(&mut self, _context: &mut ProtocolContext) {}
+    }
+
+    // Answers every ping after a delay
+    struct Slow(Duration);
+
+    impl ServiceProtocol for Slow {
+        fn init(&mut self, _context: &mut ProtocolContext) {}
+
+        fn received(&mut self, context: ProtocolContextMutRef, data: Bytes) {
+            if let Some(PingPayload::Ping(nonce)) = PingMessage::decode(&data) {
+                let control = context.control().clone();
+                let (session_id, proto_id) = (context.session.id, context.proto_id);
+                let task = Delay::new(Instant::now() + self.0).then(move |_| {
+                    let pong = PingMessage::build_pong(nonce);
+                    let _ = control.send_message_to(session_id, proto_id, pong);
+                    Ok(())
+                });
+                let _ = context.future_task(task);
+            }
+        }
+    }
+
+    // Sends data to every session all the time
+    struct Chatty;
+
+    impl ServiceProtocol for Chatty {
+        fn init(&mut self, context: &mut ProtocolContext) {
+            let proto_id = context.proto_id;
+            let _ = context.set_service_notify(proto_id, Duration::from_millis(20), 0);
+        }
+
+        fn notify(&mut self, context: &mut ProtocolContext, _token: u64) {
+            let data = Bytes::from("chat");
+            let _ = context.filter_broadcast(TargetSession::All, context.proto_id, data);
+        }
+    }
+
+    fn meta<P: ServiceProtocol + Send + 'static>(id: usize, protocol: P) -> ProtocolMeta {
+        MetaBuilder::new()
+            .id(id.into())
+            .service_handle(move || ProtocolHandle::Callback(Box::new(protocol)))
+            .build()
+    }
+
+    fn ping(interval: Duration, timeout: Duration) -> PingHandler<Sender<Event>> {
+        let (sender, receiver) = channel(1024);
+        // Events are not checked, keep the channel open
+        thread::spawn(move || receiver.wait().for_each(drop));
+        PingHandler::new(interval, timeout, sender)
+    }
+
+    // Start a service listening on loopback, dialing the address if any
+    fn run(protocols: Vec<ProtocolMeta>, dial: Option<Multiaddr>) -> Multiaddr {
+        let mut builder = ServiceBuilder::default()
+            .key_pair(SecioKeyPair::secp256k1_generated())
+            .forever(true);
+        for meta in protocols {
+            builder = builder.insert_protocol(meta);
+        }
+        let mut service = builder.build(());
+        let addr = service
+            .listen("/ip4/127.0.0.1/tcp/0".parse().unwrap())
+            .unwrap();
+        if let Some(dial) = dial {
+            service.dial(dial, DialProtocol::All).unwrap();
+        }
+        thread::spawn(|| tokio::run(service.for_each(|_| Ok(()))));
+        addr
+    }
+
+    fn wait_until<F: Fn() -> bool>(f: F) -> bool {
+        for _ in 0..100 {
+            if f() {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+        false
+    }
+
+    fn pongs(stats: &PingStatsHandle) -> usize {
+        stats
+            .all()
+            .first()
+            .map(|(_, stats)| stats.samples().len())
+            .unwrap_or(0)
+    }
+
+    fn timeouts(stats: &PingStatsHandle) -> u32 {
+        stats
+            .all()
+            .first()
+            .map(|(_, stats)| stats.consecutive_timeouts())
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_timeouts_keep_session() {
+        let handler = ping(Duration::from_millis(100), Duration::from_millis(200));
+        let stats = handler.stats_handle();
+        let peer = run(vec![meta(1, Silent)], None);
+        run(vec![meta(1, handler)], Some(peer));
+
+        assert!(wait_until(|| timeouts(&stats) >= 3));
+        assert_eq!(stats.all().len(), 1);
+    }
+
+    #[test]
+    fn test_late_pong_after_timeout() {
+        let (sender, receiver) = channel(1024);
+        let unexpected = Arc::new(AtomicBool::new(false));
+        let timeouts = Arc::new(AtomicUsize::new(0));
+        let (unexpected_clone, timeouts_clone) = (unexpected.clone(), timeouts.clone());
+        thread::spawn(move || {
+            for event in receiver.wait() {
+                match event {
+                    Ok(Event::UnexpectedError(_)) => unexpected_clone.store(true, Ordering::SeqCst),
+                    Ok(Event::Timeout(_)) => {
+                        timeouts_clone.fetch_add(1, Ordering::SeqCst);
+                    }
+                    _ => (),
+                }
+            }
+        });
+        // Pongs come after the timeout, mostly once the next ping is sent
+        let handler = PingHandler::new(
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+            sender,
+        );
+        let peer = run(vec![meta(1, Slow(Duration::from_millis(300)))], None);
+        run(vec![meta(1, handler)], Some(peer));
+
+        assert!(wait_until(|| timeouts.load(Ordering::SeqCst) >= 3));
+        assert!(!unexpected.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_max_timeouts_disconnect() {
+        let handler = ping(Duration::from_millis(100), Duration::from_millis(200)).max_timeouts(2);
+        let stats = handler.stats_handle();
+        let peer = run(vec![meta(1, Silent)], None);
+        run(vec![meta(1, handler)], Some(peer));
+
+        // The statistics of the peer are dropped with the session
+        assert!(wait_until(|| !stats.all().is_empty()));
+        assert!(wait_until(|| stats.all().is_empty()));
+    }
+
+    #[test]
+    fn test_active_interval_skips_active_session() {
+        let handler = ping(Duration::from_millis(300), Duration::from_secs(10))
+            .active_interval(Duration::from_secs(60));
+        let stats = handler.stats_handle();
+        let peer_ping = ping(Duration::from_secs(60), Duration::from_secs(60));
+        let peer = run(vec![meta(1, peer_ping), meta(2, Chatty)], None);
+        run(vec![meta(1, handler), meta(2, Silent)], Some(peer));
+
+        assert!(wait_until(|| !stats.all().is_empty()));
+        thread::sleep(Duration::from_millis(1500));
+        assert_eq!(pongs(&stats), 0);
+    }
+
+    #[test]
+    fn test_active_interval_ignores_ping_messages() {
+        // The pings of the peer do not make the session active
+        let handler = ping(Duration::from_millis(100), Duration::from_secs(10))
+            .active_interval(Duration::from_secs(60));
+        let stats = handler.stats_handle();
+        let peer_ping = ping(Duration::from_millis(50), Duration::from_secs(10));
+        let peer = run(vec![meta(1, peer_ping)], None);
+        run(vec![meta(1, handler)], Some(peer));
+
+        assert!(wait_until(|| pongs(&stats) >= 3));
+    }
+}
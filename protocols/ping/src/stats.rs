@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use p2p::yamux::RttStats;
+
+/// Ping statistics of a peer
+///
+/// The smoothed rtt is estimated as the rtt of yamux sessions, following
+/// [RFC 6298](https://tools.ietf.org/html/rfc6298#section-2), the jitter is the mean deviation between consecutive samples as in
+/// [RFC 3550](https://tools.ietf.org/html/rfc3550#appendix-A.8). Samples and loss are kept for
+/// the last `window` pings.
+#[derive(Clone, Debug)]
+pub struct PingStats {
+    window: usize,
+    rtt: RttStats,
+    jitter: Duration,
+    samples: VecDeque<Duration>,
+    // Whether each of the last pings has been answered in time
+    answered: VecDeque<bool>,
+    consecutive_timeouts: u32,
+}
+
+impl PingStats {
+    pub(crate) fn new(window: usize) -> Self {
+        PingStats {
+            window,
+            rtt: RttStats::default(),
+            jitter: Duration::default(),
+            samples: VecDeque::with_capacity(window),
+            answered: VecDeque::with_capacity(window),
+            consecutive_timeouts: 0,
+        }
+    }
+
+    /// Smoothed round trip time, `None` if no pong has been received yet
+    pub fn smoothed_rtt(&self) -> Option<Duration> {
+        self.rtt.smoothed_rtt()
+    }
+
+    /// Round trip time variance
+    pub fn rtt_variance(&self) -> Duration {
+        self.rtt.rtt_variance()
+    }
+
+    /// Variation of the round trip time
+    pub fn jitter(&self) -> Duration {
+        self.jitter
+    }
+
+    /// Ratio of the last pings which timed out, from 0 to 1
+    pub fn loss_rate(&self) -> f64 {
+        if self.answered.is_empty() {
+            return 0.0;
+        }
+        let lost = self.answered.iter().filter(|answered| !**answered).count();
+        lost as f64 / self.answered.len() as f64
+    }
+
+    /// Round trip time of the last answered pings, the oldest first
+    pub fn samples(&self) -> &VecDeque<Duration> {
+        &self.samples
+    }
+
+    /// Number of pings timed out since the last pong
+    pub fn consecutive_timeouts(&self) -> u32 {
+        self.consecutive_timeouts
+    }
+
+    pub(crate) fn pong(&mut self, rtt: Duration) {
+        self.rtt.update(rtt);
+        if let Some(last) = self.samples.back() {
+            let diff = if *last > rtt {
+                *last - rtt
+            } else {
+                rtt - *last
+            };
+            self.jitter = (self.jitter * 15 + diff) / 16;
+        }
+        push_bounded(&mut self.samples, rtt, self.window);
+        push_bounded(&mut self.answered, true, self.window);
+        self.consecutive_timeouts = 0;
+    }
+
+    pub(crate) fn timeout(&mut self) {
+        push_bounded(&mut self.answered, false, self.window);
+        self.consecutive_timeouts += 1;
+    }
+}
+
+fn push_bounded<T>(queue: &mut VecDeque<T>, item: T, bound: usize) {
+    if queue.len() >= bound {
+        queue.pop_front();
+    }
+    queue.push_back(item);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PingStats;
+    use std::time::Duration;
+
+    #[test]
+    fn test_rtt_and_jitter() {
+        let mut stats = PingStats::new(4);
+        assert_eq!(stats.smoothed_rtt(), None);
+
+        stats.pong(Duration::from_millis(100));
+        assert_eq!(stats.smoothed_rtt(), Some(Duration::from_millis(100)));
+        assert_eq!(stats.jitter(), Duration::from_millis(0));
+
+        stats.pong(Duration::from_millis(180));
+        assert_eq!(stats.smoothed_rtt(), Some(Duration::from_millis(110)));
+        assert_eq!(stats.jitter(), Duration::from_millis(5));
+
+        for _ in 0..3 {
+            stats.pong(Duration::from_millis(100));
+        }
+        assert_eq!(stats.samples().len(), 4);
+        assert_eq!(stats.samples()[0], Duration::from_millis(180));
+    }
+
+    #[test]
+    fn test_loss() {
+        let mut stats = PingStats::new(4);
+        assert!(stats.loss_rate().abs() < f64::EPSILON);
+
+        stats.timeout();
+        stats.timeout();
+        assert_eq!(stats.consecutive_timeouts(), 2);
+        assert!((stats.loss_rate() - 1.0).abs() < f64::EPSILON);
+
+        stats.pong(Duration::from_millis(100));
+        stats.pong(Duration::from_millis(100));
+        assert_eq!(stats.consecutive_timeouts(), 0);
+        assert!((stats.loss_rate() - 0.5).abs() < f64::EPSILON);
+
+        // Only the last pings count
+        stats.pong(Duration::from_millis(100));
+        assert!((stats.loss_rate() - 0.25).abs() < f64::EPSILON);
+    }
+}
@@ -1,3 +1,4 @@
+use bytes::BytesMut;
 use criterion::{criterion_group, criterion_main, Bencher, Criterion};
 use tentacle_secio::{
     codec::Hmac,
@@ -52,12 +53,67 @@ fn decode_encode(data: &[u8], cipher: CipherType) {
     assert_eq!(&decode_data[..], &data[..]);
 }
 
+fn decode_encode_in_place(data: &[u8], cipher: CipherType) {
+    let cipher_key = (0..cipher.key_size())
+        .map(|_| rand::random::<u8>())
+        .collect::<Vec<_>>();
+    let _hmac_key: [u8; 32] = rand::random();
+    let iv = (0..cipher.iv_size())
+        .map(|_| rand::random::<u8>())
+        .collect::<Vec<_>>();
+
+    let mut encode_cipher = new_stream(cipher, &cipher_key, &iv, CryptoMode::Encrypt);
+    let mut decode_cipher = new_stream(cipher, &cipher_key, &iv, CryptoMode::Decrypt);
+    let (mut decode_hmac, mut encode_hmac): (Option<Hmac>, Option<Hmac>) = match cipher {
+        CipherType::ChaCha20Poly1305 | CipherType::Aes128Gcm | CipherType::Aes256Gcm => {
+            (None, None)
+        }
+        #[cfg(unix)]
+        _ => {
+            use tentacle_secio::Digest;
+            let encode_hmac = Hmac::from_key(Digest::Sha256, &_hmac_key);
+            let decode_hmac = encode_hmac.clone();
+            (Some(decode_hmac), Some(encode_hmac))
+        }
+    };
+
+    let mut encode_data = BytesMut::from(data);
+    encode_cipher.encrypt_in_place(&mut encode_data).unwrap();
+    if encode_hmac.is_some() {
+        let signature = encode_hmac.as_mut().unwrap().sign(&encode_data[..]);
+        encode_data.extend_from_slice(signature.as_ref());
+    }
+
+    if decode_hmac.is_some() {
+        let content_length = encode_data.len() - decode_hmac.as_mut().unwrap().num_bytes();
+
+        let (crypted_data, expected_hash) = encode_data.split_at(content_length);
+
+        assert!(decode_hmac
+            .as_mut()
+            .unwrap()
+            .verify(crypted_data, expected_hash));
+
+        encode_data.truncate(content_length);
+    }
+
+    decode_cipher.decrypt_in_place(&mut encode_data).unwrap();
+
+    assert_eq!(&encode_data[..], &data[..]);
+}
+
 fn bench_test(bench: &mut Bencher, cipher: CipherType, data: &[u8]) {
     bench.iter(|| {
         decode_encode(data, cipher);
     })
 }
 
+fn bench_test_in_place(bench: &mut Bencher, cipher: CipherType, data: &[u8]) {
+    bench.iter(|| {
+        decode_encode_in_place(data, cipher);
+    })
+}
+
 fn criterion_benchmark(bench: &mut Criterion) {
     let data = (0..1024 * 256)
         .map(|_| rand::random::<u8>())
@@ -80,8 +136,25 @@ fn criterion_benchmark(bench: &mut Criterion) {
         let data = data.clone();
         move |b| bench_test(b, CipherType::Aes256Gcm, &data)
     });
-    bench.bench_function("1kb_chacha20poly1305", move |b| {
-        bench_test(b, CipherType::ChaCha20Poly1305, &data)
+    bench.bench_function("1kb_chacha20poly1305", {
+        let data = data.clone();
+        move |b| bench_test(b, CipherType::ChaCha20Poly1305, &data)
+    });
+    #[cfg(unix)]
+    bench.bench_function("1kb_aes128ctr_in_place", {
+        let data = data.clone();
+        move |b| bench_test_in_place(b, CipherType::Aes128Ctr, &data)
+    });
+    bench.bench_function("1kb_aes128gcm_in_place", {
+        let data = data.clone();
+        move |b| bench_test_in_place(b, CipherType::Aes128Gcm, &data)
+    });
+    bench.bench_function("1kb_aes256gcm_in_place", {
+        let data = data.clone();
+        move |b| bench_test_in_place(b, CipherType::Aes256Gcm, &data)
+    });
+    bench.bench_function("1kb_chacha20poly1305_in_place", move |b| {
+        bench_test_in_place(b, CipherType::ChaCha20Poly1305, &data)
     });
 
     let data = (0..1024 * 1024)
@@ -105,8 +178,25 @@ fn criterion_benchmark(bench: &mut Criterion) {
         let data = data.clone();
         move |b| bench_test(b, CipherType::Aes256Gcm, &data)
     });
-    bench.bench_function("1mb_chacha20poly1305", move |b| {
-        bench_test(b, CipherType::ChaCha20Poly1305, &data)
+    bench.bench_function("1mb_chacha20poly1305", {
+        let data = data.clone();
+        move |b| bench_test(b, CipherType::ChaCha20Poly1305, &data)
+    });
+    #[cfg(unix)]
+    bench.bench_function("1mb_aes128ctr_in_place", {
+        let data = data.clone();
+        move |b| bench_test_in_place(b, CipherType::Aes128Ctr, &data)
+    });
+    bench.bench_function("1mb_aes128gcm_in_place", {
+        let data = data.clone();
+        move |b| bench_test_in_place(b, CipherType::Aes128Gcm, &data)
+    });
+    bench.bench_function("1mb_aes256gcm_in_place", {
+        let data = data.clone();
+        move |b| bench_test_in_place(b, CipherType::Aes256Gcm, &data)
+    });
+    bench.bench_function("1mb_chacha20poly1305_in_place", move |b| {
+        bench_test_in_place(b, CipherType::ChaCha20Poly1305, &data)
     });
 }
 
@@ -299,7 +299,8 @@ where
             frame.truncate(content_length);
         }
 
-        let mut out = self.decode_cipher.decrypt(&frame).map(BytesMut::from)?;
+        self.decode_cipher.decrypt_in_place(&mut frame)?;
+        let mut out = frame;
 
         if !self.nonce.is_empty() {
             let n = min(out.len(), self.nonce.len());
@@ -321,12 +322,8 @@ where
 
     /// Encoding data
     #[inline]
-    fn encode_inner(&mut self, data: BytesMut) -> BytesMut {
-        let mut out = self
-            .encode_cipher
-            .encrypt(&data[..])
-            .map(BytesMut::from)
-            .unwrap();
+    fn encode_inner(&mut self, mut out: BytesMut) -> BytesMut {
+        self.encode_cipher.encrypt_in_place(&mut out).unwrap();
         if let Some(ref mut hmac) = self.encode_hmac {
             let signature = hmac.sign(&out[..]);
             out.extend_from_slice(signature.as_ref());
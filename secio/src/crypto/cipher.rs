@@ -60,4 +60,15 @@ impl CipherType {
             CipherType::ChaCha20Poly1305 => aead::CHACHA20_POLY1305.tag_len(),
         }
     }
+
+    /// Whether the cipher is an authenticated encryption with associated data(AEAD) algorithm,
+    /// non-AEAD ciphers need an extra hmac to verify the message.
+    #[inline]
+    pub fn is_aead(self) -> bool {
+        match self {
+            #[cfg(unix)]
+            CipherType::Aes128Ctr | CipherType::Aes256Ctr => false,
+            CipherType::Aes128Gcm | CipherType::Aes256Gcm | CipherType::ChaCha20Poly1305 => true,
+        }
+    }
 }
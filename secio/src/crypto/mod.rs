@@ -1,3 +1,5 @@
+use bytes::BytesMut;
+
 use crate::error::SecioError;
 
 /// Define cipher
@@ -18,6 +20,10 @@ pub trait StreamCipher {
     fn encrypt(&mut self, input: &[u8]) -> Result<Vec<u8>, SecioError>;
     /// Feeds data from input through the cipher, return decrypted bytes.
     fn decrypt(&mut self, input: &[u8]) -> Result<Vec<u8>, SecioError>;
+    /// Encrypt data in place, the tag(if any) is appended to the end of data.
+    fn encrypt_in_place(&mut self, data: &mut BytesMut) -> Result<(), SecioError>;
+    /// Decrypt data in place, the tag(if any) is stripped from the end of data.
+    fn decrypt_in_place(&mut self, data: &mut BytesMut) -> Result<(), SecioError>;
 }
 
 /// Crypto mode, encrypt or decrypt
@@ -96,6 +102,7 @@ mod test {
     use super::{
         cipher::CipherType, openssl_impl::OpenSSLCrypt, ring_impl::RingAeadCipher, CryptoMode,
     };
+    use bytes::BytesMut;
 
     fn test_openssl_encrypt_ring_decrypt(cipher: CipherType) {
         let key = (0..cipher.key_size())
@@ -153,22 +160,52 @@ mod test {
         assert_eq!(message, &decrypted_msg[..]);
     }
 
+    fn test_in_place_cross(cipher: CipherType) {
+        let key = (0..cipher.key_size())
+            .map(|_| rand::random::<u8>())
+            .collect::<Vec<_>>();
+        let iv = (0..cipher.iv_size())
+            .map(|_| rand::random::<u8>())
+            .collect::<Vec<_>>();
+
+        let mut openssl_encrypt = OpenSSLCrypt::new(cipher, &key, &iv);
+        let mut ring_decrypt = RingAeadCipher::new(cipher, &key, CryptoMode::Decrypt);
+        let mut ring_encrypt = RingAeadCipher::new(cipher, &key, CryptoMode::Encrypt);
+        let mut openssl_decrypt = OpenSSLCrypt::new(cipher, &key, &iv);
+
+        for message in &[&b"HELLO WORLD"[..], &b"hello, world"[..]] {
+            let mut data = BytesMut::from(*message);
+            openssl_encrypt.encrypt_in_place(&mut data).unwrap();
+            assert_eq!(data.len(), message.len() + cipher.tag_size());
+            ring_decrypt.decrypt_in_place(&mut data).unwrap();
+            assert_eq!(message, &&data[..]);
+
+            let mut data = BytesMut::from(*message);
+            ring_encrypt.encrypt_in_place(&mut data).unwrap();
+            openssl_decrypt.decrypt_in_place(&mut data).unwrap();
+            assert_eq!(message, &&data[..]);
+        }
+    }
+
     #[test]
     fn test_aes_128_gcm() {
         test_ring_encrypt_openssl_decrypt(CipherType::Aes128Gcm);
-        test_openssl_encrypt_ring_decrypt(CipherType::Aes128Gcm)
+        test_openssl_encrypt_ring_decrypt(CipherType::Aes128Gcm);
+        test_in_place_cross(CipherType::Aes128Gcm)
     }
 
     #[test]
     fn test_aes_256_gcm() {
         test_ring_encrypt_openssl_decrypt(CipherType::Aes256Gcm);
-        test_openssl_encrypt_ring_decrypt(CipherType::Aes256Gcm)
+        test_openssl_encrypt_ring_decrypt(CipherType::Aes256Gcm);
+        test_in_place_cross(CipherType::Aes256Gcm)
     }
 
     #[cfg(any(ossl110))]
     #[test]
     fn test_chacha20_poly1305() {
         test_ring_encrypt_openssl_decrypt(CipherType::ChaCha20Poly1305);
-        test_openssl_encrypt_ring_decrypt(CipherType::ChaCha20Poly1305)
+        test_openssl_encrypt_ring_decrypt(CipherType::ChaCha20Poly1305);
+        test_in_place_cross(CipherType::ChaCha20Poly1305)
     }
 }
@@ -1,5 +1,10 @@
 use bytes::{Bytes, BytesMut};
-use openssl::symm;
+use openssl::{error::ErrorStack, symm};
+use openssl_sys as ffi;
+use std::{
+    os::raw::{c_int, c_void},
+    ptr,
+};
 
 use crate::{
     crypto::{cipher::CipherType, nonce_advance, StreamCipher},
@@ -98,6 +103,143 @@ impl OpenSSLCrypt {
             symm::decrypt(self.cipher, &self.key, Some(&self.iv), input).map_err(Into::into)
         }
     }
+
+    /// Encrypt `data` in place, and append the tag to the end of `data`.
+    pub fn encrypt_in_place(&mut self, data: &mut BytesMut) -> Result<(), SecioError> {
+        if self.aead {
+            nonce_advance(self.iv.as_mut());
+        }
+        let mut ctx = CipherCtx::new(self.cipher, symm::Mode::Encrypt, &self.key, &self.iv)?;
+        ctx.crypt_in_place(&mut data[..])?;
+
+        if self.aead {
+            let tag_size = self.cipher_type.tag_size();
+            let mut tag = [0; 16];
+            ctx.get_tag(&mut tag[..tag_size])?;
+            data.extend_from_slice(&tag[..tag_size]);
+        }
+        Ok(())
+    }
+
+    /// Strip the tag from the end of `data`, and decrypt `data` in place.
+    pub fn decrypt_in_place(&mut self, data: &mut BytesMut) -> Result<(), SecioError> {
+        let tag_size = self.cipher_type.tag_size();
+        if data.len() < tag_size {
+            return Err(SecioError::FrameTooShort);
+        }
+        let crypt_data_len = data.len() - tag_size;
+
+        if self.aead {
+            nonce_advance(self.iv.as_mut());
+        }
+        let mut ctx = CipherCtx::new(self.cipher, symm::Mode::Decrypt, &self.key, &self.iv)?;
+        if self.aead {
+            ctx.set_tag(&data[crypt_data_len..])?;
+        }
+        ctx.crypt_in_place(&mut data[..crypt_data_len])?;
+        data.truncate(crypt_data_len);
+        Ok(())
+    }
+}
+
+/// Cipher context crypting in place, `symm::Crypter` takes distinct input and output buffers
+///
+/// Only for the stream-like ciphers of secio (ctr, gcm and chacha20-poly1305), with which openssl
+/// allows the same buffer as input and output and outputs nothing at finalization.
+struct CipherCtx(*mut ffi::EVP_CIPHER_CTX);
+
+impl CipherCtx {
+    fn new(
+        cipher: symm::Cipher,
+        mode: symm::Mode,
+        key: &[u8],
+        iv: &[u8],
+    ) -> Result<Self, ErrorStack> {
+        debug_assert_eq!(cipher.block_size(), 1);
+        debug_assert_eq!(Some(iv.len()), cipher.iv_len());
+        let enc = match mode {
+            symm::Mode::Encrypt => 1,
+            symm::Mode::Decrypt => 0,
+        };
+        unsafe {
+            let ctx = ffi::EVP_CIPHER_CTX_new();
+            if ctx.is_null() {
+                return Err(ErrorStack::get());
+            }
+            // Owned from here, freed on error too
+            let ctx = CipherCtx(ctx);
+            cvt(ffi::EVP_CipherInit_ex(
+                ctx.0,
+                cipher.as_ptr(),
+                ptr::null_mut(),
+                key.as_ptr(),
+                iv.as_ptr(),
+                enc,
+            ))?;
+            Ok(ctx)
+        }
+    }
+
+    fn crypt_in_place(&mut self, data: &mut [u8]) -> Result<(), ErrorStack> {
+        let data_ptr = data.as_mut_ptr();
+        let mut len: c_int = 0;
+        let mut final_len: c_int = 0;
+        let mut final_buf = [0; 16];
+        unsafe {
+            cvt(ffi::EVP_CipherUpdate(
+                self.0,
+                data_ptr,
+                &mut len,
+                data_ptr,
+                data.len() as c_int,
+            ))?;
+            // Checks the tag on decryption
+            cvt(ffi::EVP_CipherFinal_ex(
+                self.0,
+                final_buf.as_mut_ptr(),
+                &mut final_len,
+            ))?;
+        }
+        debug_assert_eq!(len as usize, data.len());
+        debug_assert_eq!(final_len, 0);
+        Ok(())
+    }
+
+    fn get_tag(&mut self, tag: &mut [u8]) -> Result<(), ErrorStack> {
+        unsafe {
+            cvt(ffi::EVP_CIPHER_CTX_ctrl(
+                self.0,
+                ffi::EVP_CTRL_GCM_GET_TAG,
+                tag.len() as c_int,
+                tag.as_mut_ptr() as *mut c_void,
+            ))
+        }
+    }
+
+    fn set_tag(&mut self, tag: &[u8]) -> Result<(), ErrorStack> {
+        unsafe {
+            cvt(ffi::EVP_CIPHER_CTX_ctrl(
+                self.0,
+                ffi::EVP_CTRL_GCM_SET_TAG,
+                tag.len() as c_int,
+                tag.as_ptr() as *mut c_void,
+            ))
+        }
+    }
+}
+
+impl Drop for CipherCtx {
+    fn drop(&mut self) {
+        unsafe { ffi::EVP_CIPHER_CTX_free(self.0) }
+    }
+}
+
+fn cvt(result: c_int) -> Result<(), ErrorStack> {
+    if result <= 0 {
+        Err(ErrorStack::get())
+    } else {
+        Ok(())
+    }
 }
 
 impl StreamCipher for OpenSSLCrypt {
@@ -108,11 +250,20 @@ impl StreamCipher for OpenSSLCrypt {
     fn decrypt(&mut self, input: &[u8]) -> Result<Vec<u8>, SecioError> {
         self.decrypt(input)
     }
+
+    fn encrypt_in_place(&mut self, data: &mut BytesMut) -> Result<(), SecioError> {
+        self.encrypt_in_place(data)
+    }
+
+    fn decrypt_in_place(&mut self, data: &mut BytesMut) -> Result<(), SecioError> {
+        self.decrypt_in_place(data)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::{CipherType, OpenSSLCrypt};
+    use bytes::BytesMut;
     use rand;
 
     fn test_openssl(mode: CipherType) {
@@ -141,6 +292,25 @@ mod test {
         let decrypted_msg = decryptor.decrypt(&encrypted_msg[..]).unwrap();
 
         assert_eq!(message, &decrypted_msg[..]);
+
+        // in place
+        for message in &[&b"HELLO WORLD"[..], &b"hello, world"[..]] {
+            let mut data = BytesMut::from(*message);
+            encryptor.encrypt_in_place(&mut data).unwrap();
+            assert_eq!(data.len(), message.len() + mode.tag_size());
+            assert_ne!(message, &&data[..message.len()]);
+            decryptor.decrypt_in_place(&mut data).unwrap();
+            assert_eq!(message, &&data[..]);
+        }
+
+        // a forged tag is refused
+        if mode.tag_size() > 0 {
+            let mut data = BytesMut::from(&b"HELLO WORLD"[..]);
+            encryptor.encrypt_in_place(&mut data).unwrap();
+            let last = data.len() - 1;
+            data[last] ^= 1;
+            assert!(decryptor.decrypt_in_place(&mut data).is_err());
+        }
     }
 
     #[test]
@@ -116,6 +116,31 @@ impl RingAeadCipher {
         }
         Ok(output)
     }
+
+    /// Encrypt `data` in place, and append the tag to the end of `data`.
+    pub fn encrypt_in_place(&mut self, data: &mut BytesMut) -> Result<(), SecioError> {
+        if let RingAeadCryptoVariant::Seal(ref mut key) = self.cipher {
+            let tag = key.seal_in_place_separate_tag(Aad::empty(), &mut data[..])?;
+            data.extend_from_slice(tag.as_ref());
+            Ok(())
+        } else {
+            unreachable!("encrypt is called on a non-seal cipher")
+        }
+    }
+
+    /// Strip the tag from the end of `data`, and decrypt `data` in place.
+    pub fn decrypt_in_place(&mut self, data: &mut BytesMut) -> Result<(), SecioError> {
+        if data.len() < self.cipher_type.tag_size() {
+            return Err(SecioError::FrameTooShort);
+        }
+        if let RingAeadCryptoVariant::Open(ref mut key) = self.cipher {
+            let len = key.open_in_place(Aad::empty(), &mut data[..])?.len();
+            data.truncate(len);
+            Ok(())
+        } else {
+            unreachable!("decrypt is called on a non-open cipher")
+        }
+    }
 }
 
 impl StreamCipher for RingAeadCipher {
@@ -126,11 +151,20 @@ impl StreamCipher for RingAeadCipher {
     fn decrypt(&mut self, input: &[u8]) -> Result<Vec<u8>, SecioError> {
         self.decrypt(input)
     }
+
+    fn encrypt_in_place(&mut self, data: &mut BytesMut) -> Result<(), SecioError> {
+        self.encrypt_in_place(data)
+    }
+
+    fn decrypt_in_place(&mut self, data: &mut BytesMut) -> Result<(), SecioError> {
+        self.decrypt_in_place(data)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::{CipherType, CryptoMode, RingAeadCipher};
+    use bytes::BytesMut;
 
     fn test_ring_aead(cipher: CipherType) {
         let key = (0..cipher.key_size())
@@ -161,6 +195,15 @@ mod test {
         let decrypted_msg = dec.decrypt(&encrypted_msg[..]).unwrap();
 
         assert_eq!(&decrypted_msg[..], message);
+
+        // in place
+        for message in &[&b"HELLO WORLD"[..], &b"hello, world"[..]] {
+            let mut data = BytesMut::from(*message);
+            enc.encrypt_in_place(&mut data).unwrap();
+            assert_eq!(data.len(), message.len() + cipher.tag_size());
+            dec.decrypt_in_place(&mut data).unwrap();
+            assert_eq!(message, &&data[..]);
+        }
     }
 
     #[test]
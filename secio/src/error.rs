@@ -46,6 +46,24 @@ pub enum SecioError {
 
     /// We received an invalid proposition from remote.
     InvalidProposition(&'static str),
+
+    /// The pre-shared key is not 32 bytes.
+    InvalidPreSharedKey,
+
+    /// Remote doesn't hold the same pre-shared key.
+    PreSharedKeyMismatch,
+
+    /// The handshake can't satisfy the cipher policy, contains the missing capability.
+    PolicyNotSatisfied(&'static str),
+
+    /// The key file can't be parsed.
+    InvalidKeyFile(&'static str),
+
+    /// The key file can be read by users other than the owner.
+    KeyFilePermissionTooOpen,
+
+    /// Wrong password or the keystore has been modified.
+    KeystoreDecryptFailed,
 }
 
 impl PartialEq for SecioError {
@@ -53,6 +71,8 @@ impl PartialEq for SecioError {
         use self::SecioError::*;
         match (self, other) {
             (InvalidProposition(i), InvalidProposition(j)) => i == j,
+            (PolicyNotSatisfied(i), PolicyNotSatisfied(j)) => i == j,
+            (InvalidKeyFile(i), InvalidKeyFile(j)) => i == j,
             (EphemeralKeyGenerationFailed, EphemeralKeyGenerationFailed)
             | (SecretGenerationFailed, SecretGenerationFailed)
             | (NoSupportIntersection, NoSupportIntersection)
@@ -62,7 +82,11 @@ impl PartialEq for SecioError {
             | (ConnectSelf, ConnectSelf)
             | (HandshakeParsingFailure, HandshakeParsingFailure)
             | (SignatureVerificationFailed, SignatureVerificationFailed)
-            | (InvalidMessage, InvalidMessage) => true,
+            | (InvalidMessage, InvalidMessage)
+            | (InvalidPreSharedKey, InvalidPreSharedKey)
+            | (PreSharedKeyMismatch, PreSharedKeyMismatch)
+            | (KeyFilePermissionTooOpen, KeyFilePermissionTooOpen)
+            | (KeystoreDecryptFailed, KeystoreDecryptFailed) => true,
             _ => false,
         }
     }
@@ -115,6 +139,12 @@ impl error::Error for SecioError {
             SecioError::InvalidMessage => "Invalid Message",
             SecioError::SignatureVerificationFailed => "Signature Verification Failed",
             SecioError::InvalidProposition(e) => e,
+            SecioError::InvalidPreSharedKey => "Invalid Pre-Shared Key",
+            SecioError::PreSharedKeyMismatch => "Pre-Shared Key Mismatch",
+            SecioError::PolicyNotSatisfied(_) => "Policy Not Satisfied",
+            SecioError::InvalidKeyFile(e) => e,
+            SecioError::KeyFilePermissionTooOpen => "Key File Permission Too Open",
+            SecioError::KeystoreDecryptFailed => "Keystore Decrypt Failed",
         }
     }
 }
@@ -137,6 +167,12 @@ impl fmt::Display for SecioError {
             SecioError::InvalidMessage => write!(f, "Invalid Message"),
             SecioError::SignatureVerificationFailed => write!(f, "Signature Verification Failed"),
             SecioError::InvalidProposition(e) => write!(f, "Invalid Proposition: {}", e),
+            SecioError::InvalidPreSharedKey => write!(f, "Invalid Pre-Shared Key"),
+            SecioError::PreSharedKeyMismatch => write!(f, "Pre-Shared Key Mismatch"),
+            SecioError::PolicyNotSatisfied(e) => write!(f, "Policy Not Satisfied, missing: {}", e),
+            SecioError::InvalidKeyFile(e) => write!(f, "Invalid Key File: {}", e),
+            SecioError::KeyFilePermissionTooOpen => write!(f, "Key File Permission Too Open"),
+            SecioError::KeystoreDecryptFailed => write!(f, "Keystore Decrypt Failed"),
         }
     }
 }
@@ -82,7 +82,7 @@ impl HandshakeContext<()> {
     }
 
     // Setup local proposition.
-    pub fn with_local(self) -> HandshakeContext<Local> {
+    pub fn with_local(self) -> Result<HandshakeContext<Local>, SecioError> {
         let nonce: [u8; 16] = rand::random();
 
         let public_key = self.config.key.public_key();
@@ -99,30 +99,22 @@ impl HandshakeContext<()> {
             .unwrap_or_else(|| support::DEFAULT_AGREEMENTS_PROPOSITION.into());
         trace!("agreements proposition: {}", proposition.exchange);
 
-        proposition.ciphers = self
-            .config
-            .ciphers_proposal
-            .clone()
-            .unwrap_or_else(|| support::DEFAULT_CIPHERS_PROPOSITION.into());
+        proposition.ciphers = self.config.ciphers_proposition()?;
         trace!("ciphers proposition: {}", proposition.ciphers);
 
-        proposition.hashes = self
-            .config
-            .digests_proposal
-            .clone()
-            .unwrap_or_else(|| support::DEFAULT_DIGESTS_PROPOSITION.into());
+        proposition.hashes = self.config.digests_proposition()?;
         trace!("digests proposition: {}", proposition.hashes);
 
         let proposition_bytes = proposition.encode();
 
-        HandshakeContext {
+        Ok(HandshakeContext {
             config: self.config,
             state: Local {
                 nonce,
                 public_key: public_key.inner(),
                 proposition_bytes,
             },
-        }
+        })
     }
 }
 
@@ -193,14 +185,15 @@ impl HandshakeContext<Local> {
         };
 
         let chosen_cipher = {
-            let ours = self
-                .config
-                .ciphers_proposal
-                .as_ref()
-                .map(AsRef::as_ref)
-                .unwrap_or(support::DEFAULT_CIPHERS_PROPOSITION);
-            let theirs = &propose.ciphers;
-            match support::select_cipher(hashes_ordering, ours, theirs) {
+            let ours = self.config.ciphers_proposition()?;
+            let theirs = match self.config.policy.ciphers_proposition(&propose.ciphers) {
+                Ok(theirs) => theirs,
+                Err(err) => {
+                    debug!("remote's ciphers proposition doesn't satisfy the policy");
+                    return Err(err);
+                }
+            };
+            match support::select_cipher(hashes_ordering, &ours, &theirs) {
                 Ok(a) => {
                     debug!("selected cipher: {:?}", a);
                     a
@@ -213,14 +206,15 @@ impl HandshakeContext<Local> {
         };
 
         let chosen_hash = {
-            let ours = self
-                .config
-                .digests_proposal
-                .as_ref()
-                .map(AsRef::as_ref)
-                .unwrap_or(support::DEFAULT_DIGESTS_PROPOSITION);
-            let theirs = &propose.hashes;
-            match support::select_digest(hashes_ordering, ours, theirs) {
+            let ours = self.config.digests_proposition()?;
+            let theirs = match self.config.policy.digests_proposition(&propose.hashes) {
+                Ok(theirs) => theirs,
+                Err(err) => {
+                    debug!("remote's digests proposition doesn't satisfy the policy");
+                    return Err(err);
+                }
+            };
+            match support::select_digest(hashes_ordering, &ours, &theirs) {
                 Ok(a) => {
                     debug!("selected hash: {:?}", a);
                     a
@@ -8,7 +8,7 @@ use crate::handshake::handshake_mol;
 #[cfg(feature = "molc")]
 use molecule::prelude::{Builder, Entity, Reader};
 
-use crate::peer_id::PeerId;
+use crate::{peer_id::PeerId, signing_digest, DEFAULT_SIGN_DOMAIN};
 
 use bytes::Bytes;
 use std::fmt;
@@ -279,6 +279,33 @@ impl PublicKey {
     pub fn peer_id(&self) -> PeerId {
         PeerId::from_public_key(self)
     }
+
+    /// Verify the signature produced by `SecioKeyPair::sign`
+    pub fn verify(&self, data: &[u8], signature: &[u8]) -> bool {
+        self.verify_with_domain(DEFAULT_SIGN_DOMAIN, data, signature)
+    }
+
+    /// Verify the signature produced by `SecioKeyPair::sign_with_domain`
+    pub fn verify_with_domain(&self, domain: &[u8], data: &[u8], signature: &[u8]) -> bool {
+        let digest = signing_digest(domain, data);
+        match self {
+            PublicKey::Secp256k1(ref key) => {
+                let message = match secp256k1::Message::from_slice(digest.as_ref()) {
+                    Ok(msg) => msg,
+                    Err(_) => return false,
+                };
+                match (
+                    secp256k1::Signature::from_der(signature),
+                    secp256k1::key::PublicKey::from_slice(key),
+                ) {
+                    (Ok(signature), Ok(pubkey)) => secp256k1::Secp256k1::verification_only()
+                        .verify(&message, &signature, &pubkey)
+                        .is_ok(),
+                    _ => false,
+                }
+            }
+        }
+    }
 }
 
 impl fmt::Debug for PublicKey {
@@ -328,4 +355,25 @@ mod tests {
 
         assert_eq!(raw, Exchange::decode(&byte).unwrap())
     }
+
+    #[test]
+    fn sign_and_verify() {
+        let key = SecioKeyPair::secp256k1_generated();
+        let pubkey = key.public_key();
+        let data = b"signed address record";
+
+        let signature = key.sign(data);
+        assert!(pubkey.verify(data, &signature));
+        assert!(!pubkey.verify(b"other data", &signature));
+        assert!(!pubkey.verify(data, &[1u8; 64]));
+        assert!(!SecioKeyPair::secp256k1_generated()
+            .public_key()
+            .verify(data, &signature));
+
+        let signature = key.sign_with_domain(b"vote", data);
+        assert!(pubkey.verify_with_domain(b"vote", data, &signature));
+        assert!(!pubkey.verify(data, &signature));
+        // The domain length is part of the message, so moving bytes across the boundary won't work
+        assert!(!pubkey.verify_with_domain(b"vot", b"esigned address record", &signature));
+    }
 }
@@ -30,8 +30,11 @@ mod handshake_mol;
 
 mod handshake_context;
 pub(crate) mod handshake_struct;
+mod policy;
 mod procedure;
 
+pub use self::policy::CipherPolicy;
+
 const MAX_FRAME_SIZE: usize = 1024 * 1024 * 8;
 
 /// Config for Secio
@@ -42,6 +45,7 @@ pub struct Config {
     pub(crate) ciphers_proposal: Option<String>,
     pub(crate) digests_proposal: Option<String>,
     pub(crate) max_frame_length: usize,
+    pub(crate) policy: CipherPolicy,
 }
 
 impl Config {
@@ -53,6 +57,7 @@ impl Config {
             ciphers_proposal: None,
             digests_proposal: None,
             max_frame_length: MAX_FRAME_SIZE,
+            policy: CipherPolicy::default(),
         }
     }
 
@@ -89,6 +94,32 @@ impl Config {
         self
     }
 
+    /// Restrict the algorithms that can be negotiated, default allow all supported algorithms.
+    pub fn cipher_policy(mut self, policy: CipherPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// The ciphers proposition after applying the policy
+    pub(crate) fn ciphers_proposition(&self) -> Result<String, SecioError> {
+        let proposition = self
+            .ciphers_proposal
+            .as_ref()
+            .map(AsRef::as_ref)
+            .unwrap_or(support::DEFAULT_CIPHERS_PROPOSITION);
+        self.policy.ciphers_proposition(proposition)
+    }
+
+    /// The digests proposition after applying the policy
+    pub(crate) fn digests_proposition(&self) -> Result<String, SecioError> {
+        let proposition = self
+            .digests_proposal
+            .as_ref()
+            .map(AsRef::as_ref)
+            .unwrap_or(support::DEFAULT_DIGESTS_PROPOSITION);
+        self.policy.digests_proposition(proposition)
+    }
+
     /// Attempts to perform a handshake on the given socket.
     ///
     /// On success, produces a `SecureStream` that can then be used to encode/decode
@@ -0,0 +1,151 @@
+use crate::{crypto::cipher::CipherType, error::SecioError, support, Digest};
+
+/// Restrict the algorithms that can be negotiated during the handshake.
+///
+/// The policy is applied to both the local proposition and the remote's,
+/// a remote that can't meet the policy will fail the handshake with `SecioError::PolicyNotSatisfied`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CipherPolicy {
+    aead_only: bool,
+    denied_ciphers: Vec<CipherType>,
+    denied_digests: Vec<Digest>,
+}
+
+impl CipherPolicy {
+    /// Allow every supported algorithm, this is the default policy
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Only allow AEAD ciphers, that is, AES-GCM and ChaCha20-Poly1305
+    pub fn strict() -> Self {
+        CipherPolicy {
+            aead_only: true,
+            ..Default::default()
+        }
+    }
+
+    /// Whether only AEAD ciphers are allowed
+    pub fn is_strict(&self) -> bool {
+        self.aead_only
+    }
+
+    /// Refuse a specific cipher
+    pub fn deny_cipher(mut self, cipher: CipherType) -> Self {
+        if !self.denied_ciphers.contains(&cipher) {
+            self.denied_ciphers.push(cipher);
+        }
+        self
+    }
+
+    /// Refuse a specific digest algorithm
+    pub fn deny_digest(mut self, digest: Digest) -> Self {
+        if !self.denied_digests.contains(&digest) {
+            self.denied_digests.push(digest);
+        }
+        self
+    }
+
+    /// Whether the cipher is allowed by this policy
+    pub fn allow_cipher(&self, cipher: CipherType) -> bool {
+        (!self.aead_only || cipher.is_aead()) && !self.denied_ciphers.contains(&cipher)
+    }
+
+    /// Whether the digest algorithm is allowed by this policy
+    pub fn allow_digest(&self, digest: Digest) -> bool {
+        !self.denied_digests.contains(&digest)
+    }
+
+    /// Apply the policy to the cipher proposition, error if nothing left
+    pub(crate) fn ciphers_proposition(&self, proposition: &str) -> Result<String, SecioError> {
+        let ciphers = support::filter_ciphers(proposition, self);
+        if ciphers.is_empty() {
+            Err(self.missing_cipher())
+        } else {
+            Ok(ciphers)
+        }
+    }
+
+    /// Apply the policy to the digest proposition, error if nothing left
+    pub(crate) fn digests_proposition(&self, proposition: &str) -> Result<String, SecioError> {
+        let digests = support::filter_digests(proposition, self);
+        if digests.is_empty() {
+            Err(SecioError::PolicyNotSatisfied("allowed digest"))
+        } else {
+            Ok(digests)
+        }
+    }
+
+    fn missing_cipher(&self) -> SecioError {
+        if self.aead_only {
+            SecioError::PolicyNotSatisfied("AEAD cipher")
+        } else {
+            SecioError::PolicyNotSatisfied("allowed cipher")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CipherPolicy;
+    use crate::{crypto::cipher::CipherType, error::SecioError, support, Digest};
+
+    #[test]
+    fn default_policy_allow_all() {
+        let policy = CipherPolicy::new();
+        assert_eq!(
+            policy
+                .ciphers_proposition(support::DEFAULT_CIPHERS_PROPOSITION)
+                .unwrap(),
+            support::DEFAULT_CIPHERS_PROPOSITION
+        );
+        assert_eq!(
+            policy
+                .digests_proposition(support::DEFAULT_DIGESTS_PROPOSITION)
+                .unwrap(),
+            support::DEFAULT_DIGESTS_PROPOSITION
+        );
+    }
+
+    #[test]
+    fn strict_policy_only_aead() {
+        let policy = CipherPolicy::strict();
+        assert_eq!(
+            policy
+                .ciphers_proposition(support::DEFAULT_CIPHERS_PROPOSITION)
+                .unwrap(),
+            "AES-128-GCM,AES-256-GCM,CHACHA20_POLY1305"
+        );
+        #[cfg(unix)]
+        assert!(!policy.allow_cipher(CipherType::Aes128Ctr));
+        assert!(policy.allow_cipher(CipherType::ChaCha20Poly1305));
+
+        assert_eq!(
+            policy.ciphers_proposition("AES-128-CTR,AES-256"),
+            Err(SecioError::PolicyNotSatisfied("AEAD cipher"))
+        );
+    }
+
+    #[test]
+    fn deny_list() {
+        let policy = CipherPolicy::strict()
+            .deny_cipher(CipherType::Aes128Gcm)
+            .deny_digest(Digest::Sha256);
+        assert_eq!(
+            policy
+                .ciphers_proposition(support::DEFAULT_CIPHERS_PROPOSITION)
+                .unwrap(),
+            "AES-256-GCM,CHACHA20_POLY1305"
+        );
+        assert_eq!(
+            policy
+                .digests_proposition(support::DEFAULT_DIGESTS_PROPOSITION)
+                .unwrap(),
+            "SHA512"
+        );
+        assert_eq!(
+            policy.digests_proposition("SHA256"),
+            Err(SecioError::PolicyNotSatisfied("allowed digest"))
+        );
+    }
+}
@@ -50,7 +50,7 @@ where
     future::ok::<_, SecioError>(HandshakeContext::new(config))
         .and_then(|empty_context| {
             // Generate our nonce.
-            let context = empty_context.with_local();
+            let context = empty_context.with_local()?;
             trace!(
                 "starting handshake; local nonce = {:?}",
                 context.state.nonce
@@ -16,8 +16,12 @@ pub mod error;
 mod exchange;
 /// Implementation of the handshake process
 pub mod handshake;
+/// Load and save key pair on disk
+pub mod keystore;
 /// Peer id
 pub mod peer_id;
+/// Private network layer with pre-shared key
+pub mod pnet;
 /// Supported algorithms
 mod support;
 
@@ -72,6 +76,39 @@ impl SecioKeyPair {
     pub fn peer_id(&self) -> PeerId {
         self.public_key().peer_id()
     }
+
+    /// Sign data with the default domain tag, verify with `PublicKey::verify`
+    pub fn sign(&self, data: &[u8]) -> Vec<u8> {
+        self.sign_with_domain(DEFAULT_SIGN_DOMAIN, data)
+    }
+
+    /// Sign data with a domain separation tag, verify with `PublicKey::verify_with_domain`.
+    ///
+    /// A signature produced under one domain never verifies under another one,
+    /// and never collides with the signature of the handshake.
+    pub fn sign_with_domain(&self, domain: &[u8], data: &[u8]) -> Vec<u8> {
+        let digest = signing_digest(domain, data);
+        match self.inner {
+            KeyPairInner::Secp256k1 { ref private } => {
+                let message = secp256k1::Message::from_slice(digest.as_ref())
+                    .expect("sha256 digest is a valid message");
+                let secp = secp256k1::Secp256k1::signing_only();
+                secp.sign(&message, private).serialize_der().to_vec()
+            }
+        }
+    }
+}
+
+/// The domain separation tag used by `SecioKeyPair::sign` and `PublicKey::verify`
+pub const DEFAULT_SIGN_DOMAIN: &[u8] = b"/tentacle/secio/sign";
+
+/// sha256(domain length(u32 big endian) | domain | data)
+pub(crate) fn signing_digest(domain: &[u8], data: &[u8]) -> ring::digest::Digest {
+    let mut ctx = ring::digest::Context::new(&ring::digest::SHA256);
+    ctx.update(&(domain.len() as u32).to_be_bytes());
+    ctx.update(domain);
+    ctx.update(data);
+    ctx.finish()
 }
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,325 @@
+//! Private network support, the idea comes from libp2p's `pnet`.
+//!
+//! Before the secio handshake starts, both sides exchange a random nonce in plaintext,
+//! then wrap the raw stream with a keystream derived from the pre-shared key and the nonce.
+//! Each side immediately sends an encrypted magic value, so a peer that doesn't hold the
+//! same key is rejected before any secio message is processed.
+use bytes::BytesMut;
+use futures::{future, Async, Future, Poll};
+use log::debug;
+use ring::{aead, hmac};
+use std::{
+    fmt,
+    io::{self, Read, Write},
+};
+use tokio::prelude::{AsyncRead, AsyncWrite};
+
+use crate::error::SecioError;
+
+/// The size of pre-shared key
+pub const KEY_SIZE: usize = 32;
+const NONCE_SIZE: usize = 24;
+const KEY_STREAM_BLOCK_SIZE: usize = 4096;
+const KEY_DERIVE_TAG: &[u8] = b"tentacle-pnet-key";
+const MAGIC: &[u8; 16] = b"/tentacle/pnet/1";
+
+/// A 32 bytes pre-shared key, only nodes that hold the same key can communicate with each other
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PreSharedKey([u8; KEY_SIZE]);
+
+impl PreSharedKey {
+    /// Create a pre-shared key from raw bytes
+    pub fn new(key: [u8; KEY_SIZE]) -> Self {
+        PreSharedKey(key)
+    }
+
+    /// Create a pre-shared key from a 32 bytes slice
+    pub fn from_slice(key: &[u8]) -> Result<Self, SecioError> {
+        if key.len() != KEY_SIZE {
+            return Err(SecioError::InvalidPreSharedKey);
+        }
+        let mut inner = [0; KEY_SIZE];
+        inner.copy_from_slice(key);
+        Ok(PreSharedKey(inner))
+    }
+
+    /// Generate a random pre-shared key
+    pub fn random() -> Self {
+        PreSharedKey(rand::random())
+    }
+
+    /// The fingerprint of the key, safe to print to log
+    pub fn fingerprint(&self) -> [u8; 16] {
+        let digest = ring::digest::digest(&ring::digest::SHA256, &self.0);
+        let mut fingerprint = [0; 16];
+        fingerprint.copy_from_slice(&digest.as_ref()[..16]);
+        fingerprint
+    }
+
+    /// Exchange nonce with remote and wrap the socket with the keyed stream cipher.
+    ///
+    /// If remote does not hold the same key, return `SecioError::PreSharedKeyMismatch`.
+    pub fn handshake<T>(self, socket: T) -> impl Future<Item = PnetStream<T>, Error = SecioError>
+    where
+        T: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let local_nonce: [u8; NONCE_SIZE] = rand::random();
+
+        tokio::io::write_all(socket, local_nonce)
+            .and_then(|(socket, _)| tokio::io::flush(socket))
+            .and_then(|socket| tokio::io::read_exact(socket, [0u8; NONCE_SIZE]))
+            .from_err()
+            .and_then(move |(socket, remote_nonce)| {
+                if remote_nonce == local_nonce {
+                    return future::Either::A(future::err(SecioError::ConnectSelf));
+                }
+                let stream = PnetStream {
+                    socket,
+                    encoder: KeyStream::new(&self, &local_nonce),
+                    decoder: KeyStream::new(&self, &remote_nonce),
+                    pending: BytesMut::new(),
+                };
+                future::Either::B(
+                    tokio::io::write_all(stream, *MAGIC)
+                        .and_then(|(stream, _)| tokio::io::flush(stream))
+                        .and_then(|stream| tokio::io::read_exact(stream, [0u8; 16]))
+                        .from_err()
+                        .and_then(|(stream, magic)| {
+                            if &magic == MAGIC {
+                                Ok(stream)
+                            } else {
+                                debug!("pnet handshake failed, remote holds a different key");
+                                Err(SecioError::PreSharedKeyMismatch)
+                            }
+                        }),
+                )
+            })
+    }
+}
+
+impl fmt::Debug for PreSharedKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PreSharedKey(0x")?;
+        for byte in self.fingerprint().iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// ChaCha20 keystream, generated block by block through sealing zero bytes with a counter nonce
+struct KeyStream {
+    key: aead::LessSafeKey,
+    counter: u64,
+    block: [u8; KEY_STREAM_BLOCK_SIZE],
+    offset: usize,
+}
+
+impl KeyStream {
+    fn new(psk: &PreSharedKey, nonce: &[u8]) -> Self {
+        let derive_key = hmac::Key::new(hmac::HMAC_SHA256, &psk.0);
+        let mut ctx = hmac::Context::with_key(&derive_key);
+        ctx.update(KEY_DERIVE_TAG);
+        ctx.update(nonce);
+        let key = ctx.sign();
+
+        let unbound = aead::UnboundKey::new(&aead::CHACHA20_POLY1305, key.as_ref())
+            .expect("hmac sha256 output is a valid chacha20 key");
+
+        KeyStream {
+            key: aead::LessSafeKey::new(unbound),
+            counter: 0,
+            block: [0; KEY_STREAM_BLOCK_SIZE],
+            offset: KEY_STREAM_BLOCK_SIZE,
+        }
+    }
+
+    fn refill(&mut self) {
+        let mut nonce = [0; aead::NONCE_LEN];
+        nonce[4..].copy_from_slice(&self.counter.to_be_bytes());
+        self.counter += 1;
+
+        for byte in self.block.iter_mut() {
+            *byte = 0;
+        }
+        // The tag is useless here, only the keystream is needed
+        let _ = self
+            .key
+            .seal_in_place_separate_tag(
+                aead::Nonce::assume_unique_for_key(nonce),
+                aead::Aad::empty(),
+                &mut self.block,
+            )
+            .expect("in-place seal of a fixed size block never fails");
+        self.offset = 0;
+    }
+
+    fn apply(&mut self, data: &mut [u8]) {
+        for byte in data.iter_mut() {
+            if self.offset == KEY_STREAM_BLOCK_SIZE {
+                self.refill();
+            }
+            *byte ^= self.block[self.offset];
+            self.offset += 1;
+        }
+    }
+}
+
+/// Stream wrapped by the pre-shared key
+pub struct PnetStream<T> {
+    socket: T,
+    encoder: KeyStream,
+    decoder: KeyStream,
+    // Encrypted data that has not been written to socket
+    pending: BytesMut,
+}
+
+impl<T> PnetStream<T>
+where
+    T: Write,
+{
+    fn write_pending(&mut self) -> io::Result<()> {
+        while !self.pending.is_empty() {
+            let n = self.socket.write(&self.pending)?;
+            if n == 0 {
+                return Err(io::ErrorKind::WriteZero.into());
+            }
+            self.pending.split_to(n);
+        }
+        Ok(())
+    }
+}
+
+impl<T> Read for PnetStream<T>
+where
+    T: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.socket.read(buf)?;
+        self.decoder.apply(&mut buf[..n]);
+        Ok(n)
+    }
+}
+
+impl<T> Write for PnetStream<T>
+where
+    T: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // The keystream has been consumed by pending data, it must be sent first
+        self.write_pending()?;
+
+        self.pending.extend_from_slice(buf);
+        self.encoder.apply(&mut self.pending);
+        match self.write_pending() {
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => Ok(buf.len()),
+            Err(e) => Err(e),
+            Ok(()) => Ok(buf.len()),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_pending()?;
+        self.socket.flush()
+    }
+}
+
+impl<T> AsyncRead for PnetStream<T>
+where
+    T: AsyncRead,
+{
+    unsafe fn prepare_uninitialized_buffer(&self, buf: &mut [u8]) -> bool {
+        self.socket.prepare_uninitialized_buffer(buf)
+    }
+}
+
+impl<T> AsyncWrite for PnetStream<T>
+where
+    T: AsyncWrite,
+{
+    fn shutdown(&mut self) -> Poll<(), io::Error> {
+        match self.write_pending() {
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => return Ok(Async::NotReady),
+            Err(e) => return Err(e),
+            Ok(()) => (),
+        }
+        self.socket.shutdown()
+    }
+}
+
+impl<T> fmt::Debug for PnetStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PnetStream")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PreSharedKey;
+    use crate::error::SecioError;
+
+    use futures::{prelude::*, sync};
+    use std::thread;
+    use tokio::net::{TcpListener, TcpStream};
+
+    fn pnet_handshake(
+        key_1: PreSharedKey,
+        key_2: PreSharedKey,
+        data: &'static [u8],
+    ) -> Result<Vec<u8>, SecioError> {
+        let listener = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+        let listener_addr = listener.local_addr().unwrap();
+
+        let (sender, receiver) = sync::oneshot::channel::<Result<Vec<u8>, SecioError>>();
+
+        let server = listener
+            .incoming()
+            .into_future()
+            .map_err(|(e, _)| e.into())
+            .and_then(move |(connect, _)| key_1.handshake(connect.unwrap()))
+            .and_then(|stream| tokio::io::read_exact(stream, [0u8; 11]).from_err())
+            .then(move |res| {
+                let _ = sender.send(res.map(|(_, data)| data.to_vec()));
+                Ok(())
+            });
+
+        let client = TcpStream::connect(&listener_addr)
+            .map_err(Into::into)
+            .and_then(move |stream| key_2.handshake(stream))
+            .and_then(move |stream| tokio::io::write_all(stream, data).from_err())
+            .map(|_| ())
+            .map_err(|_: SecioError| ());
+
+        thread::spawn(|| tokio::run(client));
+        let mut rt = tokio::runtime::Runtime::new().unwrap();
+        rt.spawn(server);
+        rt.block_on(receiver).unwrap()
+    }
+
+    #[test]
+    fn test_pnet_with_same_key() {
+        let key = PreSharedKey::random();
+        let data = b"hello world";
+
+        assert_eq!(pnet_handshake(key, key, data).unwrap(), data.to_vec());
+    }
+
+    #[test]
+    fn test_pnet_with_different_key() {
+        let data = b"hello world";
+
+        assert_eq!(
+            pnet_handshake(PreSharedKey::random(), PreSharedKey::random(), data),
+            Err(SecioError::PreSharedKeyMismatch)
+        );
+    }
+
+    #[test]
+    fn test_invalid_key_length() {
+        assert_eq!(
+            PreSharedKey::from_slice(&[0; 16]),
+            Err(SecioError::InvalidPreSharedKey)
+        );
+        assert!(PreSharedKey::from_slice(&[0; 32]).is_ok());
+    }
+}
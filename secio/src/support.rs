@@ -3,7 +3,7 @@
 /// Delete part of the structure
 use crate::error::SecioError;
 use crate::exchange::KeyAgreement;
-use crate::{crypto::cipher::CipherType, Digest};
+use crate::{crypto::cipher::CipherType, handshake::CipherPolicy, Digest};
 
 use std::cmp::Ordering;
 
@@ -147,10 +147,8 @@ pub fn select_digest(r: Ordering, ours: &str, theirs: &str) -> Result<Digest, Se
     };
     for x in a.split(',') {
         if b.split(',').any(|y| x == y) {
-            match x {
-                SHA_256 => return Ok(Digest::Sha256),
-                SHA_512 => return Ok(Digest::Sha512),
-                _ => continue,
+            if let Some(digest) = digest_from_name(x) {
+                return Ok(digest);
             }
         }
     }
@@ -168,17 +166,51 @@ pub fn select_cipher(r: Ordering, ours: &str, theirs: &str) -> Result<CipherType
     };
     for x in a.split(',') {
         if b.split(',').any(|y| x == y) {
-            match x {
-                #[cfg(unix)]
-                AES_128 | AES_128_CTR => return Ok(CipherType::Aes128Ctr),
-                #[cfg(unix)]
-                AES_256 | AES_256_CTR => return Ok(CipherType::Aes256Ctr),
-                AES_128_GCM => return Ok(CipherType::Aes128Gcm),
-                AES_256_GCM => return Ok(CipherType::Aes256Gcm),
-                CHACHA20_POLY1305 => return Ok(CipherType::ChaCha20Poly1305),
-                _ => continue,
+            if let Some(cipher) = cipher_from_name(x) {
+                return Ok(cipher);
             }
         }
     }
     Err(SecioError::NoSupportIntersection)
 }
+
+/// Parse a cipher name of proposition
+pub fn cipher_from_name(name: &str) -> Option<CipherType> {
+    match name {
+        #[cfg(unix)]
+        AES_128 | AES_128_CTR => Some(CipherType::Aes128Ctr),
+        #[cfg(unix)]
+        AES_256 | AES_256_CTR => Some(CipherType::Aes256Ctr),
+        AES_128_GCM => Some(CipherType::Aes128Gcm),
+        AES_256_GCM => Some(CipherType::Aes256Gcm),
+        CHACHA20_POLY1305 => Some(CipherType::ChaCha20Poly1305),
+        _ => None,
+    }
+}
+
+/// Parse a digest name of proposition
+pub fn digest_from_name(name: &str) -> Option<Digest> {
+    match name {
+        SHA_256 => Some(Digest::Sha256),
+        SHA_512 => Some(Digest::Sha512),
+        _ => None,
+    }
+}
+
+/// Remove the ciphers that are not allowed by policy from the proposition
+pub fn filter_ciphers(proposition: &str, policy: &CipherPolicy) -> String {
+    proposition
+        .split(',')
+        .filter(|name| cipher_from_name(name).map_or(false, |c| policy.allow_cipher(c)))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Remove the digests that are not allowed by policy from the proposition
+pub fn filter_digests(proposition: &str, policy: &CipherPolicy) -> String {
+    proposition
+        .split(',')
+        .filter(|name| digest_from_name(name).map_or(false, |d| policy.allow_digest(d)))
+        .collect::<Vec<_>>()
+        .join(",")
+}
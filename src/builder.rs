@@ -3,14 +3,19 @@ use std::{collections::HashMap, io, sync::Arc, time::Duration};
 use tokio::codec::LengthDelimitedCodec;
 
 use crate::{
+    mplex::Config as MplexConfig,
+    muxer::Muxer,
     protocol_select::SelectFn,
-    secio::SecioKeyPair,
+    secio::{handshake::CipherPolicy, pnet::PreSharedKey, SecioKeyPair},
     service::{
         config::{Meta, ServiceConfig},
         ProtocolHandle, ProtocolMeta, Service,
     },
     traits::{Codec, ServiceHandle, ServiceProtocol, SessionProtocol},
-    yamux::Config,
+    yamux::{
+        config::{StreamOpenRate, DEFAULT_STREAM_PRIORITY},
+        Capture, Config,
+    },
     ProtocolId,
 };
 
@@ -29,10 +34,17 @@ impl ServiceBuilder {
     }
 
     /// Combine the configuration of this builder with service handle to create a Service.
+    ///
+    /// Panics if a pre-shared key is set without a key pair, sessions would be opened in
+    /// plaintext outside of the private network.
     pub fn build<H>(self, handle: H) -> Service<H>
     where
         H: ServiceHandle,
     {
+        assert!(
+            self.config.pre_shared_key.is_none() || self.key_pair.is_some(),
+            "pre-shared key requires a key pair"
+        );
         Service::new(self.inner, handle, self.key_pair, self.forever, self.config)
     }
 
@@ -54,6 +66,23 @@ impl ServiceBuilder {
         self
     }
 
+    /// Enable private network mode, only nodes holding the same pre-shared key can connect to each other.
+    ///
+    /// The key is applied on the raw stream before secio handshake, so it requires `key_pair` to be set,
+    /// `build` panics otherwise. A node without the key will fail the handshake immediately.
+    pub fn pre_shared_key(mut self, key: PreSharedKey) -> Self {
+        self.config.pre_shared_key = Some(key);
+        self
+    }
+
+    /// Restrict the ciphers and digests that can be negotiated with secio, such as only allow AEAD ciphers.
+    ///
+    /// Peers that can't meet the policy will fail the handshake.
+    pub fn cipher_policy(mut self, policy: CipherPolicy) -> Self {
+        self.config.cipher_policy = policy;
+        self
+    }
+
     /// When the service has no tasks, it will be turned off by default.
     /// If you do not want to close service, set it to true.
     pub fn forever(mut self, forever: bool) -> Self {
@@ -78,11 +107,48 @@ impl ServiceBuilder {
         self
     }
 
+    /// Mplex config for service, only used by sessions running over mplex
+    ///
+    /// Panic when max_frame_length < mplex_max_message_size
+    pub fn mplex_config(mut self, config: MplexConfig) -> Self {
+        assert!(self.config.max_frame_length >= config.max_message_size);
+        self.config.mplex_config = config;
+        self
+    }
+
+    /// The muxer that sessions run over, default is yamux
+    ///
+    /// Nothing is negotiated, both sides must use the same one.
+    pub fn muxer(mut self, muxer: Muxer) -> Self {
+        self.config.muxers = vec![muxer];
+        self.config.negotiate_muxer = false;
+        self
+    }
+
+    /// Negotiate the muxer after secio handshake with multistream-select, in order of preference,
+    /// the outbound side proposes its muxers, the inbound side accepts the first it supports.
+    ///
+    /// It is negotiated even with a single muxer, so any two negotiating nodes sharing a muxer
+    /// can connect. Both sides must enable negotiation, peers that don't will fail to connect.
+    /// Without `key_pair`, the first one is used directly.
+    ///
+    /// The wire format is the same as libp2p's, but the secio handshake before it is not,
+    /// so libp2p peers still can't connect.
+    ///
+    /// Panic when muxers is empty
+    pub fn negotiate_muxers(mut self, muxers: Vec<Muxer>) -> Self {
+        assert!(!muxers.is_empty());
+        self.config.muxers = muxers;
+        self.config.negotiate_muxer = true;
+        self
+    }
+
     /// Secio max frame length
     ///
-    /// Panic when max_frame_length < yamux_max_window_size
+    /// Panic when max_frame_length < yamux_max_window_size or max_frame_length < mplex_max_message_size
     pub fn max_frame_length(mut self, size: usize) -> Self {
         assert!(size as u32 >= self.config.yamux_config.max_stream_window_size);
+        assert!(size >= self.config.mplex_config.max_message_size);
         self.config.max_frame_length = size;
         self
     }
@@ -99,6 +165,60 @@ impl ServiceBuilder {
         self
     }
 
+    /// Service-wide limit of the receive windows and received data buffered by all sessions,
+    /// default is unlimited
+    ///
+    /// Every yamux stream is charged its initial window of 256Kb when it is opened, streams
+    /// beyond the limit are refused. When the limit is reached, streams withhold window updates
+    /// until the data is consumed, and `ServiceError::RecvBudgetExhausted` is reported. The
+    /// per-session limit is `session_recv_budget` of yamux config.
+    pub fn max_buffered_recv_size(mut self, size: usize) -> Self {
+        self.config.max_buffered_recv_size = Some(size);
+        self
+    }
+
+    /// Token bucket limit of streams opened by remote in each session, default is unlimited
+    ///
+    /// Streams beyond it are reset, only works on yamux.
+    pub fn inbound_stream_rate(mut self, rate: StreamOpenRate) -> Self {
+        self.config.yamux_config.inbound_stream_rate = Some(rate);
+        self
+    }
+
+    /// The limit of streams opened by remote and still negotiating protocol in each session,
+    /// streams beyond it are reset, default is 64
+    ///
+    /// Streams reset by this limit or by the muxer limits are counted, a remote that keeps
+    /// going beyond them is reported by `ServiceError::StreamOpenAbuse`.
+    pub fn max_negotiating_streams(mut self, number: usize) -> Self {
+        self.config.max_negotiating_streams = number;
+        self
+    }
+
+    /// Report the remote by `ServiceError::StreamOpenAbuse` when the streams reset within
+    /// `window` reach `threshold`, default is 64 streams within 10 seconds
+    ///
+    /// The threshold must not be zero.
+    pub fn stream_open_abuse(mut self, window: Duration, threshold: u64) -> Self {
+        assert!(
+            threshold > 0,
+            "stream open abuse threshold must not be zero"
+        );
+        self.config.stream_abuse_window = window;
+        self.config.stream_abuse_threshold = threshold;
+        self
+    }
+
+    /// Record frames and protocol messages of all sessions after decryption, for debugging
+    ///
+    /// The capture can be printed by the `yamux-capture` binary of tokio-yamux, streams are
+    /// annotated with the protocol negotiated on them. Sessions over mplex only record
+    /// protocol messages. Buffered records are flushed when a session closes.
+    pub fn capture(mut self, capture: Capture) -> Self {
+        self.config.capture = Some(capture);
+        self
+    }
+
     /// If session is close by remote, did you want to keep unreceived message as more as possible
     /// default is false
     pub fn keep_buffer(mut self, keep: bool) -> Self {
@@ -170,6 +290,9 @@ pub struct MetaBuilder {
     select_version: SelectVersionFn,
     before_send: Option<Box<dyn Fn(bytes::Bytes) -> bytes::Bytes + Send + 'static>>,
     before_receive: BeforeReceiveFn,
+    stream_priority: u8,
+    half_close: bool,
+    raw_stream: bool,
 }
 
 impl MetaBuilder {
@@ -268,6 +391,38 @@ impl MetaBuilder {
         self
     }
 
+    /// Scheduling priority of the protocol's sub stream, default is `DEFAULT_STREAM_PRIORITY`
+    ///
+    /// When protocols of a session compete for bandwidth, each gets it in proportion to the priority.
+    /// Handles can change it at runtime with `ProtocolContextMutRef::set_stream_priority`
+    pub fn stream_priority(mut self, priority: u8) -> Self {
+        self.stream_priority = priority;
+        self
+    }
+
+    /// Whether remote finishing sending only closes the receiving half, default is false
+    ///
+    /// When enabled, `remote_finished` is called on the handle when remote half-closes
+    /// the protocol, local can keep sending until it half-closes too, then the protocol
+    /// is closed. Otherwise the protocol is closed as soon as remote finishes sending.
+    ///
+    /// Both sides should enable it, or one side closes as soon as the other half-closes.
+    pub fn half_close(mut self, enable: bool) -> Self {
+        self.half_close = enable;
+        self
+    }
+
+    /// Hand over the negotiated sub stream to the protocol handle, default is false
+    ///
+    /// When enabled, the handle gets the sub stream on `raw_stream` and reads/writes bytes on it
+    /// directly, `codec`, `before_send`, `before_receive` and `half_close` are not used,
+    /// and messages sent to the protocol through the service are dropped.
+    /// Opening and closing the protocol are reported as usual, dropping the stream closes it.
+    pub fn raw_stream(mut self, enable: bool) -> Self {
+        self.raw_stream = enable;
+        self
+    }
+
     /// Combine the configuration of this builder to create a ProtocolMeta
     pub fn build(self) -> ProtocolMeta {
         let meta = Meta {
@@ -277,6 +432,9 @@ impl MetaBuilder {
             codec: self.codec,
             select_version: self.select_version,
             before_receive: self.before_receive,
+            stream_priority: self.stream_priority,
+            half_close: self.half_close,
+            raw_stream: self.raw_stream,
         };
         ProtocolMeta {
             inner: Arc::new(meta),
@@ -299,6 +457,9 @@ impl Default for MetaBuilder {
             select_version: Box::new(|| None),
             before_send: None,
             before_receive: Box::new(|| None),
+            stream_priority: DEFAULT_STREAM_PRIORITY,
+            half_close: false,
+            raw_stream: false,
         }
     }
 }
@@ -5,21 +5,22 @@ use std::{
     ops::{Deref, DerefMut},
     sync::{
         atomic::{AtomicBool, AtomicUsize, Ordering},
-        Arc,
+        Arc, RwLock,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use crate::{
     error::Error,
     multiaddr::Multiaddr,
     protocol_select::ProtocolInfo,
-    secio::{PublicKey, SecioKeyPair},
+    secio::{pnet::PreSharedKey, PublicKey, SecioKeyPair},
     service::{
-        event::{Priority, ServiceTask},
-        DialProtocol, ServiceControl, SessionType, TargetProtocol, TargetSession,
+        event::{CloseReason, Priority, ServiceTask},
+        DialProtocol, Reachability, ServiceControl, SessionType, TargetProtocol, TargetSession,
     },
     session::SessionEvent,
+    yamux::RttStats,
     ProtocolId, SessionId,
 };
 
@@ -69,6 +70,13 @@ pub struct SessionContext {
     pub remote_pubkey: Option<PublicKey>,
     pub(crate) closed: Arc<AtomicBool>,
     pending_data_size: Arc<AtomicUsize>,
+    rtt: Arc<RwLock<RttStats>>,
+    close_reason: Arc<RwLock<Option<CloseReason>>>,
+    // Close reasons of protocols closed while the session stays open
+    proto_close_reasons: Arc<RwLock<HashMap<ProtocolId, CloseReason>>>,
+    opened_at: Instant,
+    // Milliseconds from `opened_at` to the last data received on each protocol
+    last_received: Arc<RwLock<HashMap<ProtocolId, u64>>>,
 }
 
 impl SessionContext {
@@ -87,6 +95,11 @@ impl SessionContext {
             remote_pubkey,
             closed,
             pending_data_size,
+            rtt: Arc::new(RwLock::new(RttStats::default())),
+            close_reason: Arc::new(RwLock::new(None)),
+            proto_close_reasons: Arc::new(RwLock::new(HashMap::default())),
+            opened_at: Instant::now(),
+            last_received: Arc::new(RwLock::new(HashMap::default())),
         }
     }
 
@@ -110,6 +123,101 @@ impl SessionContext {
     pub fn pending_data_size(&self) -> usize {
         self.pending_data_size.load(Ordering::Relaxed)
     }
+
+    // Update when yamux session measured a new keepalive ping
+    pub(crate) fn update_rtt(&self, rtt: RttStats) {
+        if *self.rtt.read().expect("rtt lock poisoned") != rtt {
+            *self.rtt.write().expect("rtt lock poisoned") = rtt;
+        }
+    }
+
+    // Update when a protocol stream of the session received data
+    pub(crate) fn received_data(&self, proto_id: ProtocolId) {
+        let millis = self.opened_at.elapsed().as_millis() as u64;
+        self.last_received
+            .write()
+            .expect("last received lock poisoned")
+            .insert(proto_id, millis);
+    }
+
+    // The first reason wins, it must be set before the session is marked closed
+    pub(crate) fn set_close_reason(&self, reason: CloseReason) {
+        let mut close_reason = self
+            .close_reason
+            .write()
+            .expect("close reason lock poisoned");
+        if close_reason.is_none() {
+            *close_reason = Some(reason);
+        }
+    }
+
+    /// Why the session is closed, `None` if it is still open,
+    /// protocol handles can read it in `disconnected`
+    pub fn close_reason(&self) -> Option<CloseReason> {
+        self.close_reason
+            .read()
+            .expect("close reason lock poisoned")
+            .clone()
+    }
+
+    // Set when a protocol stream is closed for a reason of its own, such as a reset
+    pub(crate) fn set_proto_close_reason(&self, proto_id: ProtocolId, reason: CloseReason) {
+        self.proto_close_reasons
+            .write()
+            .expect("close reason lock poisoned")
+            .insert(proto_id, reason);
+    }
+
+    // Cleared when the protocol is opened again
+    pub(crate) fn clear_proto_close_reason(&self, proto_id: ProtocolId) {
+        self.proto_close_reasons
+            .write()
+            .expect("close reason lock poisoned")
+            .remove(&proto_id);
+    }
+
+    /// Why a protocol is closed, the reason of the session if it is closed,
+    /// otherwise the protocol's own reason such as a stream reset
+    pub fn proto_close_reason(&self, proto_id: ProtocolId) -> Option<CloseReason> {
+        self.close_reason().or_else(|| {
+            self.proto_close_reasons
+                .read()
+                .expect("close reason lock poisoned")
+                .get(&proto_id)
+                .cloned()
+        })
+    }
+
+    /// When data was last received on a protocol stream of the session,
+    /// the open time of the session if nothing has been received yet
+    pub fn last_received(&self) -> Instant {
+        self.last_received_by(|_| true)
+    }
+
+    /// When data was last received on a protocol stream of the session other than `proto_id`,
+    /// e.g. to leave out the own messages of a keepalive protocol
+    pub fn last_received_except(&self, proto_id: ProtocolId) -> Instant {
+        self.last_received_by(|id| id != proto_id)
+    }
+
+    fn last_received_by<F: Fn(ProtocolId) -> bool>(&self, filter: F) -> Instant {
+        let millis = self
+            .last_received
+            .read()
+            .expect("last received lock poisoned")
+            .iter()
+            .filter(|(proto_id, _)| filter(**proto_id))
+            .map(|(_, millis)| *millis)
+            .max()
+            .unwrap_or(0);
+        self.opened_at + Duration::from_millis(millis)
+    }
+
+    /// Round trip time measured by yamux keepalive pings,
+    /// smoothed rtt is `None` if no ping has been acked yet
+    pub fn rtt(&self) -> RttStats {
+        *self.rtt.read().expect("rtt lock poisoned")
+    }
 }
 
 /// The Service runtime can send some instructions to the inside of the handle.
@@ -118,6 +226,7 @@ impl SessionContext {
 pub struct ServiceContext {
     listens: Vec<Multiaddr>,
     key_pair: Option<SecioKeyPair>,
+    pre_shared_key: Option<PreSharedKey>,
     inner: ServiceControl,
 }
 
@@ -128,6 +237,7 @@ impl ServiceContext {
         quick_task_sender: mpsc::UnboundedSender<ServiceTask>,
         proto_infos: HashMap<ProtocolId, ProtocolInfo>,
         key_pair: Option<SecioKeyPair>,
+        pre_shared_key: Option<PreSharedKey>,
         closed: Arc<AtomicBool>,
         timeout: Duration,
     ) -> Self {
@@ -140,6 +250,7 @@ impl ServiceContext {
                 closed,
             ),
             key_pair,
+            pre_shared_key,
             listens: Vec::new(),
         }
     }
@@ -162,6 +273,18 @@ impl ServiceContext {
         self.inner.disconnect(session_id)
     }
 
+    /// Disconnect a connection with an application defined code and message,
+    /// see `ServiceControl::disconnect_with_reason`
+    #[inline]
+    pub fn disconnect_with_reason(
+        &self,
+        session_id: SessionId,
+        code: u16,
+        message: String,
+    ) -> Result<(), Error> {
+        self.inner.disconnect_with_reason(session_id, code, message)
+    }
+
     /// Send message
     #[inline]
     pub fn send_message_to(
@@ -244,6 +367,28 @@ impl ServiceContext {
         self.inner.close_protocol(session_id, proto_id)
     }
 
+    /// Finish sending on a protocol, see `ServiceControl::half_close_protocol`
+    #[inline]
+    pub fn half_close_protocol(
+        &self,
+        session_id: SessionId,
+        proto_id: ProtocolId,
+    ) -> Result<(), Error> {
+        self.inner.half_close_protocol(session_id, proto_id)
+    }
+
+    /// Change sub stream priority of a protocol, see `ServiceControl::set_protocol_priority`
+    #[inline]
+    pub fn set_protocol_priority(
+        &self,
+        session_id: SessionId,
+        proto_id: ProtocolId,
+        priority: u8,
+    ) -> Result<(), Error> {
+        self.inner
+            .set_protocol_priority(session_id, proto_id, priority)
+    }
+
     /// Get the internal channel sender side handle
     #[inline]
     pub fn control(&self) -> &ServiceControl {
@@ -256,12 +401,30 @@ impl ServiceContext {
         &self.inner.proto_infos
     }
 
+    /// Get the reachability of the service
+    #[inline]
+    pub fn reachability(&self) -> Reachability {
+        self.inner.reachability()
+    }
+
+    /// Set the reachability of the service, used by the protocol probing it
+    #[inline]
+    pub fn set_reachability(&self, reachability: Reachability) {
+        self.inner.set_reachability(reachability)
+    }
+
     /// Get the key pair of self
     #[inline]
     pub fn key_pair(&self) -> Option<&SecioKeyPair> {
         self.key_pair.as_ref()
     }
 
+    /// Get the pre-shared key of the service, sessions of the service handshake with it first
+    #[inline]
+    pub fn pre_shared_key(&self) -> Option<PreSharedKey> {
+        self.pre_shared_key
+    }
+
     /// Get service listen address list
     #[inline]
     pub fn listens(&self) -> &[Multiaddr] {
@@ -332,6 +495,7 @@ impl ServiceContext {
         ServiceContext {
             inner: self.inner.clone(),
             key_pair: self.key_pair.clone(),
+            pre_shared_key: self.pre_shared_key,
             listens: self.listens.clone(),
         }
     }
@@ -387,6 +551,32 @@ impl<'a> ProtocolContextMutRef<'a> {
             .quick_send_message_to(self.session.id, proto_id, data)
     }
 
+    /// Finish sending on current protocol current session, messages can still be received.
+    ///
+    /// The protocol is closed when both sides finished, remote is told by yamux FIN,
+    /// protocols without `half_close` enabled on remote close on it.
+    #[inline]
+    pub fn half_close(&self) -> Result<(), Error> {
+        let proto_id = self.proto_id();
+        self.inner.half_close_protocol(self.session.id, proto_id)
+    }
+
+    /// Why current protocol current session is closed, read it in `disconnected`,
+    /// see `SessionContext::proto_close_reason`
+    #[inline]
+    pub fn close_reason(&self) -> Option<CloseReason> {
+        self.session.proto_close_reason(self.proto_id())
+    }
+
+    /// Change sub stream priority of current protocol current session,
+    /// higher priority gets more bandwidth when protocols compete
+    #[inline]
+    pub fn set_stream_priority(&self, priority: u8) -> Result<(), Error> {
+        let proto_id = self.proto_id();
+        self.inner
+            .set_protocol_priority(self.session.id, proto_id, priority)
+    }
+
     /// Protocol id
     #[inline]
     pub fn proto_id(&self) -> ProtocolId {
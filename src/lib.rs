@@ -20,6 +20,8 @@
 
 /// Re-pub bytes crate
 pub use bytes;
+/// Re-pub mplex crate
+pub use mplex;
 /// Re-pub multiaddr crate
 pub use multiaddr;
 /// Re-pub secio crate
@@ -33,10 +35,16 @@ pub mod builder;
 pub mod context;
 /// Error
 pub mod error;
+/// Stream multiplexers that a session can run over
+pub mod muxer;
+/// Signed peer records of listen addresses
+pub mod peer_record;
 /// Protocol handle callback stream
 pub(crate) mod protocol_handle_stream;
 /// Protocol select
 pub mod protocol_select;
+/// Protocol sub streams that bypass codec
+pub mod raw_substream;
 /// An abstraction of p2p service
 pub mod service;
 /// Wrapper for real data streams
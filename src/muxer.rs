@@ -0,0 +1,403 @@
+use bytes::Bytes;
+use futures::{future, prelude::*};
+use log::debug;
+use std::io;
+use tokio::prelude::{AsyncRead, AsyncWrite};
+
+use crate::{
+    mplex::{
+        session::SessionType as MplexType, Config as MplexConfig, Session as MplexSession,
+        StreamHandle as MplexStream,
+    },
+    service::SessionType,
+    yamux::{
+        frame::GoAwayReason, Capture, Config as YamuxConfig, MemoryBudget, RttStats,
+        Session as YamuxSession, StreamHandle as YamuxStream,
+    },
+};
+
+mod multistream_select;
+
+use self::multistream_select::{dialer_select, listener_select};
+
+/// Stream multiplexer that a session runs over
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Muxer {
+    /// [yamux](https://github.com/hashicorp/yamux/blob/master/spec.md), the default
+    Yamux,
+    /// [mplex](https://github.com/libp2p/specs/tree/master/mplex), used by some libp2p peers.
+    ///
+    /// It has no flow control and no keepalive, so receive budget, rtt and go away reason
+    /// are not available on it, unread data is bounded by the buffer sizes of `MplexConfig`
+    /// instead. Its frames are not captured, only protocol messages are.
+    Mplex,
+}
+
+impl Muxer {
+    /// The name used in muxer negotiation, same as libp2p
+    pub fn protocol_name(self) -> &'static str {
+        match self {
+            Muxer::Yamux => "/yamux/1.0.0",
+            Muxer::Mplex => "/mplex/6.7.0",
+        }
+    }
+
+    fn from_protocol_name(name: &str) -> Option<Muxer> {
+        [Muxer::Yamux, Muxer::Mplex]
+            .iter()
+            .find(|muxer| muxer.protocol_name() == name)
+            .cloned()
+    }
+}
+
+impl Default for Muxer {
+    fn default() -> Self {
+        Muxer::Yamux
+    }
+}
+
+/// Negotiate the muxer after secio handshake with multistream-select, as libp2p does.
+///
+/// Without negotiation, the first muxer is used directly and nothing is exchanged,
+/// so it is compatible with peers that don't negotiate. Otherwise the outbound side proposes
+/// its muxers in order, the inbound side accepts the first one it supports.
+pub(crate) fn select_muxer<T>(
+    socket: T,
+    muxers: Vec<Muxer>,
+    negotiate: bool,
+    ty: SessionType,
+) -> impl Future<Item = (Rewind<T>, Muxer), Error = io::Error>
+where
+    T: AsyncRead + AsyncWrite + Send,
+{
+    if !negotiate {
+        return future::Either::A(future::ok((Rewind::new(socket, Bytes::new()), muxers[0])));
+    }
+
+    let names = muxers
+        .iter()
+        .map(|muxer| muxer.protocol_name())
+        .collect::<Vec<_>>();
+    let task = if ty.is_outbound() {
+        future::Either::A(dialer_select(socket, names))
+    } else {
+        future::Either::B(listener_select(socket, names))
+    };
+
+    future::Either::B(task.and_then(|(socket, name)| {
+        let muxer = Muxer::from_protocol_name(name)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "no common muxer"))?;
+        // Remote may have sent muxer frames right after the negotiation
+        let parts = socket.into_parts();
+        Ok((Rewind::new(parts.io, parts.read_buf.freeze()), muxer))
+    }))
+}
+
+/// Replay the data read ahead before reading the inner stream
+pub(crate) struct Rewind<T> {
+    pre: Bytes,
+    inner: T,
+}
+
+impl<T> Rewind<T> {
+    pub fn new(inner: T, pre: Bytes) -> Self {
+        Rewind { pre, inner }
+    }
+}
+
+impl<T: io::Read> io::Read for Rewind<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pre.is_empty() {
+            return self.inner.read(buf);
+        }
+        let n = ::std::cmp::min(buf.len(), self.pre.len());
+        buf[..n].copy_from_slice(&self.pre.split_to(n));
+        Ok(n)
+    }
+}
+
+impl<T: AsyncRead> AsyncRead for Rewind<T> {}
+
+impl<T: io::Write> io::Write for Rewind<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<T: AsyncWrite> AsyncWrite for Rewind<T> {
+    fn shutdown(&mut self) -> Poll<(), io::Error> {
+        self.inner.shutdown()
+    }
+}
+
+/// The muxer session of a connection
+pub(crate) enum MuxerSession<T> {
+    Yamux(YamuxSession<T>),
+    Mplex(MplexSession<T>),
+}
+
+impl<T> MuxerSession<T>
+where
+    T: AsyncRead + AsyncWrite,
+{
+    pub fn new(
+        socket: T,
+        muxer: Muxer,
+        yamux_config: YamuxConfig,
+        mplex_config: MplexConfig,
+        ty: SessionType,
+        recv_budget: Option<MemoryBudget>,
+        capture: Option<Capture>,
+    ) -> Self {
+        match muxer {
+            Muxer::Yamux => {
+                let mut session = YamuxSession::new(socket, yamux_config, ty.into());
+                if let Some(budget) = recv_budget {
+                    session = session.with_shared_recv_budget(budget);
+                }
+                if let Some(capture) = capture {
+                    session = session.with_capture(capture);
+                }
+                MuxerSession::Yamux(session)
+            }
+            Muxer::Mplex => {
+                let ty = if ty.is_outbound() {
+                    MplexType::Client
+                } else {
+                    MplexType::Server
+                };
+                MuxerSession::Mplex(MplexSession::new(socket, mplex_config, ty))
+            }
+        }
+    }
+
+    pub fn open_stream(&mut self) -> Result<SubstreamHandle, io::Error> {
+        match self {
+            MuxerSession::Yamux(session) => session
+                .open_stream()
+                .map(SubstreamHandle::Yamux)
+                .map_err(|err| io::Error::new(io::ErrorKind::Other, err)),
+            MuxerSession::Mplex(session) => session
+                .open_stream()
+                .map(SubstreamHandle::Mplex)
+                .map_err(|err| io::Error::new(io::ErrorKind::Other, err)),
+        }
+    }
+
+    pub fn shutdown(&mut self) -> Poll<(), io::Error> {
+        match self {
+            MuxerSession::Yamux(session) => session.shutdown(),
+            MuxerSession::Mplex(session) => session.shutdown(),
+        }
+    }
+
+    /// Mplex can't carry the reason, same as `shutdown` on it
+    pub fn shutdown_with_reason(
+        &mut self,
+        code: u32,
+        message: Option<Bytes>,
+    ) -> Poll<(), io::Error> {
+        match self {
+            MuxerSession::Yamux(session) => session.shutdown_with_reason(code, message),
+            MuxerSession::Mplex(session) => session.shutdown(),
+        }
+    }
+
+    pub fn remote_go_away_reason(&self) -> Option<&GoAwayReason> {
+        match self {
+            MuxerSession::Yamux(session) => session.remote_go_away_reason(),
+            MuxerSession::Mplex(_) => None,
+        }
+    }
+
+    pub fn rtt(&self) -> RttStats {
+        match self {
+            MuxerSession::Yamux(session) => session.rtt(),
+            MuxerSession::Mplex(_) => RttStats::default(),
+        }
+    }
+
+    /// Count of streams opened by remote that the muxer reset because of its limits
+    pub fn rejected_streams(&self) -> u64 {
+        match self {
+            MuxerSession::Yamux(session) => session.rejected_streams(),
+            MuxerSession::Mplex(session) => session.rejected_streams(),
+        }
+    }
+}
+
+impl<T> Stream for MuxerSession<T>
+where
+    T: AsyncRead + AsyncWrite,
+{
+    type Item = SubstreamHandle;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        match self {
+            MuxerSession::Yamux(session) => Ok(session
+                .poll()?
+                .map(|stream| stream.map(SubstreamHandle::Yamux))),
+            MuxerSession::Mplex(session) => Ok(session
+                .poll()?
+                .map(|stream| stream.map(SubstreamHandle::Mplex))),
+        }
+    }
+}
+
+/// The sub stream of a muxer session
+#[derive(Debug)]
+pub(crate) enum SubstreamHandle {
+    Yamux(YamuxStream),
+    Mplex(MplexStream),
+}
+
+impl SubstreamHandle {
+    /// The stream id in muxer
+    pub fn id(&self) -> u64 {
+        match self {
+            SubstreamHandle::Yamux(stream) => u64::from(stream.id()),
+            SubstreamHandle::Mplex(stream) => stream.id(),
+        }
+    }
+
+    /// Scheduling priority of outbound frames, mplex doesn't schedule frames, ignored on it
+    pub fn set_priority(&self, priority: u8) {
+        if let SubstreamHandle::Yamux(stream) = self {
+            stream.set_priority(priority)
+        }
+    }
+
+    /// Whether to close local side when remote closed and all data is read
+    pub fn set_auto_close(&mut self, enable: bool) {
+        match self {
+            SubstreamHandle::Yamux(stream) => stream.set_auto_close(enable),
+            SubstreamHandle::Mplex(stream) => stream.set_auto_close(enable),
+        }
+    }
+
+    /// Reset the stream, remote can't read or write on it any more
+    pub fn reset(&mut self) {
+        match self {
+            SubstreamHandle::Yamux(stream) => {
+                if let Err(err) = stream.reset() {
+                    debug!("yamux stream reset error: {}", err);
+                }
+            }
+            SubstreamHandle::Mplex(stream) => stream.reset(),
+        }
+    }
+}
+
+impl io::Read for SubstreamHandle {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            SubstreamHandle::Yamux(stream) => stream.read(buf),
+            SubstreamHandle::Mplex(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl AsyncRead for SubstreamHandle {}
+
+impl io::Write for SubstreamHandle {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            SubstreamHandle::Yamux(stream) => stream.write(buf),
+            SubstreamHandle::Mplex(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            SubstreamHandle::Yamux(stream) => stream.flush(),
+            SubstreamHandle::Mplex(stream) => stream.flush(),
+        }
+    }
+}
+
+impl AsyncWrite for SubstreamHandle {
+    fn shutdown(&mut self) -> Poll<(), io::Error> {
+        match self {
+            SubstreamHandle::Yamux(stream) => stream.shutdown(),
+            SubstreamHandle::Mplex(stream) => stream.shutdown(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{select_muxer, Muxer, Rewind};
+    use crate::service::SessionType;
+    use bytes::Bytes;
+    use futures::prelude::*;
+    use std::io::Read;
+    use tokio::net::{TcpListener, TcpStream};
+
+    #[test]
+    fn test_rewind() {
+        let mut rewind = Rewind::new(&b"world"[..], Bytes::from("hello "));
+        let mut buf = String::new();
+        rewind.read_to_string(&mut buf).unwrap();
+        assert_eq!(buf, "hello world");
+    }
+
+    fn negotiate(server: Vec<Muxer>, client: Vec<Muxer>) -> (Option<Muxer>, Option<Muxer>) {
+        let listener = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server_task = listener
+            .incoming()
+            .into_future()
+            .map_err(|(err, _)| err)
+            .and_then(move |(socket, _)| {
+                select_muxer(socket.unwrap(), server, true, SessionType::Inbound)
+            })
+            .map(|(_, muxer)| muxer);
+        let client_task = TcpStream::connect(&addr)
+            .and_then(move |socket| select_muxer(socket, client, true, SessionType::Outbound))
+            .map(|(_, muxer)| muxer);
+
+        let mut rt = tokio::runtime::Runtime::new().unwrap();
+        let (server, client) = rt
+            .block_on(
+                server_task
+                    .then(Ok::<_, ()>)
+                    .join(client_task.then(Ok::<_, ()>)),
+            )
+            .unwrap();
+        (server.ok(), client.ok())
+    }
+
+    #[test]
+    fn test_select_muxer() {
+        // Outbound side decides by its preference
+        assert_eq!(
+            negotiate(
+                vec![Muxer::Mplex, Muxer::Yamux],
+                vec![Muxer::Yamux, Muxer::Mplex]
+            ),
+            (Some(Muxer::Yamux), Some(Muxer::Yamux))
+        );
+        assert_eq!(
+            negotiate(
+                vec![Muxer::Yamux, Muxer::Mplex],
+                vec![Muxer::Mplex, Muxer::Yamux]
+            ),
+            (Some(Muxer::Mplex), Some(Muxer::Mplex))
+        );
+        // A node negotiating a single muxer talks to one negotiating more
+        assert_eq!(
+            negotiate(vec![Muxer::Yamux], vec![Muxer::Mplex, Muxer::Yamux]),
+            (Some(Muxer::Yamux), Some(Muxer::Yamux))
+        );
+        assert_eq!(
+            negotiate(vec![Muxer::Yamux], vec![Muxer::Mplex]),
+            (None, None)
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! The subset of [multistream-select](https://github.com/multiformats/multistream-select)
+//! that libp2p uses to negotiate the muxer.
+//!
+//! Every message is a line prefixed by its unsigned varint length. Both sides send the
+//! multistream header, then the dialer proposes protocols in its order of preference,
+//! the listener echoes the first one it supports and answers `na` to the others.
+
+use bytes::{BufMut, Bytes, BytesMut};
+use futures::{future, prelude::*};
+use std::io;
+use tokio::codec::{Decoder, Encoder, Framed};
+use tokio::prelude::{AsyncRead, AsyncWrite};
+
+const MULTISTREAM_PROTOCOL: &str = "/multistream/1.0.0";
+const NOT_AVAILABLE: &str = "na";
+/// Lines longer than it are refused, protocol names are far shorter
+const MAX_MESSAGE_SIZE: usize = 1024;
+/// Proposals a listener answers before giving up
+const MAX_PROPOSALS: usize = 16;
+
+/// Codec of multistream-select lines, without the length prefix and trailing newline
+#[derive(Default)]
+pub(crate) struct MultistreamCodec;
+
+impl Decoder for MultistreamCodec {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let mut len = 0;
+        let mut prefix = 0;
+        loop {
+            let byte = match src.get(prefix) {
+                Some(byte) => *byte,
+                None => return Ok(None),
+            };
+            len |= usize::from(byte & 0x7f) << (7 * prefix);
+            prefix += 1;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            // Two bytes of varint is enough for the max message size
+            if prefix == 2 {
+                return Err(invalid_data("multistream message too long"));
+            }
+        }
+        if len == 0 || len > MAX_MESSAGE_SIZE {
+            return Err(invalid_data("invalid multistream message length"));
+        }
+        if src.len() < prefix + len {
+            return Ok(None);
+        }
+
+        src.split_to(prefix);
+        let mut message = src.split_to(len).freeze();
+        if message[len - 1] != b'\n' {
+            return Err(invalid_data("multistream message without newline"));
+        }
+        message.truncate(len - 1);
+        Ok(Some(message))
+    }
+}
+
+impl Encoder for MultistreamCodec {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        let mut len = item.len() + 1;
+        if len > MAX_MESSAGE_SIZE {
+            return Err(invalid_data("multistream message too long"));
+        }
+        dst.reserve(len + 2);
+        while len >= 0x80 {
+            dst.put_u8((len as u8 & 0x7f) | 0x80);
+            len >>= 7;
+        }
+        dst.put_u8(len as u8);
+        dst.put_slice(&item);
+        dst.put_u8(b'\n');
+        Ok(())
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn recv<T>(
+    framed: Framed<T, MultistreamCodec>,
+) -> impl Future<Item = (Framed<T, MultistreamCodec>, Bytes), Error = io::Error>
+where
+    T: AsyncRead + AsyncWrite,
+{
+    framed
+        .into_future()
+        .map_err(|(err, _)| err)
+        .and_then(|(message, framed)| {
+            message
+                .map(|message| (framed, message))
+                .ok_or_else(|| io::ErrorKind::UnexpectedEof.into())
+        })
+}
+
+fn check_header<T>(
+    (framed, header): (Framed<T, MultistreamCodec>, Bytes),
+) -> Result<Framed<T, MultistreamCodec>, io::Error> {
+    if header == MULTISTREAM_PROTOCOL.as_bytes() {
+        Ok(framed)
+    } else {
+        Err(invalid_data("not multistream-select"))
+    }
+}
+
+/// Propose the protocols in order, return the first one that the listener accepts
+pub(crate) fn dialer_select<T>(
+    socket: T,
+    protocols: Vec<&'static str>,
+) -> impl Future<Item = (Framed<T, MultistreamCodec>, &'static str), Error = io::Error>
+where
+    T: AsyncRead + AsyncWrite,
+{
+    Framed::new(socket, MultistreamCodec)
+        .send(Bytes::from(MULTISTREAM_PROTOCOL))
+        .and_then(recv)
+        .and_then(check_header)
+        .and_then(move |framed| {
+            future::loop_fn(
+                (framed, protocols.into_iter()),
+                |(framed, mut protocols)| match protocols.next() {
+                    Some(protocol) => future::Either::A(
+                        framed.send(Bytes::from(protocol)).and_then(recv).and_then(
+                            move |(framed, reply)| {
+                                if reply == protocol.as_bytes() {
+                                    Ok(future::Loop::Break((framed, protocol)))
+                                } else if reply == NOT_AVAILABLE.as_bytes() {
+                                    Ok(future::Loop::Continue((framed, protocols)))
+                                } else {
+                                    Err(invalid_data("unexpected multistream reply"))
+                                }
+                            },
+                        ),
+                    ),
+                    None => future::Either::B(future::err(invalid_data("no common protocol"))),
+                },
+            )
+        })
+}
+
+/// Accept the first proposal of the dialer that is among the protocols
+pub(crate) fn listener_select<T>(
+    socket: T,
+    protocols: Vec<&'static str>,
+) -> impl Future<Item = (Framed<T, MultistreamCodec>, &'static str), Error = io::Error>
+where
+    T: AsyncRead + AsyncWrite,
+{
+    recv(Framed::new(socket, MultistreamCodec))
+        .and_then(check_header)
+        .and_then(|framed| framed.send(Bytes::from(MULTISTREAM_PROTOCOL)))
+        .and_then(move |framed| {
+            future::loop_fn((framed, 0), move |(framed, proposals)| {
+                if proposals >= MAX_PROPOSALS {
+                    return future::Either::B(future::err(invalid_data("no common protocol")));
+                }
+                let protocols = protocols.clone();
+                future::Either::A(recv(framed).and_then(move |(framed, proposal)| {
+                    let accepted = protocols
+                        .into_iter()
+                        .find(|protocol| proposal == protocol.as_bytes());
+                    let reply = accepted.unwrap_or(NOT_AVAILABLE);
+                    framed
+                        .send(Bytes::from(reply))
+                        .map(move |framed| match accepted {
+                            Some(protocol) => future::Loop::Break((framed, protocol)),
+                            None => future::Loop::Continue((framed, proposals + 1)),
+                        })
+                }))
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultistreamCodec;
+    use bytes::{Bytes, BytesMut};
+    use tokio::codec::{Decoder, Encoder};
+
+    #[test]
+    fn test_codec() {
+        let mut codec = MultistreamCodec;
+        let mut buf = BytesMut::new();
+        codec.encode(Bytes::from("/mplex/6.7.0"), &mut buf).unwrap();
+        assert_eq!(&buf[..], &b"\x0d/mplex/6.7.0\n"[..]);
+
+        let long = Bytes::from(vec![b'a'; 200]);
+        codec.encode(long.clone(), &mut buf).unwrap();
+        assert_eq!(
+            codec.decode(&mut buf).unwrap(),
+            Some(Bytes::from("/mplex/6.7.0"))
+        );
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(long));
+        assert_eq!(codec.decode(&mut buf).unwrap(), None);
+
+        let mut without_newline = BytesMut::from(&b"\x02na"[..]);
+        assert!(codec.decode(&mut without_newline).is_err());
+    }
+}
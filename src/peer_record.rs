@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{error, fmt};
+
+use crate::{
+    multiaddr::Multiaddr,
+    secio::{PeerId, PublicKey, SecioKeyPair},
+    utils::extract_peer_id,
+};
+
+/// The domain separation tag of peer record signatures
+pub const PEER_RECORD_DOMAIN: &[u8] = b"/tentacle/peer-record";
+
+/// Records with a timestamp further in the future are rejected (seconds)
+const MAX_CLOCK_DRIFT: u64 = 10 * 60;
+const DEFAULT_MAX_RECORDS: usize = 10_000;
+
+fn now() -> std::time::Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// The sequence number of a record reached `u64::MAX`, no newer record can be signed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SeqOverflow;
+
+impl fmt::Display for SeqOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "peer record sequence number overflow")
+    }
+}
+
+impl error::Error for SeqOverflow {}
+
+/// Listen addresses of a peer with a sequence number and a timestamp, signed with the secio
+/// identity key of the peer
+///
+/// Protocols gossiping addresses of other peers send the record instead of bare addresses, so
+/// that nobody can forge the addresses of a peer id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedPeerRecord {
+    pubkey: PublicKey,
+    seq: u64,
+    timestamp: u64,
+    addrs: Vec<Multiaddr>,
+    signature: Vec<u8>,
+}
+
+impl SignedPeerRecord {
+    /// Sign a record of the addresses, the sequence number is the current unix time in
+    /// milliseconds
+    pub fn new(key_pair: &SecioKeyPair, addrs: Vec<Multiaddr>) -> Self {
+        Self::with_seq(key_pair, now().as_millis() as u64, addrs)
+    }
+
+    /// Sign a record of the addresses with the given sequence number
+    pub fn with_seq(key_pair: &SecioKeyPair, seq: u64, addrs: Vec<Multiaddr>) -> Self {
+        let timestamp = now().as_secs();
+        let signature =
+            key_pair.sign_with_domain(PEER_RECORD_DOMAIN, &signing_data(seq, timestamp, &addrs));
+        SignedPeerRecord {
+            pubkey: key_pair.public_key(),
+            seq,
+            timestamp,
+            addrs,
+            signature,
+        }
+    }
+
+    /// Sign a new record of the addresses replacing this one, its sequence number is greater
+    pub fn renew(
+        &self,
+        key_pair: &SecioKeyPair,
+        addrs: Vec<Multiaddr>,
+    ) -> Result<Self, SeqOverflow> {
+        let seq = self.seq.checked_add(1).ok_or(SeqOverflow)?;
+        Ok(Self::with_seq(
+            key_pair,
+            std::cmp::max(seq, now().as_millis() as u64),
+            addrs,
+        ))
+    }
+
+    /// Build a record received from a remote peer, check it with `verify` before use
+    pub fn from_parts(
+        pubkey: PublicKey,
+        seq: u64,
+        timestamp: u64,
+        addrs: Vec<Multiaddr>,
+        signature: Vec<u8>,
+    ) -> Self {
+        SignedPeerRecord {
+            pubkey,
+            seq,
+            timestamp,
+            addrs,
+            signature,
+        }
+    }
+
+    /// Check the signature, and that no address carries the peer id of another peer
+    pub fn verify(&self) -> bool {
+        let peer_id = self.peer_id();
+        self.addrs
+            .iter()
+            .all(|addr| extract_peer_id(addr).map_or(true, |id| id == peer_id))
+            && self.pubkey.verify_with_domain(
+                PEER_RECORD_DOMAIN,
+                &signing_data(self.seq, self.timestamp, &self.addrs),
+                &self.signature,
+            )
+    }
+
+    /// Public key of the peer
+    pub fn public_key(&self) -> &PublicKey {
+        &self.pubkey
+    }
+
+    /// Peer id of the peer
+    pub fn peer_id(&self) -> PeerId {
+        self.pubkey.peer_id()
+    }
+
+    /// Sequence number, a newer record of the peer has a greater one
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Unix time in seconds of signing
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Addresses of the peer
+    pub fn addrs(&self) -> &[Multiaddr] {
+        &self.addrs
+    }
+
+    /// Signature of the record
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+}
+
+// seq | timestamp | for each address: length(u32) | bytes, all in big endian
+fn signing_data(seq: u64, timestamp: u64, addrs: &[Multiaddr]) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(&seq.to_be_bytes());
+    data.extend_from_slice(&timestamp.to_be_bytes());
+    for addr in addrs {
+        let bytes: &[u8] = addr.as_ref();
+        data.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+        data.extend_from_slice(bytes);
+    }
+    data
+}
+
+/// Result of `PeerRecordStore::insert`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InsertResult {
+    /// The record is newer than the one kept and replaces it
+    Inserted,
+    /// The record is valid, but not newer than the one kept or from the future
+    Ignored,
+    /// The signature is invalid, or an address carries the peer id of another peer
+    Invalid,
+}
+
+struct Records {
+    records: HashMap<PeerId, SignedPeerRecord>,
+    max_records: usize,
+}
+
+/// The latest verified record of each peer
+///
+/// When full, the record with the oldest timestamp is dropped for a new one. Clones share the
+/// records, give the same store to every protocol exchanging records, e.g. discovery and identify.
+#[derive(Clone)]
+pub struct PeerRecordStore {
+    inner: Arc<RwLock<Records>>,
+}
+
+impl Default for PeerRecordStore {
+    fn default() -> Self {
+        PeerRecordStore::new(DEFAULT_MAX_RECORDS)
+    }
+}
+
+impl PeerRecordStore {
+    /// Store at most `max_records` records
+    pub fn new(max_records: usize) -> Self {
+        PeerRecordStore {
+            inner: Arc::new(RwLock::new(Records {
+                records: HashMap::default(),
+                max_records,
+            })),
+        }
+    }
+
+    /// Verify the record and keep it if it is newer than the one of the peer already kept
+    pub fn insert(&self, record: SignedPeerRecord) -> InsertResult {
+        if !record.verify() {
+            return InsertResult::Invalid;
+        }
+        if record.timestamp > now().as_secs() + MAX_CLOCK_DRIFT {
+            return InsertResult::Ignored;
+        }
+        let peer_id = record.peer_id();
+        let mut inner = self.inner.write().expect("peer records lock poisoned");
+        match inner.records.get(&peer_id) {
+            Some(old) if old.seq >= record.seq => return InsertResult::Ignored,
+            Some(_) => (),
+            None => {
+                if inner.records.len() >= inner.max_records {
+                    let oldest = inner
+                        .records
+                        .iter()
+                        .min_by_key(|(_, record)| record.timestamp)
+                        .map(|(peer_id, _)| peer_id.clone());
+                    if let Some(oldest) = oldest {
+                        inner.records.remove(&oldest);
+                    }
+                }
+            }
+        }
+        inner.records.insert(peer_id, record);
+        InsertResult::Inserted
+    }
+
+    /// The record of the peer
+    pub fn get(&self, peer_id: &PeerId) -> Option<SignedPeerRecord> {
+        self.inner
+            .read()
+            .expect("peer records lock poisoned")
+            .records
+            .get(peer_id)
+            .cloned()
+    }
+
+    /// Remove the record of the peer
+    pub fn remove(&self, peer_id: &PeerId) -> Option<SignedPeerRecord> {
+        self.inner
+            .write()
+            .expect("peer records lock poisoned")
+            .records
+            .remove(peer_id)
+    }
+
+    /// Number of records
+    pub fn len(&self) -> usize {
+        self.inner
+            .read()
+            .expect("peer records lock poisoned")
+            .records
+            .len()
+    }
+
+    /// No record kept
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{InsertResult, PeerRecordStore, SeqOverflow, SignedPeerRecord};
+    use crate::{multiaddr::Multiaddr, secio::SecioKeyPair};
+
+    fn addrs() -> Vec<Multiaddr> {
+        vec![
+            "/ip4/1.1.1.1/tcp/8000".parse().unwrap(),
+            "/dns4/example.com/tcp/8000/ws".parse().unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let key_pair = SecioKeyPair::secp256k1_generated();
+        let record = SignedPeerRecord::new(&key_pair, addrs());
+        assert!(record.verify());
+        assert_eq!(record.peer_id(), key_pair.peer_id());
+
+        let forged = SignedPeerRecord::from_parts(
+            record.public_key().clone(),
+            record.seq(),
+            record.timestamp(),
+            vec!["/ip4/2.2.2.2/tcp/8000".parse().unwrap()],
+            record.signature().to_vec(),
+        );
+        assert!(!forged.verify());
+
+        let other = SecioKeyPair::secp256k1_generated().peer_id();
+        let wrong_peer = SignedPeerRecord::new(
+            &key_pair,
+            vec![format!("/ip4/1.1.1.1/tcp/8000/p2p/{}", other.to_base58())
+                .parse()
+                .unwrap()],
+        );
+        assert!(!wrong_peer.verify());
+    }
+
+    #[test]
+    fn test_store_keeps_newer() {
+        let key_pair = SecioKeyPair::secp256k1_generated();
+        let old = SignedPeerRecord::with_seq(&key_pair, 1, addrs());
+        let new = old.renew(&key_pair, Vec::new()).unwrap();
+        let store = PeerRecordStore::default();
+        let last = SignedPeerRecord::with_seq(&key_pair, std::u64::MAX, addrs());
+        assert_eq!(last.renew(&key_pair, addrs()), Err(SeqOverflow));
+
+        assert_eq!(store.insert(new.clone()), InsertResult::Inserted);
+        assert_eq!(store.insert(old), InsertResult::Ignored);
+        assert_eq!(store.insert(new.clone()), InsertResult::Ignored);
+        assert_eq!(store.get(&key_pair.peer_id()), Some(new.clone()));
+
+        let forged = SignedPeerRecord::from_parts(
+            new.public_key().clone(),
+            new.seq() + 1,
+            new.timestamp(),
+            addrs(),
+            new.signature().to_vec(),
+        );
+        assert_eq!(store.insert(forged), InsertResult::Invalid);
+
+        // Clones share the records
+        let shared = store.clone();
+        assert_eq!(shared.remove(&key_pair.peer_id()), Some(new));
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_store_capacity() {
+        let store = PeerRecordStore::new(2);
+        for _ in 0..3 {
+            let key_pair = SecioKeyPair::secp256k1_generated();
+            let record = SignedPeerRecord::new(&key_pair, addrs());
+            assert_eq!(store.insert(record), InsertResult::Inserted);
+        }
+        assert_eq!(store.len(), 2);
+    }
+}
@@ -14,6 +14,7 @@ use crate::{
     context::{ProtocolContext, ServiceContext, SessionContext},
     error::Error,
     multiaddr::Multiaddr,
+    raw_substream::RawSubstreamSlot,
     service::future_task::BoxedFutureTask,
     session::SessionEvent,
     traits::{ServiceProtocol, SessionProtocol},
@@ -37,6 +38,17 @@ pub enum ServiceProtocolEvent {
         /// Data
         data: bytes::Bytes,
     },
+    /// Remote finished sending
+    RemoteFinished {
+        /// Session id
+        id: SessionId,
+    },
+    /// Negotiated raw sub stream
+    RawStream {
+        /// Session id
+        id: SessionId,
+        stream: RawSubstreamSlot,
+    },
     SetNotify {
         /// Timer interval
         interval: Duration,
@@ -175,6 +187,42 @@ where
                     }
                 }
             }
+            RemoteFinished { id } => {
+                if let Some(session) = self.sessions.get(&id).cloned() {
+                    if !session.closed.load(Ordering::SeqCst) {
+                        match tokio_threadpool::blocking(|| {
+                            self.handle
+                                .remote_finished(self.handle_context.as_mut(&session))
+                        }) {
+                            Ok(Async::Ready(_)) => (),
+                            Ok(Async::NotReady) => return Async::NotReady,
+                            Err(_) => self
+                                .handle
+                                .remote_finished(self.handle_context.as_mut(&session)),
+                        }
+                    }
+                }
+            }
+            RawStream { id, stream } => {
+                if let Some(session) = self.sessions.get(&id).cloned() {
+                    // The stream is taken only when the handle is actually called
+                    match tokio_threadpool::blocking(|| {
+                        if let Some(stream) = stream.take() {
+                            self.handle
+                                .raw_stream(self.handle_context.as_mut(&session), stream)
+                        }
+                    }) {
+                        Ok(Async::Ready(_)) => (),
+                        Ok(Async::NotReady) => return Async::NotReady,
+                        Err(_) => {
+                            if let Some(stream) = stream.take() {
+                                self.handle
+                                    .raw_stream(self.handle_context.as_mut(&session), stream)
+                            }
+                        }
+                    }
+                }
+            }
             Notify { token } => {
                 match tokio_threadpool::blocking(|| {
                     self.handle.notify(&mut self.handle_context, token)
@@ -250,6 +298,8 @@ impl<T> Drop for ServiceProtocolStream<T> {
             if let Some(event) = self.current_task.take() {
                 let session_id = match event {
                     Received { id, .. } => Some(id),
+                    RemoteFinished { id } => Some(id),
+                    RawStream { id, .. } => Some(id),
                     Disconnected { id } => Some(id),
                     Connected { session, .. } => Some(session.id),
                     _ => None,
@@ -364,6 +414,12 @@ pub enum SessionProtocolEvent {
         /// Data
         data: bytes::Bytes,
     },
+    /// Remote finished sending
+    RemoteFinished,
+    /// Negotiated raw sub stream
+    RawStream {
+        stream: RawSubstreamSlot,
+    },
     Notify {
         token: u64,
     },
@@ -486,6 +542,36 @@ where
                         .received(self.handle_context.as_mut(&self.context), data),
                 }
             }
+            RemoteFinished => {
+                match tokio_threadpool::blocking(|| {
+                    self.handle
+                        .remote_finished(self.handle_context.as_mut(&self.context))
+                }) {
+                    Ok(Async::Ready(_)) => (),
+                    Ok(Async::NotReady) => return Async::NotReady,
+                    Err(_) => self
+                        .handle
+                        .remote_finished(self.handle_context.as_mut(&self.context)),
+                }
+            }
+            RawStream { stream } => {
+                // The stream is taken only when the handle is actually called
+                match tokio_threadpool::blocking(|| {
+                    if let Some(stream) = stream.take() {
+                        self.handle
+                            .raw_stream(self.handle_context.as_mut(&self.context), stream)
+                    }
+                }) {
+                    Ok(Async::Ready(_)) => (),
+                    Ok(Async::NotReady) => return Async::NotReady,
+                    Err(_) => {
+                        if let Some(stream) = stream.take() {
+                            self.handle
+                                .raw_stream(self.handle_context.as_mut(&self.context), stream)
+                        }
+                    }
+                }
+            }
             Notify { token } => {
                 match tokio_threadpool::blocking(|| {
                     self.handle
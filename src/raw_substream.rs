@@ -0,0 +1,459 @@
+use bytes::BytesMut;
+use futures::{
+    prelude::*,
+    stream::iter_ok,
+    sync::{mpsc, oneshot},
+    task::AtomicTask,
+};
+use log::debug;
+use std::{
+    cmp,
+    collections::VecDeque,
+    fmt,
+    io::{self, ErrorKind, Read, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::prelude::{AsyncRead, AsyncWrite};
+
+use crate::{
+    context::SessionContext,
+    muxer::SubstreamHandle,
+    protocol_handle_stream::{ServiceProtocolEvent, SessionProtocolEvent},
+    substream::ProtocolEvent,
+    yamux::config::DEFAULT_STREAM_PRIORITY,
+    ProtocolId, SessionId, StreamId,
+};
+
+/// State shared between the raw sub stream and the task keeping its bookkeeping
+struct Shared {
+    /// The protocol is closed by local service or the session is closed
+    closed: AtomicBool,
+    /// Wake up the user reading the stream when it's closed
+    read_task: AtomicTask,
+    /// Wake up the user writing the stream when it's closed, it may be another task
+    write_task: AtomicTask,
+}
+
+/// Negotiated sub stream of a protocol with `raw_stream` enabled
+///
+/// Read and write bytes on it directly without any codec, the protocol is closed
+/// when it's dropped. Shutting it down only finishes sending, local can keep reading
+/// until remote finishes sending too.
+pub struct RawSubstream {
+    io: SubstreamHandle,
+    /// Data read ahead during protocol negotiation
+    read_buf: BytesMut,
+    proto_id: ProtocolId,
+    session_id: SessionId,
+    shared: Arc<Shared>,
+    /// Notify the bookkeeping task when dropped
+    _dropped: oneshot::Sender<()>,
+}
+
+impl RawSubstream {
+    /// Protocol id
+    pub fn proto_id(&self) -> ProtocolId {
+        self.proto_id
+    }
+
+    /// Session id
+    pub fn session_id(&self) -> SessionId {
+        self.session_id
+    }
+
+    fn check_closed(&mut self, writing: bool) -> io::Result<()> {
+        if writing {
+            self.shared.write_task.register();
+        } else {
+            self.shared.read_task.register();
+        }
+        if self.shared.closed.load(Ordering::SeqCst) {
+            let _ = self.io.shutdown();
+            return Err(io::Error::new(
+                ErrorKind::ConnectionAborted,
+                "The protocol is closed by local",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for RawSubstream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RawSubstream")
+            .field("proto_id", &self.proto_id)
+            .field("session_id", &self.session_id)
+            .field("stream_id", &self.io.id())
+            .finish()
+    }
+}
+
+impl Read for RawSubstream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.check_closed(false)?;
+        if !self.read_buf.is_empty() {
+            let n = cmp::min(buf.len(), self.read_buf.len());
+            buf[..n].copy_from_slice(&self.read_buf.split_to(n));
+            return Ok(n);
+        }
+        match self.io.read(buf) {
+            // Muxer stream reports remote finished sending as an error
+            Err(ref err) if err.kind() == ErrorKind::UnexpectedEof => Ok(0),
+            result => result,
+        }
+    }
+}
+
+impl AsyncRead for RawSubstream {}
+
+impl Write for RawSubstream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.check_closed(true)?;
+        self.io.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.check_closed(true)?;
+        self.io.flush()
+    }
+}
+
+impl AsyncWrite for RawSubstream {
+    fn shutdown(&mut self) -> Poll<(), io::Error> {
+        self.io.shutdown()
+    }
+}
+
+impl Drop for RawSubstream {
+    fn drop(&mut self) {
+        let _ = self.io.shutdown();
+    }
+}
+
+/// Hand over a raw sub stream through the cloneable protocol handle events
+#[derive(Clone)]
+pub(crate) struct RawSubstreamSlot(Arc<Mutex<Option<RawSubstream>>>);
+
+impl RawSubstreamSlot {
+    fn new(stream: RawSubstream) -> Self {
+        RawSubstreamSlot(Arc::new(Mutex::new(Some(stream))))
+    }
+
+    /// Only the first call gets the stream
+    pub fn take(&self) -> Option<RawSubstream> {
+        self.0.lock().ok().and_then(|mut stream| stream.take())
+    }
+}
+
+/// Keep the bookkeeping of a raw sub stream driven by the protocol handle,
+/// so that session and service know when the protocol is closed
+pub(crate) struct RawSubstreamTask {
+    id: StreamId,
+    proto_id: ProtocolId,
+    context: Arc<SessionContext>,
+    dead: bool,
+
+    service_proto_buf: VecDeque<ServiceProtocolEvent>,
+    session_proto_buf: VecDeque<SessionProtocolEvent>,
+
+    /// Send event to session
+    event_sender: mpsc::Sender<ProtocolEvent>,
+    /// Receive events from session
+    event_receiver: mpsc::Receiver<ProtocolEvent>,
+
+    service_proto_sender: Option<mpsc::Sender<ServiceProtocolEvent>>,
+    session_proto_sender: Option<mpsc::Sender<SessionProtocolEvent>>,
+
+    shared: Arc<Shared>,
+    /// Resolved when the raw sub stream is dropped
+    dropped: oneshot::Receiver<()>,
+    closed: Arc<AtomicBool>,
+}
+
+impl RawSubstreamTask {
+    /// Tell the protocol handles the protocol is open and hand over the stream,
+    /// the session level handle takes precedence if there is one
+    pub fn proto_open(&mut self, version: String, stream: RawSubstream) {
+        let stream = RawSubstreamSlot::new(stream);
+        if self.service_proto_sender.is_some() {
+            self.service_proto_buf
+                .push_back(ServiceProtocolEvent::Connected {
+                    session: self.context.clone(),
+                    version: version.clone(),
+                });
+            if self.session_proto_sender.is_none() {
+                self.service_proto_buf
+                    .push_back(ServiceProtocolEvent::RawStream {
+                        id: self.context.id,
+                        stream: stream.clone(),
+                    });
+            }
+        }
+
+        if self.session_proto_sender.is_some() {
+            self.session_proto_buf
+                .push_back(SessionProtocolEvent::Connected { version });
+            self.session_proto_buf
+                .push_back(SessionProtocolEvent::RawStream { stream });
+        }
+    }
+
+    /// Handling commands send by session
+    fn handle_proto_event(&mut self, event: ProtocolEvent) {
+        match event {
+            ProtocolEvent::Message { data, .. } => {
+                debug!(
+                    "proto [{}] is a raw stream, drop data: {}",
+                    self.proto_id,
+                    data.len()
+                );
+                self.context.decr_pending_data_size(data.len());
+            }
+            ProtocolEvent::Close { .. } => self.dead = true,
+            _ => (),
+        }
+    }
+
+    fn recv_event(&mut self) {
+        loop {
+            match self.event_receiver.poll() {
+                Ok(Async::Ready(Some(event))) => self.handle_proto_event(event),
+                // Must be session close
+                Ok(Async::Ready(None)) | Err(_) => self.dead = true,
+                Ok(Async::NotReady) => break,
+            }
+            if self.dead {
+                break;
+            }
+        }
+    }
+
+    fn distribute_to_user_level(&mut self) {
+        if let Some(ref mut sender) = self.service_proto_sender {
+            while let Some(event) = self.service_proto_buf.pop_front() {
+                match sender.start_send(event) {
+                    Ok(AsyncSink::Ready) => (),
+                    Ok(AsyncSink::NotReady(event)) => {
+                        debug!("service proto [{}] handle is full", self.proto_id);
+                        self.service_proto_buf.push_front(event);
+                        break;
+                    }
+                    Err(_) => {
+                        self.dead = true;
+                        break;
+                    }
+                }
+            }
+            let _ = sender.poll_complete();
+        }
+
+        if let Some(ref mut sender) = self.session_proto_sender {
+            while let Some(event) = self.session_proto_buf.pop_front() {
+                match sender.start_send(event) {
+                    Ok(AsyncSink::Ready) => (),
+                    Ok(AsyncSink::NotReady(event)) => {
+                        debug!("session proto [{}] handle is full", self.proto_id);
+                        self.session_proto_buf.push_front(event);
+                        break;
+                    }
+                    Err(_) => {
+                        self.dead = true;
+                        break;
+                    }
+                }
+            }
+            let _ = sender.poll_complete();
+        }
+    }
+
+    /// Close the raw sub stream and report it to protocol handles and session
+    fn close_proto_stream(&mut self) {
+        self.event_receiver.close();
+        self.shared.closed.store(true, Ordering::SeqCst);
+        self.shared.read_task.notify();
+        self.shared.write_task.notify();
+
+        if let Some(sender) = self.service_proto_sender.take() {
+            self.service_proto_buf
+                .push_back(ServiceProtocolEvent::Disconnected {
+                    id: self.context.id,
+                });
+            let events = self.service_proto_buf.split_off(0);
+            tokio::spawn(
+                sender
+                    .send_all(iter_ok(events))
+                    .map(|_| ())
+                    .map_err(|e| debug!("stream close event send to proto handle error: {:?}", e)),
+            );
+        }
+
+        if let Some(sender) = self.session_proto_sender.take() {
+            self.session_proto_buf
+                .push_back(SessionProtocolEvent::Disconnected);
+            let events = self.session_proto_buf.split_off(0);
+            tokio::spawn(
+                sender
+                    .send_all(iter_ok(events))
+                    .map(|_| ())
+                    .map_err(|e| debug!("stream close event send to proto handle error: {:?}", e)),
+            );
+        }
+
+        if !self.closed.load(Ordering::SeqCst) {
+            tokio::spawn(
+                self.event_sender
+                    .clone()
+                    .send(ProtocolEvent::Close {
+                        id: self.id,
+                        proto_id: self.proto_id,
+                    })
+                    .map(|_| ())
+                    .map_err(|e| debug!("stream close event send to session error: {:?}", e)),
+            );
+        }
+    }
+}
+
+impl Future for RawSubstreamTask {
+    type Item = ();
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        if !self.service_proto_buf.is_empty() || !self.session_proto_buf.is_empty() {
+            self.distribute_to_user_level();
+        }
+
+        match self.dropped.poll() {
+            Ok(Async::NotReady) => (),
+            Ok(Async::Ready(_)) | Err(_) => {
+                debug!("proto [{}] raw stream dropped", self.proto_id);
+                self.dead = true;
+            }
+        }
+
+        if !self.dead {
+            self.recv_event();
+        }
+
+        if self.dead || self.closed.load(Ordering::SeqCst) {
+            debug!("RawSubstream({}) finished", self.id);
+            self.close_proto_stream();
+            return Ok(Async::Ready(()));
+        }
+
+        Ok(Async::NotReady)
+    }
+}
+
+pub(crate) struct RawSubstreamBuilder {
+    id: StreamId,
+    proto_id: ProtocolId,
+    stream_priority: u8,
+
+    context: Arc<SessionContext>,
+
+    service_proto_sender: Option<mpsc::Sender<ServiceProtocolEvent>>,
+    session_proto_sender: Option<mpsc::Sender<SessionProtocolEvent>>,
+
+    /// Send event to session
+    event_sender: mpsc::Sender<ProtocolEvent>,
+    /// Receive events from session
+    event_receiver: mpsc::Receiver<ProtocolEvent>,
+    closed: Arc<AtomicBool>,
+}
+
+impl RawSubstreamBuilder {
+    pub fn new(
+        event_sender: mpsc::Sender<ProtocolEvent>,
+        event_receiver: mpsc::Receiver<ProtocolEvent>,
+        closed: Arc<AtomicBool>,
+        context: Arc<SessionContext>,
+    ) -> Self {
+        RawSubstreamBuilder {
+            id: 0,
+            proto_id: 0.into(),
+            stream_priority: DEFAULT_STREAM_PRIORITY,
+            context,
+            service_proto_sender: None,
+            session_proto_sender: None,
+            event_sender,
+            event_receiver,
+            closed,
+        }
+    }
+
+    pub fn stream_id(mut self, id: StreamId) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn proto_id(mut self, id: ProtocolId) -> Self {
+        self.proto_id = id;
+        self
+    }
+
+    pub fn stream_priority(mut self, priority: u8) -> Self {
+        self.stream_priority = priority;
+        self
+    }
+
+    pub fn service_proto_sender(
+        mut self,
+        sender: Option<mpsc::Sender<ServiceProtocolEvent>>,
+    ) -> Self {
+        self.service_proto_sender = sender;
+        self
+    }
+
+    pub fn session_proto_sender(
+        mut self,
+        sender: Option<mpsc::Sender<SessionProtocolEvent>>,
+    ) -> Self {
+        self.session_proto_sender = sender;
+        self
+    }
+
+    /// Build the bookkeeping task and the stream for the protocol handle
+    pub fn build(
+        self,
+        mut io: SubstreamHandle,
+        read_buf: BytesMut,
+    ) -> (RawSubstreamTask, RawSubstream) {
+        io.set_priority(self.stream_priority);
+        // Remote finishing sending must not close local side, or it can't keep writing
+        io.set_auto_close(false);
+        let shared = Arc::new(Shared {
+            closed: AtomicBool::new(false),
+            read_task: AtomicTask::new(),
+            write_task: AtomicTask::new(),
+        });
+        let (dropped_sender, dropped) = oneshot::channel();
+        let stream = RawSubstream {
+            io,
+            read_buf,
+            proto_id: self.proto_id,
+            session_id: self.context.id,
+            shared: Arc::clone(&shared),
+            _dropped: dropped_sender,
+        };
+        let task = RawSubstreamTask {
+            id: self.id,
+            proto_id: self.proto_id,
+            context: self.context,
+            dead: false,
+            service_proto_buf: VecDeque::new(),
+            session_proto_buf: VecDeque::new(),
+            event_sender: self.event_sender,
+            event_receiver: self.event_receiver,
+            service_proto_sender: self.service_proto_sender,
+            session_proto_sender: self.session_proto_sender,
+            shared,
+            dropped,
+            closed: self.closed,
+        };
+        (task, stream)
+    }
+}
@@ -1,4 +1,4 @@
-use futures::{prelude::*, sync::mpsc};
+use futures::{future, prelude::*, sync::mpsc};
 use log::{debug, error, trace, warn};
 use std::collections::{vec_deque::VecDeque, HashMap, HashSet};
 use std::sync::{
@@ -14,6 +14,7 @@ use crate::{
     context::{ServiceContext, SessionContext, SessionController},
     error::Error,
     multiaddr::{multihash::Multihash, Multiaddr, Protocol},
+    muxer::{select_muxer, Muxer},
     protocol_handle_stream::{
         ServiceProtocolEvent, ServiceProtocolStream, SessionProtocolEvent, SessionProtocolStream,
     },
@@ -29,7 +30,7 @@ use crate::{
     transports::{MultiIncoming, MultiTransport, Transport, TransportError},
     upnp::IGDClient,
     utils::extract_peer_id,
-    yamux::{session::SessionType as YamuxType, Config as YamuxConfig},
+    yamux::{session::SessionType as YamuxType, Config as YamuxConfig, MemoryBudget},
     ProtocolId, SessionId,
 };
 
@@ -40,8 +41,8 @@ pub(crate) mod future_task;
 
 pub use crate::service::{
     config::{DialProtocol, ProtocolHandle, ProtocolMeta, TargetProtocol, TargetSession},
-    control::ServiceControl,
-    event::{ProtocolEvent, ServiceError, ServiceEvent},
+    control::{Reachability, ServiceControl},
+    event::{CloseReason, Initiator, ProtocolEvent, ServiceError, ServiceEvent},
 };
 use bytes::Bytes;
 
@@ -77,6 +78,9 @@ pub struct Service<T> {
 
     dial_protocols: HashMap<Multiaddr, TargetProtocol>,
     config: ServiceConfig,
+    /// Service-wide limit of buffered received data, shared by all sessions
+    recv_budget: Option<MemoryBudget>,
+    recv_budget_exhausted: bool,
     /// service state
     state: State,
 
@@ -151,6 +155,7 @@ where
         let (future_task_sender, future_task_receiver) = mpsc::channel(SEND_SIZE);
         let shutdown = Arc::new(AtomicBool::new(false));
         let igd_client = if config.upnp { IGDClient::new() } else { None };
+        let recv_budget = config.max_buffered_recv_size.map(MemoryBudget::new);
 
         Service {
             protocol_configs,
@@ -182,10 +187,13 @@ where
                 quick_task_sender,
                 proto_infos,
                 key_pair,
+                config.pre_shared_key,
                 shutdown.clone(),
                 config.timeout,
             ),
             config,
+            recv_budget,
+            recv_budget_exhausted: false,
             service_task_receiver,
             quick_task_receiver,
             pending_tasks: VecDeque::default(),
@@ -403,6 +411,22 @@ where
         }
     }
 
+    /// Report once each time the service-wide receive budget is exhausted
+    fn recv_budget_check(&mut self) {
+        let (exhausted, used, limit) = match self.recv_budget {
+            Some(ref budget) => (budget.is_exhausted(), budget.used(), budget.limit()),
+            None => return,
+        };
+        if exhausted && !self.recv_budget_exhausted {
+            warn!("receive budget exhausted, used: {}, limit: {}", used, limit);
+            self.handle.handle_error(
+                &mut self.service_context,
+                ServiceError::RecvBudgetExhausted { used, limit },
+            );
+        }
+        self.recv_budget_exhausted = exhausted;
+    }
+
     /// Distribute event to user level
     #[inline(always)]
     fn distribute_to_user_level(&mut self) {
@@ -685,16 +709,35 @@ where
             let key_pair = key_pair.clone();
             let sender = self.session_event_sender.clone();
 
-            let handshake_task = Config::new(key_pair)
+            let secio_config = Config::new(key_pair)
                 .max_frame_length(self.config.max_frame_length)
-                .handshake(socket)
+                .cipher_policy(self.config.cipher_policy.clone());
+
+            let handshake_task = match self.config.pre_shared_key {
+                Some(psk) => future::Either::A(
+                    psk.handshake(socket)
+                        .and_then(move |socket| secio_config.handshake(socket)),
+                ),
+                None => future::Either::B(secio_config.handshake(socket)),
+            };
+
+            let muxers = self.config.muxers.clone();
+            let negotiate = self.config.negotiate_muxer;
+            let handshake_task = handshake_task.and_then(move |(handle, public_key, _)| {
+                select_muxer(handle, muxers, negotiate, ty)
+                    .from_err()
+                    .map(move |(handle, muxer)| (handle, public_key, muxer))
+            });
+
+            let handshake_task = handshake_task
                 .timeout(self.config.timeout)
                 .then(move |result| {
                     let send_task = match result {
-                        Ok((handle, public_key, _)) => {
+                        Ok((handle, public_key, muxer)) => {
                             sender.send(SessionEvent::HandshakeSuccess {
                                 handle,
                                 public_key,
+                                muxer,
                                 address: remote_address,
                                 ty,
                                 listen_address,
@@ -741,7 +784,8 @@ where
 
             tokio::spawn(future_task);
         } else {
-            self.session_open(socket, None, remote_address, ty, listen_address);
+            let muxer = self.config.muxers[0];
+            self.session_open(socket, None, remote_address, ty, listen_address, muxer);
         }
     }
 
@@ -763,6 +807,7 @@ where
         mut address: Multiaddr,
         ty: SessionType,
         listen_addr: Option<Multiaddr>,
+        muxer: Muxer,
     ) where
         H: AsyncRead + AsyncWrite + Send + 'static,
     {
@@ -875,6 +920,8 @@ where
                     .collect(),
             )
             .config(self.config.yamux_config)
+            .mplex_config(self.config.mplex_config)
+            .muxer(muxer)
             .keep_buffer(self.config.keep_buffer)
             .service_proto_senders(self.service_proto_handles.clone())
             .session_senders(
@@ -890,7 +937,14 @@ where
                     .collect(),
             )
             .context(session_context.clone())
-            .event(self.config.event.clone());
+            .event(self.config.event.clone())
+            .recv_budget(self.recv_budget.clone())
+            .max_negotiating_streams(self.config.max_negotiating_streams)
+            .stream_abuse(
+                self.config.stream_abuse_window,
+                self.config.stream_abuse_threshold,
+            )
+            .capture(self.config.capture.clone());
 
         let mut session = Session::new(
             handle,
@@ -969,10 +1023,15 @@ where
 
         if let Some(session_control) = self.sessions.remove(&id) {
             // Service handle processing flow
+            let reason = session_control
+                .inner
+                .close_reason()
+                .unwrap_or_else(CloseReason::local);
             self.handle.handle_event(
                 &mut self.service_context,
                 ServiceEvent::SessionClose {
                     session_context: session_control.inner,
+                    reason,
                 },
             );
         }
@@ -1213,11 +1272,12 @@ where
             SessionEvent::HandshakeSuccess {
                 handle,
                 public_key,
+                muxer,
                 address,
                 ty,
                 listen_address,
             } => {
-                self.session_open(handle, Some(public_key), address, ty, listen_address);
+                self.session_open(handle, Some(public_key), address, ty, listen_address, muxer);
             }
             SessionEvent::HandshakeFail { ty, error, address } => {
                 if ty.is_outbound() {
@@ -1241,6 +1301,19 @@ where
             SessionEvent::ProtocolClose { id, proto_id } => {
                 self.protocol_close(id, proto_id, Source::Internal)
             }
+            SessionEvent::ProtocolRemoteFinished { id, proto_id } => {
+                if self.config.event.contains(&proto_id) {
+                    if let Some(session_control) = self.sessions.get(&id) {
+                        self.handle.handle_proto(
+                            &mut self.service_context,
+                            ProtocolEvent::RemoteFinished {
+                                proto_id,
+                                session_context: Arc::clone(&session_control.inner),
+                            },
+                        );
+                    }
+                }
+            }
             SessionEvent::ProtocolSelectError { id, proto_name } => {
                 if let Some(session_control) = self.sessions.get(&id) {
                     self.handle.handle_error(
@@ -1300,6 +1373,17 @@ where
                     )
                 }
             }
+            SessionEvent::StreamOpenAbuse { id, rejected } => {
+                if let Some(session_control) = self.sessions.get(&id) {
+                    self.handle.handle_error(
+                        &mut self.service_context,
+                        ServiceError::StreamOpenAbuse {
+                            session_context: Arc::clone(&session_control.inner),
+                            rejected,
+                        },
+                    )
+                }
+            }
             SessionEvent::ListenStart {
                 listen_address,
                 incoming,
@@ -1330,6 +1414,8 @@ where
                 // if handle panic, close service
                 self.handle_service_task(ServiceTask::Shutdown(false));
             }
+            // Only sent from service to session
+            SessionEvent::ProtocolHalfClose { .. } | SessionEvent::ProtocolPriority { .. } => (),
         }
     }
 
@@ -1370,7 +1456,10 @@ where
                     }
                 }
             }
-            ServiceTask::Disconnect { session_id } => {
+            ServiceTask::Disconnect { session_id, reason } => {
+                if let (Some(reason), Some(control)) = (reason, self.sessions.get(&session_id)) {
+                    control.inner.set_close_reason(reason);
+                }
                 self.session_close(session_id, Source::External)
             }
             ServiceTask::FutureTask { task } => {
@@ -1458,6 +1547,40 @@ where
                 session_id,
                 proto_id,
             } => self.protocol_close(session_id, proto_id, Source::External),
+            ServiceTask::ProtocolHalfClose {
+                session_id,
+                proto_id,
+            } => {
+                debug!(
+                    "try half close session [{}] proto [{}]",
+                    session_id, proto_id
+                );
+                self.push_back(
+                    Priority::Normal,
+                    session_id,
+                    SessionEvent::ProtocolHalfClose {
+                        id: session_id,
+                        proto_id,
+                    },
+                );
+                self.distribute_to_session();
+            }
+            ServiceTask::ProtocolPriority {
+                session_id,
+                proto_id,
+                priority,
+            } => {
+                self.push_back(
+                    Priority::High,
+                    session_id,
+                    SessionEvent::ProtocolPriority {
+                        id: session_id,
+                        proto_id,
+                        priority,
+                    },
+                );
+                self.distribute_to_session();
+            }
             ServiceTask::Shutdown(quick) => {
                 self.state.pre_shutdown();
 
@@ -1704,6 +1827,8 @@ where
 
         self.session_poll();
 
+        self.recv_budget_check();
+
         // receive user task
         self.user_task_poll();
 
@@ -1,7 +1,10 @@
 use crate::{
     builder::{BeforeReceiveFn, CodecFn, NameFn, SelectVersionFn, SessionHandleFn},
+    mplex::Config as MplexConfig,
+    muxer::Muxer,
+    secio::{handshake::CipherPolicy, pnet::PreSharedKey},
     traits::{Codec, ServiceProtocol, SessionProtocol},
-    yamux::config::Config as YamuxConfig,
+    yamux::{config::Config as YamuxConfig, Capture},
     ProtocolId, SessionId,
 };
 use std::collections::HashSet;
@@ -11,12 +14,28 @@ use std::time::Duration;
 pub(crate) struct ServiceConfig {
     pub timeout: Duration,
     pub yamux_config: YamuxConfig,
+    pub mplex_config: MplexConfig,
+    /// Muxers in order of preference
+    pub muxers: Vec<Muxer>,
+    /// Negotiate the muxer even if there is only one, otherwise the first one is used directly
+    pub negotiate_muxer: bool,
     pub max_frame_length: usize,
     /// event output or callback output
     pub event: HashSet<ProtocolId>,
     pub keep_buffer: bool,
     pub upnp: bool,
     pub max_connection_number: usize,
+    pub pre_shared_key: Option<PreSharedKey>,
+    pub cipher_policy: CipherPolicy,
+    pub max_buffered_recv_size: Option<usize>,
+    /// Inbound streams negotiating protocol at the same time in a session
+    pub max_negotiating_streams: usize,
+    /// Streams reset within the window are counted to find out abuse
+    pub stream_abuse_window: Duration,
+    /// Report the remote when streams reset within the window reach it
+    pub stream_abuse_threshold: u64,
+    /// Record frames and protocol messages of all sessions
+    pub capture: Option<Capture>,
 }
 
 impl Default for ServiceConfig {
@@ -24,11 +43,21 @@ impl Default for ServiceConfig {
         ServiceConfig {
             timeout: Duration::from_secs(10),
             yamux_config: YamuxConfig::default(),
+            mplex_config: MplexConfig::default(),
+            muxers: vec![Muxer::Yamux],
+            negotiate_muxer: false,
             max_frame_length: 1024 * 1024 * 8,
             event: HashSet::default(),
             keep_buffer: false,
             upnp: false,
             max_connection_number: 65535,
+            pre_shared_key: None,
+            cipher_policy: CipherPolicy::default(),
+            max_buffered_recv_size: None,
+            max_negotiating_streams: 64,
+            stream_abuse_window: Duration::from_secs(10),
+            stream_abuse_threshold: 64,
+            capture: None,
         }
     }
 }
@@ -187,6 +216,9 @@ pub(crate) struct Meta {
     pub(crate) codec: CodecFn,
     pub(crate) select_version: SelectVersionFn,
     pub(crate) before_receive: BeforeReceiveFn,
+    pub(crate) stream_priority: u8,
+    pub(crate) half_close: bool,
+    pub(crate) raw_stream: bool,
 }
 
 /// Protocol handle
@@ -15,14 +15,45 @@ use crate::{
     multiaddr::Multiaddr,
     protocol_select::ProtocolInfo,
     service::{
-        event::Priority, DialProtocol, ServiceTask, TargetProtocol, TargetSession,
-        RECEIVED_BUFFER_SIZE,
+        event::{CloseReason, Initiator, Priority},
+        DialProtocol, ServiceTask, TargetProtocol, TargetSession, RECEIVED_BUFFER_SIZE,
     },
     ProtocolId, SessionId,
 };
 use bytes::Bytes;
 use std::sync::atomic::AtomicBool;
 
+/// Whether the service can be dialed from outside, as probed by a protocol such as autonat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reachability {
+    /// Not probed yet, or probes are inconclusive
+    Unknown,
+    /// Peers succeeded to dial back the service
+    Public,
+    /// Peers failed to dial back the service
+    Private,
+}
+
+impl From<usize> for Reachability {
+    fn from(n: usize) -> Self {
+        match n {
+            1 => Reachability::Public,
+            2 => Reachability::Private,
+            _ => Reachability::Unknown,
+        }
+    }
+}
+
+impl From<Reachability> for usize {
+    fn from(reachability: Reachability) -> Self {
+        match reachability {
+            Reachability::Unknown => 0,
+            Reachability::Public => 1,
+            Reachability::Private => 2,
+        }
+    }
+}
+
 /// Service control, used to send commands externally at runtime
 #[derive(Clone)]
 pub struct ServiceControl {
@@ -31,6 +62,7 @@ pub struct ServiceControl {
     pub(crate) proto_infos: Arc<HashMap<ProtocolId, ProtocolInfo>>,
     pub(crate) normal_count: Arc<AtomicUsize>,
     pub(crate) quick_count: Arc<AtomicUsize>,
+    reachability: Arc<AtomicUsize>,
     timeout: Duration,
     closed: Arc<AtomicBool>,
 }
@@ -50,6 +82,7 @@ impl ServiceControl {
             proto_infos: Arc::new(proto_infos),
             normal_count: Arc::new(AtomicUsize::new(0)),
             quick_count: Arc::new(AtomicUsize::new(0)),
+            reachability: Arc::new(AtomicUsize::new(Reachability::Unknown.into())),
             timeout,
             closed,
         }
@@ -108,6 +141,19 @@ impl ServiceControl {
         &self.proto_infos
     }
 
+    /// Get the reachability of the service
+    #[inline]
+    pub fn reachability(&self) -> Reachability {
+        self.reachability.load(Ordering::SeqCst).into()
+    }
+
+    /// Set the reachability of the service, used by the protocol probing it
+    #[inline]
+    pub fn set_reachability(&self, reachability: Reachability) {
+        self.reachability
+            .store(reachability.into(), Ordering::SeqCst)
+    }
+
     /// Create a new listener
     #[inline]
     pub fn listen(&self, address: Multiaddr) -> Result<(), Error> {
@@ -126,7 +172,32 @@ impl ServiceControl {
     /// Disconnect a connection
     #[inline]
     pub fn disconnect(&self, session_id: SessionId) -> Result<(), Error> {
-        self.quick_send(ServiceTask::Disconnect { session_id })
+        self.quick_send(ServiceTask::Disconnect {
+            session_id,
+            reason: None,
+        })
+    }
+
+    /// Disconnect a connection with an application defined code and message,
+    /// the remote's protocol handles and service handle will receive them.
+    ///
+    /// The code is sent in a go away code range of its own, apart from yamux's error codes,
+    /// the message is truncated to `yamux::session::MAX_GO_AWAY_MESSAGE` bytes
+    #[inline]
+    pub fn disconnect_with_reason(
+        &self,
+        session_id: SessionId,
+        code: u16,
+        message: String,
+    ) -> Result<(), Error> {
+        self.quick_send(ServiceTask::Disconnect {
+            session_id,
+            reason: Some(CloseReason::Application {
+                initiator: Initiator::Local,
+                code,
+                message,
+            }),
+        })
     }
 
     /// Send message
@@ -228,6 +299,41 @@ impl ServiceControl {
         })
     }
 
+    /// Finish sending on a protocol, messages sent before it are still delivered,
+    /// and messages from remote are still received until remote closes too
+    ///
+    /// If the protocol is not open, do nothing
+    #[inline]
+    pub fn half_close_protocol(
+        &self,
+        session_id: SessionId,
+        proto_id: ProtocolId,
+    ) -> Result<(), Error> {
+        // Same channel as normal messages, so it is behind them
+        self.send(ServiceTask::ProtocolHalfClose {
+            session_id,
+            proto_id,
+        })
+    }
+
+    /// Change the scheduling priority of a protocol's sub stream at runtime,
+    /// see `MetaBuilder::stream_priority`
+    ///
+    /// If the protocol is not open, do nothing
+    #[inline]
+    pub fn set_protocol_priority(
+        &self,
+        session_id: SessionId,
+        proto_id: ProtocolId,
+        priority: u8,
+    ) -> Result<(), Error> {
+        self.send(ServiceTask::ProtocolPriority {
+            session_id,
+            proto_id,
+            priority,
+        })
+    }
+
     /// Set a service notify token
     pub fn set_service_notify(
         &self,
@@ -8,6 +8,7 @@ use crate::{
     error::Error,
     multiaddr::Multiaddr,
     service::{TargetProtocol, TargetSession},
+    yamux::frame::{GoAwayCode, GoAwayReason},
     ProtocolId, SessionId,
 };
 use bytes::Bytes;
@@ -72,6 +73,132 @@ pub enum ServiceError {
         /// Session context
         session_context: Arc<SessionContext>,
     },
+    /// Received data buffered by all sessions reached the service-wide limit,
+    /// streams stop granting receive window until the data is consumed
+    RecvBudgetExhausted {
+        /// Buffered bytes
+        used: usize,
+        /// The limit
+        limit: usize,
+    },
+    /// Remote keeps opening streams beyond the rate limit or the stream count caps,
+    /// the excess streams are reset. Usually a sign of abuse, can be fed into banning.
+    StreamOpenAbuse {
+        /// Session context
+        session_context: Arc<SessionContext>,
+        /// Streams reset within the window configured by `ServiceBuilder::stream_open_abuse`
+        rejected: u64,
+    },
+}
+
+/// Which side initiated the close
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Initiator {
+    /// Closed by self
+    Local,
+    /// Closed by remote
+    Remote,
+}
+
+/// Why a session is closed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CloseReason {
+    /// Yamux go away, codes defined by spec are in `yamux::frame::GoAwayCode`
+    GoAway {
+        /// Which side sent the go away
+        initiator: Initiator,
+        /// Go away code
+        code: u32,
+    },
+    /// Connection reset
+    Reset {
+        /// Which side reset the connection
+        initiator: Initiator,
+    },
+    /// The protocol's muxer stream was reset while the session stays open,
+    /// only seen by the protocol's handle through `ProtocolContextMutRef::close_reason`
+    StreamReset {
+        /// Which side reset the stream
+        initiator: Initiator,
+    },
+    /// Keepalive, write or session open timeout
+    Timeout,
+    /// Connection lost without go away, such as EOF or broken pipe
+    ConnectionLost,
+    /// Code and message supplied by application through `disconnect_with_reason`
+    Application {
+        /// Which side called `disconnect_with_reason`
+        initiator: Initiator,
+        /// Application defined code, sent in its own go away code range,
+        /// see `yamux::frame::APPLICATION_GO_AWAY_CODE_BASE`
+        code: u16,
+        /// Application defined message
+        message: String,
+    },
+}
+
+impl CloseReason {
+    /// Which side initiated the close
+    pub fn initiator(&self) -> Initiator {
+        match self {
+            CloseReason::GoAway { initiator, .. }
+            | CloseReason::Reset { initiator }
+            | CloseReason::StreamReset { initiator }
+            | CloseReason::Application { initiator, .. } => *initiator,
+            CloseReason::Timeout => Initiator::Local,
+            CloseReason::ConnectionLost => Initiator::Remote,
+        }
+    }
+
+    /// Normal close by self
+    pub(crate) fn local() -> Self {
+        CloseReason::GoAway {
+            initiator: Initiator::Local,
+            code: GoAwayCode::Normal as u32,
+        }
+    }
+
+    /// Build from the go away received from remote
+    pub(crate) fn from_remote_go_away(reason: &GoAwayReason) -> Self {
+        match reason.application_code() {
+            Some(code) => CloseReason::Application {
+                initiator: Initiator::Remote,
+                code,
+                message: reason
+                    .message
+                    .as_ref()
+                    .map(|message| String::from_utf8_lossy(message).into_owned())
+                    .unwrap_or_default(),
+            },
+            None => CloseReason::GoAway {
+                initiator: Initiator::Remote,
+                code: reason.code,
+            },
+        }
+    }
+}
+
+impl fmt::Display for CloseReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CloseReason::GoAway { initiator, code } => {
+                write!(f, "{:?} go away, code: {}", initiator, code)
+            }
+            CloseReason::Reset { initiator } => write!(f, "{:?} reset", initiator),
+            CloseReason::StreamReset { initiator } => write!(f, "{:?} stream reset", initiator),
+            CloseReason::Timeout => write!(f, "Timeout"),
+            CloseReason::ConnectionLost => write!(f, "Connection lost"),
+            CloseReason::Application {
+                initiator,
+                code,
+                message,
+            } => write!(
+                f,
+                "{:?} application close, code: {}, message: {}",
+                initiator, code, message
+            ),
+        }
+    }
 }
 
 /// Event generated by the Service
@@ -81,6 +208,8 @@ pub enum ServiceEvent {
     SessionClose {
         /// Session context
         session_context: Arc<SessionContext>,
+        /// Why the session is closed
+        reason: CloseReason,
     },
     /// A session open
     SessionOpen {
@@ -127,6 +256,13 @@ pub enum ProtocolEvent {
         /// session context
         session_context: Arc<SessionContext>,
     },
+    /// Remote finished sending, only on protocols with `half_close` enabled
+    RemoteFinished {
+        /// Protocol id
+        proto_id: ProtocolId,
+        /// session context
+        session_context: Arc<SessionContext>,
+    },
 }
 
 /// Task received by the Service.
@@ -159,6 +295,22 @@ pub(crate) enum ServiceTask {
         /// protocol id
         proto_id: ProtocolId,
     },
+    /// Finish sending on specify protocol
+    ProtocolHalfClose {
+        /// Session id
+        session_id: SessionId,
+        /// protocol id
+        proto_id: ProtocolId,
+    },
+    /// Change stream priority of specify protocol
+    ProtocolPriority {
+        /// Session id
+        session_id: SessionId,
+        /// protocol id
+        proto_id: ProtocolId,
+        /// Muxer stream priority
+        priority: u8,
+    },
     /// Set service notify task
     SetProtocolNotify {
         /// Protocol id
@@ -204,6 +356,8 @@ pub(crate) enum ServiceTask {
     Disconnect {
         /// Session id
         session_id: SessionId,
+        /// Application supplied reason
+        reason: Option<CloseReason>,
     },
     /// Dial task
     Dial {
@@ -262,7 +416,7 @@ impl fmt::Debug for ServiceTask {
                 proto_id, session_id, token
             ),
             FutureTask { .. } => write!(f, "Future task"),
-            Disconnect { session_id } => write!(f, "Disconnect session [{}]", session_id),
+            Disconnect { session_id, .. } => write!(f, "Disconnect session [{}]", session_id),
             Dial { address, .. } => write!(f, "Dial address: {}", address),
             Listen { address } => write!(f, "Listen address: {}", address),
             ProtocolOpen { session_id, target } => {
@@ -272,6 +426,23 @@ impl fmt::Debug for ServiceTask {
                 session_id,
                 proto_id,
             } => write!(f, "Close session [{}] proto [{}]", session_id, proto_id),
+            ProtocolHalfClose {
+                session_id,
+                proto_id,
+            } => write!(
+                f,
+                "Half close session [{}] proto [{}]",
+                session_id, proto_id
+            ),
+            ProtocolPriority {
+                session_id,
+                proto_id,
+                priority,
+            } => write!(
+                f,
+                "Set session [{}] proto [{}] priority [{}]",
+                session_id, proto_id, priority
+            ),
             Shutdown(_) => write!(f, "Try close service"),
         }
     }
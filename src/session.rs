@@ -4,7 +4,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use std::{
     io::{self, ErrorKind},
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicUsize, Ordering},
         Arc,
     },
     time::{Duration, Instant},
@@ -18,17 +18,26 @@ use tokio::{
 use crate::{
     context::SessionContext,
     error::Error,
+    mplex::Config as MplexConfig,
     multiaddr::Multiaddr,
+    muxer::{Muxer, MuxerSession, Rewind, SubstreamHandle},
     protocol_handle_stream::{ServiceProtocolEvent, SessionProtocolEvent},
     protocol_select::{client_select, server_select, ProtocolInfo},
+    raw_substream::RawSubstreamBuilder,
     secio::{codec::stream_handle::StreamHandle as SecureHandle, PublicKey},
     service::{
-        config::Meta, event::Priority, future_task::BoxedFutureTask, SessionType,
-        BUF_SHRINK_THRESHOLD, DELAY_TIME, RECEIVED_BUFFER_SIZE, RECEIVED_SIZE, SEND_SIZE,
+        config::Meta,
+        event::{CloseReason, Initiator, Priority},
+        future_task::BoxedFutureTask,
+        SessionType, BUF_SHRINK_THRESHOLD, DELAY_TIME, RECEIVED_BUFFER_SIZE, RECEIVED_SIZE,
+        SEND_SIZE,
     },
     substream::{ProtocolEvent, SubstreamBuilder},
     transports::{MultiIncoming, MultiStream},
-    yamux::{Config, Session as YamuxSession, StreamHandle},
+    yamux::{
+        frame::{GoAwayCode, GoAwayReason},
+        Capture, Config, MemoryBudget,
+    },
     ProtocolId, SessionId, StreamId,
 };
 
@@ -48,10 +57,12 @@ pub(crate) enum SessionEvent {
         stream: MultiStream,
     },
     HandshakeSuccess {
-        /// Secure handle
-        handle: SecureHandle,
+        /// Secure handle, with the data read ahead during muxer negotiation
+        handle: Rewind<SecureHandle>,
         /// Remote Public key
         public_key: PublicKey,
+        /// Negotiated muxer
+        muxer: Muxer,
         /// Remote address
         address: Multiaddr,
         /// Session type
@@ -107,6 +118,29 @@ pub(crate) enum SessionEvent {
         /// Protocol id
         proto_id: ProtocolId,
     },
+    /// Finish sending on protocol
+    ProtocolHalfClose {
+        /// Session id
+        id: SessionId,
+        /// Protocol id
+        proto_id: ProtocolId,
+    },
+    /// Change stream priority of protocol
+    ProtocolPriority {
+        /// Session id
+        id: SessionId,
+        /// Protocol id
+        proto_id: ProtocolId,
+        /// Muxer stream priority
+        priority: u8,
+    },
+    /// Remote finished sending on protocol
+    ProtocolRemoteFinished {
+        /// Session id
+        id: SessionId,
+        /// Protocol id
+        proto_id: ProtocolId,
+    },
     ProtocolSelectError {
         /// Session id
         id: SessionId,
@@ -130,6 +164,13 @@ pub(crate) enum SessionEvent {
         id: SessionId,
         error: Error,
     },
+    /// Remote keeps opening streams beyond the limits
+    StreamOpenAbuse {
+        /// Session id
+        id: SessionId,
+        /// Streams reset within the abuse window
+        rejected: u64,
+    },
     /// Protocol handle error, will cause memory leaks/abnormal CPU usage
     ProtocolHandleError {
         /// Error message
@@ -141,7 +182,7 @@ pub(crate) enum SessionEvent {
 
 /// Wrapper for real data streams, such as TCP stream
 pub(crate) struct Session<T> {
-    socket: YamuxSession<T>,
+    socket: MuxerSession<T>,
 
     protocol_configs: HashMap<String, Arc<Meta>>,
 
@@ -188,6 +229,18 @@ pub(crate) struct Session<T> {
 
     substreams_control: Arc<AtomicBool>,
     last_sent: Instant,
+
+    /// Inbound streams negotiating protocol
+    negotiating: Arc<AtomicUsize>,
+    max_negotiating_streams: usize,
+    /// Inbound streams reset because of `max_negotiating_streams`
+    rejected_streams: u64,
+    /// Start of the current abuse check window, and the streams reset before it
+    abuse_window: (Instant, u64),
+    /// Report the remote when streams reset within the window reach the threshold
+    stream_abuse: (Duration, u64),
+    /// Record frames and protocol messages for debugging
+    capture: Option<Capture>,
     future_task_sender: mpsc::Sender<BoxedFutureTask>,
 }
 
@@ -204,7 +257,18 @@ where
         meta: SessionMeta,
         future_task_sender: mpsc::Sender<BoxedFutureTask>,
     ) -> Self {
-        let socket = YamuxSession::new(socket, meta.config, meta.context.ty.into());
+        let capture = meta
+            .capture
+            .map(|capture| capture.with_session(meta.context.id.value() as u64));
+        let socket = MuxerSession::new(
+            socket,
+            meta.muxer,
+            meta.config,
+            meta.mplex_config,
+            meta.context.ty,
+            meta.recv_budget,
+            capture.clone(),
+        );
         let (proto_event_sender, proto_event_receiver) = mpsc::channel(RECEIVED_SIZE);
         let interval = proto_event_sender.clone();
 
@@ -262,6 +326,12 @@ where
             substreams_control: Arc::new(AtomicBool::new(false)),
             event: meta.event,
             last_sent: Instant::now(),
+            negotiating: Arc::new(AtomicUsize::new(0)),
+            max_negotiating_streams: meta.max_negotiating_streams,
+            rejected_streams: 0,
+            abuse_window: (Instant::now(), 0),
+            stream_abuse: meta.stream_abuse,
+            capture,
             future_task_sender,
         }
     }
@@ -272,7 +342,7 @@ where
         &mut self,
         procedure: impl Future<
                 Item = (
-                    Framed<StreamHandle, LengthDelimitedCodec>,
+                    Framed<SubstreamHandle, LengthDelimitedCodec>,
                     String,
                     Option<String>,
                 ),
@@ -431,7 +501,17 @@ where
     }
 
     /// Handling client-initiated open protocol sub stream requests
-    fn handle_sub_stream(&mut self, sub_stream: StreamHandle) {
+    fn handle_sub_stream(&mut self, mut sub_stream: SubstreamHandle) {
+        if self.negotiating.load(Ordering::Acquire) >= self.max_negotiating_streams {
+            debug!(
+                "session [{}] too many negotiating streams, reset the new one",
+                self.context.id
+            );
+            sub_stream.reset();
+            self.rejected_streams += 1;
+            return;
+        }
+
         let proto_metas = self
             .protocol_configs
             .values()
@@ -443,15 +523,36 @@ where
             })
             .collect();
 
-        let task = server_select(sub_stream, proto_metas);
+        // Dropped when the negotiation finishes or times out
+        let guard = NegotiatingGuard::new(Arc::clone(&self.negotiating));
+        let task = server_select(sub_stream, proto_metas).then(move |result| {
+            drop(guard);
+            result
+        });
         self.select_procedure(task);
     }
 
+    /// Report the remote if it keeps opening streams beyond the limits
+    fn check_stream_open_abuse(&mut self) {
+        let rejected = self.socket.rejected_streams() + self.rejected_streams;
+        let (start, base) = self.abuse_window;
+        let (window, threshold) = self.stream_abuse;
+        if start.elapsed() > window {
+            self.abuse_window = (Instant::now(), rejected);
+        } else if rejected - base >= threshold {
+            self.abuse_window = (Instant::now(), rejected);
+            self.event_output(SessionEvent::StreamOpenAbuse {
+                id: self.context.id,
+                rejected: rejected - base,
+            });
+        }
+    }
+
     fn open_protocol(
         &mut self,
         name: String,
         version: String,
-        sub_stream: Box<Framed<StreamHandle, LengthDelimitedCodec>>,
+        sub_stream: Box<Framed<SubstreamHandle, LengthDelimitedCodec>>,
     ) {
         let proto = match self.protocol_configs.get(&name) {
             Some(proto) => proto,
@@ -473,36 +574,67 @@ where
             return;
         }
         let before_receive_fn = (proto.before_receive)();
+        let stream_priority = proto.stream_priority;
+        let half_close = proto.half_close;
+        if let Some(ref capture) = self.capture {
+            capture.annotate(
+                sub_stream.get_ref().id(),
+                &format!("{} version {}", name, version),
+            );
+        }
         let raw_part = sub_stream.into_parts();
-        let mut part = FramedParts::new(raw_part.io, (proto.codec)());
-        // Replace buffered data
-        part.read_buf.unsplit(raw_part.read_buf);
-        part.write_buf.unsplit(raw_part.write_buf);
-        let frame = Framed::from_parts(part);
         let (session_to_proto_sender, session_to_proto_receiver) = mpsc::channel(SEND_SIZE);
 
-        let mut proto_stream = SubstreamBuilder::new(
-            self.proto_event_sender.clone(),
-            session_to_proto_receiver,
-            self.substreams_control.clone(),
-            self.context.clone(),
-        )
-        .proto_id(proto_id)
-        .stream_id(self.next_stream)
-        .config(self.config)
-        .service_proto_sender(self.service_proto_senders.get(&proto_id).cloned())
-        .session_proto_sender(self.session_proto_senders.remove(&proto_id))
-        .keep_buffer(self.keep_buffer)
-        .event(self.event.contains(&proto_id))
-        .before_receive(before_receive_fn)
-        .build(frame);
+        if proto.raw_stream {
+            let (mut raw_task, raw_stream) = RawSubstreamBuilder::new(
+                self.proto_event_sender.clone(),
+                session_to_proto_receiver,
+                self.substreams_control.clone(),
+                self.context.clone(),
+            )
+            .proto_id(proto_id)
+            .stream_id(self.next_stream)
+            .stream_priority(stream_priority)
+            .service_proto_sender(self.service_proto_senders.get(&proto_id).cloned())
+            .session_proto_sender(self.session_proto_senders.remove(&proto_id))
+            .build(raw_part.io, raw_part.read_buf);
+
+            raw_task.proto_open(version.clone(), raw_stream);
+            tokio::spawn(raw_task);
+        } else {
+            let mut part = FramedParts::new(raw_part.io, (proto.codec)());
+            // Replace buffered data
+            part.read_buf.unsplit(raw_part.read_buf);
+            part.write_buf.unsplit(raw_part.write_buf);
+            let frame = Framed::from_parts(part);
+
+            let mut proto_stream = SubstreamBuilder::new(
+                self.proto_event_sender.clone(),
+                session_to_proto_receiver,
+                self.substreams_control.clone(),
+                self.context.clone(),
+            )
+            .proto_id(proto_id)
+            .stream_id(self.next_stream)
+            .config(self.config)
+            .service_proto_sender(self.service_proto_senders.get(&proto_id).cloned())
+            .session_proto_sender(self.session_proto_senders.remove(&proto_id))
+            .keep_buffer(self.keep_buffer)
+            .event(self.event.contains(&proto_id))
+            .before_receive(before_receive_fn)
+            .stream_priority(stream_priority)
+            .capture(self.capture.clone())
+            .half_close(half_close)
+            .build(frame);
+
+            proto_stream.proto_open(version.clone());
+            tokio::spawn(proto_stream.for_each(|_| Ok(())));
+        }
 
         self.sub_streams
             .insert(self.next_stream, session_to_proto_sender);
         self.proto_streams.insert(proto_id, self.next_stream);
 
-        proto_stream.proto_open(version.clone());
-
         self.event_output(SessionEvent::ProtocolOpen {
             id: self.context.id,
             proto_id,
@@ -512,7 +644,6 @@ where
         self.next_stream += 1;
 
         debug!("session [{}] proto [{}] open", self.context.id, proto_id);
-        tokio::spawn(proto_stream.for_each(|_| Ok(())));
     }
 
     /// Handling events uploaded by the protocol stream
@@ -546,6 +677,18 @@ where
                     priority: Priority::Normal,
                 })
             }
+            // Only sent from session to sub stream
+            ProtocolEvent::HalfClose { .. } | ProtocolEvent::Priority { .. } => (),
+            ProtocolEvent::RemoteFinished { proto_id, .. } => {
+                debug!(
+                    "session [{}] proto [{}] remote finished",
+                    self.context.id, proto_id
+                );
+                self.event_output(SessionEvent::ProtocolRemoteFinished {
+                    id: self.context.id,
+                    proto_id,
+                })
+            }
             ProtocolEvent::SelectError { proto_name } => {
                 self.event_output(SessionEvent::ProtocolSelectError {
                     id: self.context.id,
@@ -567,6 +710,7 @@ where
                     self.event_output(SessionEvent::SessionTimeout {
                         id: self.context.id,
                     });
+                    self.context.set_close_reason(CloseReason::Timeout);
                     self.state = SessionState::LocalClose;
                 }
             }
@@ -630,6 +774,31 @@ where
                     }
                 }
             }
+            SessionEvent::ProtocolHalfClose { proto_id, .. } => {
+                if let Some(stream_id) = self.proto_streams.get(&proto_id) {
+                    let event = ProtocolEvent::HalfClose {
+                        id: *stream_id,
+                        proto_id,
+                    };
+                    self.push_back(Priority::Normal, proto_id, event);
+                } else {
+                    debug!("proto [{}] is not open", proto_id);
+                }
+            }
+            SessionEvent::ProtocolPriority {
+                proto_id, priority, ..
+            } => {
+                if let Some(stream_id) = self.proto_streams.get(&proto_id) {
+                    let event = ProtocolEvent::Priority {
+                        id: *stream_id,
+                        proto_id,
+                        priority,
+                    };
+                    self.push_back(Priority::High, proto_id, event);
+                } else {
+                    debug!("proto [{}] is not open", proto_id);
+                }
+            }
             SessionEvent::ProtocolClose { proto_id, .. } => {
                 if !self.proto_streams.contains_key(&proto_id) {
                     debug!("proto [{}] has been closed", proto_id);
@@ -658,6 +827,12 @@ where
                 Ok(Async::Ready(Some(sub_stream))) => self.handle_sub_stream(sub_stream),
                 Ok(Async::Ready(None)) => {
                     finished = true;
+                    let reason = self
+                        .socket
+                        .remote_go_away_reason()
+                        .map(CloseReason::from_remote_go_away)
+                        .unwrap_or(CloseReason::ConnectionLost);
+                    self.context.set_close_reason(reason);
                     self.state = SessionState::RemoteClose;
                     break;
                 }
@@ -675,13 +850,29 @@ where
                     }
 
                     match err.kind() {
+                        ErrorKind::ConnectionReset => {
+                            self.context.set_close_reason(CloseReason::Reset {
+                                initiator: Initiator::Remote,
+                            });
+                            self.state = SessionState::RemoteClose
+                        }
                         ErrorKind::BrokenPipe
                         | ErrorKind::ConnectionAborted
-                        | ErrorKind::ConnectionReset
                         | ErrorKind::NotConnected
-                        | ErrorKind::UnexpectedEof => self.state = SessionState::RemoteClose,
+                        | ErrorKind::UnexpectedEof => {
+                            self.context.set_close_reason(CloseReason::ConnectionLost);
+                            self.state = SessionState::RemoteClose
+                        }
+                        ErrorKind::TimedOut => {
+                            self.context.set_close_reason(CloseReason::Timeout);
+                            self.state = SessionState::LocalClose
+                        }
                         _ => {
                             warn!("MuxerError: {:?}", err);
+                            self.context.set_close_reason(CloseReason::GoAway {
+                                initiator: Initiator::Local,
+                                code: GoAwayCode::ProtocolError as u32,
+                            });
                             self.event_output(SessionEvent::MuxerError {
                                 id: self.context.id,
                                 error: err.into(),
@@ -694,6 +885,7 @@ where
                 }
             }
         }
+        self.context.update_rtt(self.socket.rtt());
         if !finished {
             self.set_delay();
         }
@@ -770,6 +962,7 @@ where
                 if self.write_buf.len() > RECEIVED_BUFFER_SIZE {
                     if self.last_sent.elapsed() > Duration::from_secs(5) {
                         warn!("session send timeout");
+                        self.context.set_close_reason(CloseReason::Timeout);
                         self.state = SessionState::LocalClose;
                     }
                     None
@@ -823,6 +1016,11 @@ where
 
     /// Close session
     fn close_session(&mut self) {
+        if self.state == SessionState::RemoteClose {
+            self.context.set_close_reason(CloseReason::ConnectionLost);
+        } else {
+            self.context.set_close_reason(CloseReason::local());
+        }
         self.context.closed.store(true, Ordering::SeqCst);
         self.substreams_control.store(true, Ordering::SeqCst);
 
@@ -843,11 +1041,29 @@ where
 
     /// Clean env
     fn clean(&mut self) {
+        if let Some(ref capture) = self.capture {
+            capture.flush();
+        }
         self.sub_streams.clear();
         self.service_receiver.close();
         self.proto_event_receiver.close();
 
-        let _ = self.socket.shutdown();
+        // Tell remote why, if the close is initiated by self
+        let _ = match self.context.close_reason() {
+            Some(CloseReason::Application {
+                initiator: Initiator::Local,
+                code,
+                message,
+            }) => self.socket.shutdown_with_reason(
+                GoAwayReason::application_wire_code(code),
+                Some(bytes::Bytes::from(message)),
+            ),
+            Some(CloseReason::GoAway {
+                initiator: Initiator::Local,
+                code,
+            }) => self.socket.shutdown_with_reason(code, None),
+            _ => self.socket.shutdown(),
+        };
     }
 
     #[inline]
@@ -918,6 +1134,8 @@ where
 
         self.poll_inner_socket();
 
+        self.check_stream_open_abuse();
+
         self.recv_substreams();
 
         self.recv_service();
@@ -950,6 +1168,8 @@ where
 
 pub(crate) struct SessionMeta {
     config: Config,
+    mplex_config: MplexConfig,
+    muxer: Muxer,
     protocol_configs: HashMap<String, Arc<Meta>>,
     context: Arc<SessionContext>,
     timeout: Duration,
@@ -957,12 +1177,17 @@ pub(crate) struct SessionMeta {
     service_proto_senders: HashMap<ProtocolId, mpsc::Sender<ServiceProtocolEvent>>,
     session_proto_senders: HashMap<ProtocolId, mpsc::Sender<SessionProtocolEvent>>,
     event: HashSet<ProtocolId>,
+    recv_budget: Option<MemoryBudget>,
+    max_negotiating_streams: usize,
+    capture: Option<Capture>,
 }
 
 impl SessionMeta {
     pub fn new(timeout: Duration, context: Arc<SessionContext>) -> Self {
         SessionMeta {
             config: Config::default(),
+            mplex_config: MplexConfig::default(),
+            muxer: Muxer::default(),
             protocol_configs: HashMap::new(),
             context,
             timeout,
@@ -970,6 +1195,9 @@ impl SessionMeta {
             service_proto_senders: HashMap::default(),
             session_proto_senders: HashMap::default(),
             event: HashSet::new(),
+            recv_budget: None,
+            max_negotiating_streams: usize::max_value(),
+            capture: None,
         }
     }
 
@@ -983,6 +1211,16 @@ impl SessionMeta {
         self
     }
 
+    pub fn mplex_config(mut self, config: MplexConfig) -> Self {
+        self.mplex_config = config;
+        self
+    }
+
+    pub fn muxer(mut self, muxer: Muxer) -> Self {
+        self.muxer = muxer;
+        self
+    }
+
     pub fn keep_buffer(mut self, keep: bool) -> Self {
         self.keep_buffer = keep;
         self
@@ -1013,6 +1251,42 @@ impl SessionMeta {
         self.event = event;
         self
     }
+
+    pub fn recv_budget(mut self, budget: Option<MemoryBudget>) -> Self {
+        self.recv_budget = budget;
+        self
+    }
+
+    pub fn max_negotiating_streams(mut self, number: usize) -> Self {
+        self.max_negotiating_streams = number;
+        self
+    }
+
+    pub fn stream_abuse(mut self, window: Duration, threshold: u64) -> Self {
+        self.stream_abuse = (window, threshold);
+        self
+    }
+
+    pub fn capture(mut self, capture: Option<Capture>) -> Self {
+        self.capture = capture;
+        self
+    }
+}
+
+/// Count a stream as negotiating while it lives
+struct NegotiatingGuard(Arc<AtomicUsize>);
+
+impl NegotiatingGuard {
+    fn new(count: Arc<AtomicUsize>) -> Self {
+        count.fetch_add(1, Ordering::AcqRel);
+        NegotiatingGuard(count)
+    }
+}
+
+impl Drop for NegotiatingGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
 }
 
 /// Session state
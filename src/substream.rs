@@ -19,10 +19,18 @@ use crate::{
     builder::BeforeReceive,
     context::SessionContext,
     error::Error,
+    muxer::SubstreamHandle,
     protocol_handle_stream::{ServiceProtocolEvent, SessionProtocolEvent},
-    service::{event::Priority, DELAY_TIME},
+    service::{
+        event::{CloseReason, Initiator, Priority},
+        DELAY_TIME,
+    },
     traits::Codec,
-    yamux::{Config, StreamHandle},
+    yamux::{
+        capture::{Capture, Direction},
+        config::DEFAULT_STREAM_PRIORITY,
+        Config,
+    },
     ProtocolId, StreamId,
 };
 
@@ -33,8 +41,8 @@ pub(crate) enum ProtocolEvent {
     Open {
         /// Protocol name
         proto_name: String,
-        /// Yamux sub stream handle handshake framed
-        sub_stream: Box<Framed<StreamHandle, LengthDelimitedCodec>>,
+        /// Muxer sub stream handle handshake framed
+        sub_stream: Box<Framed<SubstreamHandle, LengthDelimitedCodec>>,
         /// Protocol version
         version: String,
     },
@@ -45,6 +53,29 @@ pub(crate) enum ProtocolEvent {
         /// Protocol id
         proto_id: ProtocolId,
     },
+    /// Finish sending on the protocol
+    HalfClose {
+        /// Stream id
+        id: StreamId,
+        /// Protocol id
+        proto_id: ProtocolId,
+    },
+    /// Change muxer stream priority of the protocol
+    Priority {
+        /// Stream id
+        id: StreamId,
+        /// Protocol id
+        proto_id: ProtocolId,
+        /// Muxer stream priority
+        priority: u8,
+    },
+    /// Remote finished sending on the protocol
+    RemoteFinished {
+        /// Stream id
+        id: StreamId,
+        /// Protocol id
+        proto_id: ProtocolId,
+    },
     /// Protocol data outbound and inbound
     Message {
         /// Stream id
@@ -74,7 +105,7 @@ pub(crate) enum ProtocolEvent {
 /// Each custom protocol in a session corresponds to a sub stream
 /// Can be seen as the route of each protocol
 pub(crate) struct SubStream<U> {
-    sub_stream: Framed<StreamHandle, U>,
+    sub_stream: Framed<SubstreamHandle, U>,
     id: StreamId,
     proto_id: ProtocolId,
 
@@ -93,6 +124,15 @@ pub(crate) struct SubStream<U> {
     dead: bool,
     keep_buffer: bool,
 
+    /// Remote finishing sending only closes the receiving half
+    half_close: bool,
+    /// Local asked to finish sending, the sub stream is shut down once buffers are sent
+    local_finished: bool,
+    /// Local sub stream is shut down
+    write_closed: bool,
+    /// Remote finished sending
+    remote_finished: bool,
+
     /// Send event to session
     event_sender: mpsc::Sender<ProtocolEvent>,
     /// Receive events from session
@@ -106,13 +146,23 @@ pub(crate) struct SubStream<U> {
     delay: Arc<AtomicBool>,
 
     closed: Arc<AtomicBool>,
+
+    /// Record protocol messages for debugging
+    capture: Option<Capture>,
 }
 
 impl<U> SubStream<U>
 where
     U: Codec,
 {
+    /// Scheduling priority of outbound frames of the underlying muxer stream
+    pub fn set_priority(&self, priority: u8) {
+        self.sub_stream.get_ref().set_priority(priority)
+    }
+
     pub fn proto_open(&mut self, version: String) {
+        self.context.clear_proto_close_reason(self.proto_id);
+
         if self.service_proto_sender.is_some() {
             self.service_proto_buf
                 .push_back(ServiceProtocolEvent::Connected {
@@ -150,6 +200,7 @@ where
     #[inline]
     fn send_inner(&mut self, frame: bytes::Bytes, priority: Priority) -> Result<bool, io::Error> {
         let data_size = frame.len();
+        let captured = self.capture.as_ref().map(|_| frame.clone());
         match self.sub_stream.start_send(frame) {
             Ok(AsyncSink::NotReady(frame)) => {
                 debug!("framed_stream NotReady, frame len: {:?}", frame.len());
@@ -158,6 +209,9 @@ where
             }
             Ok(AsyncSink::Ready) => {
                 self.context.decr_pending_data_size(data_size);
+                if let (Some(capture), Some(data)) = (&self.capture, captured) {
+                    capture.message(Direction::Outbound, self.sub_stream.get_ref().id(), &data);
+                }
                 Ok(false)
             }
             Err(err) => {
@@ -167,7 +221,7 @@ where
         }
     }
 
-    /// Send data to the lower `yamux` sub stream
+    /// Send data to the lower muxer sub stream
     fn send_data(&mut self) -> Result<(), io::Error> {
         while let Some(frame) = self.high_write_buf.pop_front() {
             if self.send_inner(frame, Priority::High)? && self.poll_complete()? {
@@ -183,10 +237,56 @@ where
 
         self.poll_complete()?;
 
+        if self.local_finished && !self.write_closed {
+            if self.sub_stream.close()?.is_not_ready() {
+                self.set_delay();
+                return Ok(());
+            }
+            debug!("proto [{}] finished sending", self.proto_id);
+            self.write_closed = true;
+            if self.remote_finished {
+                self.dead = true;
+            }
+        }
+
         debug!("send success, proto_id: {}", self.proto_id);
         Ok(())
     }
 
+    /// Remote finished sending
+    fn remote_eof(&mut self) {
+        if !self.half_close || self.write_closed {
+            self.dead = true;
+            return;
+        }
+        if self.remote_finished {
+            return;
+        }
+        debug!("proto [{}] remote finished sending", self.proto_id);
+        self.remote_finished = true;
+
+        if self.service_proto_sender.is_some() {
+            self.service_proto_buf
+                .push_back(ServiceProtocolEvent::RemoteFinished {
+                    id: self.context.id,
+                })
+        }
+
+        if self.session_proto_sender.is_some() {
+            self.session_proto_buf
+                .push_back(SessionProtocolEvent::RemoteFinished)
+        }
+
+        self.distribute_to_user_level();
+
+        if self.event {
+            self.output_event(ProtocolEvent::RemoteFinished {
+                id: self.id,
+                proto_id: self.proto_id,
+            })
+        }
+    }
+
     /// https://docs.rs/tokio/0.1.19/tokio/prelude/trait.Sink.html
     /// Must use poll complete to ensure data send to lower-level
     ///
@@ -273,6 +373,15 @@ where
     fn handle_proto_event(&mut self, event: ProtocolEvent) {
         match event {
             ProtocolEvent::Message { data, priority, .. } => {
+                if self.local_finished {
+                    debug!(
+                        "proto [{}] finished sending, drop data: {}",
+                        self.proto_id,
+                        data.len()
+                    );
+                    self.context.decr_pending_data_size(data.len());
+                    return;
+                }
                 debug!("proto [{}] send data: {}", self.proto_id, data.len());
                 self.push_back(priority, data);
 
@@ -296,6 +405,22 @@ where
                 self.write_buf.clear();
                 self.dead = true;
             }
+            ProtocolEvent::HalfClose { .. } => {
+                self.local_finished = true;
+                if let Err(err) = self.send_data() {
+                    debug!(
+                        "protocol [{}] close because of extern network",
+                        self.proto_id
+                    );
+                    self.output_event(ProtocolEvent::Error {
+                        id: self.id,
+                        proto_id: self.proto_id,
+                        error: err.into(),
+                    });
+                    self.dead = true;
+                }
+            }
+            ProtocolEvent::Priority { priority, .. } => self.set_priority(priority),
             _ => (),
         }
     }
@@ -396,7 +521,7 @@ where
     fn recv_frame(&mut self) {
         let mut finished = false;
         for _ in 0..64 {
-            if self.dead {
+            if self.dead || self.remote_finished {
                 break;
             }
 
@@ -412,6 +537,10 @@ where
                         self.proto_id,
                         data.len()
                     );
+                    if let Some(ref capture) = self.capture {
+                        capture.message(Direction::Inbound, self.sub_stream.get_ref().id(), &data);
+                    }
+                    self.context.received_data(self.proto_id);
 
                     let data = match self.before_receive {
                         Some(ref function) => match function(data) {
@@ -450,7 +579,7 @@ where
                 }
                 Ok(Async::Ready(None)) => {
                     debug!("protocol [{}] close", self.proto_id);
-                    self.dead = true;
+                    self.remote_eof();
                     return;
                 }
                 Ok(Async::NotReady) => {
@@ -461,11 +590,19 @@ where
                     finished = true;
                     debug!("sub stream codec error: {:?}", err);
                     match err.kind() {
+                        ErrorKind::UnexpectedEof => self.remote_eof(),
+                        ErrorKind::ConnectionReset => {
+                            self.context.set_proto_close_reason(
+                                self.proto_id,
+                                CloseReason::StreamReset {
+                                    initiator: Initiator::Remote,
+                                },
+                            );
+                            self.dead = true
+                        }
                         ErrorKind::BrokenPipe
                         | ErrorKind::ConnectionAborted
-                        | ErrorKind::ConnectionReset
-                        | ErrorKind::NotConnected
-                        | ErrorKind::UnexpectedEof => self.dead = true,
+                        | ErrorKind::NotConnected => self.dead = true,
                         _ => {
                             self.error_close(err);
                             return;
@@ -534,6 +671,7 @@ where
         if !self.read_buf.is_empty()
             || !self.write_buf.is_empty()
             || !self.high_write_buf.is_empty()
+            || (self.local_finished && !self.write_closed)
         {
             if let Err(err) = self.flush() {
                 debug!(
@@ -596,12 +734,15 @@ pub(crate) struct SubstreamBuilder {
     service_proto_sender: Option<mpsc::Sender<ServiceProtocolEvent>>,
     session_proto_sender: Option<mpsc::Sender<SessionProtocolEvent>>,
     before_receive: Option<BeforeReceive>,
+    stream_priority: u8,
 
     /// Send event to session
     event_sender: mpsc::Sender<ProtocolEvent>,
     /// Receive events from session
     event_receiver: mpsc::Receiver<ProtocolEvent>,
     closed: Arc<AtomicBool>,
+    capture: Option<Capture>,
+    half_close: bool,
 }
 
 impl SubstreamBuilder {
@@ -615,6 +756,7 @@ impl SubstreamBuilder {
             service_proto_sender: None,
             session_proto_sender: None,
             before_receive: None,
+            stream_priority: DEFAULT_STREAM_PRIORITY,
             event_receiver,
             event_sender,
             closed,
@@ -624,6 +766,8 @@ impl SubstreamBuilder {
             keep_buffer: false,
             config: Config::default(),
             event: false,
+            capture: None,
+            half_close: false,
         }
     }
 
@@ -673,11 +817,29 @@ impl SubstreamBuilder {
         self
     }
 
-    pub fn build<U>(self, sub_stream: Framed<StreamHandle, U>) -> SubStream<U>
+    pub fn stream_priority(mut self, priority: u8) -> Self {
+        self.stream_priority = priority;
+        self
+    }
+
+    pub fn capture(mut self, capture: Option<Capture>) -> Self {
+        self.capture = capture;
+        self
+    }
+
+    pub fn half_close(mut self, enable: bool) -> Self {
+        self.half_close = enable;
+        self
+    }
+
+    pub fn build<U>(self, sub_stream: Framed<SubstreamHandle, U>) -> SubStream<U>
     where
         U: Codec,
     {
-        SubStream {
+        let mut sub_stream = sub_stream;
+        // Remote finishing sending must not close local side, or it can't keep sending
+        sub_stream.get_mut().set_auto_close(!self.half_close);
+        let sub_stream = SubStream {
             sub_stream,
             id: self.id,
             proto_id: self.proto_id,
@@ -694,6 +856,11 @@ impl SubstreamBuilder {
             dead: false,
             keep_buffer: self.keep_buffer,
 
+            half_close: self.half_close,
+            local_finished: false,
+            write_closed: false,
+            remote_finished: false,
+
             event_sender: self.event_sender,
             event_receiver: self.event_receiver,
 
@@ -704,6 +871,10 @@ impl SubstreamBuilder {
             delay: Arc::new(AtomicBool::new(false)),
 
             closed: self.closed,
-        }
+
+            capture: self.capture,
+        };
+        sub_stream.set_priority(self.stream_priority);
+        sub_stream
     }
 }
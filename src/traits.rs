@@ -3,6 +3,7 @@ use tokio::codec::{Decoder, Encoder};
 
 use crate::{
     context::{ProtocolContext, ProtocolContextMutRef, ServiceContext},
+    raw_substream::RawSubstream,
     service::{ProtocolEvent, ServiceError, ServiceEvent},
 };
 
@@ -68,6 +69,16 @@ pub trait ServiceProtocol {
     fn disconnected(&mut self, _context: ProtocolContextMutRef) {}
     /// Called when the corresponding protocol message is received
     fn received(&mut self, _context: ProtocolContextMutRef, _data: bytes::Bytes) {}
+    /// Called when remote finished sending on the protocol, local can still send messages.
+    ///
+    /// Only called on protocols with `half_close` enabled
+    fn remote_finished(&mut self, _context: ProtocolContextMutRef) {}
+    /// Called right after `connected` with the negotiated sub stream, drive it to read and write
+    /// bytes directly, the protocol is closed when it is dropped.
+    ///
+    /// Only called on protocols with `raw_stream` enabled, on the session handle if the protocol
+    /// has one, otherwise on the service handle
+    fn raw_stream(&mut self, _context: ProtocolContextMutRef, _stream: RawSubstream) {}
     /// Called when the Service receives the notify task
     fn notify(&mut self, _context: &mut ProtocolContext, _token: u64) {}
     /// Behave like `Stream::poll`, but nothing output
@@ -83,6 +94,16 @@ pub trait SessionProtocol {
     fn disconnected(&mut self, _context: ProtocolContextMutRef) {}
     /// Called when the corresponding protocol message is received
     fn received(&mut self, _context: ProtocolContextMutRef, _data: bytes::Bytes) {}
+    /// Called when remote finished sending on the protocol, local can still send messages.
+    ///
+    /// Only called on protocols with `half_close` enabled
+    fn remote_finished(&mut self, _context: ProtocolContextMutRef) {}
+    /// Called right after `connected` with the negotiated sub stream, drive it to read and write
+    /// bytes directly, the protocol is closed when it is dropped.
+    ///
+    /// Only called on protocols with `raw_stream` enabled, on the session handle if the protocol
+    /// has one, otherwise on the service handle
+    fn raw_stream(&mut self, _context: ProtocolContextMutRef, _stream: RawSubstream) {}
     /// Called when the session receives the notify task
     fn notify(&mut self, _context: ProtocolContextMutRef, _token: u64) {}
     /// Behave like `Stream::poll`, but nothing output, shutdown when session close
@@ -167,6 +188,14 @@ impl ServiceProtocol for Box<dyn ServiceProtocol + Send + 'static> {
         (&mut **self).received(context, data)
     }
 
+    fn remote_finished(&mut self, context: ProtocolContextMutRef) {
+        (&mut **self).remote_finished(context)
+    }
+
+    fn raw_stream(&mut self, context: ProtocolContextMutRef, stream: RawSubstream) {
+        (&mut **self).raw_stream(context, stream)
+    }
+
     fn notify(&mut self, context: &mut ProtocolContext, token: u64) {
         (&mut **self).notify(context, token)
     }
@@ -194,6 +223,14 @@ impl ServiceProtocol for Box<dyn ServiceProtocol + Send + Sync + 'static> {
         (&mut **self).received(context, data)
     }
 
+    fn remote_finished(&mut self, context: ProtocolContextMutRef) {
+        (&mut **self).remote_finished(context)
+    }
+
+    fn raw_stream(&mut self, context: ProtocolContextMutRef, stream: RawSubstream) {
+        (&mut **self).raw_stream(context, stream)
+    }
+
     fn notify(&mut self, context: &mut ProtocolContext, token: u64) {
         (&mut **self).notify(context, token)
     }
@@ -217,6 +254,14 @@ impl SessionProtocol for Box<dyn SessionProtocol + Send + 'static> {
         (&mut **self).received(context, data)
     }
 
+    fn remote_finished(&mut self, context: ProtocolContextMutRef) {
+        (&mut **self).remote_finished(context)
+    }
+
+    fn raw_stream(&mut self, context: ProtocolContextMutRef, stream: RawSubstream) {
+        (&mut **self).raw_stream(context, stream)
+    }
+
     fn notify(&mut self, context: ProtocolContextMutRef, token: u64) {
         (&mut **self).notify(context, token)
     }
@@ -240,6 +285,14 @@ impl SessionProtocol for Box<dyn SessionProtocol + Send + Sync + 'static> {
         (&mut **self).received(context, data)
     }
 
+    fn remote_finished(&mut self, context: ProtocolContextMutRef) {
+        (&mut **self).remote_finished(context)
+    }
+
+    fn raw_stream(&mut self, context: ProtocolContextMutRef, stream: RawSubstream) {
+        (&mut **self).raw_stream(context, stream)
+    }
+
     fn notify(&mut self, context: ProtocolContextMutRef, token: u64) {
         (&mut **self).notify(context, token)
     }
@@ -111,6 +111,25 @@ pub fn extract_peer_id(addr: &Multiaddr) -> Option<PeerId> {
     })
 }
 
+/// Group of the ip, addresses of a group are likely controlled by the same operator
+///
+/// Ipv4 is grouped by /16 and ipv6 by /32, ipv4 mapped ipv6 addresses are grouped as ipv4.
+pub fn netgroup(ip: IpAddr) -> Vec<u8> {
+    match ip {
+        IpAddr::V4(ipv4) => {
+            let octets = ipv4.octets();
+            vec![4, octets[0], octets[1]]
+        }
+        IpAddr::V6(ipv6) => match ipv6.to_ipv4() {
+            Some(ipv4) => netgroup(IpAddr::V4(ipv4)),
+            None => {
+                let octets = ipv6.octets();
+                vec![6, octets[0], octets[1], octets[2], octets[3]]
+            }
+        },
+    }
+}
+
 /// Determine if it is a WebSocket protocol
 pub fn is_ws(addr: &Multiaddr) -> bool {
     let mut iter = addr.iter();
@@ -123,8 +142,9 @@ mod test {
     use crate::{
         multiaddr::Multiaddr,
         secio::SecioKeyPair,
-        utils::{extract_peer_id, is_ws, multiaddr_to_socketaddr},
+        utils::{extract_peer_id, is_ws, multiaddr_to_socketaddr, netgroup},
     };
+    use std::net::IpAddr;
 
     #[test]
     fn parser_peer_id_from_multiaddr() {
@@ -169,6 +189,16 @@ mod test {
         multiaddr_to_socketaddr(&addr).unwrap();
     }
 
+    #[test]
+    fn test_netgroup() {
+        let group = |ip: &str| netgroup(ip.parse::<IpAddr>().unwrap());
+        assert_eq!(group("1.2.3.4"), group("1.2.200.200"));
+        assert_ne!(group("1.2.3.4"), group("1.3.3.4"));
+        assert_eq!(group("::ffff:1.2.3.4"), group("1.2.3.4"));
+        assert_eq!(group("2001:db8::1"), group("2001:db8:ffff::1"));
+        assert_ne!(group("2001:db8::1"), group("2001:db9::1"));
+    }
+
     #[test]
     fn test_ws_judgment() {
         let addr_1 = "/ip4/127.0.0.1/tcp/1337/ws".parse().unwrap();
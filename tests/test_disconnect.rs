@@ -2,9 +2,11 @@ use futures::prelude::Stream;
 use std::{thread, time::Duration};
 use tentacle::{
     builder::{MetaBuilder, ServiceBuilder},
-    context::{ProtocolContext, ProtocolContextMutRef},
+    context::{ProtocolContext, ProtocolContextMutRef, ServiceContext},
     secio::SecioKeyPair,
-    service::{DialProtocol, ProtocolHandle, ProtocolMeta, Service},
+    service::{
+        CloseReason, DialProtocol, Initiator, ProtocolHandle, ProtocolMeta, Service, ServiceEvent,
+    },
     traits::{ServiceHandle, ServiceProtocol},
     ProtocolId,
 };
@@ -54,6 +56,40 @@ fn create_meta(id: impl Into<ProtocolId> + Copy + Send + 'static) -> ProtocolMet
         .build()
 }
 
+// Disconnects with a reason as soon as the protocol is open
+struct ReasonHandle;
+
+impl ServiceProtocol for ReasonHandle {
+    fn init(&mut self, _context: &mut ProtocolContext) {}
+
+    fn connected(&mut self, context: ProtocolContextMutRef, _version: &str) {
+        let session_id = context.session.id;
+        context
+            .disconnect_with_reason(session_id, 7, "bye".to_owned())
+            .unwrap();
+    }
+}
+
+#[derive(Clone)]
+struct SHandle {
+    sender: crossbeam_channel::Sender<CloseReason>,
+}
+
+impl ServiceHandle for SHandle {
+    fn handle_event(&mut self, _env: &mut ServiceContext, event: ServiceEvent) {
+        if let ServiceEvent::SessionClose { reason, .. } = event {
+            let _ = self.sender.try_send(reason);
+        }
+    }
+}
+
+fn create_reason_meta() -> ProtocolMeta {
+    MetaBuilder::new()
+        .id(1.into())
+        .service_handle(|| ProtocolHandle::Callback(Box::new(ReasonHandle)))
+        .build()
+}
+
 fn test_disconnect(secio: bool) {
     let mut service = create(secio, create_meta(1), ());
     let listen_addr = service
@@ -80,3 +116,26 @@ fn test_disconnect_with_secio() {
 fn test_disconnect_with_no_secio() {
     test_disconnect(false);
 }
+
+#[test]
+fn test_disconnect_with_reason() {
+    let (sender, receiver) = crossbeam_channel::bounded(1);
+    let mut service = create(true, create_meta(1), SHandle { sender });
+    let listen_addr = service
+        .listen("/ip4/127.0.0.1/tcp/0".parse().unwrap())
+        .unwrap();
+    thread::spawn(|| tokio::run(service.for_each(|_| Ok(()))));
+
+    let mut service = create(true, create_reason_meta(), ());
+    service.dial(listen_addr, DialProtocol::All).unwrap();
+    thread::spawn(|| tokio::run(service.for_each(|_| Ok(()))));
+
+    assert_eq!(
+        receiver.recv_timeout(Duration::from_secs(10)).unwrap(),
+        CloseReason::Application {
+            initiator: Initiator::Remote,
+            code: 7,
+            message: "bye".to_owned(),
+        }
+    );
+}
@@ -0,0 +1,142 @@
+use bytes::Bytes;
+use futures::prelude::Stream;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use tentacle::{
+    builder::{MetaBuilder, ServiceBuilder},
+    context::{ProtocolContext, ProtocolContextMutRef},
+    muxer::Muxer,
+    secio::SecioKeyPair,
+    service::{DialProtocol, ProtocolHandle, ProtocolMeta, Service},
+    traits::{ServiceHandle, ServiceProtocol},
+    ProtocolId,
+};
+
+const MESSAGE_COUNT: usize = 16;
+
+pub fn create<F>(secio: bool, muxer: Muxer, meta: ProtocolMeta, shandle: F) -> Service<F>
+where
+    F: ServiceHandle,
+{
+    let builder = ServiceBuilder::default()
+        .insert_protocol(meta)
+        .muxer(muxer)
+        .forever(true);
+
+    if secio {
+        builder
+            .key_pair(SecioKeyPair::secp256k1_generated())
+            .build(shandle)
+    } else {
+        builder.build(shandle)
+    }
+}
+
+struct PHandle {
+    received: usize,
+    remote_finished: bool,
+    test_result: Arc<AtomicBool>,
+}
+
+impl ServiceProtocol for PHandle {
+    fn init(&mut self, _context: &mut ProtocolContext) {}
+
+    fn connected(&mut self, context: ProtocolContextMutRef, _version: &str) {
+        // Outbound side sends all requests, then finishes sending
+        if context.session.ty.is_outbound() {
+            for _ in 0..MESSAGE_COUNT {
+                let _ = context.send_message(Bytes::from("request"));
+            }
+            let _ = context.half_close();
+        }
+    }
+
+    fn received(&mut self, _context: ProtocolContextMutRef, _data: bytes::Bytes) {
+        self.received += 1;
+    }
+
+    fn remote_finished(&mut self, context: ProtocolContextMutRef) {
+        self.remote_finished = true;
+        // Inbound side replies after all requests are received
+        if context.session.ty.is_inbound() && self.received == MESSAGE_COUNT {
+            for _ in 0..MESSAGE_COUNT {
+                let _ = context.send_message(Bytes::from("reply"));
+            }
+            let _ = context.half_close();
+        }
+    }
+
+    fn disconnected(&mut self, context: ProtocolContextMutRef) {
+        if context.session.ty.is_outbound() {
+            // Replies are received after local finished sending
+            if self.remote_finished && self.received == MESSAGE_COUNT {
+                self.test_result.store(true, Ordering::SeqCst);
+            }
+            let _ = context.close();
+        }
+    }
+}
+
+fn create_meta(id: ProtocolId) -> (ProtocolMeta, Arc<AtomicBool>) {
+    let test_result = Arc::new(AtomicBool::new(false));
+    let clone_result = test_result.clone();
+    (
+        MetaBuilder::new()
+            .id(id)
+            .half_close(true)
+            .service_handle(move || {
+                let handle = Box::new(PHandle {
+                    received: 0,
+                    remote_finished: false,
+                    test_result: clone_result,
+                });
+                ProtocolHandle::Callback(handle)
+            })
+            .build(),
+        test_result,
+    )
+}
+
+fn test_half_close(secio: bool, muxer: Muxer) {
+    let (meta, _) = create_meta(1.into());
+    let mut service = create(secio, muxer, meta, ());
+    let listen_addr = service
+        .listen("/ip4/127.0.0.1/tcp/0".parse().unwrap())
+        .unwrap();
+    thread::spawn(|| tokio::run(service.for_each(|_| Ok(()))));
+    thread::sleep(Duration::from_millis(100));
+
+    let (meta, result) = create_meta(1.into());
+    let mut service = create(secio, muxer, meta, ());
+    service.dial(listen_addr, DialProtocol::All).unwrap();
+    let handle = thread::spawn(|| tokio::run(service.for_each(|_| Ok(()))));
+    handle.join().unwrap();
+
+    assert!(result.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_half_close_with_secio() {
+    test_half_close(true, Muxer::Yamux)
+}
+
+#[test]
+fn test_half_close_with_no_secio() {
+    test_half_close(false, Muxer::Yamux)
+}
+
+#[test]
+fn test_half_close_over_mplex_with_secio() {
+    test_half_close(true, Muxer::Mplex)
+}
+
+#[test]
+fn test_half_close_over_mplex_with_no_secio() {
+    test_half_close(false, Muxer::Mplex)
+}
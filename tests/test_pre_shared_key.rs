@@ -0,0 +1,89 @@
+use futures::prelude::Stream;
+use std::{thread, time::Duration};
+use tentacle::{
+    builder::{MetaBuilder, ServiceBuilder},
+    context::ServiceContext,
+    multiaddr::Multiaddr,
+    secio::{pnet::PreSharedKey, SecioKeyPair},
+    service::{DialProtocol, Service, ServiceError, ServiceEvent},
+    traits::ServiceHandle,
+};
+
+pub fn create<F>(pre_shared_key: Option<PreSharedKey>, shandle: F) -> Service<F>
+where
+    F: ServiceHandle,
+{
+    let builder = ServiceBuilder::default()
+        .insert_protocol(MetaBuilder::new().id(1.into()).build())
+        .key_pair(SecioKeyPair::secp256k1_generated())
+        .forever(true);
+
+    match pre_shared_key {
+        Some(key) => builder.pre_shared_key(key).build(shandle),
+        None => builder.build(shandle),
+    }
+}
+
+#[derive(Clone)]
+struct SHandle {
+    // Whether the dial succeeded
+    sender: crossbeam_channel::Sender<bool>,
+}
+
+impl ServiceHandle for SHandle {
+    fn handle_error(&mut self, _env: &mut ServiceContext, error: ServiceError) {
+        if let ServiceError::DialerError { .. } = error {
+            let _ = self.sender.try_send(false);
+        }
+    }
+
+    fn handle_event(&mut self, _env: &mut ServiceContext, event: ServiceEvent) {
+        if let ServiceEvent::SessionOpen { .. } = event {
+            let _ = self.sender.try_send(true);
+        }
+    }
+}
+
+// Whether a node with the dialer key can connect to a node with the listener key
+fn connect(listener_key: Option<PreSharedKey>, dialer_key: Option<PreSharedKey>) -> bool {
+    let mut listener = create(listener_key, ());
+    let addr = listener
+        .listen("/ip4/127.0.0.1/tcp/0".parse::<Multiaddr>().unwrap())
+        .unwrap();
+    thread::spawn(|| tokio::run(listener.for_each(|_| Ok(()))));
+
+    let (sender, receiver) = crossbeam_channel::bounded(1);
+    let mut dialer = create(dialer_key, SHandle { sender });
+    dialer.dial(addr, DialProtocol::All).unwrap();
+    thread::spawn(|| tokio::run(dialer.for_each(|_| Ok(()))));
+
+    receiver.recv_timeout(Duration::from_secs(20)).unwrap()
+}
+
+#[test]
+#[should_panic(expected = "pre-shared key requires a key pair")]
+fn test_pre_shared_key_without_key_pair() {
+    let _ = ServiceBuilder::default()
+        .pre_shared_key(PreSharedKey::random())
+        .build(());
+}
+
+#[test]
+fn test_same_pre_shared_key() {
+    let key = PreSharedKey::random();
+    assert!(connect(Some(key), Some(key)));
+}
+
+#[test]
+fn test_different_pre_shared_key() {
+    assert!(!connect(
+        Some(PreSharedKey::random()),
+        Some(PreSharedKey::random())
+    ));
+}
+
+#[test]
+fn test_pre_shared_key_on_one_side() {
+    assert!(!connect(Some(PreSharedKey::random()), None));
+    assert!(!connect(None, Some(PreSharedKey::random())));
+}
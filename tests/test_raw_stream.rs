@@ -0,0 +1,177 @@
+use futures::{future, prelude::*, stream::iter_ok};
+use std::{
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use tentacle::{
+    builder::{MetaBuilder, ServiceBuilder},
+    context::{ProtocolContext, ProtocolContextMutRef},
+    raw_substream::RawSubstream,
+    secio::SecioKeyPair,
+    service::{DialProtocol, ProtocolHandle, ProtocolMeta, Service},
+    traits::{ServiceHandle, ServiceProtocol},
+    ProtocolId,
+};
+
+const PAYLOAD: &[u8] = b"raw bytes without any codec";
+/// Well over the initial stream window of 256KB
+const LARGE_PAYLOAD_SIZE: usize = 1024 * 1024;
+
+pub fn create<F>(secio: bool, meta: ProtocolMeta, shandle: F) -> Service<F>
+where
+    F: ServiceHandle,
+{
+    let builder = ServiceBuilder::default()
+        .insert_protocol(meta)
+        .forever(true);
+
+    if secio {
+        builder
+            .key_pair(SecioKeyPair::secp256k1_generated())
+            .build(shandle)
+    } else {
+        builder.build(shandle)
+    }
+}
+
+struct PHandle {
+    test_result: Arc<AtomicBool>,
+}
+
+impl ServiceProtocol for PHandle {
+    fn init(&mut self, _context: &mut ProtocolContext) {}
+
+    fn raw_stream(&mut self, context: ProtocolContextMutRef, stream: RawSubstream) {
+        if context.session.ty.is_inbound() {
+            // Echo back everything until remote finishes sending
+            let task = tokio::io::read_to_end(stream, Vec::new())
+                .and_then(|(stream, data)| tokio::io::write_all(stream, data))
+                .and_then(|(stream, _)| tokio::io::shutdown(stream))
+                .map(|_| ())
+                .map_err(|err| panic!("echo error: {}", err));
+            tokio::spawn(task);
+        } else {
+            let control = context.control().clone();
+            let test_result = self.test_result.clone();
+            let task = tokio::io::write_all(stream, PAYLOAD)
+                .and_then(|(stream, _)| tokio::io::shutdown(stream))
+                .and_then(|stream| tokio::io::read_to_end(stream, Vec::new()))
+                .map(move |(_, data)| {
+                    if data.as_slice() == PAYLOAD {
+                        test_result.store(true, Ordering::SeqCst);
+                    }
+                    let _ = control.close();
+                })
+                .map_err(|err| panic!("request error: {}", err));
+            tokio::spawn(task);
+        }
+    }
+}
+
+/// Send a large response only after remote finished sending
+struct LargeHandle {
+    test_result: Arc<AtomicBool>,
+}
+
+impl ServiceProtocol for LargeHandle {
+    fn init(&mut self, _context: &mut ProtocolContext) {}
+
+    fn raw_stream(&mut self, context: ProtocolContextMutRef, stream: RawSubstream) {
+        if context.session.ty.is_inbound() {
+            let task = tokio::io::read_to_end(stream, Vec::new())
+                .and_then(|(stream, _)| {
+                    iter_ok::<_, io::Error>((0..64).map(|_| vec![1; 16 * 1024])).fold(
+                        stream,
+                        |stream, chunk| {
+                            tokio::io::write_all(stream, chunk).map(|(stream, _)| stream)
+                        },
+                    )
+                })
+                .and_then(|mut stream| {
+                    // Keep handling window updates to send the data cached by the stream,
+                    // until remote closes the session
+                    future::poll_fn(move || -> Poll<(), io::Error> {
+                        match stream.flush() {
+                            Err(ref err) if err.kind() != io::ErrorKind::WouldBlock => {
+                                Err(err.kind().into())
+                            }
+                            _ => Ok(Async::NotReady),
+                        }
+                    })
+                })
+                .map_err(|_| ());
+            tokio::spawn(task);
+        } else {
+            let control = context.control().clone();
+            let test_result = self.test_result.clone();
+            let task = tokio::io::shutdown(stream)
+                .and_then(|stream| tokio::io::read_exact(stream, vec![0; LARGE_PAYLOAD_SIZE]))
+                .map(move |(_, data)| {
+                    if data.iter().all(|byte| *byte == 1) {
+                        test_result.store(true, Ordering::SeqCst);
+                    }
+                    let _ = control.close();
+                })
+                .map_err(|err| panic!("request error: {}", err));
+            tokio::spawn(task);
+        }
+    }
+}
+
+fn create_meta(id: ProtocolId, large: bool) -> (ProtocolMeta, Arc<AtomicBool>) {
+    let test_result = Arc::new(AtomicBool::new(false));
+    let clone_result = test_result.clone();
+    (
+        MetaBuilder::new()
+            .id(id)
+            .raw_stream(true)
+            .service_handle(move || {
+                let test_result = clone_result.clone();
+                if large {
+                    ProtocolHandle::Callback(Box::new(LargeHandle { test_result }))
+                } else {
+                    ProtocolHandle::Callback(Box::new(PHandle { test_result }))
+                }
+            })
+            .build(),
+        test_result,
+    )
+}
+
+fn test_raw_stream(secio: bool, large: bool) {
+    let (meta, _) = create_meta(1.into(), large);
+    let mut service = create(secio, meta, ());
+    let listen_addr = service
+        .listen("/ip4/127.0.0.1/tcp/0".parse().unwrap())
+        .unwrap();
+    thread::spawn(|| tokio::run(service.for_each(|_| Ok(()))));
+    thread::sleep(Duration::from_millis(100));
+
+    let (meta, result) = create_meta(1.into(), large);
+    let mut service = create(secio, meta, ());
+    service.dial(listen_addr, DialProtocol::All).unwrap();
+    let handle = thread::spawn(|| tokio::run(service.for_each(|_| Ok(()))));
+    handle.join().unwrap();
+
+    assert!(result.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_raw_stream_with_secio() {
+    test_raw_stream(true, false)
+}
+
+#[test]
+fn test_raw_stream_with_no_secio() {
+    test_raw_stream(false, false)
+}
+
+#[test]
+fn test_large_transfer_after_remote_half_close() {
+    test_raw_stream(false, true)
+}
@@ -0,0 +1,103 @@
+use futures::{future, prelude::*};
+use std::{io, net::SocketAddr, thread, time::Duration};
+use tentacle::{
+    builder::{MetaBuilder, ServiceBuilder},
+    context::ServiceContext,
+    multiaddr::Multiaddr,
+    service::{ServiceError, ServiceEvent},
+    traits::ServiceHandle,
+    utils::multiaddr_to_socketaddr,
+    yamux::{config::StreamOpenRate, Config, Session},
+};
+use tokio::{net::TcpStream, timer::Timeout};
+
+#[derive(Clone)]
+struct SHandle {
+    // Streams reported by stream open abuse
+    sender: crossbeam_channel::Sender<u64>,
+}
+
+impl ServiceHandle for SHandle {
+    fn handle_error(&mut self, _env: &mut ServiceContext, error: ServiceError) {
+        if let ServiceError::StreamOpenAbuse { rejected, .. } = error {
+            let _ = self.sender.try_send(rejected);
+        }
+    }
+
+    fn handle_event(&mut self, _env: &mut ServiceContext, _event: ServiceEvent) {}
+}
+
+// Start a service without secio, so a bare yamux session can talk to it
+fn start(builder: ServiceBuilder) -> (SocketAddr, crossbeam_channel::Receiver<u64>) {
+    let (sender, receiver) = crossbeam_channel::bounded(8);
+    let mut service = builder
+        .insert_protocol(MetaBuilder::new().id(1.into()).build())
+        .stream_open_abuse(Duration::from_secs(10), 4)
+        .build(SHandle { sender });
+    let addr = service
+        .listen("/ip4/127.0.0.1/tcp/0".parse::<Multiaddr>().unwrap())
+        .unwrap();
+    thread::spawn(|| tokio::run(service.for_each(|_| Ok(()))));
+    (multiaddr_to_socketaddr(&addr).unwrap(), receiver)
+}
+
+// Open streams that never negotiate a protocol, return how many of them the service resets
+fn open_streams(addr: SocketAddr, count: usize) -> usize {
+    let task = TcpStream::connect(&addr).and_then(move |socket| {
+        let mut session = Session::new_client(socket, Config::default());
+        let streams = (0..count)
+            .map(|_| session.open_stream().unwrap())
+            .collect::<Vec<_>>();
+        tokio::spawn(session.for_each(|_| Ok(())).map_err(|_| ()));
+
+        // Negotiation times out after 10 seconds, streams still open by then are not reset
+        let reads = streams.into_iter().map(|stream| {
+            Timeout::new(
+                tokio::io::read_exact(stream, [0; 1]),
+                Duration::from_secs(3),
+            )
+            .then(|result| {
+                let reset = result
+                    .err()
+                    .and_then(|err| err.into_inner())
+                    .map(|err| err.kind() == io::ErrorKind::ConnectionReset)
+                    .unwrap_or(false);
+                Ok::<_, io::Error>(reset)
+            })
+        });
+        future::join_all(reads).map(|resets| resets.into_iter().filter(|reset| *reset).count())
+    });
+    tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(task)
+        .unwrap()
+}
+
+#[test]
+fn test_max_negotiating_streams() {
+    let (addr, receiver) = start(ServiceBuilder::default().max_negotiating_streams(2));
+
+    assert_eq!(open_streams(addr, 10), 8);
+    assert!(receiver.recv_timeout(Duration::from_secs(5)).unwrap() >= 4);
+}
+
+#[test]
+fn test_inbound_stream_rate() {
+    let (addr, receiver) = start(
+        ServiceBuilder::default().inbound_stream_rate(StreamOpenRate {
+            burst: 2,
+            per_second: 1,
+        }),
+    );
+
+    assert_eq!(open_streams(addr, 10), 8);
+    assert!(receiver.recv_timeout(Duration::from_secs(5)).unwrap() >= 4);
+}
+
+#[test]
+fn test_no_abuse_within_limits() {
+    let (addr, receiver) = start(ServiceBuilder::default().max_negotiating_streams(8));
+
+    assert_eq!(open_streams(addr, 8), 0);
+    assert!(receiver.try_recv().is_err());
+}
@@ -0,0 +1,170 @@
+//! Print a capture written by `tokio_yamux::Capture`
+//!
+//! Usage: yamux-capture [--payload] <capture file>
+
+use std::{collections::HashMap, env, fs::File, io::BufReader, process, time::Duration};
+
+use tokio_yamux::{
+    capture::{CaptureReader, Direction, Record},
+    frame::{Flag, Flags},
+};
+
+fn main() {
+    let mut show_payload = false;
+    let mut path = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--payload" => show_payload = true,
+            _ => path = Some(arg),
+        }
+    }
+    let path = match path {
+        Some(path) => path,
+        None => {
+            eprintln!("Usage: yamux-capture [--payload] <capture file>");
+            process::exit(2);
+        }
+    };
+    let reader = match File::open(&path).and_then(|file| CaptureReader::new(BufReader::new(file)))
+    {
+        Ok(reader) => reader,
+        Err(err) => {
+            eprintln!("can't read capture {}: {}", path, err);
+            process::exit(1);
+        }
+    };
+
+    // Protocol negotiated on each stream of each session
+    let mut protocols: HashMap<(u64, u64), String> = HashMap::new();
+    for record in reader {
+        let record = match record {
+            Ok(record) => record,
+            Err(err) => {
+                eprintln!("broken capture: {}", err);
+                process::exit(1);
+            }
+        };
+        match record {
+            Record::Frame {
+                timestamp,
+                session,
+                direction,
+                ty,
+                flags,
+                stream_id,
+                length,
+                payload,
+            } => {
+                println!(
+                    "{} session={} {} {:?} flags={} stream={} length={} {}",
+                    format_time(timestamp),
+                    session,
+                    format_direction(direction),
+                    ty,
+                    format_flags(flags),
+                    stream_id,
+                    length,
+                    protocol(&protocols, session, u64::from(stream_id)),
+                );
+                if show_payload {
+                    print_payload(payload);
+                }
+            }
+            Record::Annotation {
+                timestamp,
+                session,
+                stream_id,
+                text,
+            } => {
+                println!(
+                    "{} session={} stream={} open {}",
+                    format_time(timestamp),
+                    session,
+                    stream_id,
+                    text
+                );
+                protocols.insert((session, stream_id), text);
+            }
+            Record::Message {
+                timestamp,
+                session,
+                direction,
+                stream_id,
+                length,
+                payload,
+            } => {
+                println!(
+                    "{} session={} {} Message stream={} length={} {}",
+                    format_time(timestamp),
+                    session,
+                    format_direction(direction),
+                    stream_id,
+                    length,
+                    protocol(&protocols, session, stream_id),
+                );
+                if show_payload {
+                    print_payload(payload);
+                }
+            }
+        }
+    }
+}
+
+fn protocol(protocols: &HashMap<(u64, u64), String>, session: u64, stream_id: u64) -> String {
+    protocols
+        .get(&(session, stream_id))
+        .map(|name| format!("[{}]", name))
+        .unwrap_or_default()
+}
+
+fn format_time(timestamp: Duration) -> String {
+    format!("{}.{:06}", timestamp.as_secs(), timestamp.subsec_micros())
+}
+
+fn format_direction(direction: Direction) -> &'static str {
+    match direction {
+        Direction::Inbound => "<-",
+        Direction::Outbound => "->",
+    }
+}
+
+fn format_flags(flags: Flags) -> String {
+    let names = [
+        (Flag::Syn, "SYN"),
+        (Flag::Ack, "ACK"),
+        (Flag::Fin, "FIN"),
+        (Flag::Rst, "RST"),
+    ]
+    .iter()
+    .filter(|(flag, _)| flags.contains(*flag))
+    .map(|(_, name)| *name)
+    .collect::<Vec<_>>();
+    if names.is_empty() {
+        "-".to_owned()
+    } else {
+        names.join("|")
+    }
+}
+
+fn print_payload(payload: Option<bytes::Bytes>) {
+    if let Some(payload) = payload {
+        for line in payload.chunks(16) {
+            let hex = line
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let ascii = line
+                .iter()
+                .map(|byte| {
+                    if byte.is_ascii_graphic() || *byte == b' ' {
+                        *byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            println!("    {:<47}  {}", hex, ascii);
+        }
+    }
+}
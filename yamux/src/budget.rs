@@ -0,0 +1,174 @@
+//! Memory budget of the receive windows granted to the remote and the data buffered by streams
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// Upper bound of the memory streams may need to buffer received data, can be shared by many
+/// sessions.
+///
+/// A stream is charged its receive window, which the remote may fill at any time, and the data
+/// buffered but not read yet. A new stream needs its whole initial window, it is refused if the
+/// budget can't afford it. When the budget is exhausted, streams withhold window updates until
+/// their buffered data is consumed, so the remote can't send more than the windows that have
+/// already been granted.
+#[derive(Clone, Debug)]
+pub struct MemoryBudget {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    limit: usize,
+    used: AtomicUsize,
+}
+
+impl MemoryBudget {
+    /// Create a budget with the limit in bytes
+    pub fn new(limit: usize) -> Self {
+        MemoryBudget {
+            inner: Arc::new(Inner {
+                limit,
+                used: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /// The limit in bytes
+    pub fn limit(&self) -> usize {
+        self.inner.limit
+    }
+
+    /// Bytes currently buffered
+    pub fn used(&self) -> usize {
+        self.inner.used.load(Ordering::Acquire)
+    }
+
+    /// Whether the buffered bytes reached the limit
+    pub fn is_exhausted(&self) -> bool {
+        self.used() >= self.inner.limit
+    }
+
+    // Acquire only if it stays within the limit
+    fn try_acquire(&self, size: usize) -> bool {
+        let mut used = self.used();
+        loop {
+            if used + size > self.inner.limit {
+                return false;
+            }
+            match self.inner.used.compare_exchange(
+                used,
+                used + size,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return true,
+                Err(current) => used = current,
+            }
+        }
+    }
+
+    fn acquire(&self, size: usize) {
+        self.inner.used.fetch_add(size, Ordering::AcqRel);
+    }
+
+    fn release(&self, size: usize) {
+        self.inner.used.fetch_sub(size, Ordering::AcqRel);
+    }
+}
+
+/// The budgets a stream is charged to, its session's and the optional shared one
+#[derive(Clone, Debug)]
+pub(crate) struct RecvBudget {
+    session: MemoryBudget,
+    shared: Option<MemoryBudget>,
+}
+
+impl RecvBudget {
+    pub(crate) fn new(session: MemoryBudget, shared: Option<MemoryBudget>) -> Self {
+        RecvBudget { session, shared }
+    }
+
+    pub(crate) fn acquire(&self, size: usize) {
+        self.session.acquire(size);
+        if let Some(ref shared) = self.shared {
+            shared.acquire(size);
+        }
+    }
+
+    /// Acquire from both budgets, or from none if one of them can't afford it
+    pub(crate) fn try_acquire(&self, size: usize) -> bool {
+        if !self.session.try_acquire(size) {
+            return false;
+        }
+        match self.shared {
+            Some(ref shared) if !shared.try_acquire(size) => {
+                self.session.release(size);
+                false
+            }
+            _ => true,
+        }
+    }
+
+    pub(crate) fn release(&self, size: usize) {
+        self.session.release(size);
+        if let Some(ref shared) = self.shared {
+            shared.release(size);
+        }
+    }
+
+    pub(crate) fn is_exhausted(&self) -> bool {
+        self.session.is_exhausted()
+            || self
+                .shared
+                .as_ref()
+                .map(MemoryBudget::is_exhausted)
+                .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemoryBudget, RecvBudget};
+
+    #[test]
+    fn test_shared_budget() {
+        let shared = MemoryBudget::new(100);
+        let budget_1 = RecvBudget::new(MemoryBudget::new(60), Some(shared.clone()));
+        let budget_2 = RecvBudget::new(MemoryBudget::new(60), Some(shared.clone()));
+
+        budget_1.acquire(50);
+        assert!(!budget_1.is_exhausted());
+        budget_2.acquire(50);
+        assert_eq!(shared.used(), 100);
+        assert!(budget_1.is_exhausted());
+        assert!(budget_2.is_exhausted());
+
+        budget_1.release(40);
+        assert!(!budget_2.is_exhausted());
+        budget_2.acquire(10);
+        // Session budget exhausted, but shared one is not
+        assert!(budget_2.is_exhausted());
+        assert!(!budget_1.is_exhausted());
+        assert!(!shared.is_exhausted());
+    }
+
+    #[test]
+    fn test_try_acquire() {
+        let shared = MemoryBudget::new(100);
+        let session = MemoryBudget::new(60);
+        let budget = RecvBudget::new(session.clone(), Some(shared.clone()));
+
+        assert!(budget.try_acquire(50));
+        assert!(!budget.try_acquire(20));
+        assert_eq!(session.used(), 50);
+
+        // Refused by the shared budget, the session budget is left untouched
+        shared.acquire(45);
+        budget.release(40);
+        assert!(!budget.try_acquire(10));
+        assert_eq!(session.used(), 10);
+        assert_eq!(shared.used(), 55);
+    }
+}
@@ -0,0 +1,471 @@
+//! Capture frames for debugging, a capture can be printed by the `yamux-capture` binary
+//!
+//! The capture starts with `CAPTURE_MAGIC` and a u16 version, followed by records, all
+//! integers are big endian. Each record starts with:
+//!
+//! ```text
+//! | kind (u8) | timestamp in micros since unix epoch (u64) | session (u64) |
+//! ```
+//!
+//! Frame record (kind 0):
+//!
+//! ```text
+//! | direction (u8) | type (u8) | flags (u16) | stream id (u32) | length (u32) | payload length (u32) | payload |
+//! ```
+//!
+//! Annotation record (kind 1), such as the protocol negotiated on a stream:
+//!
+//! ```text
+//! | stream id (u64) | text length (u32) | text |
+//! ```
+//!
+//! Message record (kind 2), data of upper layer protocol on a stream:
+//!
+//! ```text
+//! | direction (u8) | stream id (u64) | length (u32) | payload length (u32) | payload |
+//! ```
+
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use bytes::Bytes;
+use log::debug;
+
+use crate::frame::{Flags, Frame, Type};
+
+/// The magic at the start of a capture
+pub const CAPTURE_MAGIC: &[u8; 8] = b"YAMUXCAP";
+/// The format version of capture
+pub const CAPTURE_VERSION: u16 = 1;
+
+const KIND_FRAME: u8 = 0;
+const KIND_ANNOTATION: u8 = 1;
+const KIND_MESSAGE: u8 = 2;
+
+/// Whether the frame is received or sent
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Direction {
+    /// Received from remote
+    Inbound,
+    /// Sent to remote
+    Outbound,
+}
+
+impl Direction {
+    fn to_u8(self) -> u8 {
+        match self {
+            Direction::Inbound => 0,
+            Direction::Outbound => 1,
+        }
+    }
+
+    fn from_u8(value: u8) -> io::Result<Direction> {
+        match value {
+            0 => Ok(Direction::Inbound),
+            1 => Ok(Direction::Outbound),
+            _ => Err(invalid_data("invalid direction")),
+        }
+    }
+}
+
+/// The writer of a capture, can be cloned and shared by many sessions.
+///
+/// Write errors are ignored, the capture must never break a session.
+#[derive(Clone)]
+pub struct Capture {
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+    payload: bool,
+    session: u64,
+}
+
+impl Capture {
+    /// Start a capture on the writer, payload of data frames is recorded if `payload` is true
+    pub fn new<W: Write + Send + 'static>(mut writer: W, payload: bool) -> io::Result<Capture> {
+        writer.write_all(CAPTURE_MAGIC)?;
+        writer.write_u16::<BigEndian>(CAPTURE_VERSION)?;
+        writer.flush()?;
+        Ok(Capture {
+            writer: Arc::new(Mutex::new(Box::new(writer))),
+            payload,
+            session: 0,
+        })
+    }
+
+    /// Start a capture on a new file, overwrite it if it exists
+    ///
+    /// Records are buffered, they are written out by `flush`, and when the last clone of
+    /// the capture is dropped.
+    pub fn create<P: AsRef<Path>>(path: P, payload: bool) -> io::Result<Capture> {
+        Capture::new(BufWriter::new(File::create(path)?), payload)
+    }
+
+    /// Write out the records buffered by the writer
+    pub fn flush(&self) {
+        let mut writer = match self.writer.lock() {
+            Ok(writer) => writer,
+            Err(_) => return,
+        };
+        if let Err(err) = writer.flush() {
+            debug!("flush capture error: {}", err);
+        }
+    }
+
+    /// The same capture, records written by the returned one are marked with the session id
+    pub fn with_session(&self, session: u64) -> Capture {
+        Capture {
+            writer: Arc::clone(&self.writer),
+            payload: self.payload,
+            session,
+        }
+    }
+
+    /// Record a note about a stream, such as the protocol negotiated on it
+    pub fn annotate(&self, stream_id: u64, text: &str) {
+        let mut record = self.record_header(KIND_ANNOTATION);
+        record.write_u64::<BigEndian>(stream_id).unwrap();
+        record.write_u32::<BigEndian>(text.len() as u32).unwrap();
+        record.extend_from_slice(text.as_bytes());
+        self.write(record);
+    }
+
+    /// Record a message of upper layer protocol on a stream
+    pub fn message(&self, direction: Direction, stream_id: u64, data: &[u8]) {
+        let mut record = self.record_header(KIND_MESSAGE);
+        record.write_u8(direction.to_u8()).unwrap();
+        record.write_u64::<BigEndian>(stream_id).unwrap();
+        record.write_u32::<BigEndian>(data.len() as u32).unwrap();
+        self.write_payload(&mut record, Some(data));
+        self.write(record);
+    }
+
+    // Encode the frame now, the record may be written later when the frame is really sent
+    pub(crate) fn encode_frame(&self, direction: Direction, frame: &Frame) -> Vec<u8> {
+        let mut record = self.record_header(KIND_FRAME);
+        record.write_u8(direction.to_u8()).unwrap();
+        record.write_u8(frame.ty() as u8).unwrap();
+        record
+            .write_u16::<BigEndian>(frame.flags().value())
+            .unwrap();
+        record.write_u32::<BigEndian>(frame.stream_id()).unwrap();
+        record.write_u32::<BigEndian>(frame.length()).unwrap();
+        self.write_payload(&mut record, frame.body().map(|body| &body[..]));
+        record
+    }
+
+    pub(crate) fn write(&self, record: Vec<u8>) {
+        let mut writer = match self.writer.lock() {
+            Ok(writer) => writer,
+            Err(_) => return,
+        };
+        if let Err(err) = writer.write_all(&record) {
+            debug!("write capture error: {}", err);
+        }
+    }
+
+    fn record_header(&self, kind: u8) -> Vec<u8> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let micros = timestamp.as_secs() * 1_000_000 + u64::from(timestamp.subsec_micros());
+        let mut record = Vec::with_capacity(64);
+        record.write_u8(kind).unwrap();
+        record.write_u64::<BigEndian>(micros).unwrap();
+        record.write_u64::<BigEndian>(self.session).unwrap();
+        record
+    }
+
+    fn write_payload(&self, record: &mut Vec<u8>, payload: Option<&[u8]>) {
+        match payload {
+            Some(payload) if self.payload => {
+                record.write_u32::<BigEndian>(payload.len() as u32).unwrap();
+                record.extend_from_slice(payload);
+            }
+            _ => record.write_u32::<BigEndian>(0).unwrap(),
+        }
+    }
+}
+
+impl fmt::Debug for Capture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Capture")
+            .field("payload", &self.payload)
+            .field("session", &self.session)
+            .finish()
+    }
+}
+
+/// A record read from capture
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Record {
+    /// A yamux frame
+    Frame {
+        /// Time since unix epoch
+        timestamp: Duration,
+        /// Session id
+        session: u64,
+        /// Received or sent
+        direction: Direction,
+        /// Frame type
+        ty: Type,
+        /// Frame flags
+        flags: Flags,
+        /// Stream id
+        stream_id: u32,
+        /// Length field of the frame
+        length: u32,
+        /// Payload of data frames, if it is recorded
+        payload: Option<Bytes>,
+    },
+    /// A note about a stream
+    Annotation {
+        /// Time since unix epoch
+        timestamp: Duration,
+        /// Session id
+        session: u64,
+        /// Stream id
+        stream_id: u64,
+        /// The note
+        text: String,
+    },
+    /// A message of upper layer protocol
+    Message {
+        /// Time since unix epoch
+        timestamp: Duration,
+        /// Session id
+        session: u64,
+        /// Received or sent
+        direction: Direction,
+        /// Stream id
+        stream_id: u64,
+        /// Length of the message
+        length: u32,
+        /// The message, if it is recorded
+        payload: Option<Bytes>,
+    },
+}
+
+/// Read records from a capture
+pub struct CaptureReader<R> {
+    inner: R,
+}
+
+impl<R: Read> CaptureReader<R> {
+    /// Check the capture header
+    pub fn new(mut inner: R) -> io::Result<CaptureReader<R>> {
+        let mut magic = [0; 8];
+        inner.read_exact(&mut magic)?;
+        if &magic != CAPTURE_MAGIC {
+            return Err(invalid_data("not a yamux capture"));
+        }
+        let version = inner.read_u16::<BigEndian>()?;
+        if version != CAPTURE_VERSION {
+            return Err(invalid_data("unsupported capture version"));
+        }
+        Ok(CaptureReader { inner })
+    }
+
+    /// Read next record, `None` at the end of capture
+    pub fn next_record(&mut self) -> io::Result<Option<Record>> {
+        let kind = match self.inner.read_u8() {
+            Ok(kind) => kind,
+            Err(ref err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let timestamp = Duration::from_micros(self.inner.read_u64::<BigEndian>()?);
+        let session = self.inner.read_u64::<BigEndian>()?;
+        let record = match kind {
+            KIND_FRAME => {
+                let direction = Direction::from_u8(self.inner.read_u8()?)?;
+                let ty = Type::try_from(self.inner.read_u8()?)
+                    .ok_or_else(|| invalid_data("invalid frame type"))?;
+                let flags = Flags::from(self.inner.read_u16::<BigEndian>()?);
+                let stream_id = self.inner.read_u32::<BigEndian>()?;
+                let length = self.inner.read_u32::<BigEndian>()?;
+                Record::Frame {
+                    timestamp,
+                    session,
+                    direction,
+                    ty,
+                    flags,
+                    stream_id,
+                    length,
+                    payload: self.read_payload()?,
+                }
+            }
+            KIND_ANNOTATION => {
+                let stream_id = self.inner.read_u64::<BigEndian>()?;
+                let len = self.inner.read_u32::<BigEndian>()? as usize;
+                let mut text = vec![0; len];
+                self.inner.read_exact(&mut text)?;
+                Record::Annotation {
+                    timestamp,
+                    session,
+                    stream_id,
+                    text: String::from_utf8_lossy(&text).into_owned(),
+                }
+            }
+            KIND_MESSAGE => {
+                let direction = Direction::from_u8(self.inner.read_u8()?)?;
+                let stream_id = self.inner.read_u64::<BigEndian>()?;
+                let length = self.inner.read_u32::<BigEndian>()?;
+                Record::Message {
+                    timestamp,
+                    session,
+                    direction,
+                    stream_id,
+                    length,
+                    payload: self.read_payload()?,
+                }
+            }
+            _ => return Err(invalid_data("invalid record kind")),
+        };
+        Ok(Some(record))
+    }
+
+    fn read_payload(&mut self) -> io::Result<Option<Bytes>> {
+        let len = self.inner.read_u32::<BigEndian>()? as usize;
+        if len == 0 {
+            return Ok(None);
+        }
+        let mut payload = vec![0; len];
+        self.inner.read_exact(&mut payload)?;
+        Ok(Some(Bytes::from(payload)))
+    }
+}
+
+impl<R: Read> Iterator for CaptureReader<R> {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_record().transpose()
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Capture, CaptureReader, Direction, Record};
+    use crate::frame::{Flag, Flags, Frame, Type};
+    use bytes::Bytes;
+    use std::{
+        io::{self, Write},
+        sync::{Arc, Mutex},
+    };
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_capture_round_trip() {
+        let buf = SharedBuf::default();
+        let capture = Capture::new(buf.clone(), true).unwrap().with_session(7);
+
+        let frame = Frame::new_data(Flags::from(Flag::Syn), 3, Bytes::from("hello"));
+        capture.write(capture.encode_frame(Direction::Outbound, &frame));
+        capture.annotate(3, "/p2p/ping 1");
+        capture.message(Direction::Inbound, 3, b"pong");
+        let frame = Frame::new_window_update(Flags::from(Flag::Fin), 3, 0);
+        capture.write(capture.encode_frame(Direction::Inbound, &frame));
+
+        let data = buf.0.lock().unwrap().clone();
+        let records = CaptureReader::new(&data[..])
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(records.len(), 4);
+        match records[0] {
+            Record::Frame {
+                session,
+                direction,
+                ty,
+                flags,
+                stream_id,
+                length,
+                ref payload,
+                ..
+            } => {
+                assert_eq!(session, 7);
+                assert_eq!(direction, Direction::Outbound);
+                assert_eq!(ty, Type::Data);
+                assert!(flags.contains(Flag::Syn));
+                assert_eq!((stream_id, length), (3, 5));
+                assert_eq!(payload, &Some(Bytes::from("hello")));
+            }
+            _ => panic!("expect frame"),
+        }
+        match records[1] {
+            Record::Annotation {
+                stream_id,
+                ref text,
+                ..
+            } => {
+                assert_eq!(stream_id, 3);
+                assert_eq!(text, "/p2p/ping 1");
+            }
+            _ => panic!("expect annotation"),
+        }
+        match records[2] {
+            Record::Message {
+                direction,
+                length,
+                ref payload,
+                ..
+            } => {
+                assert_eq!(direction, Direction::Inbound);
+                assert_eq!(length, 4);
+                assert_eq!(payload, &Some(Bytes::from("pong")));
+            }
+            _ => panic!("expect message"),
+        }
+        match records[3] {
+            Record::Frame {
+                ty, ref payload, ..
+            } => {
+                assert_eq!(ty, Type::WindowUpdate);
+                assert!(payload.is_none());
+            }
+            _ => panic!("expect frame"),
+        }
+    }
+
+    #[test]
+    fn test_create_flush_on_drop() {
+        let path = std::env::temp_dir().join(format!("capture-{}", rand::random::<u64>()));
+        let capture = Capture::create(&path, false).unwrap();
+        capture.with_session(1).annotate(1, "/p2p/ping 1");
+        drop(capture);
+
+        let file = std::fs::File::open(&path).unwrap();
+        let records = CaptureReader::new(file)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(records.len(), 1);
+    }
+
+    #[test]
+    fn test_reject_other_file() {
+        assert!(CaptureReader::new(&b"not a capture"[..]).is_err());
+    }
+}
@@ -14,12 +14,64 @@ pub const DEFAULT_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
 pub const DEFAULT_WRITE_TIMEOUT: Duration = Duration::from_secs(10);
 /// Default max buffer size
 const MAX_BUF_SIZE: usize = 24 * 1024 * 1024;
+/// Default max size of a data frame, larger ones are split
+pub const DEFAULT_MAX_CHUNK_SIZE: u32 = 16 * 1024;
+/// Default stream priority, streams with higher priority get more bandwidth when they compete
+pub const DEFAULT_STREAM_PRIORITY: u8 = 16;
+/// Default upper bound of received data buffered by all streams in a session
+pub const DEFAULT_SESSION_RECV_BUDGET: usize = 64 * 1024 * 1024;
+/// Default upper bound of a stream's receive window when auto-tuning is enabled
+pub const DEFAULT_AUTO_TUNING_MAX_WINDOW: u32 = 16 * 1024 * 1024;
+/// Default upper bound of window growth of all streams in a session when auto-tuning is enabled
+pub const DEFAULT_CONNECTION_WINDOW_BUDGET: usize = 64 * 1024 * 1024;
+
+/// How the receive window of streams is managed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WindowMode {
+    /// Every stream uses a fixed receive window of `max_stream_window_size`
+    Fixed,
+    /// Streams start with `max_stream_window_size`, the window is doubled when
+    /// the receiver drains it within about one round trip time.
+    ///
+    /// RTT comes from keepalive pings, nothing grows if keepalive is disabled.
+    AutoTuning {
+        /// The upper bound of a stream's receive window
+        max_window_size: u32,
+        /// The upper bound of the sum of window growth of all streams in a session
+        connection_budget: usize,
+    },
+}
+
+impl WindowMode {
+    /// Auto-tuning with default bounds
+    pub const fn auto_tuning() -> Self {
+        WindowMode::AutoTuning {
+            max_window_size: DEFAULT_AUTO_TUNING_MAX_WINDOW,
+            connection_budget: DEFAULT_CONNECTION_WINDOW_BUDGET,
+        }
+    }
+}
+
+impl Default for WindowMode {
+    fn default() -> Self {
+        WindowMode::Fixed
+    }
+}
+
+/// Token bucket limit of streams opened by remote
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StreamOpenRate {
+    /// How many streams can be opened at once
+    pub burst: u32,
+    /// How many streams can be opened per second in the long run
+    pub per_second: u32,
+}
 
 /// Configuration of session and stream
 #[derive(Clone, Copy)]
 pub struct Config {
     /// AcceptBacklog is used to limit how many streams may be
-    /// waiting an accept.
+    /// waiting an accept, new streams opened by remote beyond it are reset.
     pub accept_backlog: usize,
 
     /// EnableKeepalive is used to do a period keep alive
@@ -35,13 +87,29 @@ pub struct Config {
     /// an expectation that things will move along quickly.
     pub connection_write_timeout: Duration,
 
-    /// Max stream count
+    /// Max stream count, new streams opened by remote beyond it are reset
     pub max_stream_count: usize,
 
+    /// Rate limit of streams opened by remote, streams beyond it are reset,
+    /// default is unlimited
+    pub inbound_stream_rate: Option<StreamOpenRate>,
+
     /// MaxStreamWindowSize is used to control the maximum
     /// window size that we allow for a stream.
     pub max_stream_window_size: u32,
 
+    /// Fixed window or auto-tuning, default is fixed
+    pub window_mode: WindowMode,
+
+    /// Data frames larger than it are split before sending, so small frames of other streams
+    /// are not blocked behind a large one for long, default is 16Kb
+    pub max_chunk_size: u32,
+
+    /// Upper bound of the receive windows and the received data buffered by all streams in a
+    /// session, window updates are withheld and new streams refused when it is exhausted,
+    /// default is 64Mb
+    pub session_recv_budget: usize,
+
     /// default is 1Mb
     pub send_buffer_size: usize,
 
@@ -61,6 +129,16 @@ impl Config {
     pub const fn send_event_size(&self) -> usize {
         (self.send_buffer_size / self.max_stream_window_size as usize) + 1
     }
+
+    // The max frame size can be received, a stream never grants more than its window
+    pub(crate) fn max_frame_size(&self) -> u32 {
+        match self.window_mode {
+            WindowMode::Fixed => self.max_stream_window_size,
+            WindowMode::AutoTuning {
+                max_window_size, ..
+            } => ::std::cmp::max(max_window_size, self.max_stream_window_size),
+        }
+    }
 }
 
 impl Default for Config {
@@ -71,7 +149,11 @@ impl Default for Config {
             keepalive_interval: DEFAULT_KEEPALIVE_INTERVAL,
             connection_write_timeout: DEFAULT_WRITE_TIMEOUT,
             max_stream_count: DEFAULT_MAX_STREAM_COUNT,
+            inbound_stream_rate: None,
             max_stream_window_size: INITIAL_STREAM_WINDOW,
+            window_mode: WindowMode::default(),
+            max_chunk_size: DEFAULT_MAX_CHUNK_SIZE,
+            session_recv_budget: DEFAULT_SESSION_RECV_BUDGET,
             send_buffer_size: MAX_BUF_SIZE,
             recv_buffer_size: MAX_BUF_SIZE,
         }
@@ -56,6 +56,9 @@ pub enum Error {
 
     /// Sub stream send event channel full, block to complete
     WouldBlock,
+
+    /// The receive budget can't afford the initial window of a new stream
+    RecvBudgetExhausted,
 }
 
 impl error::Error for Error {
@@ -76,6 +79,7 @@ impl error::Error for Error {
             Error::KeepAliveTimeout => "Keepalive timeout",
             Error::SubStreamRemoteClosing => "Remote sub stream is closed",
             Error::WouldBlock => "Sub stream send channel full",
+            Error::RecvBudgetExhausted => "Receive budget exhausted",
         }
     }
 }
@@ -100,6 +104,7 @@ impl fmt::Display for Error {
             Error::KeepAliveTimeout => write!(f, "Keepalive timeout"),
             Error::SubStreamRemoteClosing => write!(f, "Remote sub stream is closed"),
             Error::WouldBlock => write!(f, "Sub stream send channel full"),
+            Error::RecvBudgetExhausted => write!(f, "Receive budget exhausted"),
         }
     }
 }
@@ -63,13 +63,18 @@ impl Frame {
 
     /// Create a go away frame
     pub fn new_go_away(reason: GoAwayCode) -> Frame {
+        Frame::new_go_away_with_code(reason as u32)
+    }
+
+    /// Create a go away frame with a raw code, such as an application defined one
+    pub fn new_go_away_with_code(code: u32) -> Frame {
         Frame {
             header: Header {
                 version: PROTOCOL_VERSION,
                 ty: Type::GoAway,
                 flags: Flags::default(),
                 stream_id: RESERVED_STREAM_ID,
-                length: reason as u32,
+                length: code,
             },
             body: None,
         }
@@ -95,6 +100,10 @@ impl Frame {
         self.header.length
     }
 
+    pub(crate) fn body(&self) -> Option<&Bytes> {
+        self.body.as_ref()
+    }
+
     /// Consume current frame split into header and body
     pub fn into_parts(self) -> (Header, Option<Bytes>) {
         (self.header, self.body)
@@ -186,6 +195,12 @@ impl From<Flag> for Flags {
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub struct Flags(u16);
 
+impl From<u16> for Flags {
+    fn from(value: u16) -> Flags {
+        Flags(value)
+    }
+}
+
 impl Flags {
     /// Add a flag
     pub fn add(&mut self, flag: Flag) {
@@ -234,6 +249,38 @@ impl From<u32> for GoAwayCode {
     }
 }
 
+/// Go away codes from this value on are defined by applications, codes below it belong to yamux,
+/// so an application code is never mistaken for a protocol error
+pub const APPLICATION_GO_AWAY_CODE_BASE: u32 = 0x1_0000;
+
+/// The code of a go away and the message sent along with it
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GoAwayReason {
+    /// Raw go away code, codes defined by spec are in `GoAwayCode`
+    pub code: u32,
+    /// The message is sent as a data frame on the session stream (id 0) right before the go away,
+    /// peers that don't know it just ignore it
+    pub message: Option<Bytes>,
+}
+
+impl GoAwayReason {
+    /// Wire code of an application defined code
+    pub fn application_wire_code(code: u16) -> u32 {
+        APPLICATION_GO_AWAY_CODE_BASE + u32::from(code)
+    }
+
+    /// The application defined code, `None` if the code belongs to yamux
+    pub fn application_code(&self) -> Option<u16> {
+        if self.code >= APPLICATION_GO_AWAY_CODE_BASE
+            && self.code - APPLICATION_GO_AWAY_CODE_BASE <= u32::from(u16::max_value())
+        {
+            Some((self.code - APPLICATION_GO_AWAY_CODE_BASE) as u16)
+        } else {
+            None
+        }
+    }
+}
+
 /// The frame decoder/encoder
 pub(crate) struct FrameCodec {
     unused_data_header: Option<Header>,
@@ -352,7 +399,10 @@ impl Encoder for FrameCodec {
 
 #[cfg(test)]
 mod test {
-    use super::{Flags, Frame, FrameCodec, Type, HEADER_SIZE, INITIAL_STREAM_WINDOW};
+    use super::{
+        Flags, Frame, FrameCodec, GoAwayCode, GoAwayReason, Type, HEADER_SIZE,
+        INITIAL_STREAM_WINDOW,
+    };
     use bytes::{Bytes, BytesMut};
     use tokio::codec::{Decoder, Encoder};
 
@@ -380,4 +430,23 @@ mod test {
 
         assert_eq!(data.unwrap(), rand_data)
     }
+
+    #[test]
+    fn test_application_go_away_code() {
+        let reason = |code| GoAwayReason {
+            code,
+            message: None,
+        };
+        assert_eq!(
+            reason(GoAwayCode::ProtocolError as u32).application_code(),
+            None
+        );
+        assert_eq!(
+            reason(GoAwayReason::application_wire_code(1)).application_code(),
+            Some(1)
+        );
+        let max = GoAwayReason::application_wire_code(u16::max_value());
+        assert_eq!(reason(max).application_code(), Some(u16::max_value()));
+        assert_eq!(reason(max + 1).application_code(), None);
+    }
 }
@@ -4,21 +4,36 @@
 
 #![deny(missing_docs)]
 
+// Budget module
+pub mod budget;
+// Capture module
+pub mod capture;
 // Config module
 pub mod config;
 // Error module
 pub mod error;
 // Frame module
 pub mod frame;
+// Inbound stream rate limit module
+mod rate_limit;
+// Round trip time module
+pub mod rtt;
+// Frame scheduler module
+mod scheduler;
 // Session module
 pub mod session;
 // Stream module
 pub mod stream;
+// Window auto-tuning module
+mod window;
 
 // Stream ID type
 pub(crate) type StreamId = u32;
 
-pub use crate::{config::Config, error::Error, session::Session, stream::StreamHandle};
+pub use crate::{
+    budget::MemoryBudget, capture::Capture, config::Config, error::Error, rtt::RttStats,
+    session::Session, stream::StreamHandle,
+};
 
 // Latest Protocol Version
 pub(crate) const PROTOCOL_VERSION: u8 = 0;
@@ -0,0 +1,69 @@
+//! Token bucket limiting how fast remote can open streams
+
+use std::time::Instant;
+
+use crate::config::StreamOpenRate;
+
+#[derive(Debug)]
+pub(crate) struct TokenBucket {
+    rate: StreamOpenRate,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    pub(crate) fn new(rate: StreamOpenRate) -> Self {
+        TokenBucket {
+            rate,
+            tokens: f64::from(rate.burst),
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Take a token if there is one
+    pub(crate) fn try_acquire(&mut self, now: Instant) -> bool {
+        if now > self.last_refill {
+            let elapsed = now - self.last_refill;
+            self.last_refill = now;
+            self.tokens = (self.tokens + elapsed.as_secs_f64() * f64::from(self.rate.per_second))
+                .min(f64::from(self.rate.burst));
+        }
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TokenBucket;
+    use crate::config::StreamOpenRate;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_token_bucket() {
+        let mut bucket = TokenBucket::new(StreamOpenRate {
+            burst: 2,
+            per_second: 10,
+        });
+        let now = Instant::now();
+
+        assert!(bucket.try_acquire(now));
+        assert!(bucket.try_acquire(now));
+        assert!(!bucket.try_acquire(now));
+
+        // One token every 100ms
+        let now = now + Duration::from_millis(100);
+        assert!(bucket.try_acquire(now));
+        assert!(!bucket.try_acquire(now));
+
+        // Never more than the burst
+        let now = now + Duration::from_secs(10);
+        assert!(bucket.try_acquire(now));
+        assert!(bucket.try_acquire(now));
+        assert!(!bucket.try_acquire(now));
+    }
+}
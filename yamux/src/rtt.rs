@@ -0,0 +1,90 @@
+//! Round trip time measured by keepalive pings
+
+use std::time::{Duration, Instant};
+
+/// Round trip time statistics of a session.
+///
+/// The estimator follows [RFC 6298](https://tools.ietf.org/html/rfc6298#section-2),
+/// samples come from the keepalive pings, so nothing is measured if keepalive is disabled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RttStats {
+    smoothed: Option<Duration>,
+    variance: Duration,
+    latest: Option<Duration>,
+    last_ping_time: Option<Instant>,
+    samples: u64,
+}
+
+impl RttStats {
+    /// Smoothed round trip time, `None` if no ping has been acked yet
+    pub fn smoothed_rtt(&self) -> Option<Duration> {
+        self.smoothed
+    }
+
+    /// Round trip time variance
+    pub fn rtt_variance(&self) -> Duration {
+        self.variance
+    }
+
+    /// The latest round trip time sample
+    pub fn latest_rtt(&self) -> Option<Duration> {
+        self.latest
+    }
+
+    /// The time when the last ping has been sent
+    pub fn last_ping_time(&self) -> Option<Instant> {
+        self.last_ping_time
+    }
+
+    /// How many samples have been measured
+    pub fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    pub(crate) fn ping_sent(&mut self, at: Instant) {
+        self.last_ping_time = Some(at);
+    }
+
+    /// Add a round trip time sample
+    pub fn update(&mut self, rtt: Duration) {
+        match self.smoothed {
+            None => {
+                self.smoothed = Some(rtt);
+                self.variance = rtt / 2;
+            }
+            Some(smoothed) => {
+                let diff = if smoothed > rtt {
+                    smoothed - rtt
+                } else {
+                    rtt - smoothed
+                };
+                self.variance = (self.variance * 3 + diff) / 4;
+                self.smoothed = Some((smoothed * 7 + rtt) / 8);
+            }
+        }
+        self.latest = Some(rtt);
+        self.samples += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RttStats;
+    use std::time::Duration;
+
+    #[test]
+    fn test_rtt_estimate() {
+        let mut rtt = RttStats::default();
+        assert_eq!(rtt.smoothed_rtt(), None);
+
+        rtt.update(Duration::from_millis(100));
+        assert_eq!(rtt.smoothed_rtt(), Some(Duration::from_millis(100)));
+        assert_eq!(rtt.rtt_variance(), Duration::from_millis(50));
+
+        rtt.update(Duration::from_millis(20));
+        assert_eq!(rtt.smoothed_rtt(), Some(Duration::from_millis(90)));
+        assert_eq!(rtt.rtt_variance(), Duration::from_micros(57_500));
+        assert_eq!(rtt.latest_rtt(), Some(Duration::from_millis(20)));
+        assert_eq!(rtt.samples(), 2);
+    }
+}
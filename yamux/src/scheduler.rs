@@ -0,0 +1,240 @@
+//! Outbound frame scheduling across streams
+//!
+//! Frames of a stream keep their order, frames of different streams are interleaved by
+//! deficit round robin weighted by stream priority, so a stream pushing large data
+//! can't delay small frames of other streams for long.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc,
+    },
+};
+
+use bytes::Bytes;
+
+use crate::{
+    config::DEFAULT_STREAM_PRIORITY,
+    frame::{Flags, Frame, Type},
+    StreamId, HEADER_SIZE, RESERVED_STREAM_ID,
+};
+
+struct StreamQueue {
+    frames: VecDeque<Frame>,
+    deficit: usize,
+    priority: Arc<AtomicU8>,
+}
+
+pub(crate) struct FrameScheduler {
+    max_chunk_size: usize,
+    // Bytes a stream with default priority can send per round
+    quantum: usize,
+    // The frame failed to be sent, must be sent first
+    retry: Option<Frame>,
+    // Session level frames (ping, go away) are sent before any stream frame
+    control: VecDeque<Frame>,
+    queues: HashMap<StreamId, StreamQueue>,
+    // Streams that have pending frames, in round robin order
+    active: VecDeque<StreamId>,
+    priorities: HashMap<StreamId, Arc<AtomicU8>>,
+    len: usize,
+}
+
+impl FrameScheduler {
+    pub(crate) fn new(max_chunk_size: u32) -> Self {
+        let max_chunk_size = ::std::cmp::max(max_chunk_size as usize, 1);
+        FrameScheduler {
+            max_chunk_size,
+            quantum: max_chunk_size + HEADER_SIZE,
+            retry: None,
+            control: VecDeque::default(),
+            queues: HashMap::default(),
+            active: VecDeque::default(),
+            priorities: HashMap::default(),
+            len: 0,
+        }
+    }
+
+    /// Track the priority of a stream
+    pub(crate) fn register(&mut self, stream_id: StreamId, priority: Arc<AtomicU8>) {
+        self.priorities.insert(stream_id, priority);
+    }
+
+    /// Stream closed, the frames already queued will still be sent
+    pub(crate) fn unregister(&mut self, stream_id: StreamId) {
+        self.priorities.remove(&stream_id);
+    }
+
+    pub(crate) fn push_back(&mut self, frame: Frame) {
+        if frame.stream_id() == RESERVED_STREAM_ID {
+            self.len += 1;
+            self.control.push_back(frame);
+            return;
+        }
+
+        let stream_id = frame.stream_id();
+        if !self.queues.contains_key(&stream_id) {
+            let priority = self
+                .priorities
+                .get(&stream_id)
+                .cloned()
+                .unwrap_or_else(|| Arc::new(AtomicU8::new(DEFAULT_STREAM_PRIORITY)));
+            self.queues.insert(
+                stream_id,
+                StreamQueue {
+                    frames: VecDeque::default(),
+                    deficit: 0,
+                    priority,
+                },
+            );
+            self.active.push_back(stream_id);
+        }
+        let queue = self.queues.get_mut(&stream_id).expect("queue must exist");
+
+        if frame.ty() == Type::Data && frame.length() as usize > self.max_chunk_size {
+            // Only the first chunk carries the flags
+            let mut flags = Some(frame.flags());
+            let (_, body) = frame.into_parts();
+            let mut body = body.unwrap_or_else(Bytes::new);
+            while !body.is_empty() {
+                let chunk = body.split_to(::std::cmp::min(self.max_chunk_size, body.len()));
+                let flags = flags.take().unwrap_or_else(Flags::default);
+                queue
+                    .frames
+                    .push_back(Frame::new_data(flags, stream_id, chunk));
+                self.len += 1;
+            }
+        } else {
+            queue.frames.push_back(frame);
+            self.len += 1;
+        }
+    }
+
+    /// Put back the frame that failed to be sent
+    pub(crate) fn push_front(&mut self, frame: Frame) {
+        debug_assert!(self.retry.is_none());
+        self.len += 1;
+        self.retry = Some(frame);
+    }
+
+    pub(crate) fn pop_front(&mut self) -> Option<Frame> {
+        let frame = self.retry.take().or_else(|| self.control.pop_front());
+        if frame.is_some() {
+            self.len -= 1;
+            return frame;
+        }
+
+        loop {
+            let stream_id = *self.active.front()?;
+            let queue = self.queues.get_mut(&stream_id).expect("queue must exist");
+            let cost = queue.frames.front().map(Frame::size).unwrap_or(0);
+
+            if queue.deficit >= cost {
+                queue.deficit -= cost;
+                let frame = queue.frames.pop_front();
+                if queue.frames.is_empty() {
+                    self.queues.remove(&stream_id);
+                    self.active.pop_front();
+                }
+                self.len -= 1;
+                return frame;
+            }
+
+            // Go to next round
+            let priority = ::std::cmp::max(queue.priority.load(Ordering::Relaxed), 1) as usize;
+            queue.deficit += self.quantum * priority / DEFAULT_STREAM_PRIORITY as usize + 1;
+            self.active.rotate_left(1);
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.retry = None;
+        self.control.clear();
+        self.queues.clear();
+        self.active.clear();
+        self.len = 0;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrameScheduler;
+    use crate::{
+        config::DEFAULT_STREAM_PRIORITY,
+        frame::{Flag, Flags, Frame, GoAwayCode},
+    };
+
+    use bytes::Bytes;
+    use std::sync::{atomic::AtomicU8, Arc};
+
+    fn data(stream_id: u32, size: usize) -> Frame {
+        Frame::new_data(
+            Flags::from(Flag::Syn),
+            stream_id,
+            Bytes::from(vec![0; size]),
+        )
+    }
+
+    #[test]
+    fn test_split_large_frame() {
+        let mut scheduler = FrameScheduler::new(100);
+        scheduler.push_back(data(1, 250));
+        assert_eq!(scheduler.len(), 3);
+
+        let frames = (0..3)
+            .filter_map(|_| scheduler.pop_front())
+            .collect::<Vec<_>>();
+        assert!(scheduler.is_empty());
+        assert_eq!(
+            frames.iter().map(Frame::length).collect::<Vec<_>>(),
+            vec![100, 100, 50]
+        );
+        assert!(frames[0].flags().contains(Flag::Syn));
+        assert_eq!(frames[1].flags(), Flags::default());
+    }
+
+    #[test]
+    fn test_interleave_streams() {
+        let mut scheduler = FrameScheduler::new(100);
+        for _ in 0..4 {
+            scheduler.push_back(data(1, 100));
+        }
+        scheduler.push_back(data(3, 10));
+        scheduler.push_back(Frame::new_go_away(GoAwayCode::Normal));
+
+        // Control frame first
+        assert_eq!(scheduler.pop_front().unwrap().stream_id(), 0);
+        let order = (0..5)
+            .filter_map(|_| scheduler.pop_front())
+            .map(|frame| frame.stream_id())
+            .collect::<Vec<_>>();
+        // Small frame of stream 3 is not queued behind all frames of stream 1
+        assert_eq!(order, vec![1, 3, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_priority() {
+        let mut scheduler = FrameScheduler::new(100);
+        scheduler.register(1, Arc::new(AtomicU8::new(DEFAULT_STREAM_PRIORITY * 2)));
+        scheduler.register(3, Arc::new(AtomicU8::new(DEFAULT_STREAM_PRIORITY)));
+        for _ in 0..4 {
+            scheduler.push_back(data(1, 100));
+            scheduler.push_back(data(3, 100));
+        }
+
+        let order = (0..6)
+            .filter_map(|_| scheduler.pop_front())
+            .map(|frame| frame.stream_id())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec![1, 1, 3, 1, 1, 3]);
+    }
+}
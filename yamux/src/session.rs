@@ -10,6 +10,7 @@ use std::{
     time::{Duration, Instant},
 };
 
+use bytes::Bytes;
 use futures::{
     future::Future,
     sync::mpsc::{channel, Receiver, Sender},
@@ -21,13 +22,22 @@ use tokio::prelude::{AsyncRead, AsyncWrite};
 use tokio::timer::{Delay, Interval};
 
 use crate::{
-    config::Config,
+    budget::{MemoryBudget, RecvBudget},
+    capture::{Capture, Direction},
+    config::{Config, WindowMode},
     error::Error,
-    frame::{Flag, Flags, Frame, FrameCodec, GoAwayCode, Type},
+    frame::{Flag, Flags, Frame, FrameCodec, GoAwayCode, GoAwayReason, Type},
+    rate_limit::TokenBucket,
+    rtt::RttStats,
+    scheduler::FrameScheduler,
     stream::{StreamEvent, StreamHandle, StreamState},
-    StreamId,
+    window::AutoTuning,
+    StreamId, RESERVED_STREAM_ID,
 };
 
+/// Max length of the message sent along with go away
+pub const MAX_GO_AWAY_MESSAGE: usize = 1024;
+
 const BUF_SHRINK_THRESHOLD: usize = u8::max_value() as usize;
 const DELAY_TIME: Duration = Duration::from_millis(300);
 const TIMEOUT: Duration = Duration::from_secs(30);
@@ -48,6 +58,10 @@ pub struct Session<T> {
     // accepting futher connections. Must be first for alignment.
     local_go_away: bool,
 
+    // The message received before remote go away
+    go_away_message: Option<Bytes>,
+    remote_go_away_reason: Option<GoAwayReason>,
+
     // nextStreamID is the next stream we should
     // send. This depends if we are a client/server.
     next_stream_id: StreamId,
@@ -59,6 +73,14 @@ pub struct Session<T> {
     // pings is used to track inflight pings
     pings: BTreeMap<u32, Instant>,
     ping_id: u32,
+    // rtt measured by acked pings
+    rtt: RttStats,
+    // Shared with all streams when window auto-tuning is enabled
+    auto_tuning: Option<Arc<AutoTuning>>,
+    // Received data buffered by all streams
+    recv_budget: MemoryBudget,
+    // Shared with other sessions, such as a service-wide budget
+    shared_recv_budget: Option<MemoryBudget>,
 
     // streams maps a stream id to a sender of stream,
     streams: HashMap<StreamId, Sender<Frame>>,
@@ -66,8 +88,14 @@ pub struct Session<T> {
     inflight: HashSet<StreamId>,
     // The StreamHandle not yet been polled
     pending_streams: VecDeque<StreamHandle>,
-    // The buffer which will send to underlying network
-    write_pending_frames: VecDeque<Frame>,
+    /// Limit how fast remote can open streams
+    inbound_stream_rate: Option<TokenBucket>,
+    /// Count of streams opened by remote but reset by local limits
+    rejected_streams: u64,
+    /// Record frames for debugging
+    capture: Option<Capture>,
+    // The buffer which will send to underlying network, frames of streams are interleaved
+    write_pending_frames: FrameScheduler,
     // The buffer which will distribute to sub streams
     read_pending_frames: VecDeque<Frame>,
 
@@ -121,8 +149,18 @@ where
         let (event_sender, event_receiver) = channel(32);
         let framed_stream = Framed::new(
             raw_stream,
-            FrameCodec::default().max_frame_size(config.max_stream_window_size),
+            FrameCodec::default().max_frame_size(config.max_frame_size()),
         );
+        let auto_tuning = match config.window_mode {
+            WindowMode::Fixed => None,
+            WindowMode::AutoTuning {
+                max_window_size,
+                connection_budget,
+            } => Some(Arc::new(AutoTuning::new(
+                max_window_size,
+                connection_budget,
+            ))),
+        };
         let keepalive_receiver = if config.enable_keepalive {
             let (mut interval_sender, interval_receiver) = channel(2);
             // NOTE: Set a 300ms interval because we want shutdown service quick.
@@ -151,15 +189,24 @@ where
             eof: false,
             remote_go_away: false,
             local_go_away: false,
+            go_away_message: None,
+            remote_go_away_reason: None,
             next_stream_id,
             ty,
             config,
             pings: BTreeMap::default(),
             ping_id: 0,
+            rtt: RttStats::default(),
+            auto_tuning,
+            recv_budget: MemoryBudget::new(config.session_recv_budget),
+            shared_recv_budget: None,
             streams: HashMap::default(),
             inflight: HashSet::default(),
             pending_streams: VecDeque::default(),
-            write_pending_frames: VecDeque::default(),
+            inbound_stream_rate: config.inbound_stream_rate.map(TokenBucket::new),
+            rejected_streams: 0,
+            capture: None,
+            write_pending_frames: FrameScheduler::new(config.max_chunk_size),
             read_pending_frames: VecDeque::default(),
             event_sender,
             event_receiver,
@@ -181,6 +228,24 @@ where
         Self::new(raw_stream, config, SessionType::Client)
     }
 
+    /// Charge received data to a budget shared with other sessions as well,
+    /// must be set before any stream is opened
+    pub fn with_shared_recv_budget(mut self, budget: MemoryBudget) -> Self {
+        self.shared_recv_budget = Some(budget);
+        self
+    }
+
+    /// Record all frames sent and received by this session
+    pub fn with_capture(mut self, capture: Capture) -> Self {
+        self.capture = Some(capture);
+        self
+    }
+
+    /// Received data buffered by all streams of this session
+    pub fn recv_budget(&self) -> &MemoryBudget {
+        &self.recv_budget
+    }
+
     /// shutdown is used to close the session and all streams.
     /// Attempts to send a GoAway before closing the connection.
     pub fn shutdown(&mut self) -> Poll<(), io::Error> {
@@ -194,6 +259,38 @@ where
         Ok(Async::Ready(()))
     }
 
+    /// Same as `shutdown`, but the go away carries the code, and the message is sent before it.
+    ///
+    /// The message is truncated to `MAX_GO_AWAY_MESSAGE` bytes.
+    pub fn shutdown_with_reason(
+        &mut self,
+        code: u32,
+        message: Option<Bytes>,
+    ) -> Poll<(), io::Error> {
+        if self.is_dead() {
+            return Ok(Async::Ready(()));
+        }
+
+        self.write_pending_frames.clear();
+        if let Some(mut message) = message {
+            message.truncate(MAX_GO_AWAY_MESSAGE);
+            self.write_pending_frames.push_back(Frame::new_data(
+                Flags::default(),
+                RESERVED_STREAM_ID,
+                message,
+            ));
+        }
+        self.local_go_away = true;
+        let frame = Frame::new_go_away_with_code(code);
+        self.send_frame(frame)?;
+        Ok(Async::Ready(()))
+    }
+
+    /// The go away reason received from remote
+    pub fn remote_go_away_reason(&self) -> Option<&GoAwayReason> {
+        self.remote_go_away_reason.as_ref()
+    }
+
     // Send all pending frames to remote streams
     fn flush(&mut self) -> Result<(), io::Error> {
         self.recv_events()?;
@@ -226,6 +323,41 @@ where
         self.send_frame(frame)
     }
 
+    /// Round trip time measured by keepalive pings
+    pub fn rtt(&self) -> RttStats {
+        self.rtt
+    }
+
+    /// Count of streams opened by remote that were reset because of
+    /// `max_stream_count`, `accept_backlog`, `inbound_stream_rate` or the receive budget
+    pub fn rejected_streams(&self) -> u64 {
+        self.rejected_streams
+    }
+
+    // Whether a stream opened by remote goes beyond the limits
+    fn reject_inbound_stream(&mut self) -> bool {
+        if self.streams.len() >= self.config.max_stream_count
+            || self.pending_streams.len() >= self.config.accept_backlog
+        {
+            return true;
+        }
+        match self.inbound_stream_rate {
+            Some(ref mut bucket) => !bucket.try_acquire(Instant::now()),
+            None => false,
+        }
+    }
+
+    // Charge the initial receive window of a new stream to the budget, the remote may fill it
+    // as soon as the stream is open
+    fn reserve_recv_window(&self) -> Option<RecvBudget> {
+        let budget = RecvBudget::new(self.recv_budget.clone(), self.shared_recv_budget.clone());
+        if budget.try_acquire(self.config.max_stream_window_size as usize) {
+            Some(budget)
+        } else {
+            None
+        }
+    }
+
     /// Open a new stream to remote session
     pub fn open_stream(&mut self) -> Result<StreamHandle, Error> {
         if self.is_dead() {
@@ -233,7 +365,10 @@ where
         } else if self.remote_go_away {
             Err(Error::RemoteGoAway)
         } else {
-            let stream = self.create_stream(None)?;
+            let budget = self
+                .reserve_recv_window()
+                .ok_or(Error::RecvBudgetExhausted)?;
+            let stream = self.create_stream(None, budget)?;
             self.inflight.insert(stream.id());
             Ok(stream)
         }
@@ -256,18 +391,26 @@ where
         let ping_id = try_ready!(self.send_ping(None));
         debug!("[{:?}] sent keep_alive ping (id={:?})", self.ty, ping_id);
         self.pings.insert(ping_id, ping_at);
+        self.rtt.ping_sent(ping_at);
         Ok(Async::Ready(()))
     }
 
-    fn create_stream(&mut self, stream_id: Option<StreamId>) -> Result<StreamHandle, Error> {
+    fn create_stream(
+        &mut self,
+        stream_id: Option<StreamId>,
+        budget: RecvBudget,
+    ) -> Result<StreamHandle, Error> {
         let (stream_id, state) = match stream_id {
             Some(stream_id) => (stream_id, StreamState::SynReceived),
             None => {
                 let next_id = self.next_stream_id;
-                self.next_stream_id = self
-                    .next_stream_id
-                    .checked_add(2)
-                    .ok_or(Error::StreamsExhausted)?;
+                self.next_stream_id = match self.next_stream_id.checked_add(2) {
+                    Some(next_stream_id) => next_stream_id,
+                    None => {
+                        budget.release(self.config.max_stream_window_size as usize);
+                        return Err(Error::StreamsExhausted);
+                    }
+                };
                 (next_id, StreamState::Init)
             }
         };
@@ -280,7 +423,11 @@ where
             state,
             self.config.max_stream_window_size,
             self.config.max_stream_window_size,
+            self.auto_tuning.clone(),
+            budget,
         );
+        self.write_pending_frames
+            .register(stream_id, stream.priority_handle());
         if let Err(err) = stream.send_window_update() {
             debug!("[{:?}] stream.send_window_update error={:?}", self.ty, err);
         }
@@ -296,6 +443,10 @@ where
                 break;
             }
 
+            let record = self
+                .capture
+                .as_ref()
+                .map(|capture| capture.encode_frame(Direction::Outbound, &frame));
             match self.framed_stream.start_send(frame) {
                 Ok(AsyncSink::NotReady(frame)) => {
                     debug!("[{:?}] framed_stream NotReady, frame: {:?}", self.ty, frame);
@@ -312,6 +463,9 @@ where
                 }
                 Ok(AsyncSink::Ready) => {
                     self.last_send_success = Instant::now();
+                    if let (Some(capture), Some(record)) = (&self.capture, record) {
+                        capture.write(record);
+                    }
                 }
                 Err(err) => {
                     debug!("[{:?}] framed_stream error: {:?}", self.ty, err);
@@ -351,7 +505,13 @@ where
 
     fn handle_frame(&mut self, frame: Frame) -> Result<(), io::Error> {
         debug!("[{:?}] Session::handle_frame({:?})", self.ty, frame.ty());
+        if let Some(ref capture) = self.capture {
+            capture.write(capture.encode_frame(Direction::Inbound, &frame));
+        }
         match frame.ty() {
+            Type::Data if frame.stream_id() == RESERVED_STREAM_ID => {
+                self.handle_go_away_message(frame);
+            }
             Type::Data | Type::WindowUpdate => {
                 self.handle_stream_message(frame)?;
             }
@@ -388,9 +548,28 @@ where
                     // TODO: should report error?
                     return Ok(());
                 }
+                let budget = if self.reject_inbound_stream() {
+                    None
+                } else {
+                    self.reserve_recv_window()
+                };
+                let budget = match budget {
+                    Some(budget) => budget,
+                    None => {
+                        self.rejected_streams += 1;
+                        let flags = Flags::from(Flag::Rst);
+                        let frame = Frame::new_window_update(flags, stream_id, 0);
+                        self.send_frame(frame)?;
+                        debug!(
+                            "[{:?}] too many streams, send Reset to remote stream_id={}",
+                            self.ty, stream_id
+                        );
+                        continue;
+                    }
+                };
                 debug!("[{:?}] Accept a stream id={}", self.ty, stream_id);
                 let stream = self
-                    .create_stream(Some(stream_id))
+                    .create_stream(Some(stream_id), budget)
                     .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
                 self.pending_streams.push_back(stream);
             }
@@ -442,7 +621,13 @@ where
             // Send ping back
             self.send_ping(Some(frame.length()))?;
         } else if flags.contains(Flag::Ack) {
-            self.pings.remove(&frame.length());
+            if let Some(ping_at) = self.pings.remove(&frame.length()) {
+                self.rtt.update(ping_at.elapsed());
+                if let Some(ref tuning) = self.auto_tuning {
+                    tuning.set_rtt(self.rtt.smoothed_rtt().expect("rtt has been updated"));
+                }
+                debug!("[{:?}] keep_alive rtt: {:?}", self.ty, self.rtt);
+            }
             // If the remote peer does not follow the protocol,
             // there may be a memory leak, so here need to discard all ping ids below the ack.
             self.pings = self.pings.split_off(&frame.length());
@@ -452,7 +637,18 @@ where
         Ok(())
     }
 
+    fn handle_go_away_message(&mut self, frame: Frame) {
+        let (_, body) = frame.into_parts();
+        let mut message = body.unwrap_or_default();
+        message.truncate(MAX_GO_AWAY_MESSAGE);
+        self.go_away_message = Some(message);
+    }
+
     fn handle_go_away(&mut self, frame: &Frame) -> Result<(), io::Error> {
+        self.remote_go_away_reason = Some(GoAwayReason {
+            code: frame.length(),
+            message: self.go_away_message.take(),
+        });
         let mut close = || -> Result<(), io::Error> {
             self.remote_go_away = true;
             self.write_pending_frames.clear();
@@ -514,6 +710,7 @@ where
                 match state {
                     StreamState::Closed => {
                         self.streams.remove(&stream_id);
+                        self.write_pending_frames.unregister(stream_id);
                     }
                     StreamState::Established => {
                         self.inflight.remove(&stream_id);
@@ -650,3 +847,137 @@ where
         Ok(Async::NotReady)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Session;
+    use crate::{config::Config, error::Error};
+    use futures::{future, Async, Future, Poll, Stream};
+    use std::{
+        io::{self, Write},
+        net,
+        time::Duration,
+    };
+    use tokio::{net::TcpStream, reactor::Handle, runtime::Runtime, timer::Timeout};
+
+    // A connected pair of sessions over loopback
+    fn pair(
+        server_config: Config,
+        client_config: Config,
+    ) -> (Session<TcpStream>, Session<TcpStream>) {
+        let listener = net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = net::TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server, _) = listener.accept().unwrap();
+        let handle = Handle::default();
+        (
+            Session::new_server(TcpStream::from_std(server, &handle).unwrap(), server_config),
+            Session::new_client(TcpStream::from_std(client, &handle).unwrap(), client_config),
+        )
+    }
+
+    // A session budget affording the initial window of this many streams
+    fn budget_config(streams: usize) -> Config {
+        let mut config = Config::default();
+        config.session_recv_budget = config.max_stream_window_size as usize * streams;
+        config
+    }
+
+    #[test]
+    fn test_open_streams_beyond_recv_budget() {
+        let mut rt = Runtime::new().unwrap();
+        rt.block_on(future::lazy(|| {
+            let (_server, mut client) = pair(Config::default(), budget_config(4));
+            let streams = (0..4)
+                .map(|_| client.open_stream().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(client.open_stream().err(), Some(Error::RecvBudgetExhausted));
+            assert_eq!(client.recv_budget().used(), client.recv_budget().limit());
+
+            // Dropped streams give their window back
+            drop(streams);
+            assert_eq!(client.recv_budget().used(), 0);
+            assert!(client.open_stream().is_ok());
+            Ok::<_, ()>(())
+        }))
+        .unwrap();
+    }
+
+    #[test]
+    fn test_accept_streams_beyond_recv_budget() {
+        let mut rt = Runtime::new().unwrap();
+        let (mut server, mut client) = pair(budget_config(4), Config::default());
+        let streams = (0..10)
+            .map(|_| client.open_stream().unwrap())
+            .collect::<Vec<_>>();
+        rt.spawn(client.for_each(|_| Ok(())).map_err(|_| ()));
+
+        let mut accepted = Vec::new();
+        let task = future::poll_fn(move || {
+            while let Async::Ready(Some(stream)) = server.poll()? {
+                accepted.push(stream);
+            }
+            let rejected = server.rejected_streams() as usize;
+            if accepted.len() + rejected == 10 {
+                Ok(Async::Ready((accepted.len(), rejected)))
+            } else {
+                Ok(Async::NotReady)
+            }
+        });
+        let result = rt
+            .block_on(Timeout::new(task, Duration::from_secs(10)))
+            .unwrap();
+        // Streams beyond the budget are reset instead of getting a window
+        assert_eq!(result, (4, 6));
+        drop(streams);
+    }
+
+    #[test]
+    fn test_write_after_remote_half_close() {
+        let mut rt = Runtime::new().unwrap();
+        let (server, mut client) = pair(Config::default(), Config::default());
+        let mut stream = client.open_stream().unwrap();
+        stream.set_auto_close(false);
+        rt.spawn(client.for_each(|_| Ok(())).map_err(|_| ()));
+
+        // Finish sending right away, then receive well over the initial window
+        let server = server
+            .into_future()
+            .map_err(|(err, _)| err)
+            .and_then(|(stream, server)| {
+                tokio::spawn(server.for_each(|_| Ok(())).map_err(|_| ()));
+                tokio::io::shutdown(stream.unwrap())
+            })
+            .and_then(|stream| tokio::io::read_exact(stream, vec![0; 1024 * 1024]))
+            .map(|(_, data)| data);
+
+        let client = tokio::io::read_to_end(stream, Vec::new())
+            .and_then(|(stream, received)| {
+                // Remote finished sending, reads hit EOF
+                assert!(received.is_empty());
+                futures::stream::iter_ok::<_, io::Error>((0..64).map(|_| vec![1; 16 * 1024]))
+                    .fold(stream, |stream, chunk| {
+                        tokio::io::write_all(stream, chunk).map(|(stream, _)| stream)
+                    })
+            })
+            .and_then(|mut stream| {
+                // Keep handling window updates to send the data cached by the stream
+                future::poll_fn(move || -> Poll<(), io::Error> {
+                    match stream.flush() {
+                        Err(ref err) if err.kind() != io::ErrorKind::WouldBlock => {
+                            Err(err.kind().into())
+                        }
+                        _ => Ok(Async::NotReady),
+                    }
+                })
+            });
+
+        let task = server.select2(client).then(|result| match result {
+            Ok(future::Either::A((data, _))) => Ok(data),
+            _ => Err(()),
+        });
+        let data = rt
+            .block_on(Timeout::new(task, Duration::from_secs(10)))
+            .unwrap();
+        assert_eq!(data, vec![1; 1024 * 1024]);
+    }
+}
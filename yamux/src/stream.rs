@@ -11,7 +11,7 @@ use futures::{
     task, Async, Future, Poll, Stream,
 };
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -20,8 +20,11 @@ use tokio::prelude::{AsyncRead, AsyncWrite};
 use tokio::timer::Delay;
 
 use crate::{
+    budget::RecvBudget,
+    config::DEFAULT_STREAM_PRIORITY,
     error::Error,
     frame::{Flag, Flags, Frame, Type},
+    window::AutoTuning,
     StreamId,
 };
 
@@ -31,6 +34,7 @@ pub struct StreamHandle {
     id: StreamId,
     state: StreamState,
 
+    initial_recv_window: u32,
     max_recv_window: u32,
     recv_window: u32,
     send_window: u32,
@@ -46,10 +50,24 @@ pub struct StreamHandle {
     frame_receiver: Receiver<Frame>,
 
     delay: Arc<AtomicBool>,
+
+    // Shared with session when window auto-tuning is enabled
+    auto_tuning: Option<Arc<AutoTuning>>,
+    last_window_update: Option<Instant>,
+
+    // Buffered received data is charged to it
+    recv_budget: RecvBudget,
+    window_update_withheld: bool,
+
+    // Shared with session's frame scheduler
+    priority: Arc<AtomicU8>,
+
+    // Close local side once remote closed and all data is read
+    auto_close: bool,
 }
 
 impl StreamHandle {
-    // Create a StreamHandle from session
+    // Create a StreamHandle from session, the receive window is already charged to the budget
     pub(crate) fn new(
         id: StreamId,
         event_sender: Sender<StreamEvent>,
@@ -57,11 +75,14 @@ impl StreamHandle {
         state: StreamState,
         recv_window_size: u32,
         send_window_size: u32,
+        auto_tuning: Option<Arc<AutoTuning>>,
+        recv_budget: RecvBudget,
     ) -> StreamHandle {
         assert!(state == StreamState::Init || state == StreamState::SynReceived);
         StreamHandle {
             id,
             state,
+            initial_recv_window: recv_window_size,
             max_recv_window: recv_window_size,
             recv_window: recv_window_size,
             send_window: send_window_size,
@@ -71,6 +92,12 @@ impl StreamHandle {
             event_sender,
             frame_receiver,
             delay: Arc::new(AtomicBool::new(false)),
+            auto_tuning,
+            last_window_update: None,
+            recv_budget,
+            window_update_withheld: false,
+            priority: Arc::new(AtomicU8::new(DEFAULT_STREAM_PRIORITY)),
+            auto_close: true,
         }
     }
 
@@ -90,6 +117,42 @@ impl StreamHandle {
     pub fn send_window(&self) -> u32 {
         self.send_window
     }
+    /// Get the scheduling priority
+    pub fn priority(&self) -> u8 {
+        self.priority.load(Ordering::Relaxed)
+    }
+    /// Set the scheduling priority of outbound frames, default is `DEFAULT_STREAM_PRIORITY`.
+    ///
+    /// When streams compete, each gets bandwidth in proportion to its priority,
+    /// 0 is treated as 1.
+    pub fn set_priority(&self, priority: u8) {
+        self.priority.store(priority, Ordering::Relaxed);
+    }
+
+    /// Whether to close local side when remote closed and all data is read, default is true.
+    ///
+    /// Disable it to keep writing after remote finished sending (half-close),
+    /// local side is closed by `shutdown`.
+    pub fn set_auto_close(&mut self, enable: bool) {
+        self.auto_close = enable;
+    }
+
+    pub(crate) fn priority_handle(&self) -> Arc<AtomicU8> {
+        Arc::clone(&self.priority)
+    }
+
+    /// Reset the stream, remote gets a RST and no more data is read or written on both sides
+    pub fn reset(&mut self) -> Result<(), Error> {
+        match self.state {
+            StreamState::Reset | StreamState::Closed => return Ok(()),
+            _ => (),
+        }
+        let mut flags = self.get_flags();
+        flags.add(Flag::Rst);
+        self.send_frame(Frame::new_window_update(flags, self.id, 0))?;
+        self.state = StreamState::Reset;
+        self.close()
+    }
 
     fn close(&mut self) -> Result<(), Error> {
         match self.state {
@@ -155,6 +218,23 @@ impl StreamHandle {
         if delta < (self.max_recv_window / 2) && flags.value() == 0 {
             return Ok(());
         }
+        let delta = if self.recv_budget.is_exhausted() {
+            // Withhold the update until buffered data has been consumed
+            self.window_update_withheld = true;
+            if flags.value() == 0 {
+                debug!(
+                    "[{}] receive budget exhausted, withhold window update",
+                    self.id
+                );
+                return Ok(());
+            }
+            0
+        } else {
+            self.window_update_withheld = false;
+            let delta = delta + self.tune_recv_window();
+            self.recv_budget.acquire(delta as usize);
+            delta
+        };
         // Update our window
         self.recv_window += delta;
         let frame = Frame::new_window_update(flags, self.id, delta);
@@ -168,6 +248,32 @@ impl StreamHandle {
         Ok(())
     }
 
+    // A window update is sent every time half of the window has been drained,
+    // if that happens within one rtt, the window is smaller than the bandwidth-delay product
+    // and the sender is blocked by it, so grow the window as the budget allows.
+    fn tune_recv_window(&mut self) -> u32 {
+        let now = Instant::now();
+        let last_window_update = self.last_window_update.replace(now);
+        let tuning = match self.auto_tuning {
+            Some(ref tuning) => tuning,
+            None => return 0,
+        };
+        match (last_window_update, tuning.rtt()) {
+            (Some(last), Some(rtt)) if now - last < rtt => {
+                let growth = tuning.reserve(self.max_recv_window);
+                if growth > 0 {
+                    self.max_recv_window += growth;
+                    debug!(
+                        "[{}] receive window grows to {}",
+                        self.id, self.max_recv_window
+                    );
+                }
+                growth
+            }
+            _ => 0,
+        }
+    }
+
     fn send_data(&mut self, data: &[u8]) -> Result<(), Error> {
         let flags = self.get_flags();
         let frame = Frame::new_data(flags, self.id, Bytes::from(data));
@@ -268,6 +374,7 @@ impl StreamHandle {
 
         let (_, body) = frame.into_parts();
         if let Some(data) = body {
+            // Charged to the budget as part of the window
             self.read_buf.extend_from_slice(&data);
         }
         self.recv_window -= length;
@@ -276,10 +383,8 @@ impl StreamHandle {
 
     fn recv_frames(&mut self) -> Poll<(), Error> {
         for _ in 0..64 {
+            // Remote closing still sends window updates and RST for our sending half
             match self.state {
-                StreamState::RemoteClosing => {
-                    return Err(Error::SubStreamRemoteClosing);
-                }
                 StreamState::Reset | StreamState::Closed => {
                     return Err(Error::SessionShutdown);
                 }
@@ -327,31 +432,37 @@ impl StreamHandle {
         }
     }
 
-    fn check_self_state(&mut self) -> Result<(), io::Error> {
-        // if read buf is empty and state is close, return close error
+    // Whether all data has been read and remote closed, a reset is an error
+    fn check_self_state(&mut self) -> Result<bool, io::Error> {
         if self.read_buf.is_empty() {
             match self.state {
+                StreamState::RemoteClosing if !self.auto_close => {
+                    debug!("remote closed(EOF)");
+                    Ok(true)
+                }
                 StreamState::RemoteClosing | StreamState::Closed => {
                     debug!("closed(EOF)");
                     self.shutdown()?;
-                    Err(io::ErrorKind::UnexpectedEof.into())
+                    Ok(true)
                 }
                 StreamState::Reset => {
                     debug!("connection reset");
                     self.shutdown()?;
                     Err(io::ErrorKind::ConnectionReset.into())
                 }
-                _ => Ok(()),
+                _ => Ok(false),
             }
         } else {
-            Ok(())
+            Ok(false)
         }
     }
 }
 
 impl io::Read for StreamHandle {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        self.check_self_state()?;
+        if self.check_self_state()? {
+            return Ok(0);
+        }
 
         let rv = self.recv_frames();
         debug!(
@@ -359,7 +470,9 @@ impl io::Read for StreamHandle {
             self.id, rv, self.state
         );
 
-        self.check_self_state()?;
+        if self.check_self_state()? {
+            return Ok(0);
+        }
 
         debug!(
             "send window size: {}, receive window size: {}, send buf: {}, read buf: {}",
@@ -371,9 +484,19 @@ impl io::Read for StreamHandle {
 
         let n = ::std::cmp::min(buf.len(), self.read_buf.len());
         if n == 0 {
+            if self.window_update_withheld {
+                // Budget may be released by other streams without waking us up, retry later
+                if self.send_window_update().is_err() {
+                    return Err(io::ErrorKind::BrokenPipe.into());
+                }
+                if self.window_update_withheld {
+                    self.set_delay();
+                }
+            }
             return Err(io::ErrorKind::WouldBlock.into());
         }
         let b = self.read_buf.split_to(n);
+        self.recv_budget.release(n);
         debug!(
             "[{}] StreamHandle.read({}), buf.len()={}, read_buf.len()={}",
             self.id,
@@ -405,7 +528,6 @@ impl io::Write for StreamHandle {
                 Error::UnexpectedFlag | Error::RecvWindowExceeded | Error::InvalidMsgType => {
                     return Err(io::ErrorKind::InvalidData.into());
                 }
-                Error::SubStreamRemoteClosing => (),
                 Error::WouldBlock => return Err(io::ErrorKind::WouldBlock.into()),
                 _ => (),
             }
@@ -453,7 +575,6 @@ impl io::Write for StreamHandle {
                 Error::UnexpectedFlag | Error::RecvWindowExceeded | Error::InvalidMsgType => {
                     return Err(io::ErrorKind::InvalidData.into());
                 }
-                Error::SubStreamRemoteClosing => (),
                 Error::WouldBlock => return Err(io::ErrorKind::WouldBlock.into()),
                 _ => (),
             }
@@ -469,6 +590,16 @@ impl io::Write for StreamHandle {
 
 impl AsyncRead for StreamHandle {}
 
+impl Drop for StreamHandle {
+    fn drop(&mut self) {
+        self.recv_budget
+            .release(self.read_buf.len() + self.recv_window as usize);
+        if let Some(ref tuning) = self.auto_tuning {
+            tuning.release(self.max_recv_window - self.initial_recv_window);
+        }
+    }
+}
+
 impl AsyncWrite for StreamHandle {
     fn shutdown(&mut self) -> Poll<(), io::Error> {
         debug!("[{}] StreamHandle.shutdown()", self.id);
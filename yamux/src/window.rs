@@ -0,0 +1,103 @@
+//! Receive window auto-tuning, shared by a session and all its streams
+
+use std::{
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    time::Duration,
+};
+
+#[derive(Debug)]
+pub(crate) struct AutoTuning {
+    max_window_size: u32,
+    budget: usize,
+    // Window growth granted to all streams of the session
+    used: AtomicUsize,
+    // Smoothed rtt in microseconds, 0 means no sample yet
+    rtt: AtomicU64,
+}
+
+impl AutoTuning {
+    pub(crate) fn new(max_window_size: u32, budget: usize) -> Self {
+        AutoTuning {
+            max_window_size,
+            budget,
+            used: AtomicUsize::new(0),
+            rtt: AtomicU64::new(0),
+        }
+    }
+
+    pub(crate) fn set_rtt(&self, rtt: Duration) {
+        let micros = rtt.as_secs() * 1_000_000 + u64::from(rtt.subsec_micros());
+        self.rtt
+            .store(::std::cmp::max(micros, 1), Ordering::Relaxed);
+    }
+
+    pub(crate) fn rtt(&self) -> Option<Duration> {
+        match self.rtt.load(Ordering::Relaxed) {
+            0 => None,
+            micros => Some(Duration::from_micros(micros)),
+        }
+    }
+
+    /// Try to double the window, return the growth that the budget allows
+    pub(crate) fn reserve(&self, window: u32) -> u32 {
+        let target = ::std::cmp::min(window.saturating_mul(2), self.max_window_size);
+        if target <= window {
+            return 0;
+        }
+        let wanted = (target - window) as usize;
+
+        let mut used = self.used.load(Ordering::Acquire);
+        loop {
+            let growth = ::std::cmp::min(wanted, self.budget.saturating_sub(used));
+            if growth == 0 {
+                return 0;
+            }
+            match self.used.compare_exchange(
+                used,
+                used + growth,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return growth as u32,
+                Err(current) => used = current,
+            }
+        }
+    }
+
+    /// Give back the growth of a closed stream
+    pub(crate) fn release(&self, growth: u32) {
+        self.used.fetch_sub(growth as usize, Ordering::AcqRel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoTuning;
+    use std::time::Duration;
+
+    #[test]
+    fn test_reserve_within_budget() {
+        let tuning = AutoTuning::new(1024, 1536);
+
+        assert_eq!(tuning.reserve(256), 256);
+        assert_eq!(tuning.reserve(512), 512);
+        // Capped by max window size
+        assert_eq!(tuning.reserve(768), 256);
+        assert_eq!(tuning.reserve(1024), 0);
+        // Capped by budget
+        assert_eq!(tuning.reserve(256), 256);
+        assert_eq!(tuning.reserve(256), 256);
+        assert_eq!(tuning.reserve(256), 0);
+
+        tuning.release(512);
+        assert_eq!(tuning.reserve(256), 256);
+    }
+
+    #[test]
+    fn test_rtt() {
+        let tuning = AutoTuning::new(1024, 1024);
+        assert_eq!(tuning.rtt(), None);
+        tuning.set_rtt(Duration::from_millis(150));
+        assert_eq!(tuning.rtt(), Some(Duration::from_millis(150)));
+    }
+}